@@ -12,7 +12,7 @@ use validator::Validate;
 use crate::captcha::VerifiedCaptcha;
 use crate::entity_managers::admin_users::AdminUserManager;
 use crate::entity_managers::email_verifications::EmailVerificationManager;
-use crate::mail::client::PalformMailClient;
+use crate::i18n::request::I18NManager;
 use crate::rocket_util::validated::Validated;
 
 #[derive(Debug, Deserialize, JsonSchema, Validate)]
@@ -29,7 +29,7 @@ pub struct CreateUserRequest {
 pub async fn handler(
     request: Validated<Json<CreateUserRequest>>,
     db: &State<DatabaseConnection>,
-    mail: &State<PalformMailClient>,
+    i18n: I18NManager,
     _captcha: VerifiedCaptcha,
 ) -> Result<(), APIErrorWithStatus> {
     let txn = db
@@ -58,10 +58,10 @@ pub async fn handler(
         new_user_id,
         Some(request.email.clone()),
         AdminUserEmailVerificationPurposeEnum::NewEmail,
-        mail,
+        i18n.get_locale(),
     )
     .await
-    .map_err(|e| APIError::report_internal_error("send verification email", e))?;
+    .map_err(|e| APIError::report_internal_error("queue verification email", e))?;
 
     txn.commit().await.map_internal_error()?;
     Ok(())
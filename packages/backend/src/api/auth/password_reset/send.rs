@@ -10,7 +10,7 @@ use crate::{
     entity_managers::{
         admin_users::AdminUserManager, email_verifications::EmailVerificationManager,
     },
-    mail::client::PalformMailClient,
+    i18n::request::I18NManager,
 };
 
 #[derive(Deserialize, JsonSchema)]
@@ -23,7 +23,7 @@ pub struct SendPasswordResetRequest {
 pub async fn handler(
     data: Json<SendPasswordResetRequest>,
     db: &State<DatabaseConnection>,
-    mail: &State<PalformMailClient>,
+    i18n: I18NManager,
 ) -> Result<(), APIErrorWithStatus> {
     let txn = db
         .begin_with_config(
@@ -43,7 +43,7 @@ pub async fn handler(
             user.id,
             Some(user.email),
             AdminUserEmailVerificationPurposeEnum::PasswordReset,
-            mail,
+            i18n.get_locale(),
         )
         .await
         .map_err(|e| APIError::report_internal_error("send password reset", e))?;
@@ -12,7 +12,7 @@ use crate::{
     entity_managers::{
         admin_users::AdminUserManager, email_verifications::EmailVerificationManager,
     },
-    mail::client::PalformMailClient,
+    i18n::request::I18NManager,
 };
 
 #[derive(Deserialize, JsonSchema)]
@@ -25,7 +25,7 @@ pub struct ResendVerificationRequest {
 pub async fn handler(
     request: Json<ResendVerificationRequest>,
     db: &State<DatabaseConnection>,
-    mail: &State<PalformMailClient>,
+    i18n: I18NManager,
 ) -> Result<(), APIErrorWithStatus> {
     let txn = db
         .begin_with_config(
@@ -49,7 +49,7 @@ pub async fn handler(
             user.id,
             Some(request.email.to_owned()),
             AdminUserEmailVerificationPurposeEnum::NewEmail,
-            mail,
+            i18n.get_locale(),
         )
         .await
         .map_err(|e| APIError::report_internal_error("resend email verification", e))?;
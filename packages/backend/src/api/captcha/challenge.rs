@@ -0,0 +1,27 @@
+use palform_client_common::errors::error::{APIError, APIErrorWithStatus};
+use rocket::{post, serde::json::Json, State};
+use rocket_okapi::okapi::schemars;
+use rocket_okapi::okapi::schemars::JsonSchema;
+use rocket_okapi::openapi;
+use serde::Serialize;
+
+use crate::captcha::verifier::PalformCaptcha;
+
+#[derive(Serialize, JsonSchema)]
+pub struct APICaptchaChallenge {
+    /// Solve this with `solve_proof_of_work_js` and send the result in the `X-Captcha-Response`
+    /// header
+    challenge: String,
+}
+
+/// Get a new proof-of-work challenge, if the server is set up to use the built-in captcha
+#[openapi(tag = "Captcha", operation_id = "captcha.challenge")]
+#[post("/captcha/challenge")]
+pub fn handler(
+    captcha: &State<PalformCaptcha>,
+) -> Result<Json<APICaptchaChallenge>, APIErrorWithStatus> {
+    let challenge = captcha.issue_challenge().ok_or(APIError::BadRequest(
+        "The configured captcha provider doesn't issue challenges".to_string(),
+    ))?;
+    Ok(Json(APICaptchaChallenge { challenge }))
+}
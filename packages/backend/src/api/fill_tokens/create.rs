@@ -15,6 +15,7 @@ use serde::Deserialize;
 use crate::api::error::{APIError, APIInternalError};
 use crate::api_entities::billing::entitlement::APIEntitlementRequest;
 use crate::api_entities::fill_token::APIFillToken;
+use crate::api_entities::webhook::{APIFillTokenWebhookPayload, APIWebhookPayload};
 use crate::audit::AuditManager;
 use crate::auth::fill_access::FillAccessTokenManager;
 use crate::auth::rbac::requests::APITokenTeamEditorFromForm;
@@ -22,6 +23,7 @@ use crate::auth::tokens::APIAuthTokenSource;
 use crate::entity_managers::billing_entitlement_proxy::BillingEntitlementManager;
 use crate::entity_managers::forms::FormManager;
 use crate::entity_managers::orgs::OrganisationManager;
+use crate::entity_managers::webhook_jobs::WebhookJobsManager;
 use crate::rocket_util::from_org_id::FromOrgId;
 
 #[derive(Deserialize, JsonSchema)]
@@ -94,6 +96,19 @@ pub async fn handler(
     .await
     .map_err(|e| e.to_internal_error())?;
 
+    WebhookJobsManager::new(&txn)
+        .create_for_event(
+            form_id,
+            APIWebhookPayload::FillTokenCreated(APIFillTokenWebhookPayload {
+                fill_token_id: new_token.id,
+                form_id,
+                nickname: data.nickname.clone(),
+                expires_at: expires_at.map(|e| e.and_utc()),
+            }),
+        )
+        .await
+        .map_internal_error()?;
+
     audit
         .log_event_with_note(
             &txn,
@@ -0,0 +1,50 @@
+use palform_client_common::{
+    errors::error::{APIError, APIErrorWithStatus, APIInternalErrorResult},
+    form_management::revision::APIFormRevisionDiff,
+};
+use palform_tsid::{
+    resources::{IDForm, IDFormRevision, IDOrganisation},
+    tsid::PalformDatabaseID,
+};
+use rocket::{get, serde::json::Json, State};
+use rocket_okapi::openapi;
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    auth::rbac::requests::APITokenTeamViewerFromForm,
+    entity_managers::{form_revisions::FormRevisionManager, forms::FormManager},
+};
+
+/// Compares two revisions of a form. Either revision can be the older one.
+#[openapi(tag = "Form Revisions", operation_id = "form_revisions.diff")]
+#[get("/users/me/orgs/<org_id>/forms/<form_id>/revisions/<from_id>/diff/<to_id>")]
+pub async fn handler(
+    org_id: PalformDatabaseID<IDOrganisation>,
+    form_id: PalformDatabaseID<IDForm>,
+    from_id: PalformDatabaseID<IDFormRevision>,
+    to_id: PalformDatabaseID<IDFormRevision>,
+    _token: APITokenTeamViewerFromForm,
+    db: &State<DatabaseConnection>,
+) -> Result<Json<APIFormRevisionDiff>, APIErrorWithStatus> {
+    if !FormManager::verify_form_org(db.inner(), form_id, org_id)
+        .await
+        .map_internal_error()?
+    {
+        return Err(APIError::NotFound.into());
+    }
+
+    let mut revisions = Vec::new();
+    for revision_id in [from_id, to_id] {
+        revisions.push(
+            FormRevisionManager::get(db.inner(), form_id, revision_id)
+                .await
+                .map_err(|e| APIError::report_internal_error("get form revision", e))?
+                .ok_or(APIError::NotFound)?,
+        );
+    }
+
+    Ok(Json(APIFormRevisionDiff::between(
+        &revisions[0],
+        &revisions[1],
+    )))
+}
@@ -0,0 +1,40 @@
+use palform_client_common::{
+    errors::error::{APIError, APIErrorWithStatus, APIInternalErrorResult},
+    form_management::revision::APIFormRevision,
+};
+use palform_tsid::{
+    resources::{IDForm, IDFormRevision, IDOrganisation},
+    tsid::PalformDatabaseID,
+};
+use rocket::{get, serde::json::Json, State};
+use rocket_okapi::openapi;
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    auth::rbac::requests::APITokenTeamViewerFromForm,
+    entity_managers::{form_revisions::FormRevisionManager, forms::FormManager},
+};
+
+#[openapi(tag = "Form Revisions", operation_id = "form_revisions.get")]
+#[get("/users/me/orgs/<org_id>/forms/<form_id>/revisions/<revision_id>")]
+pub async fn handler(
+    org_id: PalformDatabaseID<IDOrganisation>,
+    form_id: PalformDatabaseID<IDForm>,
+    revision_id: PalformDatabaseID<IDFormRevision>,
+    _token: APITokenTeamViewerFromForm,
+    db: &State<DatabaseConnection>,
+) -> Result<Json<APIFormRevision>, APIErrorWithStatus> {
+    if !FormManager::verify_form_org(db.inner(), form_id, org_id)
+        .await
+        .map_internal_error()?
+    {
+        return Err(APIError::NotFound.into());
+    }
+
+    let revision = FormRevisionManager::get(db.inner(), form_id, revision_id)
+        .await
+        .map_err(|e| APIError::report_internal_error("get form revision", e))?
+        .ok_or(APIError::NotFound)?;
+
+    Ok(Json(revision))
+}
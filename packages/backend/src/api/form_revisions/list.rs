@@ -0,0 +1,37 @@
+use palform_client_common::errors::error::{APIError, APIErrorWithStatus, APIInternalErrorResult};
+use palform_tsid::{
+    resources::{IDForm, IDOrganisation},
+    tsid::PalformDatabaseID,
+};
+use rocket::{get, serde::json::Json, State};
+use rocket_okapi::openapi;
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    api_entities::form_revision::APIFormRevisionSummary,
+    auth::rbac::requests::APITokenTeamViewerFromForm,
+    entity_managers::{form_revisions::FormRevisionManager, forms::FormManager},
+};
+
+/// Lists every saved revision of the form's questions, newest first
+#[openapi(tag = "Form Revisions", operation_id = "form_revisions.list")]
+#[get("/users/me/orgs/<org_id>/forms/<form_id>/revisions")]
+pub async fn handler(
+    org_id: PalformDatabaseID<IDOrganisation>,
+    form_id: PalformDatabaseID<IDForm>,
+    _token: APITokenTeamViewerFromForm,
+    db: &State<DatabaseConnection>,
+) -> Result<Json<Vec<APIFormRevisionSummary>>, APIErrorWithStatus> {
+    if !FormManager::verify_form_org(db.inner(), form_id, org_id)
+        .await
+        .map_internal_error()?
+    {
+        return Err(APIError::NotFound.into());
+    }
+
+    let revisions = FormRevisionManager::list_for_form(db.inner(), form_id)
+        .await
+        .map_internal_error()?;
+
+    Ok(Json(revisions))
+}
@@ -0,0 +1,3 @@
+pub mod diff;
+pub mod get;
+pub mod list;
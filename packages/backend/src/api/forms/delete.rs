@@ -1,3 +1,4 @@
+use log::error;
 use palform_client_common::errors::error::APIInternalErrorResult;
 use palform_entities::sea_orm_active_enums::{AuditLogTargetResourceEnum, AuditLogVerbEnum};
 use palform_tsid::{resources::{IDForm, IDOrganisation}, tsid::PalformDatabaseID};
@@ -10,7 +11,8 @@ use crate::{
     audit::AuditManager,
     auth::rbac::requests::APITokenTeamEditorFromForm,
     auth::tokens::APIAuthTokenSource,
-    entity_managers::forms::FormManager,
+    entity_managers::{forms::FormManager, submission_files::SubmissionFilesManager},
+    storage::{buckets::SubmissionAssetsBucket, client::PalformStorage},
     rocket_util::from_org_id::FromOrgId,
 };
 
@@ -21,6 +23,7 @@ pub async fn handler(
     form_id: PalformDatabaseID<IDForm>,
     token: APITokenTeamEditorFromForm,
     db: &State<DatabaseConnection>,
+    storage: &State<PalformStorage<SubmissionAssetsBucket>>,
     audit: FromOrgId<AuditManager>,
 ) -> Result<(), (Status, Json<APIError>)> {
     let txn = db
@@ -53,5 +56,11 @@ pub async fn handler(
         .await
         .map_internal_error()?;
     txn.commit().await.map_err(|e| e.to_internal_error())?;
+
+    // The form is already gone at this point, so don't fail the request. Anything left over is
+    // picked up by the delete-orphaned-submission-files job.
+    if let Err(e) = SubmissionFilesManager::delete_all_for_form(db.inner(), storage, form_id).await {
+        error!("Failed to delete submission files for form {}: {}", form_id, e);
+    }
     Ok(())
 }
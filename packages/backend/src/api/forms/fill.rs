@@ -1,21 +1,27 @@
-use palform_client_common::errors::error::APIInternalErrorResult;
+use palform_client_common::{
+    errors::error::APIInternalErrorResult,
+    form_management::server_validation::{validate_server_answers, FormFillRequest},
+};
 use palform_tsid::{
     resources::{IDForm, IDOrganisation},
     tsid::PalformDatabaseID,
 };
-use rocket::{http::Status, post, serde::json::Json, State};
+use rocket::{data::Capped, http::Status, post, serde::json::Json, State};
 use rocket_okapi::openapi;
-use sea_orm::DatabaseConnection;
+use sea_orm::{DatabaseConnection, TransactionTrait};
 
 use crate::{
     api::error::APIError,
     auth::fill_access::APIFillAccessToken,
     captcha::VerifiedCaptcha,
-    crypto::submissions::CryptoSubmissionRepr,
-    entity_managers::{forms::FormManager, submission::SubmissionManager},
+    crypto::submissions::{parse_signing_key, CryptoSubmissionRepr},
+    entity_managers::{
+        form_revisions::FormRevisionManager, forms::FormManager, questions::QuestionManager,
+        submission::SubmissionManager, submission_files::SubmissionFilesManager,
+    },
     i18n::request::I18NManager,
-    mail::client::PalformMailClient,
     pt,
+    rate_limit::guard::FillRateLimit,
 };
 
 #[openapi(tag = "Forms", operation_id = "forms.fill")]
@@ -24,11 +30,11 @@ use crate::{
 pub async fn handler(
     org_id: PalformDatabaseID<IDOrganisation>,
     form_id: PalformDatabaseID<IDForm>,
+    _rate_limit: FillRateLimit,
     fill_access_token: APIFillAccessToken,
-    data: String,
+    data: Capped<String>,
     captcha: Option<VerifiedCaptcha>,
     db: &State<DatabaseConnection>,
-    mail_client: &State<PalformMailClient>,
     i18n: I18NManager,
 ) -> Result<(), (Status, Json<APIError>)> {
     if captcha.is_none()
@@ -39,31 +45,111 @@ pub async fn handler(
         return Err(APIError::BadRequest(pt!(i18n, "fill_missing_captcha",)).into());
     }
 
-    let data_repr = CryptoSubmissionRepr::from_pem_string(data.clone())
+    if !data.is_complete() {
+        return Err(APIError::PayloadTooLarge(pt!(i18n, "fill_too_large",)).into());
+    }
+    let request = FormFillRequest::parse(data.into_inner())
+        .map_err(|e| APIError::BadRequest(e.to_string()))?;
+
+    let revision = match request.revision_id {
+        Some(revision_id) => Some(
+            FormRevisionManager::get_published(db.inner(), form_id, revision_id)
+                .await
+                .map_err(|e| APIError::report_internal_error("get form revision", e))?
+                .ok_or(APIError::BadRequest(pt!(i18n, "fill_unknown_revision",)))?,
+        ),
+        None => match FormManager::get_published_revision_id(db.inner(), form_id)
+            .await
+            .map_internal_error()?
+        {
+            Some(revision_id) => FormRevisionManager::get(db.inner(), form_id, revision_id)
+                .await
+                .map_err(|e| APIError::report_internal_error("get published form revision", e))?,
+            None => None,
+        },
+    };
+
+    // Validate against the questions the respondent was shown, which might not be the current
+    // ones if the form was edited while they were filling it in
+    let questions = match &revision {
+        Some(revision) => revision.questions.clone(),
+        None => QuestionManager::get_all_for_form(db.inner(), form_id)
+            .await
+            .map_err(|e| APIError::report_internal_error("get questions for validation", e))?,
+    };
+    let validation_errors = validate_server_answers(
+        &questions,
+        &request.server_validation,
+        request.visited_group_ids.as_deref(),
+    )
+    .map_err(|e| APIError::BadRequest(e.to_string()))?;
+    if !validation_errors.is_empty() {
+        let details: Vec<String> = validation_errors
+            .iter()
+            .map(|e| format!("{}: {}", e.question_id, e.error))
+            .collect();
+        return Err(APIError::ValidationError(format!(
+            "{} ({})",
+            pt!(i18n, "fill_invalid_answers",),
+            details.join("; ")
+        ))
+        .into());
+    }
+
+    let signing_key = match request.signing_key {
+        Some(signing_key) => {
+            Some(parse_signing_key(&signing_key).map_err(|e| APIError::BadRequest(e.to_string()))?)
+        }
+        None => {
+            if FormManager::get_signed_submissions_required(db.inner(), form_id)
+                .await
+                .map_internal_error()?
+            {
+                return Err(APIError::BadRequest(pt!(i18n, "fill_missing_signature",)).into());
+            }
+            None
+        }
+    };
+
+    let data_repr = CryptoSubmissionRepr::from_pem_string(request.encrypted_submission)
         .map_err(|e| APIError::BadRequest(e.to_string()))?;
 
     let data_bytes = data_repr
         .to_database_bytes()
         .map_err(|e| APIError::report_internal_error("Serialize message to bytes", e))?;
+    let data_hash = CryptoSubmissionRepr::chain_data_hash(&data_bytes)
+        .map_err(|e| APIError::report_internal_error("Hash message for chain", e))?;
 
+    // The webhook jobs and notification emails are queued in the same transaction, so they're
+    // only sent if the submission is actually saved
+    let txn = db.begin().await.map_internal_error()?;
     let submission_id = SubmissionManager::create_submission(
-        db.inner(),
+        &txn,
         form_id,
         fill_access_token.token_id,
+        revision.map(|r| r.id),
         data_bytes,
+        data_hash,
+        signing_key,
     )
     .await
     .map_internal_error()?;
 
-    SubmissionManager::run_submission_notification(
-        db.inner(),
-        org_id,
-        form_id,
-        submission_id,
-        mail_client,
-    )
-    .await
-    .map_err(|e| APIError::report_internal_error("send submission notifications", e))?;
+    match &request.file_ids {
+        Some(file_ids) => SubmissionFilesManager::claim(&txn, form_id, submission_id, file_ids)
+            .await
+            .map_internal_error()?,
+        // Any of the form's unclaimed uploads could belong to this submission, so none of them
+        // can be cleaned up any more
+        None => FormManager::mark_submissions_without_file_ids(&txn, form_id)
+            .await
+            .map_internal_error()?,
+    }
+
+    SubmissionManager::run_submission_notification(&txn, org_id, form_id, submission_id)
+        .await
+        .map_err(|e| APIError::report_internal_error("queue submission notifications", e))?;
 
+    txn.commit().await.map_internal_error()?;
     Ok(())
 }
@@ -5,7 +5,9 @@ pub mod fill;
 pub mod get;
 pub mod keys;
 pub mod list;
+pub mod publish;
 pub mod relocate;
 pub mod update;
 pub mod view;
 pub mod set_auto_delete;
+pub mod set_schedule;
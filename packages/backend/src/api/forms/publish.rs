@@ -0,0 +1,70 @@
+use palform_client_common::errors::error::{APIError, APIErrorWithStatus, APIInternalErrorResult};
+use palform_entities::sea_orm_active_enums::{AuditLogTargetResourceEnum, AuditLogVerbEnum};
+use palform_tsid::{
+    resources::{IDForm, IDOrganisation},
+    tsid::PalformDatabaseID,
+};
+use rocket::{post, serde::json::Json, State};
+use rocket_okapi::openapi;
+use sea_orm::{AccessMode, DatabaseConnection, IsolationLevel, TransactionTrait};
+
+use crate::{
+    api_entities::form::APIForm,
+    audit::AuditManager,
+    auth::{rbac::requests::APITokenTeamEditorFromForm, tokens::APIAuthTokenSource},
+    entity_managers::forms::FormManager,
+    rocket_util::from_org_id::FromOrgId,
+};
+
+/// Makes the form's latest saved questions live for respondents
+#[openapi(tag = "Forms", operation_id = "forms.publish")]
+#[post("/users/me/orgs/<org_id>/forms/<form_id>/publish")]
+pub async fn handler(
+    org_id: PalformDatabaseID<IDOrganisation>,
+    form_id: PalformDatabaseID<IDForm>,
+    token: APITokenTeamEditorFromForm,
+    db: &State<DatabaseConnection>,
+    audit: FromOrgId<AuditManager>,
+) -> Result<Json<APIForm>, APIErrorWithStatus> {
+    let txn = db
+        .begin_with_config(
+            Some(IsolationLevel::RepeatableRead),
+            Some(AccessMode::ReadWrite),
+        )
+        .await
+        .map_internal_error()?;
+
+    if !FormManager::verify_form_org(&txn, form_id, org_id)
+        .await
+        .map_internal_error()?
+    {
+        return Err(APIError::NotFound.into());
+    }
+
+    let revision_id = FormManager::publish(&txn, form_id)
+        .await
+        .map_err(|e| APIError::report_internal_error("publish form", e))?;
+    FormManager::notify_updated(&txn, form_id)
+        .await
+        .map_internal_error()?;
+
+    audit
+        .log_event_with_note(
+            &txn,
+            token.get_user_id(),
+            AuditLogVerbEnum::Publish,
+            AuditLogTargetResourceEnum::Form,
+            Some(form_id.into_unknown()),
+            Some(format!("Published revision {}", revision_id)),
+        )
+        .await
+        .map_internal_error()?;
+
+    let form = FormManager::get_by_id(&txn, form_id)
+        .await
+        .map_internal_error()?
+        .ok_or(APIError::NotFound)?;
+
+    txn.commit().await.map_internal_error()?;
+    Ok(Json(form))
+}
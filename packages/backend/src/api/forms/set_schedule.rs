@@ -0,0 +1,81 @@
+use chrono::NaiveDateTime;
+use palform_client_common::errors::error::{APIError, APIErrorWithStatus, APIInternalErrorResult};
+use palform_entities::sea_orm_active_enums::{AuditLogTargetResourceEnum, AuditLogVerbEnum};
+use palform_tsid::{
+    resources::{IDForm, IDOrganisation},
+    tsid::PalformDatabaseID,
+};
+use rocket::{put, serde::json::Json, State};
+use rocket_okapi::{
+    okapi::schemars::{self, JsonSchema},
+    openapi,
+};
+use sea_orm::DatabaseConnection;
+use serde::Deserialize;
+
+use crate::{
+    audit::AuditManager,
+    auth::{rbac::requests::APITokenTeamEditorFromForm, tokens::APIAuthTokenSource},
+    entity_managers::forms::FormManager,
+    rocket_util::from_org_id::FromOrgId,
+};
+
+/// Times are in UTC. Either can be left out to keep the form open in that direction.
+#[derive(Deserialize, JsonSchema)]
+pub struct SetFormScheduleRequest {
+    opens_at: Option<NaiveDateTime>,
+    closes_at: Option<NaiveDateTime>,
+}
+
+/// Sets when a published form starts and stops accepting responses
+#[openapi(tag = "Forms", operation_id = "forms.set_schedule")]
+#[put("/users/me/orgs/<org_id>/forms/<form_id>/schedule", data = "<data>")]
+pub async fn handler(
+    org_id: PalformDatabaseID<IDOrganisation>,
+    form_id: PalformDatabaseID<IDForm>,
+    data: Json<SetFormScheduleRequest>,
+    token: APITokenTeamEditorFromForm,
+    db: &State<DatabaseConnection>,
+    audit: FromOrgId<AuditManager>,
+) -> Result<(), APIErrorWithStatus> {
+    if !FormManager::verify_form_org(db.inner(), form_id, org_id)
+        .await
+        .map_internal_error()?
+    {
+        return Err(APIError::NotFound.into());
+    }
+
+    if let (Some(opens_at), Some(closes_at)) = (data.opens_at, data.closes_at) {
+        if closes_at <= opens_at {
+            return Err(
+                APIError::BadRequest("The form must close after it opens".to_string()).into(),
+            );
+        }
+    }
+
+    FormManager::set_schedule(db.inner(), form_id, data.opens_at, data.closes_at)
+        .await
+        .map_internal_error()?;
+
+    let describe = |t: Option<NaiveDateTime>| {
+        t.map(|t| format!("{} UTC", t.format("%Y-%m-%d %H:%M")))
+            .unwrap_or("never".to_string())
+    };
+    audit
+        .log_event_with_note(
+            db.inner(),
+            token.get_user_id(),
+            AuditLogVerbEnum::Update,
+            AuditLogTargetResourceEnum::Form,
+            Some(form_id.into_unknown()),
+            Some(format!(
+                "Set form to open {} and close {}",
+                describe(data.opens_at),
+                describe(data.closes_at)
+            )),
+        )
+        .await
+        .map_internal_error()?;
+
+    Ok(())
+}
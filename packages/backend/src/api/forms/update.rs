@@ -32,6 +32,7 @@ pub struct UpdateFormRequest {
     notification_email: bool,
     end_configuration: APIFormEndConfiguration,
     enable_captcha: bool,
+    signed_submissions: bool,
 }
 
 #[openapi(tag = "Forms", operation_id = "forms.update")]
@@ -78,7 +79,11 @@ pub async fn handler(
         .map_err(|e| APIError::BadRequest(e.to_string()))?;
     form.end_configuration = Set(end_config);
     form.enable_captcha = Set(data.enable_captcha);
+    form.signed_submissions = Set(data.signed_submissions);
     form.update(&txn).await.map_internal_error()?;
+    FormManager::notify_updated(&txn, form_id)
+        .await
+        .map_internal_error()?;
 
     audit
         .log_event(
@@ -44,6 +44,7 @@ pub async fn handler(
                 user_email: key.user_email.clone(),
                 created_at: key.created_at,
                 expires_at: key.expires_at,
+                revoked_at: key.revoked_at,
             })
         })
         .collect();
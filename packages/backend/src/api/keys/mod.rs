@@ -5,4 +5,5 @@ pub mod list;
 pub mod list_org;
 pub mod register;
 pub mod register_backup;
+pub mod revoke;
 pub mod team_fingerprints;
@@ -0,0 +1,90 @@
+use palform_client_common::errors::error::{APIErrorWithStatus, APIInternalErrorResult};
+use palform_entities::sea_orm_active_enums::{AuditLogTargetResourceEnum, AuditLogVerbEnum};
+use palform_tsid::{
+    resources::{IDAdminPublicKey, IDOrganisation},
+    tsid::PalformDatabaseID,
+};
+use rocket::{post, serde::json::Json, State};
+use rocket_okapi::{
+    okapi::{schemars, schemars::JsonSchema},
+    openapi,
+};
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use sequoia_openpgp::packet::key::PublicParts;
+use serde::Deserialize;
+
+use crate::{
+    api::error::APIError,
+    audit::AuditManager,
+    auth::{
+        rbac::requests::{APITokenOrgAdmin, APITokenOrgViewer},
+        tokens::APIAuthTokenSource,
+    },
+    crypto::keys::CryptoKeyRepr,
+    entity_managers::keys::UserKeyManager,
+    rocket_util::from_org_id::FromOrgId,
+};
+
+#[derive(Deserialize, JsonSchema)]
+pub struct RevokeKeyRequest {
+    /// Armored revocation certificate signed by the key
+    revocation: String,
+}
+
+/// Revoke a key
+///
+/// Marks the key as revoked, so that new submissions are no longer encrypted to it. Submissions
+/// that were already encrypted to it can still be decrypted with it. The revocation certificate
+/// is added to the stored key, so anyone downloading the key can see that it was revoked.
+#[openapi(tag = "User keys", operation_id = "keys.revoke")]
+#[post("/users/me/orgs/<org_id>/keys/<key_id>/revoke", data = "<data>")]
+pub async fn handler(
+    org_id: PalformDatabaseID<IDOrganisation>,
+    key_id: PalformDatabaseID<IDAdminPublicKey>,
+    data: Json<RevokeKeyRequest>,
+    token: APITokenOrgViewer,
+    admin_token: Option<APITokenOrgAdmin>,
+    db: &State<DatabaseConnection>,
+    audit: FromOrgId<AuditManager>,
+) -> Result<(), APIErrorWithStatus> {
+    let txn = db.begin().await.map_internal_error()?;
+
+    let key = UserKeyManager::get_key_with_id(&txn, key_id)
+        .await
+        .map_internal_error()?
+        .ok_or(APIError::NotFound)?;
+    if key.organisation_id != org_id {
+        return Err(APIError::NotFound.into());
+    }
+    if key.user_id != token.get_user_id() && admin_token.is_none() {
+        return Err(APIError::NotAllowed.into());
+    }
+    if key.revoked_at.is_some() {
+        return Err(APIError::BadRequest("Key is already revoked".to_string()).into());
+    }
+
+    let mut key_data = CryptoKeyRepr::<PublicParts>::from_database_bytes(&key.public_key)
+        .map_err(|e| APIError::report_internal_error("decode key", e))?;
+    key_data
+        .add_revocation(&data.revocation)
+        .map_err(|e| APIError::BadRequest(e.to_string()))?;
+
+    UserKeyManager::revoke_key(&txn, key_id, key_data)
+        .await
+        .map_err(|e| APIError::report_internal_error("revoke key", e))?;
+
+    audit
+        .log_event_with_note(
+            &txn,
+            token.get_user_id(),
+            AuditLogVerbEnum::Update,
+            AuditLogTargetResourceEnum::AdminPublicKey,
+            Some(key_id.into_unknown()),
+            Some("Revoked key".to_string()),
+        )
+        .await
+        .map_internal_error()?;
+
+    txn.commit().await.map_internal_error()?;
+    Ok(())
+}
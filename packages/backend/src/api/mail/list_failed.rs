@@ -0,0 +1,23 @@
+use palform_client_common::errors::error::{APIErrorWithStatus, APIInternalErrorResult};
+use palform_tsid::{resources::IDOrganisation, tsid::PalformDatabaseID};
+use rocket::{get, serde::json::Json, State};
+use rocket_okapi::openapi;
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    api_entities::mail::APIFailedMail, auth::rbac::requests::APITokenOrgAdmin,
+    entity_managers::mail_outbox::MailOutboxManager,
+};
+
+#[openapi(tag = "Mail", operation_id = "mail.list_failed")]
+#[get("/users/me/orgs/<org_id>/mail/failed")]
+pub async fn handler(
+    org_id: PalformDatabaseID<IDOrganisation>,
+    _token: APITokenOrgAdmin,
+    db: &State<DatabaseConnection>,
+) -> Result<Json<Vec<APIFailedMail>>, APIErrorWithStatus> {
+    let failed = MailOutboxManager::list_failed_for_org(db.inner(), org_id)
+        .await
+        .map_internal_error()?;
+    Ok(Json(failed))
+}
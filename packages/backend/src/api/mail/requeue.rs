@@ -0,0 +1,30 @@
+use palform_client_common::errors::error::{APIError, APIErrorWithStatus, APIInternalErrorResult};
+use palform_tsid::{
+    resources::{IDMailOutboxItem, IDOrganisation},
+    tsid::PalformDatabaseID,
+};
+use rocket::{post, State};
+use rocket_okapi::openapi;
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    auth::rbac::requests::APITokenOrgAdmin, entity_managers::mail_outbox::MailOutboxManager,
+};
+
+#[openapi(tag = "Mail", operation_id = "mail.requeue")]
+#[post("/users/me/orgs/<org_id>/mail/<mail_id>/requeue")]
+pub async fn handler(
+    org_id: PalformDatabaseID<IDOrganisation>,
+    mail_id: PalformDatabaseID<IDMailOutboxItem>,
+    _token: APITokenOrgAdmin,
+    db: &State<DatabaseConnection>,
+) -> Result<(), APIErrorWithStatus> {
+    if !MailOutboxManager::requeue(db.inner(), org_id, mail_id)
+        .await
+        .map_internal_error()?
+    {
+        return Err(APIError::BadRequest("Only dead emails can be requeued".to_string()).into());
+    }
+
+    Ok(())
+}
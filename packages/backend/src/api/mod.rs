@@ -1,14 +1,17 @@
 pub mod admin_users;
 pub mod audit;
 pub mod auth;
+pub mod captcha;
 pub mod error;
 pub mod feedback;
 pub mod fill_tokens;
 pub mod form_brandings;
+pub mod form_revisions;
 pub mod form_templates;
 pub mod forms;
 pub mod induction;
 pub mod keys;
+pub mod mail;
 pub mod organisation_auth_config;
 pub mod organisation_auth_team_mappings;
 pub mod organisation_invites;
@@ -18,6 +21,9 @@ pub mod organisation_teams;
 pub mod organisations;
 pub mod question_groups;
 pub mod questions;
+pub mod server_signing_key;
+pub mod service_account_api_keys;
+pub mod storage;
 pub mod submissions;
 pub mod team_assets;
 pub mod webhooks;
@@ -3,11 +3,11 @@ use palform_entities::sea_orm_active_enums::{AuditLogTargetResourceEnum, AuditLo
 use palform_tsid::{resources::IDOrganisation, tsid::PalformDatabaseID};
 use rocket::{delete, State};
 use rocket_okapi::openapi;
-use sea_orm::DatabaseConnection;
+use sea_orm::{DatabaseConnection, TransactionTrait};
 
 use crate::{
     audit::AuditManager, auth::rbac::requests::APITokenOrgAdmin, auth::tokens::APIAuthTokenSource,
-    config::Config, entity_managers::orgs::OrganisationManager, mail::client::PalformMailClient,
+    config::Config, entity_managers::orgs::OrganisationManager,
     rocket_util::from_org_id::FromOrgId,
 };
 
@@ -19,15 +19,15 @@ pub async fn handler(
     audit: FromOrgId<AuditManager>,
     db: &State<DatabaseConnection>,
     config: &State<Config>,
-    mail: &State<PalformMailClient>,
 ) -> Result<(), APIErrorWithStatus> {
-    OrganisationManager::send_staff_deletion_request(org_id, config, mail)
+    let txn = db.begin().await.map_internal_error()?;
+    OrganisationManager::send_staff_deletion_request(&txn, org_id, config)
         .await
         .map_err(|e| APIError::report_internal_error("send org deletion request", e))?;
 
     audit
         .log_event_with_note(
-            db.inner(),
+            &txn,
             token.get_user_id(),
             AuditLogVerbEnum::Delete,
             AuditLogTargetResourceEnum::Organisation,
@@ -37,5 +37,6 @@ pub async fn handler(
         .await
         .map_internal_error()?;
 
+    txn.commit().await.map_internal_error()?;
     Ok(())
 }
@@ -15,7 +15,8 @@ use sea_orm::{AccessMode, DatabaseConnection, IsolationLevel, TransactionTrait};
 use serde::Deserialize;
 
 use crate::{
-    auth::rbac::requests::APITokenTeamEditorFromForm, entity_managers::questions::QuestionManager,
+    auth::rbac::requests::APITokenTeamEditorFromForm,
+    entity_managers::{forms::FormManager, questions::QuestionManager},
 };
 
 #[derive(JsonSchema, Deserialize)]
@@ -69,6 +70,10 @@ pub async fn handler(
     .await
     .map_err(|e| APIError::report_internal_error("save all questions and groups in form", e))?;
 
+    FormManager::notify_updated(&txn, form_id)
+        .await
+        .map_internal_error()?;
+
     txn.commit().await.map_internal_error()?;
     Ok(())
 }
@@ -0,0 +1,26 @@
+use palform_client_common::errors::error::APIErrorWithStatus;
+use rocket::{get, serde::json::Json, State};
+use rocket_okapi::okapi::schemars;
+use rocket_okapi::okapi::schemars::JsonSchema;
+use rocket_okapi::openapi;
+use serde::Serialize;
+
+use crate::crypto::signing::ServerSigningKey;
+
+#[derive(Serialize, JsonSchema)]
+pub struct APIServerSigningKey {
+    /// Base64-encoded Ed25519 public key
+    public_key: String,
+}
+
+/// The key used to sign export bundles, for checking them offline
+#[openapi(tag = "Server Signing Key", operation_id = "server_signing_key.get")]
+#[get("/server_signing_key")]
+pub fn handler(
+    signing_key: &State<Option<ServerSigningKey>>,
+) -> Result<Json<APIServerSigningKey>, APIErrorWithStatus> {
+    let signing_key = ServerSigningKey::configured(signing_key)?;
+    Ok(Json(APIServerSigningKey {
+        public_key: signing_key.public_key_base64(),
+    }))
+}
@@ -0,0 +1,85 @@
+use chrono::{Duration, Utc};
+use palform_client_common::errors::error::{APIError, APIErrorWithStatus, APIInternalErrorResult};
+use palform_tsid::{
+    resources::{IDAdminUser, IDOrganisation, IDServiceAccountAPIKey},
+    tsid::PalformDatabaseID,
+};
+use rocket::{post, serde::json::Json, State};
+use rocket_okapi::{
+    okapi::schemars::{self, JsonSchema},
+    openapi,
+};
+use sea_orm::DatabaseConnection;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    auth::{api_keys::APIKeyScope, rbac::requests::APITokenOrgAdmin, tokens::APIAuthTokenSource},
+    entity_managers::service_account_api_keys::ServiceAccountAPIKeysManager,
+};
+
+#[derive(Deserialize, JsonSchema)]
+pub struct CreateServiceAccountAPIKeyRequest {
+    /// Must be a member of this organisation and no other
+    pub user_id: PalformDatabaseID<IDAdminUser>,
+    pub name: String,
+    /// e.g. `forms:write`
+    pub scopes: Vec<String>,
+    /// The key never expires if not provided
+    pub expires_in_days: Option<u32>,
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct CreateServiceAccountAPIKeyResponse {
+    pub id: PalformDatabaseID<IDServiceAccountAPIKey>,
+    /// This is the only time the key is shown
+    pub key: String,
+}
+
+/// Create an API key for a service account in the organisation. If the request is itself made
+/// with an API key, the new key can only have scopes that key has.
+#[openapi(
+    tag = "Service Account API Keys",
+    operation_id = "service_account_api_keys.create"
+)]
+#[post("/users/me/orgs/<org_id>/api-keys", data = "<data>")]
+pub async fn handler(
+    org_id: PalformDatabaseID<IDOrganisation>,
+    data: Json<CreateServiceAccountAPIKeyRequest>,
+    token: APITokenOrgAdmin,
+    db: &State<DatabaseConnection>,
+) -> Result<Json<CreateServiceAccountAPIKeyResponse>, APIErrorWithStatus> {
+    let scopes = data
+        .scopes
+        .iter()
+        .map(|s| APIKeyScope::try_from(s.clone()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| APIError::ValidationError(e.to_string()))?;
+    if !scopes.iter().all(|s| token.has_scope(s)) {
+        return Err(APIError::NotAllowed.into());
+    }
+
+    if !ServiceAccountAPIKeysManager::can_org_manage_user(db.inner(), org_id, data.user_id)
+        .await
+        .map_internal_error()?
+    {
+        return Err(APIError::ValidationError(
+            "API keys can only be created for users who are only in this organisation".to_string(),
+        )
+        .into());
+    }
+
+    let expires_at = data
+        .expires_in_days
+        .map(|days| (Utc::now() + Duration::days(days.into())).naive_utc());
+    let (id, key) = ServiceAccountAPIKeysManager::create(
+        db.inner(),
+        data.user_id,
+        data.name.clone(),
+        scopes,
+        expires_at,
+    )
+    .await
+    .map_internal_error()?;
+
+    Ok(Json(CreateServiceAccountAPIKeyResponse { id, key }))
+}
@@ -0,0 +1,35 @@
+use palform_client_common::errors::error::{APIError, APIErrorWithStatus, APIInternalErrorResult};
+use palform_tsid::{
+    resources::{IDOrganisation, IDServiceAccountAPIKey},
+    tsid::PalformDatabaseID,
+};
+use rocket::{delete, State};
+use rocket_okapi::openapi;
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    auth::rbac::requests::APITokenOrgAdmin,
+    entity_managers::service_account_api_keys::ServiceAccountAPIKeysManager,
+};
+
+/// Revoke an API key, which stops working straight away
+#[openapi(
+    tag = "Service Account API Keys",
+    operation_id = "service_account_api_keys.delete"
+)]
+#[delete("/users/me/orgs/<org_id>/api-keys/<key_id>")]
+pub async fn handler(
+    org_id: PalformDatabaseID<IDOrganisation>,
+    key_id: PalformDatabaseID<IDServiceAccountAPIKey>,
+    _token: APITokenOrgAdmin,
+    db: &State<DatabaseConnection>,
+) -> Result<(), APIErrorWithStatus> {
+    if !ServiceAccountAPIKeysManager::revoke_in_org(db.inner(), org_id, key_id)
+        .await
+        .map_internal_error()?
+    {
+        return Err(APIError::NotFound.into());
+    }
+
+    Ok(())
+}
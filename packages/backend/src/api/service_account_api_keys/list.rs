@@ -0,0 +1,28 @@
+use palform_client_common::errors::error::{APIErrorWithStatus, APIInternalErrorResult};
+use palform_tsid::{resources::IDOrganisation, tsid::PalformDatabaseID};
+use rocket::{get, serde::json::Json, State};
+use rocket_okapi::openapi;
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    api_entities::service_account_api_key::APIServiceAccountAPIKey,
+    auth::rbac::requests::APITokenOrgAdmin,
+    entity_managers::service_account_api_keys::ServiceAccountAPIKeysManager,
+};
+
+/// List the API keys of service accounts that are only in this organisation
+#[openapi(
+    tag = "Service Account API Keys",
+    operation_id = "service_account_api_keys.list"
+)]
+#[get("/users/me/orgs/<org_id>/api-keys")]
+pub async fn handler(
+    org_id: PalformDatabaseID<IDOrganisation>,
+    _token: APITokenOrgAdmin,
+    db: &State<DatabaseConnection>,
+) -> Result<Json<Vec<APIServiceAccountAPIKey>>, APIErrorWithStatus> {
+    let keys = ServiceAccountAPIKeysManager::list_for_org(db.inner(), org_id)
+        .await
+        .map_internal_error()?;
+    Ok(Json(keys))
+}
@@ -0,0 +1,52 @@
+use std::path::PathBuf;
+
+use palform_client_common::errors::error::{APIError, APIErrorWithStatus};
+use rocket::{
+    get,
+    http::ContentType,
+    response::stream::{One, ReaderStream},
+    State,
+};
+
+use crate::storage::{
+    backend::ObjectReader,
+    buckets::{StorageBucket, SubmissionAssetsBucket, TeamAssetsBucket},
+    client::PalformStorage,
+};
+
+/// Serves files from storage backends that can't generate their own download links (i.e. anything
+/// other than S3). Links are generated by `PalformStorage::download_url`.
+#[get("/storage/<bucket>/<path..>?<expires>&<signature>")]
+pub async fn handler(
+    bucket: &str,
+    path: PathBuf,
+    expires: i64,
+    signature: &str,
+    team_assets: &State<PalformStorage<TeamAssetsBucket>>,
+    submission_assets: &State<PalformStorage<SubmissionAssetsBucket>>,
+) -> Result<(ContentType, ReaderStream<One<ObjectReader>>), APIErrorWithStatus> {
+    let path = path
+        .iter()
+        .map(|s| s.to_str().ok_or(APIError::NotFound))
+        .collect::<Result<Vec<_>, _>>()?
+        .join("/");
+
+    let file = if bucket == TeamAssetsBucket::name() {
+        team_assets.get_from_link(&path, expires, signature).await
+    } else if bucket == SubmissionAssetsBucket::name() {
+        submission_assets
+            .get_from_link(&path, expires, signature)
+            .await
+    } else {
+        return Err(APIError::NotFound.into());
+    }
+    .map_err(|e| APIError::report_internal_error("get file from storage link", e))?
+    .ok_or(APIError::NotFound)?;
+
+    let content_type = file
+        .content_type
+        .as_deref()
+        .and_then(ContentType::parse_flexible)
+        .unwrap_or(ContentType::Binary);
+    Ok((content_type, ReaderStream::one(file.data)))
+}
@@ -3,12 +3,18 @@ use palform_tsid::{
     resources::{IDForm, IDOrganisation, IDSubmissionFile},
     tsid::PalformDatabaseID,
 };
-use rocket::{get, State};
+use rocket::{
+    get,
+    http::ContentType,
+    response::stream::{One, ReaderStream},
+    State,
+};
 use rocket_okapi::openapi;
 
 use crate::{
     auth::rbac::requests::APITokenTeamViewerFromForm,
-    palform_s3::{buckets::S3BucketSubmissionAssets, client::PalformS3Client},
+    entity_managers::submission_files::SubmissionFilesManager,
+    storage::{backend::ObjectReader, buckets::SubmissionAssetsBucket, client::PalformStorage},
 };
 
 #[openapi(tag = "Submission Asset", operation_id = "submission.assets.get_link")]
@@ -18,15 +24,13 @@ pub async fn handler(
     form_id: PalformDatabaseID<IDForm>,
     file_id: PalformDatabaseID<IDSubmissionFile>,
     _token: APITokenTeamViewerFromForm,
-    s3: &State<PalformS3Client<S3BucketSubmissionAssets>>,
-) -> Result<Vec<u8>, APIErrorWithStatus> {
-    let resp = s3
-        .bucket
-        .get_object(format!("{}/{}", form_id, file_id))
+    storage: &State<PalformStorage<SubmissionAssetsBucket>>,
+) -> Result<(ContentType, ReaderStream<One<ObjectReader>>), APIErrorWithStatus> {
+    let file = storage
+        .get(&SubmissionFilesManager::file_path(form_id, file_id))
         .await
-        .map_err(|e| {
-            APIError::report_internal_error("sign download url for submission asset", e)
-        })?;
+        .map_err(|e| APIError::report_internal_error("get submission asset", e))?
+        .ok_or(APIError::NotFound)?;
 
-    Ok(resp.to_vec())
+    Ok((ContentType::Binary, ReaderStream::one(file.data)))
 }
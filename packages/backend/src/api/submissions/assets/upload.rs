@@ -1,39 +1,141 @@
-use palform_client_common::errors::error::{APIError, APIErrorWithStatus};
-use palform_tsid::{resources::{IDForm, IDOrganisation, IDSubmissionFile}, tsid::PalformDatabaseID};
-use rocket::{form::Form, fs::TempFile, post, serde::json::Json, tokio::io::AsyncReadExt, State};
+use palform_client_common::{
+    errors::error::{APIError, APIErrorWithStatus},
+    form_management::question_types::APIQuestionConfiguration,
+};
+use palform_tsid::{
+    resources::{IDForm, IDOrganisation, IDQuestion, IDSubmissionFile},
+    tsid::PalformDatabaseID,
+};
+use rocket::{
+    data::{ByteUnit, Data},
+    post,
+    serde::json::Json,
+    State,
+};
+use sea_orm::{DatabaseConnection, TransactionTrait};
 
 use crate::{
+    api_entities::billing::entitlement::APIEntitlementRequest,
     auth::fill_access::APIFillAccessToken,
-    palform_s3::{buckets::S3BucketSubmissionAssets, client::PalformS3Client},
+    config::Config,
+    entity_managers::{
+        billing_entitlement_proxy::BillingEntitlementManager,
+        form_revisions::FormRevisionManager,
+        forms::FormManager,
+        questions::QuestionManager,
+        submission_files::{SubmissionFileError, SubmissionFilesManager},
+    },
+    i18n::request::I18NManager,
+    storage::{buckets::SubmissionAssetsBucket, client::PalformStorage},
+    pt,
+    rate_limit::guard::FillRateLimit,
+    rocket_util::from_org_id::FromOrgIdTrait,
 };
 
-#[post("/fill/orgs/<_org_id>/forms/<form_id>/assets", data = "<data>")]
+/// Takes the encrypted file as the raw request body. `question_id` must be a file upload question
+/// in the published form, and its own size limit is applied as well as the server's.
+#[post(
+    "/fill/orgs/<org_id>/forms/<form_id>/assets?<question_id>",
+    data = "<data>"
+)]
+#[allow(clippy::too_many_arguments)]
 pub async fn handler(
-    _org_id: PalformDatabaseID<IDOrganisation>,
+    org_id: PalformDatabaseID<IDOrganisation>,
     form_id: PalformDatabaseID<IDForm>,
-    data: Form<TempFile<'_>>,
+    question_id: PalformDatabaseID<IDQuestion>,
+    data: Data<'_>,
+    _rate_limit: FillRateLimit,
     _token: APIFillAccessToken,
-    s3: &State<PalformS3Client<S3BucketSubmissionAssets>>,
+    storage: &State<PalformStorage<SubmissionAssetsBucket>>,
+    db: &State<DatabaseConnection>,
+    config: &State<Config>,
+    i18n: I18NManager,
 ) -> Result<Json<PalformDatabaseID<IDSubmissionFile>>, APIErrorWithStatus> {
-    let mut buf = Vec::new();
-    data.open()
+    if !FormManager::verify_form_org(db.inner(), form_id, org_id)
         .await
-        .map_err(|e| APIError::BadRequest(e.to_string()))?
-        .read_to_end(&mut buf)
+        .map_err(|e| APIError::report_internal_error("verify form org", e))?
+    {
+        return Err(APIError::NotFound.into());
+    }
+
+    // Respondents only ever see the published questions, not any unpublished edits
+    let questions = match FormManager::get_published_revision_id(db.inner(), form_id)
         .await
-        .map_err(|e| APIError::BadRequest(e.to_string()))?;
+        .map_err(|e| APIError::report_internal_error("get published revision id", e))?
+    {
+        Some(revision_id) => FormRevisionManager::get(db.inner(), form_id, revision_id)
+            .await
+            .map_err(|e| APIError::report_internal_error("get published form revision", e))?
+            .map(|revision| revision.questions)
+            .unwrap_or_default(),
+        None => QuestionManager::get_all_for_form(db.inner(), form_id)
+            .await
+            .map_err(|e| APIError::report_internal_error("get form questions", e))?,
+    };
+    let question = questions
+        .into_iter()
+        .find(|q| q.id == question_id)
+        .ok_or(APIError::NotFound)?;
 
-    let file_id = PalformDatabaseID::<IDSubmissionFile>::random();
+    let mut max_size_mb = config.submission_asset_max_size_mb;
+    match question.configuration {
+        APIQuestionConfiguration::FileUpload { max_size_mb: q, .. } => {
+            if let Some(question_max_size_mb) = q {
+                max_size_mb = max_size_mb.min(question_max_size_mb.into());
+            }
+        }
+        _ => {
+            return Err(APIError::BadRequest(pt!(i18n, "upload_not_file_question",)).into());
+        }
+    }
 
-    s3.bucket
-        // we expect the file to be encrypted (a raw binary stream) so we don't care about
-        // content-type
-        .put_object(
-            format!("{}/{}", form_id, file_id),
-            &buf,
-        )
-        .await
-        .map_err(|e| APIError::report_internal_error("upload submission asset to s3", e))?;
+    let max_size = ByteUnit::Megabyte(max_size_mb);
+    let (file_id, size) =
+        SubmissionFilesManager::upload(storage, form_id, data.open(max_size + 1), max_size.as_u64())
+            .await
+            .map_err(|e| match e {
+                SubmissionFileError::TooLarge => {
+                    APIError::PayloadTooLarge(pt!(i18n, "upload_too_large",)).into()
+                }
+                SubmissionFileError::Storage(e) => {
+                    APIError::report_internal_error("upload submission asset", e)
+                }
+            })?;
+
+    if let Err(e) = record_within_quota(db.inner(), org_id, form_id, file_id, size).await {
+        SubmissionFilesManager::discard(storage, form_id, file_id)
+            .await
+            .map_err(|e| APIError::report_internal_error("discard submission asset", e))?;
+        return Err(e);
+    }
 
     Ok(Json(file_id))
 }
+
+/// Checks the quota and records the file together, with the organisation locked, so uploads
+/// happening at the same time are counted against each other
+async fn record_within_quota(
+    db: &DatabaseConnection,
+    org_id: PalformDatabaseID<IDOrganisation>,
+    form_id: PalformDatabaseID<IDForm>,
+    file_id: PalformDatabaseID<IDSubmissionFile>,
+    size: u64,
+) -> Result<(), APIErrorWithStatus> {
+    let txn = db
+        .begin()
+        .await
+        .map_err(|e| APIError::report_internal_error("begin transaction", e))?;
+    SubmissionFilesManager::lock_org_storage(&txn, org_id)
+        .await
+        .map_err(|e| APIError::report_internal_error("lock org storage", e))?;
+    BillingEntitlementManager::new(org_id)
+        .check_entitlement(&txn, APIEntitlementRequest::SubmissionFileStorage(size))
+        .await?;
+    SubmissionFilesManager::record(&txn, form_id, file_id, size)
+        .await
+        .map_err(|e| APIError::report_internal_error("record submission asset", e))?;
+    txn.commit()
+        .await
+        .map_err(|e| APIError::report_internal_error("commit submission asset", e))?;
+    Ok(())
+}
@@ -0,0 +1,208 @@
+use std::pin::Pin;
+
+use async_stream::stream;
+use chrono::{DateTime, Utc};
+use log::error;
+use palform_client_common::{
+    errors::error::{APIError, APIErrorWithStatus, APIInternalErrorResult},
+    form_management::bundle::{
+        submission_bundle_path, SubmissionBundleEntry, SubmissionBundleFile,
+        SubmissionBundleFillToken, SubmissionBundleManifest, SubmissionBundleSchema,
+        SUBMISSION_BUNDLE_MANIFEST_PATH, SUBMISSION_BUNDLE_MANIFEST_SIGNATURE_PATH,
+        SUBMISSION_BUNDLE_SCHEMA_PATH, SUBMISSION_BUNDLE_VERSION,
+    },
+};
+use palform_tsid::{
+    resources::{IDForm, IDOrganisation},
+    tsid::PalformDatabaseID,
+};
+use ring::digest::{digest, SHA256};
+use rocket::{
+    futures::{Stream, StreamExt},
+    get,
+    response::stream::ByteStream,
+    State,
+};
+use rocket_okapi::openapi;
+use sea_orm::DatabaseConnection;
+use tar::{EntryType, Header};
+
+use crate::{
+    auth::{fill_access::FillAccessTokenManager, rbac::requests::APITokenTeamViewerFromForm},
+    crypto::{signing::ServerSigningKey, submissions::CryptoSubmissionRepr},
+    entity_managers::{
+        form_revisions::FormRevisionManager,
+        forms::{FormManager, GetFormError},
+        submission::SubmissionManager,
+    },
+};
+
+const TAR_BLOCK_SIZE: usize = 512;
+
+type BundleStream = Pin<Box<dyn Stream<Item = Vec<u8>> + Send>>;
+
+fn tar_entry(path: &str, data: &[u8], mtime: u64) -> Result<Vec<u8>, std::io::Error> {
+    let mut header = Header::new_ustar();
+    header.set_path(path)?;
+    header.set_entry_type(EntryType::Regular);
+    header.set_size(data.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(mtime);
+    header.set_cksum();
+
+    let mut entry = Vec::with_capacity(TAR_BLOCK_SIZE * 2 + data.len());
+    entry.extend_from_slice(header.as_bytes());
+    entry.extend_from_slice(data);
+    entry.resize(entry.len().next_multiple_of(TAR_BLOCK_SIZE), 0);
+    Ok(entry)
+}
+
+fn bundle_file(path: String, data: &[u8]) -> SubmissionBundleFile {
+    SubmissionBundleFile {
+        path,
+        sha256: faster_hex::hex_string(digest(&SHA256, data).as_ref()),
+    }
+}
+
+/// Streams every encrypted submission in the form as a tar archive, together with the form's
+/// questions at the time of export (and every earlier revision of them) and a signed manifest
+/// listing the hash of each file. The manifest is written last, so a bundle cut off part-way
+/// through won't verify.
+#[openapi(tag = "Submissions", operation_id = "submissions.bundle")]
+#[get("/users/me/orgs/<org_id>/forms/<form_id>/submissions/bundle")]
+pub async fn handler(
+    org_id: PalformDatabaseID<IDOrganisation>,
+    form_id: PalformDatabaseID<IDForm>,
+    _token: APITokenTeamViewerFromForm,
+    db: &State<DatabaseConnection>,
+    signing_key: &State<Option<ServerSigningKey>>,
+) -> Result<ByteStream<BundleStream>, APIErrorWithStatus> {
+    let signing_key = ServerSigningKey::configured(signing_key)?.clone();
+
+    if !FormManager::verify_form_org(db.inner(), form_id, org_id)
+        .await
+        .map_internal_error()?
+    {
+        return Err(APIError::NotFound.into());
+    }
+
+    let form = FormManager::get_with_questions(db.inner(), form_id)
+        .await
+        .map_err(|e| match e {
+            GetFormError::NotFound => APIError::NotFound.into(),
+            e => APIError::report_internal_error("Getting form for bundle", e),
+        })?;
+
+    let fill_tokens = FillAccessTokenManager::list_for_form(db.inner(), form_id)
+        .await
+        .map_internal_error()?
+        .into_iter()
+        .map(|t| SubmissionBundleFillToken {
+            id: t.id,
+            nickname: t.nickname,
+        })
+        .collect();
+
+    let revisions = FormRevisionManager::list_full_for_form(db.inner(), form_id)
+        .await
+        .map_err(|e| APIError::report_internal_error("Getting form revisions for bundle", e))?;
+
+    let schema = serde_json::to_vec_pretty(&SubmissionBundleSchema {
+        form_id,
+        title: form.form.title,
+        editor_name: form.form.editor_name,
+        groups: form.groups,
+        questions: form.questions,
+        fill_tokens,
+        revisions,
+    })
+    .map_err(|e| APIError::report_internal_error("Serialising bundle schema", e))?;
+
+    let exported_at = Utc::now();
+    let mtime = exported_at.timestamp() as u64;
+    let schema_entry = tar_entry(SUBMISSION_BUNDLE_SCHEMA_PATH, &schema, mtime)
+        .map_err(|e| APIError::report_internal_error("Writing bundle schema", e))?;
+
+    let db = db.inner().clone();
+    Ok(ByteStream(Box::pin(stream! {
+        yield schema_entry;
+
+        let mut manifest = SubmissionBundleManifest {
+            version: SUBMISSION_BUNDLE_VERSION,
+            form_id,
+            exported_at,
+            schema: bundle_file(SUBMISSION_BUNDLE_SCHEMA_PATH.to_string(), &schema),
+            submissions: Vec::new(),
+            signing_key: signing_key.public_key_base64(),
+        };
+
+        let mut submissions = match SubmissionManager::stream_for_form(&db, form_id).await {
+            Ok(submissions) => submissions,
+            Err(e) => {
+                error!("Streaming submissions for bundle: {}", e);
+                return;
+            }
+        };
+
+        while let Some(submission) = submissions.next().await {
+            let submission = match submission {
+                Ok(submission) => submission,
+                Err(e) => {
+                    error!("Reading submission for bundle: {}", e);
+                    return;
+                }
+            };
+
+            let pem = match CryptoSubmissionRepr::to_pem_string(&submission.encrypted_data) {
+                Ok(pem) => pem,
+                Err(e) => {
+                    error!("Creating PEM string for bundle: {}", e);
+                    return;
+                }
+            };
+
+            let path = submission_bundle_path(submission.id);
+            match tar_entry(&path, pem.as_bytes(), mtime) {
+                Ok(entry) => yield entry,
+                Err(e) => {
+                    error!("Writing bundle submission: {}", e);
+                    return;
+                }
+            }
+
+            manifest.submissions.push(SubmissionBundleEntry {
+                id: submission.id,
+                created_at: DateTime::from_naive_utc_and_offset(submission.created_at, Utc),
+                for_token: submission.for_token,
+                form_revision_id: submission.form_revision_id,
+                signing_key: submission.signing_key,
+                file: bundle_file(path, pem.as_bytes()),
+            });
+        }
+
+        let manifest = match serde_json::to_vec_pretty(&manifest) {
+            Ok(manifest) => manifest,
+            Err(e) => {
+                error!("Serialising bundle manifest: {}", e);
+                return;
+            }
+        };
+        let signature = signing_key.sign_base64(&manifest);
+
+        for (path, data) in [
+            (SUBMISSION_BUNDLE_MANIFEST_PATH, manifest.as_slice()),
+            (SUBMISSION_BUNDLE_MANIFEST_SIGNATURE_PATH, signature.as_bytes()),
+        ] {
+            match tar_entry(path, data, mtime) {
+                Ok(entry) => yield entry,
+                Err(e) => {
+                    error!("Writing bundle manifest: {}", e);
+                    return;
+                }
+            }
+        }
+
+        // A tar archive ends with two empty blocks
+        yield vec![0; TAR_BLOCK_SIZE * 2];
+    })))
+}
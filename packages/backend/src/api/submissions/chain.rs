@@ -0,0 +1,68 @@
+use palform_client_common::errors::error::{APIErrorWithStatus, APIInternalErrorResult};
+use palform_tsid::{
+    resources::{IDForm, IDOrganisation},
+    tsid::PalformDatabaseID,
+};
+use rocket::{get, serde::json::Json, State};
+use rocket_okapi::openapi;
+use sea_orm::{AccessMode, DatabaseConnection, IsolationLevel, TransactionTrait};
+
+use crate::{
+    api::error::APIError,
+    api_entities::submission::APISubmissionChain,
+    auth::rbac::requests::APITokenTeamViewerFromForm,
+    crypto::signing::ServerSigningKey,
+    entity_managers::{forms::FormManager, submission_chain::SubmissionChainManager},
+};
+
+/// Lists every entry in the form's submission chain, with a signed checkpoint of its head. Each
+/// submission and deletion is chained to the one before it, so clients can use this to check
+/// that the submissions they've synced are complete and in order. Submissions made before the
+/// chain was added aren't in it.
+///
+/// The chain only proves anything if clients pin the signing key and remember the last checkpoint
+/// they saw, checking that each new chain carries on from it. Otherwise the server could rewrite
+/// the whole chain and sign it with a new key.
+#[openapi(tag = "Submissions", operation_id = "submissions.chain")]
+#[get("/users/me/orgs/<org_id>/forms/<form_id>/submissions/chain")]
+pub async fn handler(
+    org_id: PalformDatabaseID<IDOrganisation>,
+    form_id: PalformDatabaseID<IDForm>,
+    _token: APITokenTeamViewerFromForm,
+    db: &State<DatabaseConnection>,
+    signing_key: &State<Option<ServerSigningKey>>,
+) -> Result<Json<APISubmissionChain>, APIErrorWithStatus> {
+    let signing_key = ServerSigningKey::configured(signing_key)?;
+
+    // Read the entries and head from the same snapshot, so they match even if a submission comes
+    // in part-way through
+    let txn = db
+        .begin_with_config(
+            Some(IsolationLevel::RepeatableRead),
+            Some(AccessMode::ReadOnly),
+        )
+        .await
+        .map_internal_error()?;
+
+    if !FormManager::verify_form_org(&txn, form_id, org_id)
+        .await
+        .map_internal_error()?
+    {
+        return Err(APIError::NotFound.into());
+    }
+
+    let entries = SubmissionChainManager::list_entries(&txn, form_id)
+        .await
+        .map_internal_error()?;
+    let checkpoint = SubmissionChainManager::get_checkpoint(&txn, form_id)
+        .await
+        .map_internal_error()?;
+    txn.commit().await.map_internal_error()?;
+
+    Ok(Json(APISubmissionChain {
+        entries,
+        signature: signing_key.sign_base64(checkpoint.signed_message().as_bytes()),
+        signing_key: signing_key.public_key_base64(),
+        checkpoint,
+    }))
+}
@@ -60,6 +60,7 @@ fn filter_certs_containing_key_handles(
                 user_email: key.user_email,
                 created_at: key.created_at,
                 expires_at: key.expires_at,
+                revoked_at: key.revoked_at,
                 key_fingerprint: repr.fingerprint().to_hex(),
             })
         }
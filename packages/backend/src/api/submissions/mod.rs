@@ -1,5 +1,9 @@
 pub mod assets;
+pub mod bundle;
+pub mod chain;
 pub mod crypto;
 pub mod delete;
 pub mod list;
 pub mod num_since;
+pub mod recipients;
+pub mod rewrap;
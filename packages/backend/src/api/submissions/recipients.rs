@@ -0,0 +1,48 @@
+use palform_client_common::errors::error::{APIErrorWithStatus, APIInternalErrorResult};
+use palform_tsid::{
+    resources::{IDForm, IDOrganisation},
+    tsid::PalformDatabaseID,
+};
+use rocket::{get, serde::json::Json, State};
+use rocket_okapi::openapi;
+use sea_orm::DatabaseConnection;
+use sequoia_openpgp::packet::key::PublicParts;
+
+use crate::{
+    api::error::APIError,
+    auth::rbac::requests::APITokenTeamViewerFromForm,
+    crypto::keys::{CryptoKeyRepr, KeyConversionError},
+    entity_managers::{forms::FormManager, keys::UserKeyManager},
+};
+
+/// The public keys that new submissions to the form are encrypted to. Existing submissions can be
+/// re-wrapped for any of these that can't read them yet.
+#[openapi(tag = "Submissions", operation_id = "submissions.recipients")]
+#[get("/users/me/orgs/<org_id>/forms/<form_id>/submissions/recipients")]
+pub async fn handler(
+    org_id: PalformDatabaseID<IDOrganisation>,
+    form_id: PalformDatabaseID<IDForm>,
+    _token: APITokenTeamViewerFromForm,
+    db: &State<DatabaseConnection>,
+) -> Result<Json<Vec<String>>, APIErrorWithStatus> {
+    if !FormManager::verify_form_org(db.inner(), form_id, org_id)
+        .await
+        .map_internal_error()?
+    {
+        return Err(APIError::NotFound.into());
+    }
+
+    let team_id = FormManager::get_form_team_id(db.inner(), form_id)
+        .await
+        .map_internal_error()?;
+    let models = UserKeyManager::list_all_team_keys(db.inner(), org_id, team_id)
+        .await
+        .map_internal_error()?;
+
+    let keys: Result<Vec<String>, KeyConversionError> = models
+        .iter()
+        .map(|e| CryptoKeyRepr::<PublicParts>::from_database_bytes(&e.public_key)?.to_pem_string())
+        .collect();
+    let keys = keys.map_err(|e| APIError::report_internal_error("converting team keys", e))?;
+    Ok(Json(keys))
+}
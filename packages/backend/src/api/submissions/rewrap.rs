@@ -0,0 +1,153 @@
+use palform_client_common::errors::error::{APIErrorWithStatus, APIInternalErrorResult};
+use palform_crypto::policy::recipient_cert_policy;
+use palform_entities::sea_orm_active_enums::{AuditLogTargetResourceEnum, AuditLogVerbEnum};
+use palform_tsid::{
+    resources::{IDForm, IDOrganisation},
+    tsid::PalformDatabaseID,
+};
+use rocket::{post, serde::json::Json, State};
+use rocket_okapi::openapi;
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use sequoia_openpgp::{packet::key::PublicParts, Fingerprint, KeyHandle};
+
+use crate::{
+    api::error::APIError,
+    api_entities::submission::APISubmissionRewrap,
+    audit::AuditManager,
+    auth::{rbac::requests::APITokenTeamEditorFromForm, tokens::APIAuthTokenSource},
+    crypto::{keys::CryptoKeyRepr, submissions::CryptoSubmissionRepr},
+    entity_managers::{forms::FormManager, keys::UserKeyManager, submission::SubmissionManager},
+    rocket_util::from_org_id::FromOrgId,
+};
+
+/// Adds key packets made on the client to existing submissions, so that keys added to the team
+/// after a submission was made can decrypt it. The server never sees the session keys, so it can
+/// only check that the packets are for keys in the form's team.
+///
+/// A packet for a key that an earlier rewrap already added replaces that one, so a broken packet
+/// can be fixed by someone who can read the submission. Packets from the respondent are never
+/// replaced.
+#[openapi(tag = "Submissions", operation_id = "submissions.rewrap")]
+#[post(
+    "/users/me/orgs/<org_id>/forms/<form_id>/submissions/rewrap",
+    data = "<request>"
+)]
+pub async fn handler(
+    org_id: PalformDatabaseID<IDOrganisation>,
+    form_id: PalformDatabaseID<IDForm>,
+    request: Json<Vec<APISubmissionRewrap>>,
+    token: APITokenTeamEditorFromForm,
+    db: &State<DatabaseConnection>,
+    audit: FromOrgId<AuditManager>,
+) -> Result<(), APIErrorWithStatus> {
+    if !FormManager::verify_form_org(db.inner(), form_id, org_id)
+        .await
+        .map_internal_error()?
+    {
+        return Err(APIError::NotFound.into());
+    }
+
+    let team_id = FormManager::get_form_team_id(db.inner(), form_id)
+        .await
+        .map_internal_error()?;
+    let team_keys = UserKeyManager::list_all_team_keys(db.inner(), org_id, team_id)
+        .await
+        .map_internal_error()?;
+
+    let policy = recipient_cert_policy();
+    let mut allowed_fingerprints = Vec::<Fingerprint>::new();
+    for key in team_keys {
+        let repr = CryptoKeyRepr::<PublicParts>::from_database_bytes(&key.public_key)
+            .map_err(|e| APIError::report_internal_error("decode team key", e))?;
+        allowed_fingerprints.extend(
+            repr.all_subkey_fingerprints(&policy)
+                .map_err(|e| APIError::report_internal_error("list team key fingerprints", e))?,
+        );
+    }
+
+    let txn = db.begin().await.map_internal_error()?;
+    for rewrap in request.into_inner() {
+        if !SubmissionManager::verify_submission_form(&txn, rewrap.submission_id, form_id)
+            .await
+            .map_internal_error()?
+        {
+            return Err(APIError::NotFound.into());
+        }
+
+        let pkesks = CryptoSubmissionRepr::parse_key_packets(&rewrap.key_packets)
+            .map_err(|e| APIError::BadRequest(e.to_string()))?;
+        let all_allowed = pkesks.iter().all(|pkesk| {
+            pkesk.recipient().is_some_and(|recipient| {
+                allowed_fingerprints
+                    .iter()
+                    .any(|f| recipient.aliases(KeyHandle::from(f)))
+            })
+        });
+        if !all_allowed {
+            return Err(APIError::BadRequest(
+                "Key packets can only be added for keys in the form's team".to_string(),
+            )
+            .into());
+        }
+
+        let submission = SubmissionManager::get_by_id(&txn, rewrap.submission_id)
+            .await
+            .map_internal_error()?
+            .ok_or(APIError::NotFound)?;
+        let mut repr = CryptoSubmissionRepr::from_database_bytes(&submission.encrypted_data)
+            .map_err(|e| APIError::report_internal_error("decode submission message bytes", e))?;
+        let replaceable: Vec<KeyHandle> =
+            SubmissionManager::list_rewrapped_recipients(&txn, rewrap.submission_id)
+                .await
+                .map_internal_error()?
+                .iter()
+                .filter_map(|recipient| recipient.parse().ok())
+                .collect();
+        let changes = repr
+            .add_key_packets(pkesks, &replaceable)
+            .map_err(|e| APIError::BadRequest(e.to_string()))?;
+
+        audit
+            .log_event_with_note(
+                &txn,
+                token.get_user_id(),
+                AuditLogVerbEnum::Update,
+                AuditLogTargetResourceEnum::Submission,
+                Some(rewrap.submission_id.into_unknown()),
+                Some(format!(
+                    "Rewrap added {} key packets and replaced {}",
+                    changes.added.len(),
+                    changes.replaced.len()
+                )),
+            )
+            .await
+            .map_internal_error()?;
+
+        if changes.added.is_empty() && changes.replaced.is_empty() {
+            continue;
+        }
+
+        let data = repr
+            .to_database_bytes()
+            .map_err(|e| APIError::report_internal_error("encode submission message bytes", e))?;
+        SubmissionManager::set_encrypted_data(&txn, rewrap.submission_id, data)
+            .await
+            .map_internal_error()?;
+        SubmissionManager::record_rewrapped_recipients(
+            &txn,
+            rewrap.submission_id,
+            changes
+                .added
+                .iter()
+                .chain(changes.replaced.iter())
+                .map(|recipient| recipient.to_hex())
+                .collect(),
+            token.get_user_id(),
+        )
+        .await
+        .map_internal_error()?;
+    }
+
+    txn.commit().await.map_internal_error()?;
+    Ok(())
+}
@@ -10,7 +10,7 @@ use sea_orm::DatabaseConnection;
 use crate::{
     auth::rbac::requests::APITokenTeamViewerFromTeam,
     entity_managers::team_assets::TeamAssetsManager,
-    palform_s3::{buckets::S3BucketTeamAssets, client::PalformS3Client},
+    storage::{buckets::TeamAssetsBucket, client::PalformStorage},
 };
 
 #[openapi(tag = "Team Assets", operation_id = "organisation.team.asset.get")]
@@ -20,7 +20,7 @@ pub async fn handler(
     team_id: PalformDatabaseID<IDTeam>,
     asset_id: PalformDatabaseID<IDTeamAsset>,
     _token: APITokenTeamViewerFromTeam,
-    s3: &State<PalformS3Client<S3BucketTeamAssets>>,
+    storage: &State<PalformStorage<TeamAssetsBucket>>,
     db: &State<DatabaseConnection>,
 ) -> Result<Json<String>, APIErrorWithStatus> {
     let m = TeamAssetsManager::new(team_id);
@@ -34,7 +34,7 @@ pub async fn handler(
     }
 
     let asset = m
-        .get(db.inner(), s3, asset_id)
+        .get(db.inner(), storage, asset_id)
         .await
         .map_err(|e| APIError::report_internal_error("get team asset for admin", e))?;
 
@@ -7,7 +7,7 @@ use sea_orm::DatabaseConnection;
 use crate::{
     auth::fill_access::APIFillAccessToken,
     entity_managers::{forms::FormManager, team_assets::TeamAssetsManager},
-    palform_s3::{buckets::S3BucketTeamAssets, client::PalformS3Client},
+    storage::{buckets::TeamAssetsBucket, client::PalformStorage},
 };
 
 #[openapi(
@@ -20,7 +20,7 @@ pub async fn handler(
     form_id: PalformDatabaseID<IDForm>,
     asset_id: PalformDatabaseID<IDTeamAsset>,
     _token: APIFillAccessToken,
-    s3: &State<PalformS3Client<S3BucketTeamAssets>>,
+    storage: &State<PalformStorage<TeamAssetsBucket>>,
     db: &State<DatabaseConnection>,
 ) -> Result<Redirect, APIErrorWithStatus> {
     let form_team = FormManager::get_form_team_id(db.inner(), form_id)
@@ -29,7 +29,7 @@ pub async fn handler(
 
     let m = TeamAssetsManager::new(form_team);
     let asset = m
-        .get(db.inner(), s3, asset_id)
+        .get(db.inner(), storage, asset_id)
         .await
         .map_err(|e| APIError::report_internal_error("get single team asset", e))?;
 
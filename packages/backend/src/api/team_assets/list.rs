@@ -8,7 +8,7 @@ use crate::{
     api_entities::team_asset::APITeamAsset,
     auth::rbac::requests::APITokenTeamViewerFromTeam,
     entity_managers::team_assets::TeamAssetsManager,
-    palform_s3::{buckets::S3BucketTeamAssets, client::PalformS3Client},
+    storage::{buckets::TeamAssetsBucket, client::PalformStorage},
 };
 
 #[openapi(tag = "Team Assets", operation_id = "organisation.team.asset.list")]
@@ -17,12 +17,12 @@ pub async fn handler(
     _org_id: PalformDatabaseID<IDOrganisation>,
     team_id: PalformDatabaseID<IDTeam>,
     _token: APITokenTeamViewerFromTeam,
-    s3: &State<PalformS3Client<S3BucketTeamAssets>>,
+    storage: &State<PalformStorage<TeamAssetsBucket>>,
     db: &State<DatabaseConnection>,
 ) -> Result<Json<Vec<APITeamAsset>>, APIErrorWithStatus> {
     let m = TeamAssetsManager::new(team_id);
     let assets = m
-        .list(db.inner(), s3)
+        .list(db.inner(), storage)
         .await
         .map_err(|e| APIError::report_internal_error("list assets", e))?;
 
@@ -6,7 +6,7 @@ use sea_orm::DatabaseConnection;
 use crate::{
     auth::rbac::requests::APITokenTeamEditorFromTeam,
     entity_managers::team_assets::TeamAssetsManager,
-    palform_s3::{buckets::S3BucketTeamAssets, client::PalformS3Client},
+    storage::{buckets::TeamAssetsBucket, client::PalformStorage},
 };
 
 #[post("/users/me/orgs/<_org_id>/teams/<team_id>/assets", data = "<data>")]
@@ -15,7 +15,7 @@ pub async fn handler(
     team_id: PalformDatabaseID<IDTeam>,
     data: Form<TempFile<'_>>,
     _token: APITokenTeamEditorFromTeam,
-    s3_client: &State<PalformS3Client<S3BucketTeamAssets>>,
+    storage: &State<PalformStorage<TeamAssetsBucket>>,
     db: &State<DatabaseConnection>,
 ) -> Result<Json<PalformDatabaseID<IDTeamAsset>>, APIErrorWithStatus> {
     let m = TeamAssetsManager::new(team_id);
@@ -32,7 +32,7 @@ pub async fn handler(
     ))?;
 
     let asset_id = m
-        .create(db.inner(), s3_client, &buf, &content_type.to_string())
+        .create(db.inner(), storage, &buf, &content_type.to_string())
         .await
         .map_err(|e| APIError::report_internal_error("upload asset", e))?;
 
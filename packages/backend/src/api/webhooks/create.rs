@@ -1,4 +1,5 @@
 use palform_client_common::errors::error::{APIError, APIErrorWithStatus, APIInternalErrorResult};
+use palform_entities::sea_orm_active_enums::WebhookEventEnum;
 use palform_tsid::{
     resources::{IDForm, IDOrganisation, IDWebhook},
     tsid::PalformDatabaseID,
@@ -8,7 +9,7 @@ use rocket_okapi::{
     okapi::schemars::{self, JsonSchema},
     openapi,
 };
-use sea_orm::DatabaseConnection;
+use sea_orm::{AccessMode, DatabaseConnection, IsolationLevel, TransactionTrait};
 use serde::{Deserialize, Serialize};
 use url::Url;
 
@@ -19,6 +20,13 @@ use crate::{
 #[derive(Deserialize, JsonSchema)]
 pub struct CreateWebhookRequest {
     pub endpoint: Url,
+    /// Defaults to just `SubmissionCreated` if not provided
+    #[serde(default = "default_events")]
+    pub events: Vec<WebhookEventEnum>,
+}
+
+fn default_events() -> Vec<WebhookEventEnum> {
+    vec![WebhookEventEnum::SubmissionCreated]
 }
 
 #[derive(Serialize, JsonSchema)]
@@ -43,10 +51,21 @@ pub async fn handler(
         .await
         .map_err(|e| APIError::ValidationError(format!("TCP connection test: {}", e)))?;
 
-    let new_webhook = WebhookManager::create(db.inner(), form_id, data.endpoint.clone())
+    let txn = db
+        .begin_with_config(
+            Some(IsolationLevel::ReadCommitted),
+            Some(AccessMode::ReadWrite),
+        )
         .await
         .map_internal_error()?;
 
+    let new_webhook =
+        WebhookManager::create(&txn, form_id, data.endpoint.clone(), data.events.clone())
+            .await
+            .map_internal_error()?;
+
+    txn.commit().await.map_internal_error()?;
+
     Ok(Json(CreateWebhookResponse {
         id: new_webhook.id,
         signing_secret: new_webhook.signing_secret,
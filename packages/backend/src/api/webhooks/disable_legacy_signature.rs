@@ -0,0 +1,32 @@
+use palform_client_common::errors::error::{APIError, APIErrorWithStatus, APIInternalErrorResult};
+use palform_tsid::{
+    resources::{IDForm, IDOrganisation, IDWebhook},
+    tsid::PalformDatabaseID,
+};
+use rocket::{post, State};
+use rocket_okapi::openapi;
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    auth::rbac::requests::APITokenTeamEditorFromForm, entity_managers::webhooks::WebhookManager,
+};
+
+/// Stop sending the deprecated `X-Palform-Signature` header to the webhook. This can't be undone.
+#[openapi(tag = "Webhooks", operation_id = "webhooks.disable_legacy_signature")]
+#[post("/users/me/orgs/<_org_id>/forms/<form_id>/webhooks/<webhook_id>/disable_legacy_signature")]
+pub async fn handler(
+    _org_id: PalformDatabaseID<IDOrganisation>,
+    form_id: PalformDatabaseID<IDForm>,
+    webhook_id: PalformDatabaseID<IDWebhook>,
+    _token: APITokenTeamEditorFromForm,
+    db: &State<DatabaseConnection>,
+) -> Result<(), APIErrorWithStatus> {
+    if !WebhookManager::disable_legacy_signature(db.inner(), form_id, webhook_id)
+        .await
+        .map_internal_error()?
+    {
+        return Err(APIError::NotFound.into());
+    }
+
+    Ok(())
+}
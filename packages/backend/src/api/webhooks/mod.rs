@@ -1,4 +1,8 @@
 pub mod create;
 pub mod delete;
+pub mod disable_legacy_signature;
 pub mod list;
 pub mod list_jobs;
+pub mod requeue_job;
+pub mod rotate_secret;
+pub mod update_events;
@@ -0,0 +1,38 @@
+use palform_client_common::errors::error::{APIError, APIErrorWithStatus, APIInternalErrorResult};
+use palform_tsid::{
+    resources::{IDForm, IDOrganisation, IDWebhook, IDWebhookJob},
+    tsid::PalformDatabaseID,
+};
+use rocket::{post, State};
+use rocket_okapi::openapi;
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    auth::rbac::requests::APITokenTeamEditorFromForm,
+    entity_managers::{webhook_jobs::WebhookJobsManager, webhooks::WebhookManager},
+};
+
+#[openapi(tag = "Webhooks", operation_id = "webhooks.requeue_job")]
+#[post("/users/me/orgs/<_org_id>/forms/<form_id>/webhooks/<webhook_id>/jobs/<job_id>/requeue")]
+pub async fn handler(
+    _org_id: PalformDatabaseID<IDOrganisation>,
+    form_id: PalformDatabaseID<IDForm>,
+    webhook_id: PalformDatabaseID<IDWebhook>,
+    job_id: PalformDatabaseID<IDWebhookJob>,
+    _token: APITokenTeamEditorFromForm,
+    db: &State<DatabaseConnection>,
+) -> Result<(), APIErrorWithStatus> {
+    if !WebhookManager::verify_form(db.inner(), webhook_id, form_id)
+        .await
+        .map_internal_error()?
+    {
+        return Err(APIError::NotFound.into());
+    }
+
+    let wjm = WebhookJobsManager::new(db.inner());
+    if !wjm.requeue(webhook_id, job_id).await.map_internal_error()? {
+        return Err(APIError::BadRequest("Only dead jobs can be requeued".to_string()).into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use chrono::Duration;
+use palform_client_common::errors::error::{APIError, APIErrorWithStatus, APIInternalErrorResult};
+use palform_tsid::{
+    resources::{IDForm, IDOrganisation, IDWebhook},
+    tsid::PalformDatabaseID,
+};
+use rocket::{post, serde::json::Json, State};
+use rocket_okapi::{
+    okapi::schemars::{self, JsonSchema},
+    openapi,
+};
+use sea_orm::DatabaseConnection;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    auth::rbac::requests::APITokenTeamEditorFromForm, entity_managers::webhooks::WebhookManager,
+};
+
+const MAX_OVERLAP_HOURS: u32 = 24 * 7;
+
+#[derive(Deserialize, JsonSchema)]
+pub struct RotateWebhookSecretRequest {
+    /// How long the old secret should keep being used alongside the new one
+    pub overlap_hours: u32,
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct RotateWebhookSecretResponse {
+    pub signing_secret: String,
+}
+
+#[openapi(tag = "Webhooks", operation_id = "webhooks.rotate_secret")]
+#[post(
+    "/users/me/orgs/<_org_id>/forms/<form_id>/webhooks/<webhook_id>/rotate_secret",
+    data = "<data>"
+)]
+pub async fn handler(
+    _org_id: PalformDatabaseID<IDOrganisation>,
+    form_id: PalformDatabaseID<IDForm>,
+    webhook_id: PalformDatabaseID<IDWebhook>,
+    data: Json<RotateWebhookSecretRequest>,
+    _token: APITokenTeamEditorFromForm,
+    db: &State<DatabaseConnection>,
+) -> Result<Json<RotateWebhookSecretResponse>, APIErrorWithStatus> {
+    if data.overlap_hours > MAX_OVERLAP_HOURS {
+        return Err(APIError::ValidationError(format!(
+            "Overlap must be at most {} hours",
+            MAX_OVERLAP_HOURS
+        ))
+        .into());
+    }
+
+    let signing_secret = WebhookManager::rotate_signing_secret(
+        db.inner(),
+        form_id,
+        webhook_id,
+        Duration::hours(data.overlap_hours.into()),
+    )
+    .await
+    .map_internal_error()?
+    .ok_or(APIError::NotFound)?;
+
+    Ok(Json(RotateWebhookSecretResponse { signing_secret }))
+}
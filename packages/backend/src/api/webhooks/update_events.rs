@@ -0,0 +1,58 @@
+use palform_client_common::errors::error::{APIError, APIErrorWithStatus, APIInternalErrorResult};
+use palform_entities::sea_orm_active_enums::WebhookEventEnum;
+use palform_tsid::{
+    resources::{IDForm, IDOrganisation, IDWebhook},
+    tsid::PalformDatabaseID,
+};
+use rocket::{put, serde::json::Json, State};
+use rocket_okapi::{
+    okapi::schemars::{self, JsonSchema},
+    openapi,
+};
+use sea_orm::{AccessMode, DatabaseConnection, IsolationLevel, TransactionTrait};
+use serde::Deserialize;
+
+use crate::{
+    auth::rbac::requests::APITokenTeamEditorFromForm, entity_managers::webhooks::WebhookManager,
+};
+
+#[derive(Deserialize, JsonSchema)]
+pub struct UpdateWebhookEventsRequest {
+    pub events: Vec<WebhookEventEnum>,
+}
+
+#[openapi(tag = "Webhooks", operation_id = "webhooks.update_events")]
+#[put(
+    "/users/me/orgs/<_org_id>/forms/<form_id>/webhooks/<webhook_id>/events",
+    data = "<data>"
+)]
+pub async fn handler(
+    _org_id: PalformDatabaseID<IDOrganisation>,
+    form_id: PalformDatabaseID<IDForm>,
+    webhook_id: PalformDatabaseID<IDWebhook>,
+    data: Json<UpdateWebhookEventsRequest>,
+    _token: APITokenTeamEditorFromForm,
+    db: &State<DatabaseConnection>,
+) -> Result<(), APIErrorWithStatus> {
+    let txn = db
+        .begin_with_config(
+            Some(IsolationLevel::RepeatableRead),
+            Some(AccessMode::ReadWrite),
+        )
+        .await
+        .map_internal_error()?;
+
+    if !WebhookManager::verify_form(&txn, webhook_id, form_id)
+        .await
+        .map_internal_error()?
+    {
+        return Err(APIError::NotFound.into());
+    }
+
+    WebhookManager::set_events(&txn, webhook_id, data.events.clone())
+        .await
+        .map_internal_error()?;
+
+    txn.commit().await.map_internal_error()?;
+    Ok(())
+}
@@ -25,6 +25,8 @@ pub enum APIEntitlementRequest {
     OIDC,
     SubmissionAutoDelete,
     FormCaptcha,
+    /// The size in bytes of a file about to be stored
+    SubmissionFileStorage(u64),
 }
 
 impl Display for APIEntitlementRequest {
@@ -45,6 +47,7 @@ impl Display for APIEntitlementRequest {
             Self::OIDC => write!(f, "OpenID Connect is not available"),
             Self::SubmissionAutoDelete => write!(f, "Auto-deleting submissions is not available"),
             Self::FormCaptcha => write!(f, "Captcha-protected forms are not available"),
+            Self::SubmissionFileStorage(_) => write!(f, "Not enough storage for uploaded files"),
         }
     }
 }
@@ -66,6 +69,9 @@ pub struct APIEntitlementInfo {
     pub oidc: bool,
     pub submission_auto_delete: bool,
     pub form_captcha: bool,
+    /// Total size of files uploaded to the organisation's forms
+    #[serde(default)]
+    pub submission_file_storage_mb: Option<i32>,
 }
 
 impl Default for APIEntitlementInfo {
@@ -86,6 +92,7 @@ impl Default for APIEntitlementInfo {
             oidc: false,
             submission_auto_delete: false,
             form_captcha: false,
+            submission_file_storage_mb: Some(100),
         }
     }
 }
@@ -108,6 +115,7 @@ impl APIEntitlementInfo {
             oidc: Set(self.oidc),
             submission_auto_delete: Set(self.submission_auto_delete),
             form_captcha: Set(self.form_captcha),
+            submission_file_storage_mb: Set(self.submission_file_storage_mb),
             ..Default::default()
         }
     }
@@ -13,7 +13,7 @@ pub struct APIFillToken {
     form_id: PalformDatabaseID<IDForm>,
     created_at: NaiveDateTime,
     expires_at: Option<NaiveDateTime>,
-    nickname: String,
+    pub nickname: String,
     short_link: Option<String>,
 }
 
@@ -1,7 +1,8 @@
+use chrono::NaiveDateTime;
 use palform_client_common::form_management::form_end::APIFormEndConfiguration;
 use palform_client_common::form_management::question_group::APIQuestionGroup;
 use palform_client_common::form_management::question_types::APIQuestion;
-use palform_tsid::resources::{IDForm, IDFormBranding, IDTeam};
+use palform_tsid::resources::{IDForm, IDFormBranding, IDFormRevision, IDTeam};
 use palform_tsid::tsid::PalformDatabaseID;
 use rocket_okapi::okapi::schemars;
 use rocket_okapi::okapi::schemars::JsonSchema;
@@ -24,6 +25,16 @@ pub struct APIForm {
     pub end_configuration: APIFormEndConfiguration,
     pub enable_captcha: bool,
     pub one_question_per_page: bool,
+    /// The revision respondents see. Saving questions doesn't change this until the form is
+    /// published again.
+    pub published_revision_id: Option<PalformDatabaseID<IDFormRevision>>,
+    /// `None` if the form is still a draft
+    pub published_at: Option<NaiveDateTime>,
+    pub opens_at: Option<NaiveDateTime>,
+    pub closes_at: Option<NaiveDateTime>,
+    /// Respondents sign their submission with a single-use key, and get a receipt they can use to
+    /// prove what they sent
+    pub signed_submissions: bool,
 }
 
 #[derive(Serialize, JsonSchema, FromQueryResult)]
@@ -33,9 +44,19 @@ pub struct APIFrontendForm {
     pub editor_name: String,
     #[serde(skip)]
     pub branding_id: Option<PalformDatabaseID<IDFormBranding>>,
+    #[serde(skip)]
+    pub published_revision_id: Option<PalformDatabaseID<IDFormRevision>>,
     pub end_configuration: APIFormEndConfiguration,
     pub enable_captcha: bool,
     pub one_question_per_page: bool,
+    pub signed_submissions: bool,
+}
+
+#[derive(FromQueryResult)]
+pub struct FormFillSchedule {
+    pub published_at: Option<NaiveDateTime>,
+    pub opens_at: Option<NaiveDateTime>,
+    pub closes_at: Option<NaiveDateTime>,
 }
 
 #[derive(Serialize, JsonSchema)]
@@ -50,4 +71,7 @@ pub struct APIFormWithQuestions {
     pub branding: Option<APIFormBranding>,
     #[serde(rename = "o")]
     pub org_name: String,
+    /// The revision the questions and groups belong to, to be sent back with the submission
+    #[serde(rename = "r")]
+    pub revision_id: Option<PalformDatabaseID<IDFormRevision>>,
 }
@@ -0,0 +1,53 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+use palform_client_common::form_management::revision::APIFormRevision;
+use palform_tsid::{resources::IDFormRevision, tsid::PalformDatabaseID};
+use rocket_okapi::okapi::schemars;
+use rocket_okapi::okapi::schemars::JsonSchema;
+use sea_orm::FromQueryResult;
+use serde::Serialize;
+
+#[derive(Serialize, JsonSchema, Clone)]
+pub struct APIFormRevisionSummary {
+    pub id: PalformDatabaseID<IDFormRevision>,
+    pub number: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(FromQueryResult)]
+pub struct FormRevisionSummaryRow {
+    pub id: PalformDatabaseID<IDFormRevision>,
+    pub number: i32,
+    pub created_at: NaiveDateTime,
+}
+
+impl From<FormRevisionSummaryRow> for APIFormRevisionSummary {
+    fn from(value: FormRevisionSummaryRow) -> Self {
+        Self {
+            id: value.id,
+            number: value.number,
+            created_at: value.created_at.and_utc(),
+        }
+    }
+}
+
+#[derive(FromQueryResult, Clone)]
+pub struct FormRevisionWithEncodedSchema {
+    pub id: PalformDatabaseID<IDFormRevision>,
+    pub number: i32,
+    pub created_at: NaiveDateTime,
+    pub groups: sea_orm::JsonValue,
+    pub questions: sea_orm::JsonValue,
+}
+
+impl TryFrom<FormRevisionWithEncodedSchema> for APIFormRevision {
+    type Error = serde_json::Error;
+    fn try_from(value: FormRevisionWithEncodedSchema) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: value.id,
+            number: value.number,
+            created_at: value.created_at.and_utc(),
+            groups: serde_json::from_value(value.groups)?,
+            questions: serde_json::from_value(value.questions)?,
+        })
+    }
+}
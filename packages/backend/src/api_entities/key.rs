@@ -20,6 +20,8 @@ pub struct APIUserKey {
     pub has_backup: bool,
     pub created_at: NaiveDateTime,
     pub expires_at: NaiveDateTime,
+    /// New submissions aren't encrypted to revoked keys
+    pub revoked_at: Option<NaiveDateTime>,
 }
 
 impl TryFrom<admin_public_key::Model> for APIUserKey {
@@ -34,6 +36,7 @@ impl TryFrom<admin_public_key::Model> for APIUserKey {
             has_backup: value.private_key_backup.is_some(),
             created_at: value.created_at,
             expires_at: value.expires_at,
+            revoked_at: value.revoked_at,
         })
     }
 }
@@ -47,6 +50,7 @@ pub struct UserKeyWithIdentity {
     pub user_display_name: Option<String>,
     pub created_at: NaiveDateTime,
     pub expires_at: NaiveDateTime,
+    pub revoked_at: Option<NaiveDateTime>,
 }
 
 #[derive(Serialize, JsonSchema, Clone)]
@@ -58,4 +62,5 @@ pub struct APIUserKeyWithIdentity {
     pub user_display_name: Option<String>,
     pub created_at: NaiveDateTime,
     pub expires_at: NaiveDateTime,
+    pub revoked_at: Option<NaiveDateTime>,
 }
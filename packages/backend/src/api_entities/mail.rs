@@ -0,0 +1,19 @@
+use chrono::NaiveDateTime;
+use palform_tsid::{resources::IDMailOutboxItem, tsid::PalformDatabaseID};
+use rocket_okapi::okapi::schemars::{self, JsonSchema};
+use sea_orm::FromQueryResult;
+use serde::Serialize;
+
+/// An email about the organisation that hasn't been delivered yet, either because it's waiting to
+/// be retried or because it ran out of retries
+#[derive(Serialize, JsonSchema, Clone, FromQueryResult)]
+pub struct APIFailedMail {
+    pub id: PalformDatabaseID<IDMailOutboxItem>,
+    pub recipient: String,
+    pub template: String,
+    pub created_at: NaiveDateTime,
+    pub retries: i32,
+    pub error: Option<String>,
+    pub next_attempt_at: NaiveDateTime,
+    pub dead_at: Option<NaiveDateTime>,
+}
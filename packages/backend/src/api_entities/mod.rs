@@ -6,8 +6,10 @@ pub mod feedback_items;
 pub mod fill_token;
 pub mod form;
 pub mod form_brandings;
+pub mod form_revision;
 pub mod form_template;
 pub mod key;
+pub mod mail;
 pub mod org;
 pub mod organisation_auth_config;
 pub mod organisation_auth_team_mapping;
@@ -16,6 +18,7 @@ pub mod organisation_member;
 pub mod organisation_team;
 pub mod question;
 pub mod question_group;
+pub mod service_account_api_key;
 pub mod submission;
 pub mod team_asset;
 pub mod webhook;
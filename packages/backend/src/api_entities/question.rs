@@ -1,5 +1,5 @@
 use palform_client_common::form_management::question_types::{
-    APIQuestion, APIQuestionConfiguration,
+    APIQuestion, APIQuestionConfiguration, APIQuestionServerValidation,
 };
 use palform_entities::question;
 use palform_tsid::{
@@ -17,12 +17,17 @@ pub struct QuestionWithEncodedConfiguration {
     pub required: bool,
     pub group_id: PalformDatabaseID<IDQuestionGroup>,
     pub configuration: sea_orm::JsonValue,
+    pub server_validation: Option<sea_orm::JsonValue>,
 }
 
 impl TryFrom<QuestionWithEncodedConfiguration> for APIQuestion {
     type Error = serde_json::Error;
     fn try_from(value: QuestionWithEncodedConfiguration) -> Result<Self, Self::Error> {
         let config: APIQuestionConfiguration = serde_json::from_value(value.configuration)?;
+        let server_validation = value
+            .server_validation
+            .map(serde_json::from_value)
+            .transpose()?;
         Ok(Self {
             id: value.id,
             title: value.title,
@@ -31,6 +36,7 @@ impl TryFrom<QuestionWithEncodedConfiguration> for APIQuestion {
             required: value.required,
             group_id: value.group_id,
             configuration: config,
+            server_validation,
         })
     }
 }
@@ -41,6 +47,12 @@ impl QuestionWithEncodedConfiguration {
     ) -> Result<sea_orm::JsonValue, serde_json::Error> {
         serde_json::to_value(config)
     }
+
+    pub fn encode_server_validation(
+        server_validation: Option<APIQuestionServerValidation>,
+    ) -> Result<Option<sea_orm::JsonValue>, serde_json::Error> {
+        server_validation.map(serde_json::to_value).transpose()
+    }
 }
 
 impl From<question::Model> for QuestionWithEncodedConfiguration {
@@ -53,6 +65,7 @@ impl From<question::Model> for QuestionWithEncodedConfiguration {
             required: value.required,
             group_id: value.group_id,
             configuration: value.configuration,
+            server_validation: value.server_validation,
         }
     }
 }
@@ -0,0 +1,24 @@
+use chrono::NaiveDateTime;
+use palform_tsid::{
+    resources::{IDAdminUser, IDServiceAccountAPIKey},
+    tsid::PalformDatabaseID,
+};
+use rocket_okapi::okapi::schemars::{self, JsonSchema};
+use serde::Serialize;
+
+/// An API key for a service account in the organisation. The key itself is only shown once, when
+/// it's created.
+#[derive(Serialize, JsonSchema, Clone)]
+pub struct APIServiceAccountAPIKey {
+    pub id: PalformDatabaseID<IDServiceAccountAPIKey>,
+    pub user_id: PalformDatabaseID<IDAdminUser>,
+    pub name: String,
+    /// The first few characters of the key, to help tell keys apart
+    pub prefix: String,
+    /// e.g. `forms:write`
+    pub scopes: Vec<String>,
+    pub created_at: NaiveDateTime,
+    /// Keys without an expiry last until they're revoked
+    pub expires_at: Option<NaiveDateTime>,
+    pub last_used_at: Option<NaiveDateTime>,
+}
@@ -1,13 +1,16 @@
 use chrono::{DateTime, Utc};
+use palform_client_common::form_management::submission_chain::{
+    SubmissionChainCheckpoint, SubmissionChainEntry,
+};
 use palform_entities::submission;
 use palform_tsid::{
-    resources::{IDFillAccessToken, IDForm, IDSubmission, IDTeam},
+    resources::{IDFillAccessToken, IDForm, IDFormRevision, IDSubmission, IDTeam},
     tsid::PalformDatabaseID,
 };
 use rocket_okapi::okapi::schemars;
 use rocket_okapi::okapi::schemars::JsonSchema;
 use sea_orm::FromQueryResult;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::crypto::submissions::{CryptoSubmissionRepr, SubmissionConversionError};
 
@@ -24,7 +27,13 @@ pub struct APISubmission {
     pub id: PalformDatabaseID<IDSubmission>,
     pub created_at: DateTime<Utc>,
     pub for_token: Option<PalformDatabaseID<IDFillAccessToken>>,
+    /// The revision of the form this submission was filled against. Submissions made before
+    /// revisions were introduced don't have one, and use the form's current questions.
+    pub form_revision_id: Option<PalformDatabaseID<IDFormRevision>>,
     pub data: String,
+    /// The public key the respondent signed the submission with, if the form asked for signed
+    /// submissions
+    pub signing_key: Option<String>,
 }
 
 impl TryFrom<submission::Model> for APISubmission {
@@ -35,17 +44,41 @@ impl TryFrom<submission::Model> for APISubmission {
             id: value.id,
             created_at: value.created_at.and_utc(),
             for_token: value.for_token,
+            form_revision_id: value.form_revision_id,
             data: pem_data,
+            signing_key: value.signing_key,
         })
     }
 }
 
+/// New PKESK packets for a submission, made by re-wrapping its session key on the client
+#[derive(Deserialize, JsonSchema)]
+pub struct APISubmissionRewrap {
+    pub submission_id: PalformDatabaseID<IDSubmission>,
+    /// Armored PKESK packets to add to the submission's message. A packet for a key that an earlier
+    /// rewrap added a packet for replaces it.
+    pub key_packets: String,
+}
+
+/// The whole of a form's submission chain, and a checkpoint of its head signed by the server
+#[derive(Serialize, JsonSchema)]
+pub struct APISubmissionChain {
+    pub entries: Vec<SubmissionChainEntry>,
+    pub checkpoint: SubmissionChainCheckpoint,
+    /// Base64-encoded Ed25519 signature of the checkpoint's signed message
+    pub signature: String,
+    /// Base64-encoded Ed25519 public key that made the signature. Clients should check this against
+    /// a key they already trust, rather than trusting it as-is.
+    pub signing_key: String,
+}
+
 #[derive(Serialize, Clone)]
 pub struct APISubmissionWebhookPayload {
     pub submission_id: PalformDatabaseID<IDSubmission>,
     pub form_id: PalformDatabaseID<IDForm>,
     pub created_at: DateTime<Utc>,
     pub payload: String,
+    pub signing_key: Option<String>,
 }
 
 #[derive(Serialize, JsonSchema, Clone, FromQueryResult)]
@@ -1,12 +1,15 @@
-use chrono::NaiveDateTime;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use palform_entities::sea_orm_active_enums::WebhookEventEnum;
 use palform_tsid::{
-    resources::{IDForm, IDWebhook, IDWebhookJob},
+    resources::{IDFillAccessToken, IDForm, IDSubmission, IDWebhook, IDWebhookJob},
     tsid::PalformDatabaseID,
 };
 use rocket_okapi::okapi::schemars::{self, JsonSchema};
 use sea_orm::FromQueryResult;
 use serde::Serialize;
 
+use super::submission::APISubmissionWebhookPayload;
+
 #[derive(Serialize, JsonSchema, Clone)]
 pub struct APIWebhook {
     pub id: PalformDatabaseID<IDWebhook>,
@@ -14,12 +17,72 @@ pub struct APIWebhook {
     pub endpoint: String,
     pub created_at: NaiveDateTime,
     pub is_healthy: bool,
+    pub events: Vec<WebhookEventEnum>,
+    /// Whether the deprecated `X-Palform-Signature` header is still sent. Only webhooks created
+    /// before it was deprecated have this on.
+    pub legacy_signature: bool,
 }
 
 #[derive(Serialize, JsonSchema, Clone, FromQueryResult)]
 pub struct APIWebhookJob {
     pub id: PalformDatabaseID<IDWebhookJob>,
+    pub event: WebhookEventEnum,
     pub done_at: Option<NaiveDateTime>,
     pub retries: Option<i32>,
     pub error: Option<String>,
+    pub next_attempt_at: NaiveDateTime,
+    pub dead_at: Option<NaiveDateTime>,
+}
+
+/// The body sent to a webhook endpoint. The `event` field says which of the variants it is.
+#[derive(Serialize, Clone)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum APIWebhookPayload {
+    SubmissionCreated(APISubmissionWebhookPayload),
+    SubmissionDeleted(APISubmissionDeletedWebhookPayload),
+    FormUpdated(APIFormUpdatedWebhookPayload),
+    FillTokenCreated(APIFillTokenWebhookPayload),
+    FillTokenExpired(APIFillTokenWebhookPayload),
+    AutoDeleteRan(APIAutoDeleteWebhookPayload),
+}
+
+impl APIWebhookPayload {
+    pub fn event(&self) -> WebhookEventEnum {
+        match self {
+            Self::SubmissionCreated(_) => WebhookEventEnum::SubmissionCreated,
+            Self::SubmissionDeleted(_) => WebhookEventEnum::SubmissionDeleted,
+            Self::FormUpdated(_) => WebhookEventEnum::FormUpdated,
+            Self::FillTokenCreated(_) => WebhookEventEnum::FillTokenCreated,
+            Self::FillTokenExpired(_) => WebhookEventEnum::FillTokenExpired,
+            Self::AutoDeleteRan(_) => WebhookEventEnum::AutoDeleteRan,
+        }
+    }
+}
+
+#[derive(Serialize, Clone)]
+pub struct APISubmissionDeletedWebhookPayload {
+    pub submission_id: PalformDatabaseID<IDSubmission>,
+    pub form_id: PalformDatabaseID<IDForm>,
+    pub deleted_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Clone)]
+pub struct APIFormUpdatedWebhookPayload {
+    pub form_id: PalformDatabaseID<IDForm>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Clone)]
+pub struct APIFillTokenWebhookPayload {
+    pub fill_token_id: PalformDatabaseID<IDFillAccessToken>,
+    pub form_id: PalformDatabaseID<IDForm>,
+    pub nickname: String,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, Clone)]
+pub struct APIAutoDeleteWebhookPayload {
+    pub form_id: PalformDatabaseID<IDForm>,
+    pub deleted_submission_ids: Vec<PalformDatabaseID<IDSubmission>>,
+    pub ran_at: DateTime<Utc>,
 }
@@ -0,0 +1,182 @@
+use std::fmt::Display;
+
+use rocket::{http::Method, Request};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+#[error("Unknown API key scope: {0}")]
+pub struct UnknownScopeError(String);
+
+/// Routes that use POST to send a query, rather than to change anything
+const READ_ONLY_POST_ROUTES: [&str; 2] = [
+    // submissions.num_since
+    "/users/me/orgs/<org_id>/submissions",
+    // billing.entitlement.test
+    "/users/me/orgs/<org_id>/billing/entitlements/test",
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum APIKeyResource {
+    /// The organisation itself, including its settings, billing, audit logs and auth config
+    Org,
+    /// Organisation members and invites
+    Members,
+    /// Teams, along with their members, assets and brandings
+    Teams,
+    Keys,
+    Forms,
+    Submissions,
+    Webhooks,
+}
+
+impl APIKeyResource {
+    const ALL: [Self; 7] = [
+        Self::Org,
+        Self::Members,
+        Self::Teams,
+        Self::Keys,
+        Self::Forms,
+        Self::Submissions,
+        Self::Webhooks,
+    ];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Org => "org",
+            Self::Members => "members",
+            Self::Teams => "teams",
+            Self::Keys => "keys",
+            Self::Forms => "forms",
+            Self::Submissions => "submissions",
+            Self::Webhooks => "webhooks",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum APIKeyAccess {
+    Read,
+    /// Also grants read access
+    Write,
+}
+
+/// A permission granted to a service account API key, written as e.g. `forms:write`. Keys are
+/// still limited by the service account's own organisation and team roles.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct APIKeyScope {
+    pub resource: APIKeyResource,
+    pub access: APIKeyAccess,
+}
+
+impl APIKeyScope {
+    /// Whether a key with this scope can be used for something that needs `required`
+    pub fn grants(&self, required: &APIKeyScope) -> bool {
+        self.resource == required.resource && self.access >= required.access
+    }
+
+    /// Works out which scope an API key needs to use the route handling `request`, based on the
+    /// part of the organisation it accesses. Anything other than GET needs write access, unless
+    /// it's listed in `READ_ONLY_POST_ROUTES`.
+    pub fn required_for_request(request: &Request<'_>) -> Option<Self> {
+        let route = request.route()?;
+
+        // Skip the `/users/me/orgs/<org_id>` part, and any IDs
+        let mut segments = route
+            .uri
+            .path()
+            .split('/')
+            .filter(|s| !s.is_empty() && !s.starts_with('<'))
+            .skip(3)
+            // e.g. `teams.my`
+            .map(|s| s.split('.').next().unwrap_or(s));
+
+        let resource = match segments.next() {
+            None => APIKeyResource::Org,
+            Some("forms") => {
+                let mut resource = APIKeyResource::Forms;
+                for segment in segments {
+                    match segment {
+                        "submissions" | "submission-assets" => {
+                            resource = APIKeyResource::Submissions
+                        }
+                        "webhooks" => resource = APIKeyResource::Webhooks,
+                        _ => {}
+                    }
+                }
+                resource
+            }
+            Some("submissions") => APIKeyResource::Submissions,
+            Some("teams") => APIKeyResource::Teams,
+            Some("members") | Some("invites") => APIKeyResource::Members,
+            Some("keys") => APIKeyResource::Keys,
+            Some(_) => APIKeyResource::Org,
+        };
+
+        let access = if request.method() == Method::Get
+            || (request.method() == Method::Post
+                && READ_ONLY_POST_ROUTES.contains(&route.uri.path()))
+        {
+            APIKeyAccess::Read
+        } else {
+            APIKeyAccess::Write
+        };
+
+        Some(Self { resource, access })
+    }
+
+    /// Parses a comma-separated list of scopes
+    pub fn parse_list(list: &str) -> Result<Vec<Self>, UnknownScopeError> {
+        list.split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(|s| Self::try_from(s.to_string()))
+            .collect()
+    }
+
+    pub fn all_names() -> Vec<String> {
+        APIKeyResource::ALL
+            .iter()
+            .flat_map(|&resource| {
+                [APIKeyAccess::Read, APIKeyAccess::Write]
+                    .map(|access| Self { resource, access }.to_string())
+            })
+            .collect()
+    }
+}
+
+impl Display for APIKeyScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let access = match self.access {
+            APIKeyAccess::Read => "read",
+            APIKeyAccess::Write => "write",
+        };
+        write!(f, "{}:{}", self.resource.name(), access)
+    }
+}
+
+impl TryFrom<String> for APIKeyScope {
+    type Error = UnknownScopeError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let (resource, access) = value
+            .split_once(':')
+            .ok_or_else(|| UnknownScopeError(value.clone()))?;
+        let resource = APIKeyResource::ALL
+            .into_iter()
+            .find(|r| r.name() == resource)
+            .ok_or_else(|| UnknownScopeError(value.clone()))?;
+        let access = match access {
+            "read" => APIKeyAccess::Read,
+            "write" => APIKeyAccess::Write,
+            _ => return Err(UnknownScopeError(value)),
+        };
+        Ok(Self { resource, access })
+    }
+}
+
+impl From<APIKeyScope> for String {
+    fn from(value: APIKeyScope) -> Self {
+        value.to_string()
+    }
+}
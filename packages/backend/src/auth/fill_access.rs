@@ -6,7 +6,6 @@ use palform_tsid::{
     tsid::PalformDatabaseID,
 };
 use rocket::{
-    outcome::try_outcome,
     request::{self, FromRequest},
     serde::json::Json,
 };
@@ -16,101 +15,127 @@ use rocket_okapi::{
 };
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DatabaseConnection, DbErr,
-    EntityTrait, JoinType, PaginatorTrait, QueryFilter, QuerySelect, RelationTrait, Set,
+    EntityTrait, FromQueryResult, JoinType, PaginatorTrait, QueryFilter, QuerySelect,
+    RelationTrait, Set,
 };
 
 use crate::{
     api_entities::fill_token::{APIExchangedShortLink, APIFillToken},
+    entity_managers::forms::FormManager,
     i18n::request::I18NManager,
-    into_outcome, pt,
+    pt,
 };
 
+#[derive(Clone)]
 pub struct APIFillAccessToken {
     pub token_id: PalformDatabaseID<IDFillAccessToken>,
     pub form_id: PalformDatabaseID<IDForm>,
+    /// The organisation that owns the form
+    pub org_id: PalformDatabaseID<IDOrganisation>,
 }
 
-#[rocket::async_trait]
-impl<'a> FromRequest<'a> for APIFillAccessToken {
-    type Error = Json<APIError>;
-    async fn from_request(
-        request: &'a request::Request<'_>,
-    ) -> request::Outcome<Self, Self::Error> {
-        let i18n_manager = try_outcome!(I18NManager::from_request(request).await);
-
-        let fill_access_token = into_outcome!(
-            into_outcome!(
-                request
-                    .query_value::<PalformDatabaseID<IDFillAccessToken>>("f")
-                    .ok_or(APIError::BadRequest("Missing `f` parameter".to_string())),
-                request
-            )
-            .map_err(|e| {
-                APIError::BadRequest(format!("Failed to parse fill token: {}", e))
-            }),
-            request
-        );
-
-        let form_id = into_outcome!(
-            into_outcome!(
-                request
-                    .param::<PalformDatabaseID<IDForm>>(4)
-                    .ok_or(APIError::BadRequest(
-                        "Wrong number of path segments".to_string(),
-                    )),
-                request
-            )
+impl APIFillAccessToken {
+    async fn resolve(request: &request::Request<'_>) -> Result<Self, APIError> {
+        let i18n_manager = I18NManager::from_request(request)
+            .await
+            .succeeded()
+            .ok_or_else(|| {
+                log::error!("Get I18N manager");
+                APIError::Internal
+            })?;
+
+        let fill_access_token = request
+            .query_value::<PalformDatabaseID<IDFillAccessToken>>("f")
+            .ok_or(APIError::BadRequest("Missing `f` parameter".to_string()))?
+            .map_err(|e| APIError::BadRequest(format!("Failed to parse fill token: {}", e)))?;
+
+        let form_id = request
+            .param::<PalformDatabaseID<IDForm>>(4)
+            .ok_or(APIError::BadRequest(
+                "Wrong number of path segments".to_string(),
+            ))?
+            .map_err(|e| APIError::BadRequest(format!("Failed to parse form_id segment: {}", e)))?;
+
+        let db = request
+            .rocket()
+            .state::<DatabaseConnection>()
+            .ok_or_else(|| {
+                log::error!("DB not found in state");
+                APIError::Internal
+            })?;
+
+        let token_data = FillAccessTokenManager::lookup(db, fill_access_token)
+            .await
             .map_err(|e| {
-                APIError::BadRequest(format!(
-                    "Failed to parse form_id segment: {}",
-                    e
-                ))
-            }),
-            request
-        );
-
-        let db = into_outcome!(
-            request
-                .rocket()
-                .state::<DatabaseConnection>()
-                .ok_or_else(|| APIError::report_internal_error_without_error(
-                    "DB not found in state"
-                )),
-            request
-        );
-
-        let token_data = into_outcome!(
-            into_outcome!(
-                FillAccessTokenManager::lookup(db, fill_access_token)
-                    .await
-                    .map_err(|e| APIError::report_internal_error("Lookup fill token data", e)),
-                request
-            )
-            .ok_or(APIError::NotAllowed),
-            request
-        );
+                log::error!("Lookup fill token data: {}", e);
+                APIError::Internal
+            })?
+            .ok_or(APIError::NotAllowed)?;
 
         if token_data.form_id != form_id {
-            let err = APIError::NotFound;
-            request.local_cache(|| err.clone());
-            return request::Outcome::Error(err.into());
+            return Err(APIError::NotFound);
         }
 
         if let Some(expires_at) = token_data.expires_at {
             if expires_at < Utc::now().naive_utc() {
-                let err = APIError::BadRequest(pt!(i18n_manager, "fill_form_expired",));
-                request.local_cache(|| err.clone());
-                return request::Outcome::Error(err.into());
+                return Err(APIError::BadRequest(
+                    pt!(i18n_manager, "fill_form_expired",),
+                ));
             }
         }
 
-        request::Outcome::Success(APIFillAccessToken {
+        let schedule = FormManager::get_fill_schedule(db, form_id)
+            .await
+            .map_err(|e| {
+                log::error!("Get form schedule: {}", e);
+                APIError::Internal
+            })?
+            .ok_or(APIError::NotFound)?;
+
+        let now = Utc::now().naive_utc();
+        if schedule.published_at.is_none() {
+            return Err(APIError::BadRequest(pt!(
+                i18n_manager,
+                "fill_form_not_published",
+            )));
+        } else if schedule.opens_at.is_some_and(|opens_at| now < opens_at) {
+            return Err(APIError::BadRequest(pt!(
+                i18n_manager,
+                "fill_form_not_open",
+            )));
+        } else if schedule.closes_at.is_some_and(|closes_at| now >= closes_at) {
+            return Err(APIError::BadRequest(pt!(i18n_manager, "fill_form_closed",)));
+        }
+
+        Ok(APIFillAccessToken {
             token_id: token_data.id,
             form_id: token_data.form_id,
+            org_id: token_data.org_id,
         })
     }
 }
 
+#[rocket::async_trait]
+impl<'a> FromRequest<'a> for APIFillAccessToken {
+    type Error = Json<APIError>;
+    async fn from_request(
+        request: &'a request::Request<'_>,
+    ) -> request::Outcome<Self, Self::Error> {
+        // Cached so that other guards (like the fill rate limit) can use the token without
+        // looking it up again
+        let resolved = request
+            .local_cache_async(async { Self::resolve(request).await })
+            .await;
+        match resolved {
+            Ok(token) => request::Outcome::Success(token.clone()),
+            Err(err) => {
+                request.local_cache(|| err.clone());
+                request::Outcome::Error(err.clone().into())
+            }
+        }
+    }
+}
+
 impl<'a> OpenApiFromRequest<'a> for APIFillAccessToken {
     fn from_request_input(
         _gen: &mut rocket_okapi::gen::OpenApiGenerator,
@@ -133,13 +158,31 @@ impl<'a> OpenApiFromRequest<'a> for APIFillAccessToken {
     }
 }
 
+#[derive(FromQueryResult)]
+struct FillAccessTokenWithOrg {
+    id: PalformDatabaseID<IDFillAccessToken>,
+    form_id: PalformDatabaseID<IDForm>,
+    expires_at: Option<NaiveDateTime>,
+    org_id: PalformDatabaseID<IDOrganisation>,
+}
+
 pub struct FillAccessTokenManager;
 impl FillAccessTokenManager {
     async fn lookup<T: ConnectionTrait>(
         conn: &T,
         id: PalformDatabaseID<IDFillAccessToken>,
-    ) -> Result<Option<fill_access_token::Model>, DbErr> {
-        FillAccessToken::find_by_id(id).one(conn).await
+    ) -> Result<Option<FillAccessTokenWithOrg>, DbErr> {
+        FillAccessToken::find_by_id(id)
+            .join(JoinType::InnerJoin, fill_access_token::Relation::Form.def())
+            .join(JoinType::InnerJoin, form::Relation::Team.def())
+            .select_only()
+            .column(fill_access_token::Column::Id)
+            .column(fill_access_token::Column::FormId)
+            .column(fill_access_token::Column::ExpiresAt)
+            .column_as(team::Column::OrganisationId, "org_id")
+            .into_model()
+            .one(conn)
+            .await
     }
 
     pub async fn list_for_form<T: ConnectionTrait>(
@@ -1,3 +1,4 @@
+pub mod api_keys;
 pub mod fill_access;
 pub mod oidc;
 pub mod oidc_common;
@@ -19,6 +19,7 @@ use sea_orm::DatabaseConnection;
 
 use crate::{
     auth::{
+        api_keys::APIKeyScope,
         rbac::teams_manager::TeamsRBACManager,
         tokens::{APIAuthToken, APIAuthTokenSource, APIAuthTokenSourceAny},
     },
@@ -170,6 +171,17 @@ impl<'a, Role: TeamRoleType, Target: OrgRoleTargetType, Source: APIAuthTokenSour
         );
 
         let target = Target::target();
+
+        let required_scope = into_outcome!(
+            APIKeyScope::required_for_request(request).ok_or_else(|| {
+                APIError::report_internal_error_without_error("Request has no matching route")
+            }),
+            request
+        );
+        if !api_token.has_scope(&required_scope) {
+            into_outcome!(Err(APIError::NotAllowed), request);
+        }
+
         if target == OrgRoleTarget::OrgViewAndTeamRoleFromForm
             || target == OrgRoleTarget::OrgViewAndTeamRoleFromTeam
         {
@@ -245,7 +257,7 @@ impl<'a, Role: TeamRoleType, Target: OrgRoleTargetType, Source: APIAuthTokenSour
             description += " Allows frontend user tokens (HTTP basic auth) generated with the authentication endpoints.";
         }
         if Source::allow_service_account() {
-            description += " Allows service-account tokens via the public Palform API (HTTP bearer auth). Please use the format `Bearer {service_account_token}`.";
+            description += " Allows service-account tokens via the public Palform API (HTTP bearer auth). Please use the format `Bearer {api_key}`. API keys only work on routes covered by their scopes.";
         }
 
         if let Some(role) = Role::role() {
@@ -28,7 +28,10 @@ use sea_orm::{
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::{entity_managers::admin_users::AdminUserManager, into_outcome};
+use crate::{
+    auth::api_keys::APIKeyScope,
+    entity_managers::service_account_api_keys::ServiceAccountAPIKeysManager, into_outcome,
+};
 
 #[derive(Deserialize, Serialize, JsonSchema)]
 pub struct NewAPIAuthToken {
@@ -42,8 +45,10 @@ pub trait APIAuthTokenSource: Clone + Send {
     fn allow_personal() -> bool;
 
     fn from_personal(model: auth_token::Model) -> Self;
-    fn from_service_account(model: admin_user::Model) -> Self;
+    fn from_service_account(model: admin_user::Model, scopes: Vec<APIKeyScope>) -> Self;
     fn get_user_id(&self) -> PalformDatabaseID<IDAdminUser>;
+    /// Personal tokens have every scope, but service account API keys are limited to theirs
+    fn has_scope(&self, scope: &APIKeyScope) -> bool;
 
     fn to_string() -> String;
 }
@@ -62,12 +67,15 @@ impl APIAuthTokenSource for APIAuthTokenSourcePersonal {
     fn from_personal(model: auth_token::Model) -> Self {
         Self { model }
     }
-    fn from_service_account(_: admin_user::Model) -> Self {
+    fn from_service_account(_: admin_user::Model, _: Vec<APIKeyScope>) -> Self {
         unimplemented!("Cannot construct Personal token for ServiceAccount")
     }
     fn get_user_id(&self) -> PalformDatabaseID<IDAdminUser> {
         self.model.user_id
     }
+    fn has_scope(&self, _: &APIKeyScope) -> bool {
+        true
+    }
     fn to_string() -> String {
         "Personal".to_string()
     }
@@ -76,6 +84,7 @@ impl APIAuthTokenSource for APIAuthTokenSourcePersonal {
 #[derive(Clone)]
 pub struct APIAuthTokenSourceServiceAccount {
     pub model: admin_user::Model,
+    pub scopes: Vec<APIKeyScope>,
 }
 impl APIAuthTokenSource for APIAuthTokenSourceServiceAccount {
     fn allow_service_account() -> bool {
@@ -87,12 +96,15 @@ impl APIAuthTokenSource for APIAuthTokenSourceServiceAccount {
     fn from_personal(_: auth_token::Model) -> Self {
         unimplemented!("Cannot construct ServiceAccount token for Personal")
     }
-    fn from_service_account(model: admin_user::Model) -> Self {
-        Self { model }
+    fn from_service_account(model: admin_user::Model, scopes: Vec<APIKeyScope>) -> Self {
+        Self { model, scopes }
     }
     fn get_user_id(&self) -> PalformDatabaseID<IDAdminUser> {
         self.model.id
     }
+    fn has_scope(&self, scope: &APIKeyScope) -> bool {
+        self.scopes.iter().any(|s| s.grants(scope))
+    }
     fn to_string() -> String {
         "ServiceAccount".to_string()
     }
@@ -101,7 +113,7 @@ impl APIAuthTokenSource for APIAuthTokenSourceServiceAccount {
 #[derive(Clone)]
 pub enum APIAuthTokenSourceAny {
     Personal(auth_token::Model),
-    ServiceAccount(admin_user::Model),
+    ServiceAccount(admin_user::Model, Vec<APIKeyScope>),
 }
 impl APIAuthTokenSource for APIAuthTokenSourceAny {
     fn allow_personal() -> bool {
@@ -113,15 +125,21 @@ impl APIAuthTokenSource for APIAuthTokenSourceAny {
     fn from_personal(model: auth_token::Model) -> Self {
         Self::Personal(model)
     }
-    fn from_service_account(model: admin_user::Model) -> Self {
-        Self::ServiceAccount(model)
+    fn from_service_account(model: admin_user::Model, scopes: Vec<APIKeyScope>) -> Self {
+        Self::ServiceAccount(model, scopes)
     }
     fn get_user_id(&self) -> PalformDatabaseID<IDAdminUser> {
         match self {
-            Self::ServiceAccount(m) => m.id,
+            Self::ServiceAccount(m, _) => m.id,
             Self::Personal(m) => m.user_id,
         }
     }
+    fn has_scope(&self, scope: &APIKeyScope) -> bool {
+        match self {
+            Self::ServiceAccount(_, scopes) => scopes.iter().any(|s| s.grants(scope)),
+            Self::Personal(_) => true,
+        }
+    }
     fn to_string() -> String {
         "Any".to_string()
     }
@@ -238,21 +256,21 @@ impl<'a, Source: APIAuthTokenSource> FromRequest<'a> for APIAuthToken<Source> {
 
         if Source::allow_service_account() {
             if let Some(bearer_header) = bearer_header {
-                let matching_user = into_outcome!(
-                    AdminUserManager::get_user_by_service_account_token(
-                        db,
-                        bearer_header.to_string()
-                    )
-                    .await
-                    .map_internal_error(),
+                let matching_key = into_outcome!(
+                    ServiceAccountAPIKeysManager::authenticate(db, bearer_header)
+                        .await
+                        .map_internal_error(),
                     request
                 );
 
-                let matching_user =
-                    into_outcome!(matching_user.ok_or(APIError::NotAllowed), request);
+                let (matching_user, matching_key) =
+                    into_outcome!(matching_key.ok_or(APIError::NotAllowed), request);
 
                 return request::Outcome::Success(Self {
-                    source: Source::from_service_account(matching_user),
+                    source: Source::from_service_account(
+                        matching_user,
+                        ServiceAccountAPIKeysManager::scopes(&matching_key),
+                    ),
                 });
             }
         }
@@ -273,7 +291,7 @@ impl<'a, Source: APIAuthTokenSource> OpenApiFromRequest<'a> for APIAuthToken<Sou
             description += " Allows frontend user tokens (HTTP basic auth) generated with the authentication endpoints.";
         }
         if Source::allow_service_account() {
-            description += " Allows service-account tokens via the public Palform API (HTTP bearer auth). Please use the format `Bearer {service_account_token}`.";
+            description += " Allows service-account tokens via the public Palform API (HTTP bearer auth). Please use the format `Bearer {api_key}`. API keys only work on routes covered by their scopes.";
         }
 
         let security_scheme = SecurityScheme {
@@ -285,7 +303,7 @@ impl<'a, Source: APIAuthTokenSource> OpenApiFromRequest<'a> for APIAuthToken<Sou
                     "bearer".to_owned()
                 },
                 bearer_format: if Source::allow_service_account() {
-                    Some("Service Account authentication token".to_owned())
+                    Some("Service account API key".to_owned())
                 } else {
                     None
                 },
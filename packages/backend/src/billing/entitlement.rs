@@ -16,11 +16,14 @@ use crate::{
         organisation_members::OrganisationMembersManager,
         organisation_teams::OrganisationTeamsManager,
         questions::QuestionManager,
+        submission_files::SubmissionFilesManager,
     },
 };
 
 use super::error::BillingError;
 
+const BYTES_PER_MB: u64 = 1_000_000;
+
 /// Don't use this struct directly, use
 /// [BillingEntitlementManager](crate::entity_managers::billing_entitlement::BillingEntitlementManager) instead
 pub struct INTERNALBillingEntitlementManager {
@@ -135,6 +138,14 @@ impl INTERNALBillingEntitlementManager {
             APIEntitlementRequest::OIDC => oe.oidc,
             APIEntitlementRequest::SubmissionAutoDelete => oe.submission_auto_delete,
             APIEntitlementRequest::FormCaptcha => oe.form_captcha,
+            APIEntitlementRequest::SubmissionFileStorage(upload_size) => {
+                if let Some(storage_mb) = oe.submission_file_storage_mb {
+                    let used = SubmissionFilesManager::billing_count(conn, self.org_id).await?;
+                    used + upload_size <= storage_mb as u64 * BYTES_PER_MB
+                } else {
+                    true
+                }
+            }
         })
     }
 
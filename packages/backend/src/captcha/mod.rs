@@ -7,58 +7,20 @@ use rocket_okapi::{
     okapi::{schemars::schema::InstanceType, Map},
     request::OpenApiFromRequest,
 };
-use serde::{Deserialize, Serialize};
-use thiserror::Error;
 
-use crate::{config::Config, into_outcome};
+use crate::into_outcome;
 
-pub struct VerifiedCaptcha;
-
-#[derive(Debug, Error)]
-pub enum CaptchaVerificationError {
-    #[error("connection: {0}")]
-    Request(#[from] reqwest::Error),
-    #[error("failed")]
-    CaptchaFailed,
-}
+use self::verifier::PalformCaptcha;
 
-#[derive(Serialize)]
-struct CaptchaVerificationRequest {
-    secret: String,
-    response: String,
-}
-
-#[derive(Deserialize)]
-struct CaptchaVerificationResponse {
-    success: bool,
-}
+pub mod provider;
+pub mod verifier;
 
-impl VerifiedCaptcha {
-    async fn verify_token(config: &Config, token: &str) -> Result<(), CaptchaVerificationError> {
-        if config.skip_captcha {
-            return Ok(());
-        }
+mod proof_of_work;
+mod siteverify;
 
-        let params = CaptchaVerificationRequest {
-            secret: config.captcha_secret_key.clone(),
-            response: token.to_owned(),
-        };
-
-        let resp = reqwest::Client::default()
-            .post("https://challenges.cloudflare.com/turnstile/v0/siteverify")
-            .form(&params)
-            .send()
-            .await?
-            .json::<CaptchaVerificationResponse>()
-            .await?;
-
-        if !resp.success {
-            Err(CaptchaVerificationError::CaptchaFailed)
-        } else {
-            Ok(())
-        }
-    }
-}
+/// A request guard for routes that need a captcha, which checks the response in the
+/// `X-Captcha-Response` header
+pub struct VerifiedCaptcha;
 
 #[rocket::async_trait]
 impl<'a> FromRequest<'a> for VerifiedCaptcha {
@@ -74,10 +36,14 @@ impl<'a> FromRequest<'a> for VerifiedCaptcha {
             request
         );
 
-        let config = into_outcome!(request.rocket().state::<Config>().ok_or(APIError::Internal));
+        let captcha = into_outcome!(request
+            .rocket()
+            .state::<PalformCaptcha>()
+            .ok_or(APIError::Internal));
 
         into_outcome!(
-            VerifiedCaptcha::verify_token(config, header)
+            captcha
+                .verify(header)
                 .await
                 .map_err(|e| APIError::CaptchaError(e.to_string())),
             request
@@ -98,7 +64,9 @@ impl<'a> OpenApiFromRequest<'a> for VerifiedCaptcha {
             rocket_okapi::okapi::openapi3::Parameter {
                 name: "X-Captcha-Response".to_string(),
                 location: "header".to_string(),
-                description: Some("Response from the Cloudflare Turnstile captcha".to_string()),
+                description: Some(
+                    "Response from the captcha, or the solved proof-of-work challenge".to_string(),
+                ),
                 required,
                 deprecated: false,
                 allow_empty_value: false,
@@ -0,0 +1,89 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use base64::prelude::*;
+use chrono::{Duration, Utc};
+use palform_client_common::captcha::{ProofOfWorkChallenge, ProofOfWorkSolution};
+use rand::RngCore;
+use ring::hmac;
+
+use super::{provider::CaptchaProvider, verifier::CaptchaVerificationError};
+
+/// How long the client has to solve a challenge and use the solution
+const CHALLENGE_VALID_MINUTES: i64 = 10;
+
+/// A captcha that doesn't need a third party. Challenges are signed with the captcha secret key,
+/// so any server instance can check them without storing them first.
+pub struct ProofOfWorkProvider {
+    key: hmac::Key,
+    difficulty: u8,
+    /// Salts of challenges that have already been solved, so each solution can only be used once.
+    /// This isn't shared between server instances, so a solution could be used once per instance
+    /// at most.
+    used_salts: Mutex<HashMap<String, i64>>,
+}
+
+impl ProofOfWorkProvider {
+    pub fn new(secret: &str, difficulty: u8) -> Self {
+        Self {
+            key: hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes()),
+            difficulty,
+            used_salts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn sign(&self, salt: &str, expires_at: i64, difficulty: u8) -> String {
+        let payload = ProofOfWorkChallenge::signed_payload(salt, expires_at, difficulty);
+        BASE64_URL_SAFE_NO_PAD.encode(hmac::sign(&self.key, payload.as_bytes()))
+    }
+
+    fn signature_valid(&self, challenge: &ProofOfWorkChallenge) -> bool {
+        let payload = ProofOfWorkChallenge::signed_payload(
+            &challenge.salt,
+            challenge.expires_at,
+            challenge.difficulty,
+        );
+        BASE64_URL_SAFE_NO_PAD
+            .decode(&challenge.signature)
+            .is_ok_and(|signature| hmac::verify(&self.key, payload.as_bytes(), &signature).is_ok())
+    }
+
+    /// Returns `false` if the salt has been used before
+    fn mark_used(&self, salt: &str, expires_at: i64) -> bool {
+        let mut used_salts = self.used_salts.lock().expect("Lock used captcha salts");
+        let now = Utc::now().timestamp();
+        used_salts.retain(|_, expires_at| *expires_at > now);
+        used_salts.insert(salt.to_string(), expires_at).is_none()
+    }
+}
+
+#[rocket::async_trait]
+impl CaptchaProvider for ProofOfWorkProvider {
+    async fn verify(&self, response: &str) -> Result<(), CaptchaVerificationError> {
+        let ProofOfWorkSolution { challenge, nonce } = ProofOfWorkSolution::parse(response)?;
+
+        if !self.signature_valid(&challenge)
+            || challenge.expires_at < Utc::now().timestamp()
+            || !challenge.is_solution(nonce)
+            || !self.mark_used(&challenge.salt, challenge.expires_at)
+        {
+            return Err(CaptchaVerificationError::CaptchaFailed);
+        }
+
+        Ok(())
+    }
+
+    fn issue_challenge(&self) -> Option<String> {
+        let mut salt = [0u8; 16];
+        rand::thread_rng().fill_bytes(&mut salt);
+        let salt = faster_hex::hex_string(&salt);
+        let expires_at = (Utc::now() + Duration::minutes(CHALLENGE_VALID_MINUTES)).timestamp();
+
+        let challenge = ProofOfWorkChallenge {
+            signature: self.sign(&salt, expires_at, self.difficulty),
+            salt,
+            expires_at,
+            difficulty: self.difficulty,
+        };
+        Some(challenge.to_string())
+    }
+}
@@ -0,0 +1,13 @@
+use super::verifier::CaptchaVerificationError;
+
+/// Something that can check the response to a captcha
+#[rocket::async_trait]
+pub trait CaptchaProvider: Send + Sync {
+    async fn verify(&self, response: &str) -> Result<(), CaptchaVerificationError>;
+
+    /// A new challenge for the client to solve, for providers that don't use a third party
+    /// widget. Returns `None` if the provider doesn't issue challenges.
+    fn issue_challenge(&self) -> Option<String> {
+        None
+    }
+}
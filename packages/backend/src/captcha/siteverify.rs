@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::CaptchaProviderKind;
+
+use super::{provider::CaptchaProvider, verifier::CaptchaVerificationError};
+
+#[derive(Serialize)]
+struct SiteVerifyRequest<'a> {
+    secret: &'a str,
+    response: &'a str,
+}
+
+#[derive(Deserialize)]
+struct SiteVerifyResponse {
+    success: bool,
+}
+
+/// Turnstile, hCaptcha and reCAPTCHA (v2) all check responses with the same `siteverify` API,
+/// just at a different URL
+pub struct SiteVerifyProvider {
+    url: &'static str,
+    secret: String,
+    http_client: reqwest::Client,
+}
+
+impl SiteVerifyProvider {
+    pub fn new(kind: &CaptchaProviderKind, secret: String) -> Self {
+        let url = match kind {
+            CaptchaProviderKind::Turnstile => {
+                "https://challenges.cloudflare.com/turnstile/v0/siteverify"
+            }
+            CaptchaProviderKind::Hcaptcha => "https://api.hcaptcha.com/siteverify",
+            CaptchaProviderKind::Recaptcha => "https://www.google.com/recaptcha/api/siteverify",
+            CaptchaProviderKind::ProofOfWork => {
+                unreachable!("Proof of work captchas are checked locally")
+            }
+        };
+
+        Self {
+            url,
+            secret,
+            http_client: reqwest::Client::default(),
+        }
+    }
+}
+
+#[rocket::async_trait]
+impl CaptchaProvider for SiteVerifyProvider {
+    async fn verify(&self, response: &str) -> Result<(), CaptchaVerificationError> {
+        let resp = self
+            .http_client
+            .post(self.url)
+            .form(&SiteVerifyRequest {
+                secret: &self.secret,
+                response,
+            })
+            .send()
+            .await?
+            .json::<SiteVerifyResponse>()
+            .await?;
+
+        if !resp.success {
+            Err(CaptchaVerificationError::CaptchaFailed)
+        } else {
+            Ok(())
+        }
+    }
+}
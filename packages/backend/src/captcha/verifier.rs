@@ -0,0 +1,55 @@
+use palform_client_common::captcha::ProofOfWorkError;
+use thiserror::Error;
+
+use crate::config::{CaptchaProviderKind, Config};
+
+use super::{
+    proof_of_work::ProofOfWorkProvider, provider::CaptchaProvider, siteverify::SiteVerifyProvider,
+};
+
+#[derive(Debug, Error)]
+pub enum CaptchaVerificationError {
+    #[error("connection: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("proof of work: {0}")]
+    ProofOfWork(#[from] ProofOfWorkError),
+    #[error("failed")]
+    CaptchaFailed,
+}
+
+/// Checks captcha responses with whichever provider is configured
+pub struct PalformCaptcha {
+    provider: Box<dyn CaptchaProvider>,
+    skip: bool,
+}
+
+impl PalformCaptcha {
+    pub fn init(config: &Config) -> Self {
+        let provider: Box<dyn CaptchaProvider> = match config.captcha_provider {
+            CaptchaProviderKind::ProofOfWork => Box::new(ProofOfWorkProvider::new(
+                &config.captcha_secret_key,
+                config.captcha_pow_difficulty,
+            )),
+            ref kind => Box::new(SiteVerifyProvider::new(
+                kind,
+                config.captcha_secret_key.clone(),
+            )),
+        };
+
+        Self {
+            provider,
+            skip: config.skip_captcha,
+        }
+    }
+
+    pub async fn verify(&self, response: &str) -> Result<(), CaptchaVerificationError> {
+        if self.skip {
+            return Ok(());
+        }
+        self.provider.verify(response).await
+    }
+
+    pub fn issue_challenge(&self) -> Option<String> {
+        self.provider.issue_challenge()
+    }
+}
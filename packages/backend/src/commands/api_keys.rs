@@ -0,0 +1,93 @@
+use chrono::{Duration, Utc};
+use clap::ArgMatches;
+use palform_tsid::{
+    resources::{IDAdminUser, IDServiceAccountAPIKey},
+    tsid::PalformDatabaseID,
+};
+use sea_orm::{DatabaseConnection, DbErr};
+
+use crate::{
+    auth::api_keys::APIKeyScope,
+    entity_managers::service_account_api_keys::ServiceAccountAPIKeysManager,
+};
+
+fn invalid_arg(e: impl ToString) -> DbErr {
+    DbErr::Custom(e.to_string())
+}
+
+fn required_arg<'a>(matches: &'a ArgMatches, name: &str) -> &'a str {
+    matches
+        .get_one::<String>(name)
+        .expect("Required argument is missing")
+}
+
+pub async fn command_create_api_key(
+    db: &DatabaseConnection,
+    matches: &ArgMatches,
+) -> Result<(), DbErr> {
+    let user_id = PalformDatabaseID::<IDAdminUser>::from_str(required_arg(matches, "user-id"))
+        .map_err(invalid_arg)?;
+    let scopes = APIKeyScope::parse_list(required_arg(matches, "scopes")).map_err(invalid_arg)?;
+    let expires_in_days: i64 = required_arg(matches, "expires-in-days")
+        .parse()
+        .map_err(invalid_arg)?;
+
+    let expires_at = (Utc::now() + Duration::days(expires_in_days)).naive_utc();
+    let (key_id, key) = ServiceAccountAPIKeysManager::create(
+        db,
+        user_id,
+        required_arg(matches, "name").to_string(),
+        scopes,
+        Some(expires_at),
+    )
+    .await?;
+
+    println!("Created API key {} (expires {})", key_id, expires_at);
+    println!("{}", key);
+    Ok(())
+}
+
+pub async fn command_list_api_keys(
+    db: &DatabaseConnection,
+    matches: &ArgMatches,
+) -> Result<(), DbErr> {
+    let user_id = PalformDatabaseID::<IDAdminUser>::from_str(required_arg(matches, "user-id"))
+        .map_err(invalid_arg)?;
+
+    for key in ServiceAccountAPIKeysManager::list_for_user(db, user_id).await? {
+        let scopes = ServiceAccountAPIKeysManager::scopes(&key)
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        println!(
+            "{}\t{}\t{}...\t{}\texpires {}\tlast used {}",
+            key.id,
+            key.name,
+            key.prefix,
+            scopes,
+            key.expires_at
+                .map(|t| t.to_string())
+                .unwrap_or("never".to_string()),
+            key.last_used_at
+                .map(|t| t.to_string())
+                .unwrap_or("never".to_string())
+        );
+    }
+    Ok(())
+}
+
+pub async fn command_revoke_api_key(
+    db: &DatabaseConnection,
+    matches: &ArgMatches,
+) -> Result<(), DbErr> {
+    let key_id =
+        PalformDatabaseID::<IDServiceAccountAPIKey>::from_str(required_arg(matches, "key-id"))
+            .map_err(invalid_arg)?;
+
+    if !ServiceAccountAPIKeysManager::revoke(db, key_id).await? {
+        return Err(DbErr::RecordNotFound(key_id.to_string()));
+    }
+    println!("Revoked API key {}", key_id);
+    Ok(())
+}
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use figment::{providers::Env, Figment};
 use serde::Deserialize;
 use url::Url;
@@ -18,26 +20,152 @@ pub struct Config {
     pub smtp_from_address: String,
     pub smtp_starttls: bool,
     pub smtp_skip_startup_check: bool,
+    /// `builtin` renders emails from the templates bundled with the backend. `mailgun` sends
+    /// Mailgun template headers instead, so the templates must be set up in Mailgun.
+    #[serde(default)]
+    pub mail_template_mode: MailTemplateMode,
+    /// Used for emails to recipients whose language we don't know, e.g. response notifications
+    #[serde(default = "default_mail_locale")]
+    pub mail_locale: String,
+    #[serde(default = "default_mail_max_retries")]
+    pub mail_max_retries: i32,
+    #[serde(default = "default_mail_poll_interval_secs")]
+    pub mail_poll_interval_secs: u64,
+    /// Sending an email is given up on (and retried later) if the mail server takes longer than
+    /// this
+    #[serde(default = "default_mail_send_timeout_secs")]
+    pub mail_send_timeout_secs: u64,
+    /// How many emails are sent at once
+    #[serde(default = "default_mail_max_concurrency")]
+    pub mail_max_concurrency: usize,
+    /// Sent emails are deleted from the outbox after this long
+    #[serde(default = "default_mail_retention_days")]
+    pub mail_retention_days: i64,
+    /// Users are emailed this long before one of their keys expires, unless they have another key
+    /// that lasts longer
+    #[serde(default = "default_key_expiry_warning_days")]
+    pub key_expiry_warning_days: i64,
 
     pub event_notification_address: String,
 
-    pub s3_endpoint_url: String,
-    pub s3_region: String,
-    pub s3_access_key: String,
-    pub s3_secret_key: String,
-    pub s3_team_assets_bucket: String,
-    pub s3_submission_assets_bucket: String,
+    /// Where uploaded files are kept. S3 needs the `s3_*` options, and local storage needs
+    /// `storage_directory`.
+    #[serde(default)]
+    pub storage_backend: StorageBackendKind,
+    #[serde(default)]
+    pub storage_directory: Option<PathBuf>,
+    /// The public URL of this API, used in links to files that aren't stored in S3
+    #[serde(default)]
+    pub storage_public_url: Option<Url>,
+
+    #[serde(default)]
+    pub s3_endpoint_url: Option<String>,
+    #[serde(default)]
+    pub s3_region: Option<String>,
+    #[serde(default)]
+    pub s3_access_key: Option<String>,
+    #[serde(default)]
+    pub s3_secret_key: Option<String>,
+    #[serde(default)]
+    pub s3_team_assets_bucket: Option<String>,
+    #[serde(default)]
+    pub s3_submission_assets_bucket: Option<String>,
+
+    /// Applies to every file uploaded with a submission, on top of any per-question limit
+    #[serde(default = "default_submission_asset_max_size_mb")]
+    pub submission_asset_max_size_mb: u64,
 
+    /// Which service checks captcha responses. `proof_of_work` is built in, so respondents'
+    /// details aren't sent to a third party.
+    #[serde(default)]
+    pub captcha_provider: CaptchaProviderKind,
+    /// The captcha service's secret key. The proof-of-work captcha signs its challenges with it.
     pub captcha_secret_key: String,
+    /// How many leading zero bits a proof-of-work solution needs. Each extra bit doubles how long
+    /// it takes to solve.
+    #[serde(default = "default_captcha_pow_difficulty")]
+    pub captcha_pow_difficulty: u8,
     pub skip_captcha: bool,
     pub social_auth_providers: Vec<ConfigSocialAuthProvider>,
 
+    #[serde(default = "default_webhook_max_retries")]
+    pub webhook_max_retries: i32,
+    #[serde(default = "default_webhook_request_timeout_secs")]
+    pub webhook_request_timeout_secs: u64,
+    #[serde(default = "default_webhook_max_concurrency")]
+    pub webhook_max_concurrency: usize,
+    #[serde(default = "default_webhook_max_concurrency_per_endpoint")]
+    pub webhook_max_concurrency_per_endpoint: usize,
+    #[serde(default = "default_webhook_poll_interval_secs")]
+    pub webhook_poll_interval_secs: u64,
+
+    /// Where rate limit buckets are kept. Use `postgres` when running more than one server, so they
+    /// share the same limits.
+    #[serde(default)]
+    pub rate_limit_store: RateLimitStoreKind,
+    /// Requests to the fill endpoints allowed from one IP address with one fill token
+    #[serde(default = "default_rate_limit_fill_client_per_minute")]
+    pub rate_limit_fill_client_per_minute: u32,
+    #[serde(default = "default_rate_limit_fill_form_per_minute")]
+    pub rate_limit_fill_form_per_minute: u32,
+    #[serde(default = "default_rate_limit_fill_org_per_minute")]
+    pub rate_limit_fill_org_per_minute: u32,
+    /// Header holding the client's IP address, such as `X-Real-IP` or `X-Forwarded-For`, when the
+    /// server is behind a reverse proxy that sets it. Leave unset if clients can reach the server
+    /// directly, as they could then put any address in the header. When unset, the address of the
+    /// connection is used.
+    #[serde(default)]
+    pub rate_limit_trusted_proxy_header: Option<String>,
+
+    /// Base64-encoded PKCS#8 Ed25519 key used to sign export bundles and submission chain
+    /// checkpoints. Generate one with `openssl genpkey -algorithm ed25519 -outform DER | base64 -w0`
+    /// and keep it the same across restarts and on every server, as clients pin the public key.
+    /// Without it, those endpoints return an error.
+    #[serde(default)]
+    pub server_signing_key: Option<String>,
+
     #[cfg(feature = "saas")]
     pub stripe_secret_key: String,
     #[cfg(feature = "saas")]
     pub stripe_webhook_secret: String,
 }
 
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageBackendKind {
+    #[default]
+    S3,
+    Local,
+    /// Files are lost when the server stops, so this is only useful for testing
+    Memory,
+}
+
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum MailTemplateMode {
+    #[default]
+    Builtin,
+    Mailgun,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum CaptchaProviderKind {
+    #[default]
+    Turnstile,
+    Hcaptcha,
+    Recaptcha,
+    ProofOfWork,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum RateLimitStoreKind {
+    #[default]
+    Memory,
+    Postgres,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct ConfigSocialAuthProvider {
     pub service: SocialAuthService,
@@ -46,6 +174,74 @@ pub struct ConfigSocialAuthProvider {
     pub client_secret: String,
 }
 
+fn default_mail_locale() -> String {
+    "en".to_string()
+}
+
+fn default_mail_max_retries() -> i32 {
+    8
+}
+
+fn default_mail_poll_interval_secs() -> u64 {
+    5
+}
+
+fn default_mail_send_timeout_secs() -> u64 {
+    30
+}
+
+fn default_mail_max_concurrency() -> usize {
+    4
+}
+
+fn default_mail_retention_days() -> i64 {
+    30
+}
+
+fn default_key_expiry_warning_days() -> i64 {
+    14
+}
+
+fn default_captcha_pow_difficulty() -> u8 {
+    18
+}
+
+fn default_submission_asset_max_size_mb() -> u64 {
+    10_000
+}
+
+fn default_webhook_max_retries() -> i32 {
+    8
+}
+
+fn default_webhook_request_timeout_secs() -> u64 {
+    15
+}
+
+fn default_webhook_max_concurrency() -> usize {
+    32
+}
+
+fn default_webhook_max_concurrency_per_endpoint() -> usize {
+    2
+}
+
+fn default_webhook_poll_interval_secs() -> u64 {
+    5
+}
+
+fn default_rate_limit_fill_client_per_minute() -> u32 {
+    30
+}
+
+fn default_rate_limit_fill_form_per_minute() -> u32 {
+    600
+}
+
+fn default_rate_limit_fill_org_per_minute() -> u32 {
+    2000
+}
+
 impl Config {
     pub fn parse_config() -> Config {
         Figment::new()
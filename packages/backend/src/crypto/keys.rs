@@ -10,12 +10,17 @@ use palform_crypto::{
     policy::recipient_cert_policy,
 };
 use sequoia_openpgp::{
+    armor::{Kind, Reader, ReaderMode},
     cert::amalgamation::key::ValidKeyAmalgamation,
-    packet::key::{KeyParts, PublicParts, UnspecifiedRole},
+    packet::{
+        key::{KeyParts, PublicParts, UnspecifiedRole},
+        Packet,
+    },
     parse::Parse,
     policy::StandardPolicy,
     serialize::SerializeInto,
-    Cert, Fingerprint,
+    types::RevocationStatus,
+    Cert, Fingerprint, PacketPile,
 };
 use thiserror::Error;
 
@@ -26,6 +31,8 @@ pub enum KeyConversionError {
     PGP(String),
     #[error("Never-expiring encryption keys are not supported")]
     NeverExpiringKey,
+    #[error("This is not a valid revocation certificate for the key")]
+    InvalidRevocation,
 }
 
 pub struct CryptoKeyRepr<P: KeyParts> {
@@ -114,4 +121,41 @@ impl<P: KeyParts> CryptoKeyRepr<P> {
         let dt_exp_time: DateTime<Utc> = exp_time.into();
         Ok(dt_exp_time)
     }
+    pub fn is_revoked(&self) -> bool {
+        let p = recipient_cert_policy();
+        matches!(
+            self.cert.revocation_status(&p, None),
+            RevocationStatus::Revoked(_)
+        )
+    }
+
+    /// Adds an armored revocation certificate to the key, checking that it was signed by the key
+    pub fn add_revocation(&mut self, revocation_pem: &str) -> Result<(), KeyConversionError> {
+        let r = Reader::from_bytes(
+            revocation_pem.as_bytes(),
+            ReaderMode::Tolerant(Some(Kind::Signature)),
+        );
+        let pile =
+            PacketPile::from_reader(r).map_err(|e| KeyConversionError::PGP(e.to_string()))?;
+        let mut packets = pile.into_children();
+        let signature = match (packets.next(), packets.next()) {
+            (Some(Packet::Signature(signature)), None) => signature,
+            _ => return Err(KeyConversionError::InvalidRevocation),
+        };
+        let (cert, _) = self
+            .cert
+            .clone()
+            .insert_packets(signature)
+            .map_err(|e| KeyConversionError::PGP(e.to_string()))?;
+
+        let revoked = Self {
+            cert,
+            parts: PhantomData,
+        };
+        if !revoked.is_revoked() {
+            return Err(KeyConversionError::InvalidRevocation);
+        }
+        *self = revoked;
+        Ok(())
+    }
 }
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use base64::prelude::*;
+use palform_client_common::errors::error::{APIError, APIErrorWithStatus};
+use ring::signature::{Ed25519KeyPair, KeyPair};
+use thiserror::Error;
+
+use crate::config::Config;
+
+#[derive(Error, Debug)]
+pub enum ServerSigningKeyError {
+    #[error("Decoding base64: {0}")]
+    Base64(#[from] base64::DecodeError),
+    #[error("Invalid Ed25519 key: {0}")]
+    Key(String),
+}
+
+/// Ed25519 key the server uses to sign things clients may want to check offline later, such as
+/// export bundles.
+#[derive(Clone)]
+pub struct ServerSigningKey {
+    key_pair: Arc<Ed25519KeyPair>,
+}
+
+impl ServerSigningKey {
+    /// Loads the key from `server_signing_key` (a base64-encoded PKCS#8 document). Both v1
+    /// documents (as made by OpenSSL) and v2 documents are accepted.
+    ///
+    /// The option isn't required, so that servers set up before it was added still start. Without
+    /// it, this returns `None` and the endpoints that sign things fail.
+    pub fn init(config: &Config) -> Result<Option<Self>, ServerSigningKeyError> {
+        let Some(encoded) = &config.server_signing_key else {
+            log::warn!(
+                "server_signing_key isn't set, so bundles and submission chains can't be signed"
+            );
+            return Ok(None);
+        };
+
+        let pkcs8 = BASE64_STANDARD.decode(encoded.trim())?;
+        let key_pair = Ed25519KeyPair::from_pkcs8_maybe_unchecked(&pkcs8)
+            .map_err(|e| ServerSigningKeyError::Key(e.to_string()))?;
+        Ok(Some(Self {
+            key_pair: Arc::new(key_pair),
+        }))
+    }
+
+    /// The key from the server's state, or an internal error if it isn't configured
+    pub fn configured(key: &Option<Self>) -> Result<&Self, APIErrorWithStatus> {
+        key.as_ref().ok_or_else(|| {
+            APIError::report_internal_error_without_error(
+                "Can't sign without server_signing_key set in the config",
+            )
+        })
+    }
+
+    pub fn public_key_base64(&self) -> String {
+        BASE64_STANDARD.encode(self.key_pair.public_key().as_ref())
+    }
+
+    pub fn sign_base64(&self, message: &[u8]) -> String {
+        BASE64_STANDARD.encode(self.key_pair.sign(message).as_ref())
+    }
+}
@@ -1,9 +1,12 @@
+use palform_crypto::{
+    chain::submission_data_hash, keys::parse::public_key_from_pem, policy::recipient_cert_policy,
+};
 use sequoia_openpgp::{
     armor::{Kind, Reader, ReaderMode, Writer},
-    packet::Packet,
+    packet::{Packet, PKESK},
     parse::Parse,
     serialize::SerializeInto,
-    KeyHandle, Message,
+    KeyHandle, Message, PacketPile,
 };
 use std::io::Write;
 use thiserror::Error;
@@ -13,6 +16,37 @@ pub enum SubmissionConversionError {
     #[error("PGP: {0}")]
     #[allow(clippy::upper_case_acronyms)]
     PGP(String),
+    #[error("Key packets must only contain PKESK packets with a recipient")]
+    InvalidKeyPackets,
+    #[error("Signing key must be a public key that can make signatures")]
+    InvalidSigningKey,
+}
+
+/// Checks the key a signed submission was sent with, and re-armors it so that only its public
+/// parts are stored
+pub fn parse_signing_key(pem: &str) -> Result<String, SubmissionConversionError> {
+    let cert = public_key_from_pem(pem.to_string())
+        .map_err(|_| SubmissionConversionError::InvalidSigningKey)?;
+    let policy = recipient_cert_policy();
+    let valid_cert = cert
+        .with_policy(&policy, None)
+        .map_err(|_| SubmissionConversionError::InvalidSigningKey)?;
+    if valid_cert.keys().for_signing().next().is_none() {
+        return Err(SubmissionConversionError::InvalidSigningKey);
+    }
+
+    let armored = cert
+        .armored()
+        .to_vec()
+        .map_err(|e| SubmissionConversionError::PGP(e.to_string()))?;
+    String::from_utf8(armored).map_err(|e| SubmissionConversionError::PGP(e.to_string()))
+}
+
+/// The recipients whose key packets were changed by [`CryptoSubmissionRepr::add_key_packets`]
+#[derive(Default)]
+pub struct KeyPacketChanges {
+    pub added: Vec<KeyHandle>,
+    pub replaced: Vec<KeyHandle>,
 }
 
 pub struct CryptoSubmissionRepr {
@@ -57,6 +91,11 @@ impl CryptoSubmissionRepr {
         Ok(pem_string)
     }
 
+    /// The hash of the message's database bytes for the form's submission chain
+    pub fn chain_data_hash(bytes: &[u8]) -> Result<String, SubmissionConversionError> {
+        submission_data_hash(bytes).map_err(|e| SubmissionConversionError::PGP(e.to_string()))
+    }
+
     pub fn get_decrypting_key_handles(&self) -> Vec<KeyHandle> {
         let mut handles = Vec::new();
         for child in self.message.packets().children() {
@@ -68,4 +107,63 @@ impl CryptoSubmissionRepr {
         }
         handles
     }
+
+    /// Parses the armored PKESK packets made by `palform_crypto::decrypt::rewrap`
+    pub fn parse_key_packets(pem_string: &str) -> Result<Vec<PKESK>, SubmissionConversionError> {
+        let r = Reader::from_bytes(
+            pem_string.as_bytes(),
+            ReaderMode::Tolerant(Some(Kind::File)),
+        );
+        let pile = PacketPile::from_reader(r)
+            .map_err(|e| SubmissionConversionError::PGP(e.to_string()))?;
+
+        pile.into_children()
+            .map(|packet| match packet {
+                Packet::PKESK(pkesk) if pkesk.recipient().is_some() => Ok(pkesk),
+                _ => Err(SubmissionConversionError::InvalidKeyPackets),
+            })
+            .collect()
+    }
+
+    /// Lets more keys decrypt the message, by adding PKESK packets holding its session key
+    /// encrypted to them. A packet for a key that can already decrypt it replaces the existing one
+    /// if that key is in `replaceable`, and is skipped otherwise.
+    pub fn add_key_packets(
+        &mut self,
+        pkesks: Vec<PKESK>,
+        replaceable: &[KeyHandle],
+    ) -> Result<KeyPacketChanges, SubmissionConversionError> {
+        let mut packets: Vec<Packet> = self.message.packets().children().cloned().collect();
+
+        let mut changes = KeyPacketChanges::default();
+        for pkesk in pkesks {
+            let Some(recipient) = pkesk.recipient() else {
+                continue;
+            };
+            let existing = packets.iter().position(|p| {
+                matches!(p, Packet::PKESK(existing)
+                    if existing.recipient().is_some_and(|r| r.aliases(&recipient)))
+            });
+
+            match existing {
+                None => {
+                    let insert_at = packets
+                        .iter()
+                        .position(|p| !matches!(p, Packet::PKESK(_)))
+                        .unwrap_or(packets.len());
+                    packets.insert(insert_at, pkesk.into());
+                    changes.added.push(recipient);
+                }
+                Some(i) if replaceable.iter().any(|h| h.aliases(&recipient)) => {
+                    packets[i] = pkesk.into();
+                    changes.replaced.push(recipient);
+                }
+                Some(_) => {}
+            }
+        }
+
+        self.message = Message::try_from(PacketPile::from(packets))
+            .map_err(|e| SubmissionConversionError::PGP(e.to_string()))?;
+        Ok(changes)
+    }
 }
@@ -83,16 +83,6 @@ impl AdminUserManager {
             .await
     }
 
-    pub async fn get_user_by_service_account_token<T: ConnectionTrait>(
-        conn: &T,
-        token: String,
-    ) -> Result<Option<admin_user::Model>, DbErr> {
-        AdminUser::find()
-            .filter(admin_user::Column::ServiceAccountAuthToken.eq(Some(token)))
-            .one(conn)
-            .await
-    }
-
     fn get_argon2<'k>() -> Argon2<'k> {
         Argon2::default()
     }
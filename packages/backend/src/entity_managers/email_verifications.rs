@@ -1,5 +1,5 @@
 use chrono::{Duration, Utc};
-use lettre::message::{header::ContentType, Mailbox};
+use lettre::message::Mailbox;
 use palform_entities::{
     admin_user, admin_user_email_verification, prelude::*,
     sea_orm_active_enums::AdminUserEmailVerificationPurposeEnum,
@@ -12,13 +12,11 @@ use sea_orm::{
     ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseTransaction, DbErr, EntityTrait,
     QueryFilter, QuerySelect, Set,
 };
-use serde::Serialize;
 use thiserror::Error;
 
-use crate::mail::{
-    client::PalformMailClient,
-    headers::{MailgunHeader, MailgunTemplateNameHeader, MailgunVariableListHeader},
-};
+use crate::mail::templates::{PasswordResetMail, VerifyEmailMail};
+
+use super::mail_outbox::MailOutboxManager;
 
 pub struct EmailVerificationManager;
 
@@ -26,14 +24,8 @@ pub struct EmailVerificationManager;
 pub enum EmailVerificationError {
     #[error("{0}")]
     DBError(#[from] DbErr),
-    #[error("Mail: {0}")]
-    MailTransport(#[from] lettre::transport::smtp::Error),
-    #[error("Construct email message: {0}")]
-    MailStructure(#[from] lettre::error::Error),
     #[error("Parse email: {0}")]
     MailAddress(#[from] lettre::address::AddressError),
-    #[error("Process mail variables: {0}")]
-    MailVariables(#[from] serde_json::Error),
 }
 
 impl EmailVerificationManager {
@@ -57,7 +49,7 @@ impl EmailVerificationManager {
         user_id: PalformDatabaseID<IDAdminUser>,
         email_address: Option<String>,
         purpose: AdminUserEmailVerificationPurposeEnum,
-        mail: &PalformMailClient,
+        locale: &str,
     ) -> Result<(), EmailVerificationError> {
         let expires_at = Utc::now() + Duration::minutes(15);
         let verification_id = PalformDatabaseID::<IDAdminUserEmailVerification>::random();
@@ -84,34 +76,31 @@ impl EmailVerificationManager {
                 .ok_or(DbErr::RecordNotFound("User not found".to_string()))?;
         }
 
-        #[derive(Serialize)]
-        struct EmailVariables {
-            verification_id: String,
+        // Catch invalid addresses now, rather than retrying them in the mail job
+        user_email.parse::<Mailbox>()?;
+        let verification_id = verification_id.to_string();
+        match purpose {
+            AdminUserEmailVerificationPurposeEnum::NewEmail => {
+                MailOutboxManager::enqueue(
+                    conn,
+                    user_email,
+                    &VerifyEmailMail { verification_id },
+                    Some(locale),
+                    None,
+                )
+                .await?
+            }
+            AdminUserEmailVerificationPurposeEnum::PasswordReset => {
+                MailOutboxManager::enqueue(
+                    conn,
+                    user_email,
+                    &PasswordResetMail { verification_id },
+                    Some(locale),
+                    None,
+                )
+                .await?
+            }
         }
-        let email_variables = EmailVariables {
-            verification_id: verification_id.to_string(),
-        };
-        let email_variables = serde_json::to_string(&email_variables)?;
-
-        let b = mail.get_email_builder();
-        let to_address: Mailbox = user_email.parse()?;
-        let message = b
-            .to(to_address)
-            .subject("Verify your Palform email")
-            .header(MailgunHeader::<MailgunTemplateNameHeader>::new(
-                match purpose {
-                    AdminUserEmailVerificationPurposeEnum::NewEmail => "verify_email",
-                    AdminUserEmailVerificationPurposeEnum::PasswordReset => "password_reset",
-                }
-                .to_string(),
-            ))
-            .header(MailgunHeader::<MailgunVariableListHeader>::new(
-                email_variables,
-            ))
-            .header(ContentType::TEXT_HTML)
-            .body(Vec::new())?;
-
-        mail.send_email(message).await?;
         Ok(())
     }
 
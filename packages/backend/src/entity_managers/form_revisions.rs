@@ -0,0 +1,178 @@
+use chrono::Utc;
+use palform_client_common::form_management::{
+    question_group::APIQuestionGroup, question_types::APIQuestion, revision::APIFormRevision,
+};
+use palform_entities::{form_revision, prelude::*};
+use palform_tsid::{
+    resources::{IDForm, IDFormRevision},
+    tsid::PalformDatabaseID,
+};
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, ConnectionTrait, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, QuerySelect, Set,
+};
+use thiserror::Error;
+
+use crate::api_entities::form_revision::{
+    APIFormRevisionSummary, FormRevisionSummaryRow, FormRevisionWithEncodedSchema,
+};
+
+use super::{
+    question_groups::QuestionGroupManager,
+    questions::{GetQuestionError, QuestionManager},
+};
+
+#[derive(Debug, Error)]
+pub enum FormRevisionError {
+    #[error("Database: {0}")]
+    DB(#[from] DbErr),
+    #[error("Encode/decode schema: {0}")]
+    Serde(#[from] serde_json::Error),
+}
+
+impl From<GetQuestionError> for FormRevisionError {
+    fn from(value: GetQuestionError) -> Self {
+        match value {
+            GetQuestionError::DB(e) => Self::DB(e),
+            GetQuestionError::Decode(e) => Self::Serde(e),
+        }
+    }
+}
+
+pub struct FormRevisionManager;
+
+impl FormRevisionManager {
+    /// Stores the given questions and groups as the form's next revision. Its questions and groups
+    /// are never modified after this.
+    pub async fn create<T: ConnectionTrait>(
+        conn: &T,
+        form_id: PalformDatabaseID<IDForm>,
+        groups: &[APIQuestionGroup],
+        questions: &[APIQuestion],
+    ) -> Result<PalformDatabaseID<IDFormRevision>, FormRevisionError> {
+        let last_number: Option<i32> = FormRevision::find()
+            .select_only()
+            .column_as(form_revision::Column::Number.max(), "number")
+            .filter(form_revision::Column::FormId.eq(form_id))
+            .into_tuple::<Option<i32>>()
+            .one(conn)
+            .await?
+            .flatten();
+
+        let new_revision = form_revision::ActiveModel {
+            id: Set(PalformDatabaseID::<IDFormRevision>::random()),
+            form_id: Set(form_id),
+            number: Set(last_number.unwrap_or(0) + 1),
+            groups: Set(serde_json::to_value(groups)?),
+            questions: Set(serde_json::to_value(questions)?),
+            ..Default::default()
+        };
+        let resp = new_revision.insert(conn).await?;
+        Ok(resp.id)
+    }
+
+    /// Like `create`, but snapshots whatever is currently saved in the form
+    pub async fn create_from_current<T: ConnectionTrait>(
+        conn: &T,
+        form_id: PalformDatabaseID<IDForm>,
+    ) -> Result<PalformDatabaseID<IDFormRevision>, FormRevisionError> {
+        let groups = QuestionGroupManager::list_all_for_form(conn, form_id).await?;
+        let questions = QuestionManager::get_all_for_form(conn, form_id).await?;
+        Self::create(conn, form_id, &groups, &questions).await
+    }
+
+    /// Records that the revision has been shown to respondents, if it hasn't been already
+    pub async fn mark_published<T: ConnectionTrait>(
+        conn: &T,
+        revision_id: PalformDatabaseID<IDFormRevision>,
+    ) -> Result<(), DbErr> {
+        FormRevision::update_many()
+            .col_expr(
+                form_revision::Column::PublishedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(form_revision::Column::Id.eq(revision_id))
+            .filter(form_revision::Column::PublishedAt.is_null())
+            .exec(conn)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn get_latest_id<T: ConnectionTrait>(
+        conn: &T,
+        form_id: PalformDatabaseID<IDForm>,
+    ) -> Result<Option<PalformDatabaseID<IDFormRevision>>, DbErr> {
+        FormRevision::find()
+            .filter(form_revision::Column::FormId.eq(form_id))
+            .order_by_desc(form_revision::Column::Number)
+            .select_only()
+            .column(form_revision::Column::Id)
+            .into_tuple()
+            .one(conn)
+            .await
+    }
+
+    pub async fn list_for_form<T: ConnectionTrait>(
+        conn: &T,
+        form_id: PalformDatabaseID<IDForm>,
+    ) -> Result<Vec<APIFormRevisionSummary>, DbErr> {
+        let resp = FormRevision::find()
+            .filter(form_revision::Column::FormId.eq(form_id))
+            .order_by_desc(form_revision::Column::Number)
+            .select_only()
+            .column(form_revision::Column::Id)
+            .column(form_revision::Column::Number)
+            .column(form_revision::Column::CreatedAt)
+            .into_model::<FormRevisionSummaryRow>()
+            .all(conn)
+            .await?;
+        Ok(resp.into_iter().map(APIFormRevisionSummary::from).collect())
+    }
+
+    pub async fn list_full_for_form<T: ConnectionTrait>(
+        conn: &T,
+        form_id: PalformDatabaseID<IDForm>,
+    ) -> Result<Vec<APIFormRevision>, FormRevisionError> {
+        let resp = FormRevision::find()
+            .filter(form_revision::Column::FormId.eq(form_id))
+            .order_by_asc(form_revision::Column::Number)
+            .into_model::<FormRevisionWithEncodedSchema>()
+            .all(conn)
+            .await?;
+
+        let revisions: Result<Vec<APIFormRevision>, serde_json::Error> =
+            resp.into_iter().map(APIFormRevision::try_from).collect();
+        Ok(revisions?)
+    }
+
+    pub async fn get<T: ConnectionTrait>(
+        conn: &T,
+        form_id: PalformDatabaseID<IDForm>,
+        revision_id: PalformDatabaseID<IDFormRevision>,
+    ) -> Result<Option<APIFormRevision>, FormRevisionError> {
+        let resp = FormRevision::find_by_id(revision_id)
+            .filter(form_revision::Column::FormId.eq(form_id))
+            .into_model::<FormRevisionWithEncodedSchema>()
+            .one(conn)
+            .await?;
+
+        Ok(resp.map(APIFormRevision::try_from).transpose()?)
+    }
+
+    /// Like `get`, but only finds revisions that have been published at some point. Drafts were
+    /// never shown to respondents, so submissions can't be made against them.
+    pub async fn get_published<T: ConnectionTrait>(
+        conn: &T,
+        form_id: PalformDatabaseID<IDForm>,
+        revision_id: PalformDatabaseID<IDFormRevision>,
+    ) -> Result<Option<APIFormRevision>, FormRevisionError> {
+        let resp = FormRevision::find_by_id(revision_id)
+            .filter(form_revision::Column::FormId.eq(form_id))
+            .filter(form_revision::Column::PublishedAt.is_not_null())
+            .into_model::<FormRevisionWithEncodedSchema>()
+            .one(conn)
+            .await?;
+
+        Ok(resp.map(APIFormRevision::try_from).transpose()?)
+    }
+}
@@ -23,7 +23,7 @@ use crate::api_entities::{
     form_template::{APIFormTemplate, APIFormTemplateCategory},
 };
 
-use super::forms::FormManager;
+use super::{form_revisions::FormRevisionManager, forms::FormManager};
 
 pub struct FormTemplatesManager;
 
@@ -226,6 +226,7 @@ impl FormTemplatesManager {
                 position: Set(question.position),
                 required: Set(question.required),
                 internal_name: Set(question.internal_name),
+                server_validation: Set(question.server_validation),
             };
 
             new_question.insert(conn).await?;
@@ -294,6 +295,10 @@ impl FormTemplatesManager {
             }
         }
 
+        FormRevisionManager::create_from_current(conn, new_form_id)
+            .await
+            .map_err(|e| DbErr::Custom(format!("Create first revision: {}", e)))?;
+
         let newly_created_form =
             FormManager::get_by_id(conn, new_form_id)
                 .await?
@@ -1,27 +1,34 @@
-use chrono::{Duration, Utc};
+use chrono::{Duration, NaiveDateTime, Utc};
 use palform_client_common::form_management::form_end::APIFormEndConfiguration;
-use palform_entities::{
-    deleted_submission, form, organisation, prelude::*, submission, team, team_membership,
-};
+use palform_entities::{form, organisation, prelude::*, submission, team, team_membership};
 use palform_migration::all;
 use palform_tsid::{
-    resources::{IDAdminUser, IDForm, IDFormBranding, IDOrganisation, IDSubmission, IDTeam},
+    resources::{
+        IDAdminUser, IDForm, IDFormBranding, IDFormRevision, IDOrganisation, IDSubmission, IDTeam,
+    },
     tsid::PalformDatabaseID,
 };
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, FromQueryResult, JoinType,
-    PaginatorTrait, QueryFilter, QuerySelect, RelationTrait, Set,
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, ConnectionTrait, DbErr, EntityTrait,
+    FromQueryResult, JoinType, PaginatorTrait, QueryFilter, QuerySelect, RelationTrait, Set,
+    StreamTrait,
 };
 use thiserror::Error;
 
 use crate::api_entities::{
-    form::{APIForm, APIFormWithQuestions, APIFrontendForm},
+    form::{APIForm, APIFormWithQuestions, APIFrontendForm, FormFillSchedule},
     form_brandings::APIFormBranding,
+    webhook::{APIAutoDeleteWebhookPayload, APIFormUpdatedWebhookPayload, APIWebhookPayload},
 };
 
 use super::{
-    billing_entitlement_proxy::BillingEntitlementCountTrait, form_brandings::FormBrandingManager,
-    question_groups::QuestionGroupManager, questions::QuestionManager,
+    billing_entitlement_proxy::BillingEntitlementCountTrait,
+    form_brandings::FormBrandingManager,
+    form_revisions::{FormRevisionError, FormRevisionManager},
+    question_groups::QuestionGroupManager,
+    questions::QuestionManager,
+    submission::SubmissionManager,
+    webhook_jobs::WebhookJobsManager,
 };
 
 #[derive(Error, Debug)]
@@ -34,6 +41,15 @@ pub enum GetFormError {
     NotFound,
 }
 
+impl From<FormRevisionError> for GetFormError {
+    fn from(value: FormRevisionError) -> Self {
+        match value {
+            FormRevisionError::DB(e) => Self::DBError(e),
+            FormRevisionError::Serde(e) => Self::DecodeError(e),
+        }
+    }
+}
+
 #[derive(FromQueryResult)]
 struct FormOnlyTeamId {
     team_id: PalformDatabaseID<IDTeam>,
@@ -74,6 +90,7 @@ impl FormManager {
             .await
     }
 
+    /// Gets the form as respondents see it, with the questions from its published revision
     pub async fn get_with_questions<T: ConnectionTrait>(
         conn: &T,
         form_id: PalformDatabaseID<IDForm>,
@@ -84,16 +101,30 @@ impl FormManager {
             .await?
             .ok_or(GetFormError::NotFound)?;
 
-        let questions = QuestionManager::get_all_for_form(conn, form_id)
-            .await
-            .map_err(|e| match e {
-                super::questions::GetQuestionError::Decode(e) => GetFormError::DecodeError(e),
-                super::questions::GetQuestionError::DB(e) => GetFormError::DBError(e),
-            })?;
-
-        let groups = QuestionGroupManager::list_all_for_form(conn, form_id)
-            .await
-            .map_err(GetFormError::DBError)?;
+        // Forms published before revisions existed, and not edited since, don't have a published
+        // revision and just use their saved questions
+        let (questions, groups, revision_id) = match form.published_revision_id {
+            Some(revision_id) => {
+                let revision = FormRevisionManager::get(conn, form_id, revision_id)
+                    .await?
+                    .ok_or(GetFormError::NotFound)?;
+                (revision.questions, revision.groups, Some(revision.id))
+            }
+            None => {
+                let questions = QuestionManager::get_all_for_form(conn, form_id)
+                    .await
+                    .map_err(|e| match e {
+                        super::questions::GetQuestionError::Decode(e) => {
+                            GetFormError::DecodeError(e)
+                        }
+                        super::questions::GetQuestionError::DB(e) => GetFormError::DBError(e),
+                    })?;
+                let groups = QuestionGroupManager::list_all_for_form(conn, form_id)
+                    .await
+                    .map_err(GetFormError::DBError)?;
+                (questions, groups, None)
+            }
+        };
 
         let mut branding: Option<APIFormBranding> = None;
         if let Some(branding_id) = &form.branding_id {
@@ -123,6 +154,7 @@ impl FormManager {
             groups,
             branding,
             org_name,
+            revision_id,
         })
     }
 
@@ -174,6 +206,125 @@ impl FormManager {
             .map(|v| v.unwrap_or(false))
     }
 
+    pub async fn get_signed_submissions_required<T: ConnectionTrait>(
+        conn: &T,
+        form_id: PalformDatabaseID<IDForm>,
+    ) -> Result<bool, DbErr> {
+        Form::find_by_id(form_id)
+            .select_only()
+            .column(form::Column::SignedSubmissions)
+            .into_tuple()
+            .one(conn)
+            .await
+            .map(|v| v.unwrap_or(false))
+    }
+
+    pub async fn get_fill_schedule<T: ConnectionTrait>(
+        conn: &T,
+        form_id: PalformDatabaseID<IDForm>,
+    ) -> Result<Option<FormFillSchedule>, DbErr> {
+        Form::find_by_id(form_id)
+            .select_only()
+            .column(form::Column::PublishedAt)
+            .column(form::Column::OpensAt)
+            .column(form::Column::ClosesAt)
+            .into_model()
+            .one(conn)
+            .await
+    }
+
+    pub async fn get_published_revision_id<T: ConnectionTrait>(
+        conn: &T,
+        form_id: PalformDatabaseID<IDForm>,
+    ) -> Result<Option<PalformDatabaseID<IDFormRevision>>, DbErr> {
+        let resp: Option<Option<PalformDatabaseID<IDFormRevision>>> = Form::find_by_id(form_id)
+            .select_only()
+            .column(form::Column::PublishedRevisionId)
+            .into_tuple()
+            .one(conn)
+            .await?;
+        Ok(resp.flatten())
+    }
+
+    /// Makes the form's latest revision the one respondents see. If the form has never been
+    /// saved, its current (probably empty) questions are snapshotted first.
+    pub async fn publish<T: ConnectionTrait>(
+        conn: &T,
+        form_id: PalformDatabaseID<IDForm>,
+    ) -> Result<PalformDatabaseID<IDFormRevision>, FormRevisionError> {
+        let revision_id = match FormRevisionManager::get_latest_id(conn, form_id).await? {
+            Some(revision_id) => revision_id,
+            None => FormRevisionManager::create_from_current(conn, form_id).await?,
+        };
+
+        let updated_form = form::ActiveModel {
+            id: Set(form_id),
+            published_revision_id: Set(Some(revision_id)),
+            published_at: Set(Some(Utc::now().naive_utc())),
+            ..Default::default()
+        };
+        updated_form.update(conn).await?;
+        FormRevisionManager::mark_published(conn, revision_id).await?;
+        Ok(revision_id)
+    }
+
+    /// Forms published before revisions existed serve their saved questions directly. Before
+    /// those questions are changed, pin the form to a revision of what's currently live so the
+    /// changes don't go live until the form is published again.
+    pub async fn pin_published_revision<T: ConnectionTrait>(
+        conn: &T,
+        form_id: PalformDatabaseID<IDForm>,
+    ) -> Result<(), FormRevisionError> {
+        let form = Form::find_by_id(form_id)
+            .one(conn)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Form not found".to_string()))?;
+        if form.published_at.is_none() || form.published_revision_id.is_some() {
+            return Ok(());
+        }
+
+        let revision_id = match FormRevisionManager::get_latest_id(conn, form_id).await? {
+            Some(revision_id) => revision_id,
+            None => FormRevisionManager::create_from_current(conn, form_id).await?,
+        };
+        let updated_form = form::ActiveModel {
+            id: Set(form_id),
+            published_revision_id: Set(Some(revision_id)),
+            ..Default::default()
+        };
+        updated_form.update(conn).await?;
+        FormRevisionManager::mark_published(conn, revision_id).await?;
+        Ok(())
+    }
+
+    pub async fn mark_submissions_without_file_ids<T: ConnectionTrait>(
+        conn: &T,
+        form_id: PalformDatabaseID<IDForm>,
+    ) -> Result<(), DbErr> {
+        Form::update_many()
+            .col_expr(form::Column::SubmissionsWithoutFileIds, Expr::value(true))
+            .filter(form::Column::Id.eq(form_id))
+            .filter(form::Column::SubmissionsWithoutFileIds.eq(false))
+            .exec(conn)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn set_schedule<T: ConnectionTrait>(
+        conn: &T,
+        form_id: PalformDatabaseID<IDForm>,
+        opens_at: Option<NaiveDateTime>,
+        closes_at: Option<NaiveDateTime>,
+    ) -> Result<(), DbErr> {
+        let updated_form = form::ActiveModel {
+            id: Set(form_id),
+            opens_at: Set(opens_at),
+            closes_at: Set(closes_at),
+            ..Default::default()
+        };
+        updated_form.update(conn).await.map(|_| ())
+    }
+
     pub async fn verify_form_org<T: ConnectionTrait>(
         conn: &T,
         form_id: PalformDatabaseID<IDForm>,
@@ -258,7 +409,27 @@ impl FormManager {
         updated_form.update(conn).await.map(|_| ())
     }
 
-    pub async fn delete_all_old_submissions<T: ConnectionTrait>(conn: &T) -> Result<(), DbErr> {
+    /// Let the form's webhooks know that its settings or questions changed
+    pub async fn notify_updated<T: ConnectionTrait + StreamTrait>(
+        conn: &T,
+        form_id: PalformDatabaseID<IDForm>,
+    ) -> Result<(), DbErr> {
+        WebhookJobsManager::new(conn)
+            .create_for_event(
+                form_id,
+                APIWebhookPayload::FormUpdated(APIFormUpdatedWebhookPayload {
+                    form_id,
+                    updated_at: Utc::now(),
+                }),
+            )
+            .await
+    }
+
+    pub async fn delete_all_old_submissions<T: ConnectionTrait + StreamTrait>(
+        conn: &T,
+    ) -> Result<(), DbErr> {
+        let wjm = WebhookJobsManager::new(conn);
+
         let forms: Vec<(PalformDatabaseID<IDForm>, Option<i32>)> = Form::find()
             .join(JoinType::InnerJoin, form::Relation::Submission.def())
             .filter(form::Column::AutoDeleteSubmissionAfterDays.is_not_null())
@@ -287,19 +458,24 @@ impl FormManager {
                 .await?;
 
             for delete_id in &delete_ids {
-                let new_deletion = deleted_submission::ActiveModel {
-                    id: Set(*delete_id),
-                    form_id: Set(form_id),
-                    ..Default::default()
-                };
-                new_deletion.insert(conn).await?;
+                SubmissionManager::record_deletion(conn, *delete_id, form_id).await?;
             }
 
             if !delete_ids.is_empty() {
                 Submission::delete_many()
-                    .filter(submission::Column::Id.is_in(delete_ids))
+                    .filter(submission::Column::Id.is_in(delete_ids.clone()))
                     .exec(conn)
                     .await?;
+
+                wjm.create_for_event(
+                    form_id,
+                    APIWebhookPayload::AutoDeleteRan(APIAutoDeleteWebhookPayload {
+                        form_id,
+                        deleted_submission_ids: delete_ids,
+                        ran_at: Utc::now(),
+                    }),
+                )
+                .await?;
             }
         }
 
@@ -1,4 +1,4 @@
-use chrono::Utc;
+use chrono::{NaiveDateTime, Utc};
 use palform_entities::admin_public_key;
 use palform_entities::admin_user;
 use palform_entities::prelude::*;
@@ -89,7 +89,8 @@ impl UserKeyManager {
             .filter(all![
                 team_membership::Column::TeamId.eq(team_id),
                 admin_public_key::Column::OrganisationId.eq(org_id),
-                admin_public_key::Column::ExpiresAt.gt(Utc::now().naive_utc())
+                admin_public_key::Column::ExpiresAt.gt(Utc::now().naive_utc()),
+                admin_public_key::Column::RevokedAt.is_null()
             ])
             .all(conn)
             .await
@@ -141,10 +142,77 @@ impl UserKeyManager {
             private_key_backup: Set(None),
             expires_at: Set(key_expiration.naive_utc()),
             created_at: NotSet,
+            revoked_at: Set(None),
+            expiry_warning_sent_at: Set(None),
         };
         new_record.insert(conn).map_err(|e| e.into()).await
     }
 
+    /// Stores the key with its revocation certificate added, so it's no longer used for new
+    /// submissions
+    pub async fn revoke_key<T: ConnectionTrait>(
+        conn: &T,
+        key_id: PalformDatabaseID<IDAdminPublicKey>,
+        revoked_key_data: CryptoKeyRepr<PublicParts>,
+    ) -> Result<(), RegisterKeyError> {
+        let updated_record = admin_public_key::ActiveModel {
+            id: Set(key_id),
+            public_key: Set(revoked_key_data.to_database_bytes()?),
+            revoked_at: Set(Some(Utc::now().naive_utc())),
+            ..Default::default()
+        };
+        updated_record.update(conn).await?;
+        Ok(())
+    }
+
+    /// Keys expiring before `before` whose owners haven't been warned yet, with their owners
+    pub async fn list_expiring_keys<T: ConnectionTrait>(
+        conn: &T,
+        before: NaiveDateTime,
+    ) -> Result<Vec<(admin_public_key::Model, Option<admin_user::Model>)>, DbErr> {
+        AdminPublicKey::find()
+            .filter(all![
+                admin_public_key::Column::ExpiresAt.gt(Utc::now().naive_utc()),
+                admin_public_key::Column::ExpiresAt.lt(before),
+                admin_public_key::Column::RevokedAt.is_null(),
+                admin_public_key::Column::ExpiryWarningSentAt.is_null()
+            ])
+            .find_also_related(AdminUser)
+            .all(conn)
+            .await
+    }
+
+    /// Whether the user has a key in the organisation that will still be usable at `at`
+    pub async fn has_key_valid_at<T: ConnectionTrait>(
+        conn: &T,
+        user_id: PalformDatabaseID<IDAdminUser>,
+        organisation_id: PalformDatabaseID<IDOrganisation>,
+        at: NaiveDateTime,
+    ) -> Result<bool, DbErr> {
+        AdminPublicKey::find()
+            .filter(all![
+                admin_public_key::Column::UserId.eq(user_id),
+                admin_public_key::Column::OrganisationId.eq(organisation_id),
+                admin_public_key::Column::ExpiresAt.gt(at),
+                admin_public_key::Column::RevokedAt.is_null()
+            ])
+            .count(conn)
+            .await
+            .map(|c| c > 0)
+    }
+
+    pub async fn set_expiry_warning_sent<T: ConnectionTrait>(
+        conn: &T,
+        key_id: PalformDatabaseID<IDAdminPublicKey>,
+    ) -> Result<(), DbErr> {
+        let updated_record = admin_public_key::ActiveModel {
+            id: Set(key_id),
+            expiry_warning_sent_at: Set(Some(Utc::now().naive_utc())),
+            ..Default::default()
+        };
+        updated_record.update(conn).await.map(|_| ())
+    }
+
     pub async fn delete_key_with_id<T: ConnectionTrait>(
         conn: &T,
         key_id: PalformDatabaseID<IDAdminPublicKey>,
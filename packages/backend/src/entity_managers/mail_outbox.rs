@@ -0,0 +1,190 @@
+use chrono::{NaiveDateTime, Utc};
+use palform_entities::{mail_outbox, prelude::*};
+use palform_migration::{all, any};
+use palform_tsid::{
+    resources::{IDMailOutboxItem, IDOrganisation},
+    tsid::PalformDatabaseID,
+};
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait,
+    Order, QueryFilter, QueryOrder, QuerySelect, Set,
+};
+
+use crate::{
+    api_entities::mail::APIFailedMail, jobs::backoff::next_attempt_at,
+    mail::templates::MailTemplate,
+};
+
+/// Emails are written to the outbox in the same transaction as whatever they're about, and
+/// delivered later by the mail job. That way an email is never sent for a change that got rolled
+/// back, and a failure to send doesn't fail the request.
+pub struct MailOutboxManager;
+
+impl MailOutboxManager {
+    /// Queue an email to `recipient`. If their `locale` isn't known, the configured default is
+    /// used when it's sent. Emails about an organisation should set `org_id` so its admins can see
+    /// if they fail.
+    pub async fn enqueue<T: ConnectionTrait, M: MailTemplate>(
+        conn: &T,
+        recipient: String,
+        mail: &M,
+        locale: Option<&str>,
+        org_id: Option<PalformDatabaseID<IDOrganisation>>,
+    ) -> Result<(), DbErr> {
+        let variables = serde_json::to_value(mail).map_err(|e| DbErr::Json(e.to_string()))?;
+        let new_item = mail_outbox::ActiveModel {
+            id: Set(PalformDatabaseID::<IDMailOutboxItem>::random()),
+            organisation_id: Set(org_id),
+            recipient: Set(recipient),
+            template: Set(M::NAME.to_string()),
+            variables: Set(variables),
+            locale: Set(locale.map(str::to_string)),
+            ..Default::default()
+        };
+        new_item.insert(conn).await?;
+        Ok(())
+    }
+
+    fn due_condition() -> Condition {
+        let now = Utc::now().naive_utc();
+        all![
+            mail_outbox::Column::SentAt.is_null(),
+            mail_outbox::Column::DeadAt.is_null(),
+            mail_outbox::Column::NextAttemptAt.lte(now),
+            any![
+                mail_outbox::Column::LockedUntil.is_null(),
+                mail_outbox::Column::LockedUntil.lte(now)
+            ]
+        ]
+    }
+
+    /// List the oldest emails that are due to be sent. Like
+    /// [`super::webhook_jobs::WebhookJobsManager::list_due`], each one still needs to be
+    /// [`Self::claim`]ed.
+    pub async fn list_due<T: ConnectionTrait>(
+        conn: &T,
+        limit: u64,
+    ) -> Result<Vec<PalformDatabaseID<IDMailOutboxItem>>, DbErr> {
+        MailOutbox::find()
+            .filter(Self::due_condition())
+            .order_by(mail_outbox::Column::CreatedAt, Order::Asc)
+            .select_only()
+            .column(mail_outbox::Column::Id)
+            .limit(limit)
+            .into_tuple()
+            .all(conn)
+            .await
+    }
+
+    /// Take a lease on the email until `locked_until`, as long as it's still due and no other
+    /// worker holds a lease on it. Works the same way as
+    /// [`super::webhook_jobs::WebhookJobsManager::claim`].
+    pub async fn claim<T: ConnectionTrait>(
+        conn: &T,
+        id: PalformDatabaseID<IDMailOutboxItem>,
+        locked_until: NaiveDateTime,
+    ) -> Result<Option<mail_outbox::Model>, DbErr> {
+        let claimed = MailOutbox::update_many()
+            .col_expr(mail_outbox::Column::LockedUntil, Expr::value(locked_until))
+            .filter(mail_outbox::Column::Id.eq(id))
+            .filter(Self::due_condition())
+            .exec_with_returning(conn)
+            .await?;
+        Ok(claimed.into_iter().next())
+    }
+
+    /// Record the outcome of sending a [`Self::claim`]ed email, scheduling a retry or marking it
+    /// dead if it failed. Nothing is recorded if the lease ran out and another worker has claimed
+    /// the email since.
+    pub async fn record_result<T: ConnectionTrait, E: ToString>(
+        conn: &T,
+        item: &mail_outbox::Model,
+        result: Result<(), E>,
+        max_retries: i32,
+    ) -> Result<(), DbErr> {
+        let mut updated_item = mail_outbox::ActiveModel {
+            locked_until: Set(None),
+            ..Default::default()
+        };
+
+        match result {
+            Ok(_) => updated_item.sent_at = Set(Some(Utc::now().naive_utc())),
+            Err(e) => {
+                let retries = item.retries + 1;
+                updated_item.retries = Set(retries);
+                updated_item.error = Set(Some(e.to_string()));
+
+                if retries >= max_retries {
+                    updated_item.dead_at = Set(Some(Utc::now().naive_utc()));
+                } else {
+                    updated_item.next_attempt_at = Set(next_attempt_at(retries));
+                }
+            }
+        }
+
+        MailOutbox::update_many()
+            .set(updated_item)
+            .filter(mail_outbox::Column::Id.eq(item.id))
+            .filter(mail_outbox::Column::LockedUntil.eq(item.locked_until))
+            .exec(conn)
+            .await?;
+        Ok(())
+    }
+
+    /// Emails about the organisation that have failed at least once and haven't been sent since
+    pub async fn list_failed_for_org<T: ConnectionTrait>(
+        conn: &T,
+        org_id: PalformDatabaseID<IDOrganisation>,
+    ) -> Result<Vec<APIFailedMail>, DbErr> {
+        MailOutbox::find()
+            .filter(all![
+                mail_outbox::Column::OrganisationId.eq(org_id),
+                mail_outbox::Column::SentAt.is_null(),
+                mail_outbox::Column::Retries.gt(0)
+            ])
+            .order_by(mail_outbox::Column::CreatedAt, Order::Desc)
+            .into_model()
+            .all(conn)
+            .await
+    }
+
+    /// Put a dead email back into the queue so it gets sent on the next run, with its retry count
+    /// reset. Returns `false` if the email doesn't exist in the organisation or isn't dead.
+    pub async fn requeue<T: ConnectionTrait>(
+        conn: &T,
+        org_id: PalformDatabaseID<IDOrganisation>,
+        id: PalformDatabaseID<IDMailOutboxItem>,
+    ) -> Result<bool, DbErr> {
+        let result = MailOutbox::update_many()
+            .filter(all![
+                mail_outbox::Column::Id.eq(id),
+                mail_outbox::Column::OrganisationId.eq(org_id),
+                mail_outbox::Column::SentAt.is_null(),
+                mail_outbox::Column::DeadAt.is_not_null()
+            ])
+            .set(mail_outbox::ActiveModel {
+                dead_at: Set(None),
+                retries: Set(0),
+                error: Set(None),
+                next_attempt_at: Set(Utc::now().naive_utc()),
+                ..Default::default()
+            })
+            .exec(conn)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    /// Sent emails are only kept around for a while, since they include things like verification
+    /// links
+    pub async fn delete_sent_before<T: ConnectionTrait>(
+        conn: &T,
+        before: NaiveDateTime,
+    ) -> Result<u64, DbErr> {
+        let result = MailOutbox::delete_many()
+            .filter(mail_outbox::Column::SentAt.lt(before))
+            .exec(conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
+}
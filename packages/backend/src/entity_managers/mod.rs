@@ -5,10 +5,12 @@ pub mod billing_entitlement_proxy;
 pub mod email_verifications;
 pub mod feedback;
 pub mod form_brandings;
+pub mod form_revisions;
 pub mod form_templates;
 pub mod forms;
 pub mod induction;
 pub mod keys;
+pub mod mail_outbox;
 pub mod organisation_auth_config;
 pub mod organisation_auth_team_mappings;
 pub mod organisation_invites;
@@ -17,8 +19,11 @@ pub mod organisation_teams;
 pub mod orgs;
 pub mod question_groups;
 pub mod questions;
+pub mod service_account_api_keys;
 pub mod social_auth_connections;
 pub mod submission;
+pub mod submission_chain;
+pub mod submission_files;
 pub mod team_assets;
 pub mod webhook_jobs;
 pub mod webhooks;
@@ -15,10 +15,7 @@ use thiserror::Error;
 use crate::{
     api_entities::org::APIOrganisation,
     config::Config,
-    mail::{
-        client::PalformMailClient,
-        events::{EventNotficationManager, EventNotificationError},
-    },
+    mail::events::EventNotficationManager,
     rocket_util::from_org_id::FromOrgIdTrait,
 };
 
@@ -179,13 +176,13 @@ impl OrganisationManager {
             .await
     }
 
-    pub async fn send_staff_deletion_request(
+    pub async fn send_staff_deletion_request<T: ConnectionTrait>(
+        conn: &T,
         org_id: PalformDatabaseID<IDOrganisation>,
         config: &Config,
-        mail: &PalformMailClient,
-    ) -> Result<(), EventNotificationError> {
+    ) -> Result<(), DbErr> {
         EventNotficationManager::notify_event(
-            mail,
+            conn,
             config,
             "organisation requested deletion".to_string(),
             org_id.into_unknown(),
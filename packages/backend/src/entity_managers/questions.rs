@@ -16,7 +16,11 @@ use thiserror::Error;
 
 use crate::api_entities::question::QuestionWithEncodedConfiguration;
 
-use super::billing_entitlement_proxy::BillingEntitlementContextualCountTrait;
+use super::{
+    billing_entitlement_proxy::BillingEntitlementContextualCountTrait,
+    form_revisions::{FormRevisionError, FormRevisionManager},
+    forms::FormManager,
+};
 
 #[derive(Debug, Error)]
 pub enum GetQuestionError {
@@ -34,6 +38,8 @@ pub enum SetQuestionError {
     Encode(#[from] serde_json::Error),
     #[error("{0}")]
     Validation(String),
+    #[error("Create revision: {0}")]
+    Revision(#[from] FormRevisionError),
 }
 
 pub struct QuestionManager;
@@ -107,12 +113,15 @@ impl QuestionManager {
         true
     }
 
+    /// Replaces the form's questions and groups, and records them as a new form revision
     pub async fn save_questions_and_groups<T: ConnectionTrait>(
         conn: &T,
         form_id: PalformDatabaseID<IDForm>,
         groups: Vec<APIQuestionGroup>,
         questions: Vec<APIQuestion>,
     ) -> Result<(), SetQuestionError> {
+        FormManager::pin_published_revision(conn, form_id).await?;
+
         let current_question_ids: Vec<PalformDatabaseID<IDQuestion>> = Question::find()
             .select_only()
             .column(question::Column::Id)
@@ -173,6 +182,20 @@ impl QuestionManager {
                     QuestionWithEncodedConfiguration::encode_config(q.configuration.clone())
                         .map_err(SetQuestionError::Encode)?;
 
+                let server_validation = QuestionWithEncodedConfiguration::encode_server_validation(
+                    q.server_validation.clone(),
+                )
+                .map_err(SetQuestionError::Encode)?;
+                if q.server_validation
+                    .as_ref()
+                    .is_some_and(|v| !v.supports(&q.configuration))
+                {
+                    return Err(SetQuestionError::Validation(
+                        "server validation setting isn't supported for this question type"
+                            .to_string(),
+                    ));
+                }
+
                 if let Some(internal_name) = q.internal_name.clone() {
                     if !Self::validate_question_internal_name(internal_name) {
                         return Err(SetQuestionError::Validation(
@@ -192,6 +215,7 @@ impl QuestionManager {
                         configuration: Set(configuration),
                         position: Set(i as i32),
                         group_id: Set(q.group_id),
+                        server_validation: Set(server_validation),
                     },
                 ))
             })
@@ -240,6 +264,8 @@ impl QuestionManager {
                 .await?;
         }
 
+        FormRevisionManager::create(conn, form_id, &groups, &questions).await?;
+
         Ok(())
     }
 }
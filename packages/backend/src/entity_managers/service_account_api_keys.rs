@@ -0,0 +1,212 @@
+use chrono::{NaiveDateTime, Utc};
+use palform_entities::{admin_user, organisation_membership, prelude::*, service_account_api_key};
+use palform_tsid::{
+    resources::{IDAdminUser, IDOrganisation, IDServiceAccountAPIKey},
+    tsid::PalformDatabaseID,
+};
+use rand::distributions::{Alphanumeric, DistString};
+use ring::digest::{digest, SHA256};
+use sea_orm::{
+    sea_query::{Expr, Query, SelectStatement},
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, PaginatorTrait,
+    QueryFilter, QueryOrder, Set,
+};
+use subtle::ConstantTimeEq;
+
+use crate::{
+    api_entities::service_account_api_key::APIServiceAccountAPIKey, auth::api_keys::APIKeyScope,
+};
+
+/// Keys are looked up by their first few characters, then checked against the hash
+const KEY_PREFIX_LENGTH: usize = 12;
+
+pub struct ServiceAccountAPIKeysManager;
+
+impl ServiceAccountAPIKeysManager {
+    fn hash_key(key: &str) -> String {
+        faster_hex::hex_string(digest(&SHA256, key.as_bytes()).as_ref())
+    }
+
+    fn key_prefix(key: &str) -> String {
+        key.chars().take(KEY_PREFIX_LENGTH).collect()
+    }
+
+    /// Old service account tokens that started with the same characters as another were migrated
+    /// with a prefix taken from their hash instead. This is never the same length as a normal
+    /// prefix, so the two can't clash.
+    fn hashed_key_prefix(hash: &str) -> String {
+        format!("sha256:{}", &hash[..16])
+    }
+
+    /// Returns the new key's ID and the key itself, which can't be retrieved again
+    pub async fn create<T: ConnectionTrait>(
+        conn: &T,
+        user_id: PalformDatabaseID<IDAdminUser>,
+        name: String,
+        scopes: Vec<APIKeyScope>,
+        expires_at: Option<NaiveDateTime>,
+    ) -> Result<(PalformDatabaseID<IDServiceAccountAPIKey>, String), DbErr> {
+        let key = format!(
+            "pfk_{}",
+            Alphanumeric.sample_string(&mut rand::thread_rng(), 40)
+        );
+        let scopes = serde_json::to_value(scopes).map_err(|e| DbErr::Custom(e.to_string()))?;
+
+        let new_key = service_account_api_key::ActiveModel {
+            id: Set(PalformDatabaseID::<IDServiceAccountAPIKey>::random()),
+            user_id: Set(user_id),
+            name: Set(name),
+            prefix: Set(Self::key_prefix(&key)),
+            hash: Set(Self::hash_key(&key)),
+            scopes: Set(scopes),
+            expires_at: Set(expires_at),
+            ..Default::default()
+        };
+        let new_key = new_key.insert(conn).await?;
+        Ok((new_key.id, key))
+    }
+
+    /// Finds the service account that owns `key`, if the key is valid and hasn't expired
+    pub async fn authenticate<T: ConnectionTrait>(
+        conn: &T,
+        key: &str,
+    ) -> Result<Option<(admin_user::Model, service_account_api_key::Model)>, DbErr> {
+        let hash = Self::hash_key(key);
+        let Some((key_model, Some(user))) = ServiceAccountApiKey::find()
+            .filter(
+                service_account_api_key::Column::Prefix
+                    .is_in([Self::key_prefix(key), Self::hashed_key_prefix(&hash)]),
+            )
+            .find_also_related(AdminUser)
+            .all(conn)
+            .await?
+            .into_iter()
+            .find(|(k, _)| bool::from(k.hash.as_bytes().ct_eq(hash.as_bytes())))
+        else {
+            return Ok(None);
+        };
+
+        let now = Utc::now().naive_utc();
+        if key_model
+            .expires_at
+            .is_some_and(|expires_at| expires_at < now)
+        {
+            return Ok(None);
+        }
+
+        let mut active_key: service_account_api_key::ActiveModel = key_model.into();
+        active_key.last_used_at = Set(Some(now));
+        let key_model = active_key.update(conn).await?;
+
+        Ok(Some((user, key_model)))
+    }
+
+    /// Scopes that can't be parsed (e.g. from a newer version) are ignored
+    pub fn scopes(key: &service_account_api_key::Model) -> Vec<APIKeyScope> {
+        serde_json::from_value::<Vec<String>>(key.scopes.clone())
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|s| APIKeyScope::try_from(s).ok())
+            .collect()
+    }
+
+    pub async fn list_for_user<T: ConnectionTrait>(
+        conn: &T,
+        user_id: PalformDatabaseID<IDAdminUser>,
+    ) -> Result<Vec<service_account_api_key::Model>, DbErr> {
+        ServiceAccountApiKey::find()
+            .filter(service_account_api_key::Column::UserId.eq(user_id))
+            .order_by_asc(service_account_api_key::Column::CreatedAt)
+            .all(conn)
+            .await
+    }
+
+    /// Users who are members of `org_id` and no other organisation. Org admins can only manage
+    /// keys for these, so they can't use a key to get into another organisation (or break its
+    /// integrations) through a user they share with it.
+    fn users_only_in_org(org_id: PalformDatabaseID<IDOrganisation>) -> SelectStatement {
+        Query::select()
+            .column(organisation_membership::Column::UserId)
+            .from(OrganisationMembership)
+            .group_by_col(organisation_membership::Column::UserId)
+            .and_having(
+                Expr::col(organisation_membership::Column::OrganisationId)
+                    .min()
+                    .eq(org_id),
+            )
+            .and_having(
+                Expr::col(organisation_membership::Column::OrganisationId)
+                    .max()
+                    .eq(org_id),
+            )
+            .to_owned()
+    }
+
+    pub async fn can_org_manage_user<T: ConnectionTrait>(
+        conn: &T,
+        org_id: PalformDatabaseID<IDOrganisation>,
+        user_id: PalformDatabaseID<IDAdminUser>,
+    ) -> Result<bool, DbErr> {
+        AdminUser::find_by_id(user_id)
+            .filter(admin_user::Column::Id.in_subquery(Self::users_only_in_org(org_id)))
+            .count(conn)
+            .await
+            .map(|c| c == 1)
+    }
+
+    pub async fn list_for_org<T: ConnectionTrait>(
+        conn: &T,
+        org_id: PalformDatabaseID<IDOrganisation>,
+    ) -> Result<Vec<APIServiceAccountAPIKey>, DbErr> {
+        let keys = ServiceAccountApiKey::find()
+            .filter(
+                service_account_api_key::Column::UserId
+                    .in_subquery(Self::users_only_in_org(org_id)),
+            )
+            .order_by_asc(service_account_api_key::Column::CreatedAt)
+            .all(conn)
+            .await?;
+
+        Ok(keys
+            .into_iter()
+            .map(|key| APIServiceAccountAPIKey {
+                scopes: Self::scopes(&key).iter().map(|s| s.to_string()).collect(),
+                id: key.id,
+                user_id: key.user_id,
+                name: key.name,
+                prefix: key.prefix,
+                created_at: key.created_at,
+                expires_at: key.expires_at,
+                last_used_at: key.last_used_at,
+            })
+            .collect())
+    }
+
+    /// Returns false if the key didn't exist or isn't one the organisation can manage
+    pub async fn revoke_in_org<T: ConnectionTrait>(
+        conn: &T,
+        org_id: PalformDatabaseID<IDOrganisation>,
+        key_id: PalformDatabaseID<IDServiceAccountAPIKey>,
+    ) -> Result<bool, DbErr> {
+        let result = ServiceAccountApiKey::delete_many()
+            .filter(service_account_api_key::Column::Id.eq(key_id))
+            .filter(
+                service_account_api_key::Column::UserId
+                    .in_subquery(Self::users_only_in_org(org_id)),
+            )
+            .exec(conn)
+            .await?;
+        Ok(result.rows_affected > 0)
+    }
+
+    /// Returns false if the key didn't exist
+    pub async fn revoke<T: ConnectionTrait>(
+        conn: &T,
+        key_id: PalformDatabaseID<IDServiceAccountAPIKey>,
+    ) -> Result<bool, DbErr> {
+        let result = ServiceAccountApiKey::delete_by_id(key_id)
+            .exec(conn)
+            .await?;
+        Ok(result.rows_affected > 0)
+    }
+}
@@ -1,52 +1,71 @@
 use chrono::{DateTime, NaiveDateTime, Utc};
-use lettre::{
-    address::AddressError,
-    message::{header::ContentType, Mailbox},
-};
+use lettre::message::Mailbox;
+use palform_client_common::form_management::submission_chain::SubmissionChainEntryKind;
 use palform_entities::{
-    admin_user, deleted_submission, form, prelude::*, submission, team, team_membership,
+    admin_user, deleted_submission, form, prelude::*, submission, submission_key_packet, team,
+    team_membership,
 };
 use palform_migration::all;
 use palform_tsid::{
-    resources::{IDAdminUser, IDFillAccessToken, IDForm, IDOrganisation, IDSubmission, IDTeam},
+    resources::{
+        IDAdminUser, IDFillAccessToken, IDForm, IDFormRevision, IDOrganisation, IDSubmission,
+        IDTeam,
+    },
     tsid::PalformDatabaseID,
 };
+use rocket::futures::Stream;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait, FromQueryResult,
-    JoinType, Order, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, RelationTrait, Set, StreamTrait,
+    sea_query::OnConflict, ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DbErr,
+    EntityTrait, FromQueryResult, JoinType, Order, PaginatorTrait, QueryFilter, QueryOrder,
+    QuerySelect, RelationTrait, Set, StreamTrait,
 };
-use serde::Serialize;
-use thiserror::Error;
 
 use crate::{
-    api_entities::submission::APISubmissionCountPerForm, entity_managers::webhook_jobs::WebhookJobsManager, mail::{
-        client::PalformMailClient,
-        headers::{MailgunHeader, MailgunTemplateNameHeader, MailgunVariableListHeader},
-    }
+    api_entities::{
+        submission::APISubmissionCountPerForm,
+        webhook::{APISubmissionDeletedWebhookPayload, APIWebhookPayload},
+    },
+    entity_managers::{
+        mail_outbox::MailOutboxManager, submission_chain::SubmissionChainManager,
+        webhook_jobs::WebhookJobsManager,
+    },
+    mail::templates::FormResponseMail,
 };
 
-#[derive(Debug, Error)]
-pub enum SubmissionNotificationError {
-    #[error("{0}")]
-    DBError(#[from] DbErr),
-    #[error("Sending email: {0}")]
-    Email(String),
-}
-
 pub struct SubmissionManager;
 
 impl SubmissionManager {
+    /// `data_hash` is the submission's hash for the form's chain, from
+    /// `CryptoSubmissionRepr::chain_data_hash`
     pub async fn create_submission<T: ConnectionTrait>(
         conn: &T,
         form_id: PalformDatabaseID<IDForm>,
         fill_token_id: PalformDatabaseID<IDFillAccessToken>,
+        form_revision_id: Option<PalformDatabaseID<IDFormRevision>>,
         data: Vec<u8>,
+        data_hash: String,
+        signing_key: Option<String>,
     ) -> Result<PalformDatabaseID<IDSubmission>, DbErr> {
+        let id = PalformDatabaseID::<IDSubmission>::random();
+        let link = SubmissionChainManager::append(
+            conn,
+            form_id,
+            SubmissionChainEntryKind::Created,
+            id,
+            Some(&data_hash),
+        )
+        .await?;
+
         let new_submission = submission::ActiveModel {
-            id: Set(PalformDatabaseID::<IDSubmission>::random()),
+            id: Set(id),
             form_id: Set(form_id),
             encrypted_data: Set(data),
             for_token: Set(Some(fill_token_id)),
+            form_revision_id: Set(form_revision_id),
+            signing_key: Set(signing_key),
+            chain_index: Set(Some(link.index)),
+            data_hash: Set(Some(data_hash)),
+            chain_hash: Set(Some(link.hash)),
             ..Default::default()
         };
         let resp = new_submission.insert(conn).await?;
@@ -92,6 +111,19 @@ impl SubmissionManager {
             .await
     }
 
+    /// Like `list_submissions`, but yields rows one at a time so large forms can be exported
+    /// without loading every ciphertext into memory.
+    pub async fn stream_for_form<'a, T: ConnectionTrait + StreamTrait + Send>(
+        conn: &'a T,
+        form_id: PalformDatabaseID<IDForm>,
+    ) -> Result<impl Stream<Item = Result<submission::Model, DbErr>> + Send + 'a, DbErr> {
+        Submission::find()
+            .filter(submission::Column::FormId.eq(form_id))
+            .order_by(submission::Column::CreatedAt, Order::Asc)
+            .stream(conn)
+            .await
+    }
+
     pub async fn list_deleted_submissions<T: ConnectionTrait>(
         conn: &T,
         form_id: PalformDatabaseID<IDForm>,
@@ -154,21 +186,70 @@ impl SubmissionManager {
         Ok(resp == 1)
     }
 
-    pub async fn delete_submission<T: ConnectionTrait>(
+    pub async fn delete_submission<T: ConnectionTrait + StreamTrait>(
         conn: &T,
         id: PalformDatabaseID<IDSubmission>,
         form_id: PalformDatabaseID<IDForm>,
     ) -> Result<(), DbErr> {
+        let new_deletion = Self::record_deletion(conn, id, form_id).await?;
         Submission::delete_by_id(id).exec(conn).await?;
 
+        let wjm = WebhookJobsManager::new(conn);
+        wjm.create_for_event(
+            form_id,
+            APIWebhookPayload::SubmissionDeleted(APISubmissionDeletedWebhookPayload {
+                submission_id: id,
+                form_id,
+                deleted_at: new_deletion.deleted_at.and_utc(),
+            }),
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Adds the `deleted_submission` row and chain entry for a submission that's about to be
+    /// deleted. The submission's own chain entry is copied over, so the chain can still be checked
+    /// once it's gone.
+    pub async fn record_deletion<T: ConnectionTrait>(
+        conn: &T,
+        id: PalformDatabaseID<IDSubmission>,
+        form_id: PalformDatabaseID<IDForm>,
+    ) -> Result<deleted_submission::Model, DbErr> {
+        let (created_chain_index, created_data_hash, created_chain_hash): (
+            Option<i64>,
+            Option<String>,
+            Option<String>,
+        ) = Submission::find_by_id(id)
+            .select_only()
+            .column(submission::Column::ChainIndex)
+            .column(submission::Column::DataHash)
+            .column(submission::Column::ChainHash)
+            .into_tuple()
+            .one(conn)
+            .await?
+            .unwrap_or_default();
+
+        let link = SubmissionChainManager::append(
+            conn,
+            form_id,
+            SubmissionChainEntryKind::Deleted,
+            id,
+            None,
+        )
+        .await?;
+
         let new_deletion = deleted_submission::ActiveModel {
             id: Set(id),
             form_id: Set(form_id),
+            chain_index: Set(Some(link.index)),
+            chain_hash: Set(Some(link.hash)),
+            created_chain_index: Set(created_chain_index),
+            created_data_hash: Set(created_data_hash),
+            created_chain_hash: Set(created_chain_hash),
             ..Default::default()
         };
-        new_deletion.insert(conn).await?;
-
-        Ok(())
+        new_deletion.insert(conn).await
     }
 
     pub async fn get_by_id<T: ConnectionTrait>(
@@ -178,13 +259,77 @@ impl SubmissionManager {
         Submission::find_by_id(submission_id).one(conn).await
     }
 
+    pub async fn set_encrypted_data<T: ConnectionTrait>(
+        conn: &T,
+        submission_id: PalformDatabaseID<IDSubmission>,
+        data: Vec<u8>,
+    ) -> Result<(), DbErr> {
+        let submission = submission::ActiveModel {
+            id: Set(submission_id),
+            encrypted_data: Set(data),
+            ..Default::default()
+        };
+        submission.update(conn).await?;
+        Ok(())
+    }
+
+    /// Recipients (as hex key handles) whose key packets were added to the submission by rewraps,
+    /// rather than by the respondent
+    pub async fn list_rewrapped_recipients<T: ConnectionTrait>(
+        conn: &T,
+        submission_id: PalformDatabaseID<IDSubmission>,
+    ) -> Result<Vec<String>, DbErr> {
+        SubmissionKeyPacket::find()
+            .filter(submission_key_packet::Column::SubmissionId.eq(submission_id))
+            .select_only()
+            .column(submission_key_packet::Column::Recipient)
+            .into_tuple()
+            .all(conn)
+            .await
+    }
+
+    /// Records that `user_id` added or replaced the key packets for `recipients` in a rewrap
+    pub async fn record_rewrapped_recipients<T: ConnectionTrait>(
+        conn: &T,
+        submission_id: PalformDatabaseID<IDSubmission>,
+        recipients: Vec<String>,
+        user_id: PalformDatabaseID<IDAdminUser>,
+    ) -> Result<(), DbErr> {
+        if recipients.is_empty() {
+            return Ok(());
+        }
+
+        let now = Utc::now().naive_utc();
+        SubmissionKeyPacket::insert_many(recipients.into_iter().map(|recipient| {
+            submission_key_packet::ActiveModel {
+                submission_id: Set(submission_id),
+                recipient: Set(recipient),
+                added_by_id: Set(Some(user_id)),
+                created_at: Set(now),
+            }
+        }))
+        .on_conflict(
+            OnConflict::columns([
+                submission_key_packet::Column::SubmissionId,
+                submission_key_packet::Column::Recipient,
+            ])
+            .update_columns([
+                submission_key_packet::Column::AddedById,
+                submission_key_packet::Column::CreatedAt,
+            ])
+            .to_owned(),
+        )
+        .exec_without_returning(conn)
+        .await?;
+        Ok(())
+    }
+
     pub async fn run_submission_notification<T: ConnectionTrait + StreamTrait>(
         conn: &T,
         org_id: PalformDatabaseID<IDOrganisation>,
         form_id: PalformDatabaseID<IDForm>,
         submission_id: PalformDatabaseID<IDSubmission>,
-        mail_client: &PalformMailClient,
-    ) -> Result<(), SubmissionNotificationError> {
+    ) -> Result<(), DbErr> {
         let wjm = WebhookJobsManager::new(conn);
         wjm.create(submission_id).await?;
 
@@ -219,47 +364,14 @@ impl SubmissionManager {
                 .await?;
 
             for email in email_addresses {
-                let parsed_to_address: Result<Mailbox, AddressError> = email.parse();
-                if let Ok(parsed_to_address) = parsed_to_address {
-                    #[derive(Serialize)]
-                    struct MessageVariables {
-                        form_editor_name: String,
-                        org_id: String,
-                        form_id: String,
-                    }
-
-                    let message_variables = MessageVariables {
+                if email.parse::<Mailbox>().is_ok() {
+                    let template = FormResponseMail {
                         form_editor_name: form_settings.editor_name.clone(),
                         org_id: org_id.to_string(),
                         form_id: form_id.to_string(),
                     };
-                    let message_variables =
-                        serde_json::to_string(&message_variables).map_err(|e| {
-                            SubmissionNotificationError::Email(format!(
-                                "serialize message variables: {}",
-                                e
-                            ))
-                        })?;
-
-                    let message = mail_client
-                        .get_email_builder()
-                        .to(parsed_to_address)
-                        .subject("New form response")
-                        .header(MailgunHeader::<MailgunTemplateNameHeader>::new(
-                            "form_response".to_string(),
-                        ))
-                        .header(MailgunHeader::<MailgunVariableListHeader>::new(
-                            message_variables,
-                        ))
-                        .header(ContentType::TEXT_HTML)
-                        .body(Vec::new())
-                        .map_err(|e| {
-                            SubmissionNotificationError::Email(format!("build message: {}", e))
-                        })?;
-
-                    mail_client.send_email(message).await.map_err(|e| {
-                        SubmissionNotificationError::Email(format!("send email: {}", e))
-                    })?;
+
+                    MailOutboxManager::enqueue(conn, email, &template, None, Some(org_id)).await?;
                 }
             }
         }
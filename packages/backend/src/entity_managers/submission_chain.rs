@@ -0,0 +1,155 @@
+use chrono::Utc;
+use palform_client_common::form_management::submission_chain::{
+    next_submission_chain_hash, submission_chain_genesis, SubmissionChainCheckpoint,
+    SubmissionChainEntry, SubmissionChainEntryKind,
+};
+use palform_entities::{deleted_submission, form, prelude::*, submission};
+use palform_tsid::{
+    resources::{IDForm, IDSubmission},
+    tsid::PalformDatabaseID,
+};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QuerySelect,
+    Set,
+};
+
+pub struct SubmissionChainLink {
+    pub index: i64,
+    pub hash: String,
+}
+
+pub struct SubmissionChainManager;
+
+impl SubmissionChainManager {
+    async fn get_head<T: ConnectionTrait>(
+        conn: &T,
+        form_id: PalformDatabaseID<IDForm>,
+        lock: bool,
+    ) -> Result<(String, i64), DbErr> {
+        let mut query = Form::find_by_id(form_id)
+            .select_only()
+            .column(form::Column::SubmissionChainHead)
+            .column(form::Column::SubmissionChainLength);
+        if lock {
+            query = query.lock_exclusive();
+        }
+
+        let (head, length): (Option<String>, i64) = query
+            .into_tuple()
+            .one(conn)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Form not found".to_string()))?;
+        Ok((
+            head.unwrap_or_else(|| submission_chain_genesis(form_id)),
+            length,
+        ))
+    }
+
+    /// Adds an entry to the end of the form's chain. The form row stays locked until the
+    /// transaction ends, so this should be called as late as possible.
+    pub async fn append<T: ConnectionTrait>(
+        conn: &T,
+        form_id: PalformDatabaseID<IDForm>,
+        kind: SubmissionChainEntryKind,
+        submission_id: PalformDatabaseID<IDSubmission>,
+        data_hash: Option<&str>,
+    ) -> Result<SubmissionChainLink, DbErr> {
+        let (head, length) = Self::get_head(conn, form_id, true).await?;
+        let hash = next_submission_chain_hash(&head, length, kind, submission_id, data_hash);
+
+        let form = form::ActiveModel {
+            id: Set(form_id),
+            submission_chain_head: Set(Some(hash.clone())),
+            submission_chain_length: Set(length + 1),
+            ..Default::default()
+        };
+        form.update(conn).await?;
+
+        Ok(SubmissionChainLink {
+            index: length,
+            hash,
+        })
+    }
+
+    /// Every entry in the form's chain, in order. Should be read in the same transaction as the
+    /// checkpoint, so the two match.
+    pub async fn list_entries<T: ConnectionTrait>(
+        conn: &T,
+        form_id: PalformDatabaseID<IDForm>,
+    ) -> Result<Vec<SubmissionChainEntry>, DbErr> {
+        let submissions = Submission::find()
+            .filter(submission::Column::FormId.eq(form_id))
+            .filter(submission::Column::ChainIndex.is_not_null())
+            .select_only()
+            .column(submission::Column::Id)
+            .column(submission::Column::ChainIndex)
+            .column(submission::Column::DataHash)
+            .column(submission::Column::ChainHash)
+            .into_tuple::<(
+                PalformDatabaseID<IDSubmission>,
+                Option<i64>,
+                Option<String>,
+                Option<String>,
+            )>()
+            .all(conn)
+            .await?;
+
+        let deleted = DeletedSubmission::find()
+            .filter(deleted_submission::Column::FormId.eq(form_id))
+            .filter(deleted_submission::Column::ChainIndex.is_not_null())
+            .all(conn)
+            .await?;
+
+        let mut entries = Vec::with_capacity(submissions.len() + deleted.len() * 2);
+        for (submission_id, index, data_hash, chain_hash) in submissions {
+            if let (Some(index), Some(chain_hash)) = (index, chain_hash) {
+                entries.push(SubmissionChainEntry {
+                    index,
+                    kind: SubmissionChainEntryKind::Created,
+                    submission_id,
+                    data_hash,
+                    chain_hash,
+                });
+            }
+        }
+
+        for deletion in deleted {
+            if let (Some(index), Some(chain_hash)) =
+                (deletion.created_chain_index, deletion.created_chain_hash)
+            {
+                entries.push(SubmissionChainEntry {
+                    index,
+                    kind: SubmissionChainEntryKind::Created,
+                    submission_id: deletion.id,
+                    data_hash: deletion.created_data_hash,
+                    chain_hash,
+                });
+            }
+            if let (Some(index), Some(chain_hash)) = (deletion.chain_index, deletion.chain_hash) {
+                entries.push(SubmissionChainEntry {
+                    index,
+                    kind: SubmissionChainEntryKind::Deleted,
+                    submission_id: deletion.id,
+                    data_hash: None,
+                    chain_hash,
+                });
+            }
+        }
+
+        entries.sort_by_key(|e| e.index);
+        Ok(entries)
+    }
+
+    pub async fn get_checkpoint<T: ConnectionTrait>(
+        conn: &T,
+        form_id: PalformDatabaseID<IDForm>,
+    ) -> Result<SubmissionChainCheckpoint, DbErr> {
+        let (head, length) = Self::get_head(conn, form_id, false).await?;
+        Ok(SubmissionChainCheckpoint {
+            form_id,
+            length,
+            head,
+            created_at: Utc::now(),
+        })
+    }
+}
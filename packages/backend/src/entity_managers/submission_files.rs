@@ -0,0 +1,225 @@
+use chrono::{Duration, Utc};
+use palform_entities::{form, organisation, prelude::*, submission_file, team};
+use palform_tsid::{
+    resources::{IDForm, IDOrganisation, IDSubmission, IDSubmissionFile},
+    tsid::PalformDatabaseID,
+};
+use rocket::tokio::io::AsyncRead;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, JoinType, QueryFilter,
+    QuerySelect, RelationTrait, Set,
+};
+use thiserror::Error;
+
+use crate::storage::{
+    buckets::SubmissionAssetsBucket,
+    client::{PalformStorage, StorageError},
+};
+
+use super::billing_entitlement_proxy::BillingEntitlementCountTrait;
+
+/// Uploads that haven't been attached to a submission by then are assumed to be abandoned
+const UNCLAIMED_FILE_GRACE_PERIOD: Duration = Duration::hours(24);
+
+#[derive(Debug, Error)]
+pub enum SubmissionFileError {
+    #[error("Storage: {0}")]
+    Storage(#[from] StorageError),
+    #[error("File is too large")]
+    TooLarge,
+}
+
+impl BillingEntitlementCountTrait for SubmissionFilesManager {
+    /// The total size in bytes of files uploaded to the organisation's forms
+    async fn billing_count<T: ConnectionTrait>(
+        conn: &T,
+        org_id: PalformDatabaseID<IDOrganisation>,
+    ) -> Result<u64, DbErr> {
+        let total: Option<i64> = SubmissionFile::find()
+            .join(JoinType::InnerJoin, submission_file::Relation::Form.def())
+            .join(JoinType::InnerJoin, form::Relation::Team.def())
+            .filter(team::Column::OrganisationId.eq(org_id))
+            .select_only()
+            .column_as(submission_file::Column::Size.sum(), "total")
+            .into_tuple::<Option<i64>>()
+            .one(conn)
+            .await?
+            .flatten();
+        Ok(total.unwrap_or(0) as u64)
+    }
+}
+
+pub struct SubmissionFilesManager;
+
+impl SubmissionFilesManager {
+    pub fn file_path(
+        form_id: PalformDatabaseID<IDForm>,
+        file_id: PalformDatabaseID<IDSubmissionFile>,
+    ) -> String {
+        format!("{}/{}", form_id, file_id)
+    }
+
+    /// Streams an (already encrypted) file to storage, stopping once it goes over `max_size`
+    /// bytes. Returns the new file's ID and size. The file isn't recorded until `record` is called.
+    pub async fn upload<R: AsyncRead + Unpin + Send>(
+        storage: &PalformStorage<SubmissionAssetsBucket>,
+        form_id: PalformDatabaseID<IDForm>,
+        mut reader: R,
+        max_size: u64,
+    ) -> Result<(PalformDatabaseID<IDSubmissionFile>, u64), SubmissionFileError> {
+        let file_id = PalformDatabaseID::<IDSubmissionFile>::random();
+        let path = Self::file_path(form_id, file_id);
+
+        let size = storage
+            .put_stream(&path, &mut reader, max_size)
+            .await
+            .map_err(|e| match e {
+                StorageError::TooLarge => SubmissionFileError::TooLarge,
+                e => e.into(),
+            })?;
+
+        Ok((file_id, size))
+    }
+
+    /// Locks the organisation until the transaction ends, so that uploads happening at the same
+    /// time can't each fit in the storage quota but go over it together. The quota should be
+    /// checked and the file recorded in the same transaction.
+    pub async fn lock_org_storage<T: ConnectionTrait>(
+        conn: &T,
+        org_id: PalformDatabaseID<IDOrganisation>,
+    ) -> Result<(), DbErr> {
+        Organisation::find_by_id(org_id)
+            .select_only()
+            .column(organisation::Column::Id)
+            .lock_exclusive()
+            .into_tuple::<PalformDatabaseID<IDOrganisation>>()
+            .one(conn)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Organisation not found".to_string()))?;
+        Ok(())
+    }
+
+    /// Records an uploaded file as not belonging to any submission yet
+    pub async fn record<T: ConnectionTrait>(
+        conn: &T,
+        form_id: PalformDatabaseID<IDForm>,
+        file_id: PalformDatabaseID<IDSubmissionFile>,
+        size: u64,
+    ) -> Result<(), DbErr> {
+        let new_file = submission_file::ActiveModel {
+            id: Set(file_id),
+            form_id: Set(form_id),
+            submission_id: Set(None),
+            size: Set(size as i64),
+            ..Default::default()
+        };
+        new_file.insert(conn).await?;
+        Ok(())
+    }
+
+    /// Removes an uploaded file that was never recorded
+    pub async fn discard(
+        storage: &PalformStorage<SubmissionAssetsBucket>,
+        form_id: PalformDatabaseID<IDForm>,
+        file_id: PalformDatabaseID<IDSubmissionFile>,
+    ) -> Result<(), StorageError> {
+        storage.delete(&Self::file_path(form_id, file_id)).await?;
+        Ok(())
+    }
+
+    /// Attaches uploaded files to the submission they were sent with. Files from other forms, or
+    /// that already belong to a submission, are ignored.
+    pub async fn claim<T: ConnectionTrait>(
+        conn: &T,
+        form_id: PalformDatabaseID<IDForm>,
+        submission_id: PalformDatabaseID<IDSubmission>,
+        file_ids: &[PalformDatabaseID<IDSubmissionFile>],
+    ) -> Result<(), DbErr> {
+        if file_ids.is_empty() {
+            return Ok(());
+        }
+
+        SubmissionFile::update_many()
+            .col_expr(
+                submission_file::Column::SubmissionId,
+                Some(submission_id).into(),
+            )
+            .filter(submission_file::Column::Id.is_in(file_ids.to_vec()))
+            .filter(submission_file::Column::FormId.eq(form_id))
+            .filter(submission_file::Column::SubmissionId.is_null())
+            .exec(conn)
+            .await?;
+        Ok(())
+    }
+
+    /// Files that were never attached to a submission, or whose submission has since been deleted.
+    /// Forms that have had submissions from clients that don't send their file IDs are skipped, as
+    /// their files would never be claimed.
+    pub async fn list_unclaimed<T: ConnectionTrait>(
+        conn: &T,
+    ) -> Result<
+        Vec<(
+            PalformDatabaseID<IDSubmissionFile>,
+            PalformDatabaseID<IDForm>,
+        )>,
+        DbErr,
+    > {
+        let cut_off = (Utc::now() - UNCLAIMED_FILE_GRACE_PERIOD).naive_utc();
+        SubmissionFile::find()
+            .join(JoinType::InnerJoin, submission_file::Relation::Form.def())
+            .filter(submission_file::Column::SubmissionId.is_null())
+            .filter(submission_file::Column::CreatedAt.lt(cut_off))
+            .filter(form::Column::SubmissionsWithoutFileIds.eq(false))
+            .select_only()
+            .column(submission_file::Column::Id)
+            .column(submission_file::Column::FormId)
+            .into_tuple()
+            .all(conn)
+            .await
+    }
+
+    pub async fn delete<T: ConnectionTrait>(
+        conn: &T,
+        storage: &PalformStorage<SubmissionAssetsBucket>,
+        file_id: PalformDatabaseID<IDSubmissionFile>,
+        form_id: PalformDatabaseID<IDForm>,
+    ) -> Result<(), StorageError> {
+        storage.delete(&Self::file_path(form_id, file_id)).await?;
+        SubmissionFile::delete_by_id(file_id).exec(conn).await?;
+        Ok(())
+    }
+
+    /// Deletes every file uploaded to the form, whether or not it's tracked in the database, and then
+    /// the form's file records. The records are kept until the files are gone so that the cleanup
+    /// job can retry if this fails.
+    pub async fn delete_all_for_form<T: ConnectionTrait>(
+        conn: &T,
+        storage: &PalformStorage<SubmissionAssetsBucket>,
+        form_id: PalformDatabaseID<IDForm>,
+    ) -> Result<(), StorageError> {
+        for path in storage.list(&format!("{}/", form_id)).await? {
+            storage.delete(&path).await?;
+        }
+        SubmissionFile::delete_many()
+            .filter(submission_file::Column::FormId.eq(form_id))
+            .exec(conn)
+            .await?;
+        Ok(())
+    }
+
+    /// Finds the IDs of forms that no longer exist but still have file records. These are left
+    /// behind if deleting a form's files failed.
+    pub async fn list_deleted_forms_with_files<T: ConnectionTrait>(
+        conn: &T,
+    ) -> Result<Vec<PalformDatabaseID<IDForm>>, DbErr> {
+        SubmissionFile::find()
+            .join(JoinType::LeftJoin, submission_file::Relation::Form.def())
+            .filter(form::Column::Id.is_null())
+            .select_only()
+            .column(submission_file::Column::FormId)
+            .distinct()
+            .into_tuple()
+            .all(conn)
+            .await
+    }
+}
@@ -10,9 +10,9 @@ use sea_orm::{
 
 use crate::{
     api_entities::team_asset::APITeamAsset,
-    palform_s3::{
-        buckets::S3BucketTeamAssets,
-        client::{PalformS3Client, PalformS3Error},
+    storage::{
+        buckets::TeamAssetsBucket,
+        client::{PalformStorage, StorageError},
     },
 };
 
@@ -26,16 +26,16 @@ impl TeamAssetsManager {
     }
 
     fn asset_path(&self, asset_id: &PalformDatabaseID<IDTeamAsset>) -> String {
-        format!("/{}/{}", self.team_id, asset_id)
+        format!("{}/{}", self.team_id, asset_id)
     }
 
     pub async fn create<T: ConnectionTrait>(
         &self,
         conn: &T,
-        s3: &PalformS3Client<S3BucketTeamAssets>,
+        storage: &PalformStorage<TeamAssetsBucket>,
         data: &[u8],
         content_type: &str,
-    ) -> Result<PalformDatabaseID<IDTeamAsset>, PalformS3Error> {
+    ) -> Result<PalformDatabaseID<IDTeamAsset>, StorageError> {
         let asset_id = PalformDatabaseID::<IDTeamAsset>::random();
         let new_asset = team_asset::ActiveModel {
             id: Set(asset_id),
@@ -43,8 +43,8 @@ impl TeamAssetsManager {
             ..Default::default()
         };
 
-        s3.bucket
-            .put_object_with_content_type(self.asset_path(&asset_id), data, content_type)
+        storage
+            .put(&self.asset_path(&asset_id), data, content_type)
             .await?;
         new_asset.insert(conn).await?;
         Ok(asset_id)
@@ -52,12 +52,11 @@ impl TeamAssetsManager {
 
     async fn create_api_team_asset(
         &self,
-        s3: &PalformS3Client<S3BucketTeamAssets>,
+        storage: &PalformStorage<TeamAssetsBucket>,
         model: team_asset::Model,
-    ) -> Result<APITeamAsset, PalformS3Error> {
-        let url = s3
-            .bucket
-            .presign_get(self.asset_path(&model.id), 60 * 60, None)
+    ) -> Result<APITeamAsset, StorageError> {
+        let url = storage
+            .download_url(&self.asset_path(&model.id), 60 * 60)
             .await?;
 
         Ok(APITeamAsset::from(model, url))
@@ -66,8 +65,8 @@ impl TeamAssetsManager {
     pub async fn list<T: ConnectionTrait>(
         &self,
         conn: &T,
-        s3: &PalformS3Client<S3BucketTeamAssets>,
-    ) -> Result<Vec<APITeamAsset>, PalformS3Error> {
+        storage: &PalformStorage<TeamAssetsBucket>,
+    ) -> Result<Vec<APITeamAsset>, StorageError> {
         let assets = TeamAsset::find()
             .filter(team_asset::Column::TeamId.eq(self.team_id))
             .all(conn)
@@ -75,7 +74,7 @@ impl TeamAssetsManager {
 
         let mut api_assets = Vec::<APITeamAsset>::new();
         for asset in assets {
-            api_assets.push(self.create_api_team_asset(s3, asset).await?);
+            api_assets.push(self.create_api_team_asset(storage, asset).await?);
         }
 
         Ok(api_assets)
@@ -84,15 +83,15 @@ impl TeamAssetsManager {
     pub async fn get<T: ConnectionTrait>(
         &self,
         conn: &T,
-        s3: &PalformS3Client<S3BucketTeamAssets>,
+        storage: &PalformStorage<TeamAssetsBucket>,
         asset_id: PalformDatabaseID<IDTeamAsset>,
-    ) -> Result<APITeamAsset, PalformS3Error> {
+    ) -> Result<APITeamAsset, StorageError> {
         let asset = TeamAsset::find_by_id(asset_id)
             .one(conn)
             .await?
-            .ok_or(PalformS3Error::AssetNotFound)?;
+            .ok_or(StorageError::AssetNotFound)?;
 
-        self.create_api_team_asset(s3, asset).await
+        self.create_api_team_asset(storage, asset).await
     }
 
     pub async fn verify_asset_team<T: ConnectionTrait>(
@@ -1,24 +1,32 @@
 use std::collections::{hash_map::Entry, HashMap};
 
-use chrono::Utc;
-use palform_entities::{form, prelude::*, submission, webhook, webhook_job};
+use chrono::{NaiveDateTime, Utc};
+use palform_entities::{
+    fill_access_token, form, prelude::*, sea_orm_active_enums::WebhookEventEnum, submission,
+    webhook, webhook_event_subscription, webhook_job,
+};
+use palform_migration::{all, any};
 use palform_tsid::{
-    resources::{IDSubmission, IDWebhook, IDWebhookJob},
+    resources::{IDForm, IDSubmission, IDWebhook, IDWebhookJob},
     tsid::PalformDatabaseID,
 };
+use palform_webhook_signature::{
+    signature_header, DELIVERY_ID_HEADER, SIGNATURE_HEADER, TIMESTAMP_HEADER,
+};
 use ring::hmac;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, JoinType, Order,
-    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, RelationTrait, Set, StreamTrait,
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DbErr, EntityTrait,
+    JoinType, Order, QueryFilter, QueryOrder, QuerySelect, RelationTrait, Set, StreamTrait,
 };
 use thiserror::Error;
 
 use crate::{
     api_entities::{
         submission::{APISubmission, APISubmissionWebhookPayload},
-        webhook::APIWebhookJob,
+        webhook::{APIFillTokenWebhookPayload, APIWebhookJob, APIWebhookPayload},
     },
     crypto::submissions::SubmissionConversionError,
+    jobs::backoff::next_attempt_at,
 };
 
 use super::submission::SubmissionManager;
@@ -38,6 +46,8 @@ pub enum WebhookJobError {
     Serialize(#[from] serde_json::Error),
     #[error("Sending request: {0}")]
     Http(#[from] reqwest::Error),
+    #[error("Job has neither a payload nor a submission")]
+    MissingPayload,
     #[error("Non-success response: {0}")]
     ServerError(String),
 }
@@ -69,7 +79,14 @@ impl<'a, T: ConnectionTrait + StreamTrait> WebhookJobsManager<'a, T> {
         let webhooks = Webhook::find()
             .join(JoinType::LeftJoin, webhook::Relation::Form.def())
             .join(JoinType::InnerJoin, form::Relation::Submission.def())
-            .filter(submission::Column::Id.eq(submission_id))
+            .join(
+                JoinType::InnerJoin,
+                webhook::Relation::WebhookEventSubscription.def(),
+            )
+            .filter(all![
+                submission::Column::Id.eq(submission_id),
+                webhook_event_subscription::Column::Event.eq(WebhookEventEnum::SubmissionCreated)
+            ])
             .all(self.conn)
             .await?;
 
@@ -77,7 +94,8 @@ impl<'a, T: ConnectionTrait + StreamTrait> WebhookJobsManager<'a, T> {
             let new_job = webhook_job::ActiveModel {
                 id: Set(PalformDatabaseID::<IDWebhookJob>::random()),
                 webhook_id: Set(webhook.id),
-                submission_id: Set(submission_id),
+                submission_id: Set(Some(submission_id)),
+                event: Set(WebhookEventEnum::SubmissionCreated),
                 ..Default::default()
             };
 
@@ -87,34 +105,196 @@ impl<'a, T: ConnectionTrait + StreamTrait> WebhookJobsManager<'a, T> {
         Ok(())
     }
 
-    pub async fn run_all_jobs(&mut self) -> Result<(), DbErr> {
-        let mut all_jobs = WebhookJob::find()
-            .filter(webhook_job::Column::DoneAt.is_null())
+    /// Queue a job for every webhook on the form that's subscribed to the payload's event. Unlike
+    /// [`Self::create`], the payload is stored as-is, since whatever it describes might not exist
+    /// anymore by the time the job runs.
+    pub async fn create_for_event(
+        &self,
+        form_id: PalformDatabaseID<IDForm>,
+        payload: APIWebhookPayload,
+    ) -> Result<(), DbErr> {
+        let event = payload.event();
+        let webhook_ids: Vec<PalformDatabaseID<IDWebhook>> = Webhook::find()
+            .join(
+                JoinType::InnerJoin,
+                webhook::Relation::WebhookEventSubscription.def(),
+            )
+            .filter(all![
+                webhook::Column::FormId.eq(form_id),
+                webhook_event_subscription::Column::Event.eq(event.clone())
+            ])
+            .select_only()
+            .column(webhook::Column::Id)
+            .into_tuple()
+            .all(self.conn)
+            .await?;
+
+        if webhook_ids.is_empty() {
+            return Ok(());
+        }
+
+        let payload = serde_json::to_value(payload).map_err(|e| DbErr::Json(e.to_string()))?;
+        for webhook_id in webhook_ids {
+            let new_job = webhook_job::ActiveModel {
+                id: Set(PalformDatabaseID::<IDWebhookJob>::random()),
+                webhook_id: Set(webhook_id),
+                event: Set(event.clone()),
+                payload: Set(Some(payload.clone())),
+                ..Default::default()
+            };
+
+            new_job.insert(self.conn).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Fill tokens don't do anything when they expire, so we look for ones that have expired since
+    /// the last run and queue their events here. Tokens are marked as queued in the same statement
+    /// that finds them, so workers running at the same time never queue the same event twice.
+    pub async fn queue_fill_token_expiries(&self) -> Result<(), DbErr> {
+        let expired_tokens = FillAccessToken::update_many()
+            .col_expr(
+                fill_access_token::Column::ExpiryEventQueued,
+                Expr::value(true),
+            )
+            .filter(all![
+                fill_access_token::Column::ExpiresAt.lte(Utc::now().naive_utc()),
+                fill_access_token::Column::ExpiryEventQueued.eq(false)
+            ])
+            .exec_with_returning(self.conn)
+            .await?;
+
+        for token in expired_tokens {
+            self.create_for_event(
+                token.form_id,
+                APIWebhookPayload::FillTokenExpired(APIFillTokenWebhookPayload {
+                    fill_token_id: token.id,
+                    form_id: token.form_id,
+                    nickname: token.nickname,
+                    expires_at: token.expires_at.map(|e| e.and_utc()),
+                }),
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Put a dead job back into the queue so it gets delivered on the next run, with its retry
+    /// count reset. Returns `false` if the job doesn't exist or isn't dead.
+    pub async fn requeue(
+        &self,
+        webhook_id: PalformDatabaseID<IDWebhook>,
+        job_id: PalformDatabaseID<IDWebhookJob>,
+    ) -> Result<bool, DbErr> {
+        let result = WebhookJob::update_many()
+            .filter(all![
+                webhook_job::Column::Id.eq(job_id),
+                webhook_job::Column::WebhookId.eq(webhook_id),
+                webhook_job::Column::DoneAt.is_null(),
+                webhook_job::Column::DeadAt.is_not_null()
+            ])
+            .set(webhook_job::ActiveModel {
+                dead_at: Set(None),
+                retries: Set(0),
+                error: Set(None),
+                next_attempt_at: Set(Utc::now().naive_utc()),
+                ..Default::default()
+            })
+            .exec(self.conn)
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    fn due_condition() -> Condition {
+        let now = Utc::now().naive_utc();
+        all![
+            webhook_job::Column::DoneAt.is_null(),
+            webhook_job::Column::DeadAt.is_null(),
+            webhook_job::Column::NextAttemptAt.lte(now),
+            any![
+                webhook_job::Column::LockedUntil.is_null(),
+                webhook_job::Column::LockedUntil.lte(now)
+            ]
+        ]
+    }
+
+    /// List the oldest jobs that are due to run, along with the endpoint they'll be sent to. This
+    /// doesn't lock anything, so each job still needs to be [`Self::claim`]ed before running it.
+    pub async fn list_due(
+        &self,
+        limit: u64,
+    ) -> Result<Vec<(PalformDatabaseID<IDWebhookJob>, String)>, DbErr> {
+        WebhookJob::find()
+            .join(JoinType::InnerJoin, webhook_job::Relation::Webhook.def())
+            .filter(Self::due_condition())
             .order_by(webhook_job::Column::CreatedAt, Order::Asc)
-            .paginate(self.conn, 100);
-
-        while let Some(jobs) = all_jobs.fetch_and_next().await? {
-            for job in jobs {
-                let result = self.run_job(&job).await;
-
-                let mut new_job = webhook_job::ActiveModel {
-                    id: Set(job.id),
-                    ..Default::default()
-                };
-
-                match result {
-                    Ok(_) => new_job.done_at = Set(Some(Utc::now().naive_utc())),
-                    Err(e) => {
-                        new_job.done_at = Set(None);
-                        new_job.retries = Set(job.retries + 1);
-                        new_job.error = Set(Some(e.to_string()));
-                    }
-                }
+            .select_only()
+            .column(webhook_job::Column::Id)
+            .column(webhook::Column::Endpoint)
+            .limit(limit)
+            .into_tuple()
+            .all(self.conn)
+            .await
+    }
+
+    /// Take a lease on the job until `locked_until`, as long as it's still due and no other worker
+    /// holds a lease on it. Nothing stays locked in the database while the job is delivered, and
+    /// if the worker never records the outcome, the job becomes due again once the lease runs out.
+    pub async fn claim(
+        &self,
+        job_id: PalformDatabaseID<IDWebhookJob>,
+        locked_until: NaiveDateTime,
+    ) -> Result<Option<webhook_job::Model>, DbErr> {
+        let claimed = WebhookJob::update_many()
+            .col_expr(webhook_job::Column::LockedUntil, Expr::value(locked_until))
+            .filter(webhook_job::Column::Id.eq(job_id))
+            .filter(Self::due_condition())
+            .exec_with_returning(self.conn)
+            .await?;
+        Ok(claimed.into_iter().next())
+    }
+
+    /// Deliver a job [`Self::claim`]ed by this worker and record the outcome, scheduling a retry
+    /// or marking it dead if it failed. The outcome isn't recorded if the lease ran out and
+    /// another worker has claimed the job since.
+    pub async fn process_job(
+        &mut self,
+        job: &webhook_job::Model,
+        max_retries: i32,
+        http_client: &reqwest::Client,
+    ) -> Result<(), DbErr> {
+        let result = self.run_job(job, http_client).await;
+
+        let mut new_job = webhook_job::ActiveModel {
+            locked_until: Set(None),
+            ..Default::default()
+        };
+
+        match result {
+            Ok(_) => new_job.done_at = Set(Some(Utc::now().naive_utc())),
+            Err(e) => {
+                let retries = job.retries + 1;
+                new_job.done_at = Set(None);
+                new_job.retries = Set(retries);
+                new_job.error = Set(Some(e.to_string()));
 
-                new_job.update(self.conn).await?;
+                if retries >= max_retries {
+                    new_job.dead_at = Set(Some(Utc::now().naive_utc()));
+                } else {
+                    new_job.next_attempt_at = Set(next_attempt_at(retries));
+                }
             }
         }
 
+        WebhookJob::update_many()
+            .set(new_job)
+            .filter(webhook_job::Column::Id.eq(job.id))
+            .filter(webhook_job::Column::LockedUntil.eq(job.locked_until))
+            .exec(self.conn)
+            .await?;
         Ok(())
     }
 
@@ -137,38 +317,77 @@ impl<'a, T: ConnectionTrait + StreamTrait> WebhookJobsManager<'a, T> {
         Ok(cached_webhook.to_owned())
     }
 
-    fn sign_payload(secret: String, payload: &[u8]) -> String {
+    /// Signs the body on its own, for receivers that haven't moved to the timestamped signature
+    /// yet. This is open to replays, so new integrations should check
+    /// [`palform_webhook_signature::SIGNATURE_HEADER`] instead.
+    fn sign_payload_legacy(secret: &str, payload: &[u8]) -> String {
         let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
         faster_hex::hex_string(hmac::sign(&key, payload).as_ref())
     }
 
-    async fn run_job(&mut self, job: &webhook_job::Model) -> Result<(), WebhookJobError> {
+    fn active_signing_secrets(webhook: &webhook::Model) -> Vec<&str> {
+        let mut secrets = vec![webhook.signing_secret.as_str()];
+        if let (Some(previous), Some(expires_at)) = (
+            &webhook.previous_signing_secret,
+            webhook.previous_signing_secret_expires_at,
+        ) {
+            if expires_at > Utc::now().naive_utc() {
+                secrets.push(previous.as_str());
+            }
+        }
+        secrets
+    }
+
+    async fn run_job(
+        &mut self,
+        job: &webhook_job::Model,
+        http_client: &reqwest::Client,
+    ) -> Result<(), WebhookJobError> {
         let webhook = self.get_webhook(job.webhook_id).await?;
-        let submission = SubmissionManager::get_by_id(self.conn, job.submission_id)
-            .await?
-            .ok_or(WebhookJobError::DBError(DbErr::RecordNotFound(
-                "Submission".to_string(),
-            )))?;
-        let submission = APISubmission::try_from(submission)?;
-
-        let payload = APISubmissionWebhookPayload {
-            submission_id: submission.id,
-            form_id: webhook.form_id,
-            created_at: submission.created_at,
-            payload: submission.data,
-        };
+        let payload = match (&job.payload, job.submission_id) {
+            (Some(payload), _) => serde_json::to_vec(payload)?,
+            (None, Some(submission_id)) => {
+                let submission = SubmissionManager::get_by_id(self.conn, submission_id)
+                    .await?
+                    .ok_or(WebhookJobError::DBError(DbErr::RecordNotFound(
+                        "Submission".to_string(),
+                    )))?;
+                let submission = APISubmission::try_from(submission)?;
 
-        let payload = serde_json::to_vec(&payload)?;
-        let signature = Self::sign_payload(webhook.signing_secret, &payload);
+                serde_json::to_vec(&APIWebhookPayload::SubmissionCreated(
+                    APISubmissionWebhookPayload {
+                        submission_id: submission.id,
+                        form_id: webhook.form_id,
+                        created_at: submission.created_at,
+                        payload: submission.data,
+                        signing_key: submission.signing_key,
+                    },
+                ))?
+            }
+            (None, None) => return Err(WebhookJobError::MissingPayload),
+        };
 
-        let http_client = reqwest::Client::new();
-        let resp = http_client
+        let delivery_id = job.id.to_string();
+        let timestamp = Utc::now().timestamp();
+        let signatures = signature_header(
+            &Self::active_signing_secrets(&webhook),
+            timestamp,
+            &delivery_id,
+            &payload,
+        );
+        let mut request = http_client
             .post(webhook.endpoint)
-            .body(payload)
             .header(reqwest::header::CONTENT_TYPE, "application/json")
-            .header("x-palform-signature", signature)
-            .send()
-            .await?;
+            .header(SIGNATURE_HEADER, signatures)
+            .header(TIMESTAMP_HEADER, timestamp.to_string())
+            .header(DELIVERY_ID_HEADER, delivery_id);
+        if webhook.legacy_signature {
+            request = request.header(
+                "x-palform-signature",
+                Self::sign_payload_legacy(&webhook.signing_secret, &payload),
+            );
+        }
+        let resp = request.body(payload).send().await?;
 
         let status = resp.status();
         if !status.is_success() {
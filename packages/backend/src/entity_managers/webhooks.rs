@@ -1,4 +1,8 @@
-use palform_entities::{prelude::*, webhook, webhook_job};
+use chrono::Utc;
+use palform_entities::{
+    prelude::*, sea_orm_active_enums::WebhookEventEnum, webhook, webhook_event_subscription,
+    webhook_job,
+};
 use palform_migration::all;
 use palform_tsid::{
     resources::{IDForm, IDWebhook},
@@ -7,8 +11,8 @@ use palform_tsid::{
 use rand::Rng;
 use rocket::tokio::{net::TcpStream, time::timeout};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, PaginatorTrait,
-    QueryFilter, Set,
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, JoinType,
+    PaginatorTrait, QueryFilter, QuerySelect, RelationTrait, Set,
 };
 use std::time::Duration;
 use thiserror::Error;
@@ -61,10 +65,29 @@ impl WebhookManager {
             .all(conn)
             .await?;
 
+        let subscriptions: Vec<(PalformDatabaseID<IDWebhook>, WebhookEventEnum)> =
+            WebhookEventSubscription::find()
+                .join(
+                    JoinType::InnerJoin,
+                    webhook_event_subscription::Relation::Webhook.def(),
+                )
+                .filter(webhook::Column::FormId.eq(form_id))
+                .select_only()
+                .column(webhook_event_subscription::Column::WebhookId)
+                .column(webhook_event_subscription::Column::Event)
+                .into_tuple()
+                .all(conn)
+                .await?;
+
         Ok(webhooks
             .iter()
             .map(|(w, jobs)| {
-                let is_healthy = jobs.iter().all(|job| job.error.is_none());
+                let is_healthy = jobs.iter().all(|job| job.dead_at.is_none());
+                let events = subscriptions
+                    .iter()
+                    .filter(|(webhook_id, _)| *webhook_id == w.id)
+                    .map(|(_, event)| event.clone())
+                    .collect();
 
                 APIWebhook {
                     id: w.id,
@@ -72,6 +95,8 @@ impl WebhookManager {
                     endpoint: w.endpoint.clone(),
                     created_at: w.created_at,
                     is_healthy,
+                    events,
+                    legacy_signature: w.legacy_signature,
                 }
             })
             .collect())
@@ -133,16 +158,101 @@ impl WebhookManager {
         conn: &T,
         form_id: PalformDatabaseID<IDForm>,
         endpoint: Url,
+        events: Vec<WebhookEventEnum>,
     ) -> Result<webhook::Model, DbErr> {
         let new_webhook = webhook::ActiveModel {
             id: Set(PalformDatabaseID::<IDWebhook>::random()),
             form_id: Set(form_id),
             endpoint: Set(endpoint.to_string()),
             signing_secret: Set(Self::create_signing_secret()),
+            legacy_signature: Set(false),
             ..Default::default()
         };
 
-        new_webhook.insert(conn).await
+        let new_webhook = new_webhook.insert(conn).await?;
+        Self::set_events(conn, new_webhook.id, events).await?;
+        Ok(new_webhook)
+    }
+
+    /// Replace the set of events the webhook is subscribed to
+    pub async fn set_events<T: ConnectionTrait>(
+        conn: &T,
+        webhook_id: PalformDatabaseID<IDWebhook>,
+        events: Vec<WebhookEventEnum>,
+    ) -> Result<(), DbErr> {
+        WebhookEventSubscription::delete_many()
+            .filter(webhook_event_subscription::Column::WebhookId.eq(webhook_id))
+            .exec(conn)
+            .await?;
+
+        let mut unique_events = Vec::<WebhookEventEnum>::new();
+        for event in events {
+            if !unique_events.contains(&event) {
+                unique_events.push(event);
+            }
+        }
+
+        if unique_events.is_empty() {
+            return Ok(());
+        }
+
+        WebhookEventSubscription::insert_many(unique_events.into_iter().map(|event| {
+            webhook_event_subscription::ActiveModel {
+                webhook_id: Set(webhook_id),
+                event: Set(event),
+            }
+        }))
+        .exec(conn)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Replace the signing secret with a new one. The old secret stays valid for `overlap`, during
+    /// which deliveries are signed with both.
+    pub async fn rotate_signing_secret<T: ConnectionTrait>(
+        conn: &T,
+        form_id: PalformDatabaseID<IDForm>,
+        webhook_id: PalformDatabaseID<IDWebhook>,
+        overlap: chrono::Duration,
+    ) -> Result<Option<String>, DbErr> {
+        let Some(webhook) = Webhook::find_by_id(webhook_id)
+            .filter(webhook::Column::FormId.eq(form_id))
+            .one(conn)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let new_secret = Self::create_signing_secret();
+        let updated_webhook = webhook::ActiveModel {
+            id: Set(webhook.id),
+            signing_secret: Set(new_secret.clone()),
+            previous_signing_secret: Set(Some(webhook.signing_secret)),
+            previous_signing_secret_expires_at: Set(Some((Utc::now() + overlap).naive_utc())),
+            ..Default::default()
+        };
+        updated_webhook.update(conn).await?;
+
+        Ok(Some(new_secret))
+    }
+
+    /// Stop sending the deprecated `X-Palform-Signature` header, once the receiver checks the
+    /// timestamped signature instead. Returns false if the webhook doesn't exist.
+    pub async fn disable_legacy_signature<T: ConnectionTrait>(
+        conn: &T,
+        form_id: PalformDatabaseID<IDForm>,
+        webhook_id: PalformDatabaseID<IDWebhook>,
+    ) -> Result<bool, DbErr> {
+        let result = Webhook::update_many()
+            .col_expr(webhook::Column::LegacySignature, Expr::value(false))
+            .filter(all![
+                webhook::Column::Id.eq(webhook_id),
+                webhook::Column::FormId.eq(form_id)
+            ])
+            .exec(conn)
+            .await?;
+        Ok(result.rows_affected == 1)
     }
 
     pub async fn delete<T: ConnectionTrait>(
@@ -0,0 +1,15 @@
+use chrono::{Duration, NaiveDateTime, Utc};
+use rand::Rng;
+
+const BACKOFF_BASE_SECS: i64 = 30;
+const BACKOFF_MAX_SECS: i64 = 60 * 60 * 12;
+
+/// When to retry something that has failed `retries` times. This is exponential backoff with
+/// "equal jitter": the delay is somewhere between half and all of `base * 2^retries`, capped at
+/// 12 hours.
+pub fn next_attempt_at(retries: i32) -> NaiveDateTime {
+    let exp = BACKOFF_BASE_SECS.saturating_mul(1i64 << retries.clamp(0, 30));
+    let capped = exp.min(BACKOFF_MAX_SECS);
+    let delay = capped / 2 + rand::thread_rng().gen_range(0..=capped / 2);
+    (Utc::now() + Duration::seconds(delay)).naive_utc()
+}
@@ -0,0 +1,10 @@
+use chrono::{Duration, Utc};
+use sea_orm::{DatabaseConnection, DbErr};
+
+use crate::rate_limit::postgres::PostgresRateLimitStore;
+
+pub async fn job_delete_old_rate_limit_buckets(db: &DatabaseConnection) -> Result<(), DbErr> {
+    PostgresRateLimitStore::delete_idle_buckets(db, (Utc::now() - Duration::hours(1)).naive_utc())
+        .await?;
+    Ok(())
+}
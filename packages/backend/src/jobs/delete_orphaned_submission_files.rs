@@ -0,0 +1,37 @@
+use log::{error, info};
+use sea_orm::{DatabaseConnection, DbErr};
+
+use crate::{
+    config::Config,
+    entity_managers::submission_files::SubmissionFilesManager,
+    storage::{buckets::SubmissionAssetsBucket, client::PalformStorage},
+};
+
+/// Deletes uploads that never became part of a submission (or whose submission was deleted), as
+/// well as any files left behind by forms that no longer exist
+pub async fn job_delete_orphaned_submission_files(
+    db: &DatabaseConnection,
+    config: &Config,
+) -> Result<(), DbErr> {
+    let storage = PalformStorage::<SubmissionAssetsBucket>::init(config)
+        .map_err(|e| DbErr::Custom(format!("init storage: {}", e)))?;
+
+    let unclaimed = SubmissionFilesManager::list_unclaimed(db).await?;
+    let mut deleted_files = 0;
+    for (file_id, form_id) in unclaimed {
+        match SubmissionFilesManager::delete(db, &storage, file_id, form_id).await {
+            Ok(_) => deleted_files += 1,
+            Err(e) => error!("Failed to delete submission file {}: {}", file_id, e),
+        }
+    }
+    info!("Deleted {} orphaned submission files", deleted_files);
+
+    let deleted_forms = SubmissionFilesManager::list_deleted_forms_with_files(db).await?;
+    for form_id in deleted_forms {
+        if let Err(e) = SubmissionFilesManager::delete_all_for_form(db, &storage, form_id).await {
+            error!("Failed to delete files for deleted form {}: {}", form_id, e);
+        }
+    }
+
+    Ok(())
+}
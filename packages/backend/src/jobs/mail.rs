@@ -0,0 +1,108 @@
+use std::time::Duration;
+
+use chrono::Utc;
+use log::{error, info};
+use palform_tsid::{resources::IDMailOutboxItem, tsid::PalformDatabaseID};
+use rocket::{
+    futures::{stream, StreamExt, TryStreamExt},
+    tokio::time::{sleep, timeout},
+};
+use sea_orm::{DatabaseConnection, DbErr};
+
+use crate::{
+    config::Config,
+    entity_managers::mail_outbox::MailOutboxManager,
+    mail::client::{MailError, PalformMailClient},
+};
+
+const BATCH_SIZE: u64 = 100;
+
+/// Sends queued emails. Like the webhook worker, each email is claimed with a short lease before
+/// it's sent, so several workers can share the outbox without a transaction being held open while
+/// talking to the mail server.
+struct MailWorker {
+    db: DatabaseConnection,
+    mail: PalformMailClient,
+    max_retries: i32,
+    send_timeout: Duration,
+    lease: chrono::Duration,
+    max_concurrency: usize,
+    retention: chrono::Duration,
+}
+
+impl MailWorker {
+    async fn new(db: &DatabaseConnection, config: &Config) -> Self {
+        Self {
+            db: db.clone(),
+            mail: PalformMailClient::new(config.clone()).await,
+            max_retries: config.mail_max_retries,
+            send_timeout: Duration::from_secs(config.mail_send_timeout_secs),
+            // Long enough for sending to time out and the outcome to be recorded
+            lease: chrono::Duration::seconds(config.mail_send_timeout_secs as i64 + 60),
+            max_concurrency: config.mail_max_concurrency,
+            retention: chrono::Duration::days(config.mail_retention_days),
+        }
+    }
+
+    /// Returns whether the email was actually sent (or attempted), i.e. whether it was still due
+    /// and not already claimed by another worker.
+    async fn run_one(&self, id: PalformDatabaseID<IDMailOutboxItem>) -> Result<bool, DbErr> {
+        let locked_until = (Utc::now() + self.lease).naive_utc();
+        let Some(item) = MailOutboxManager::claim(&self.db, id, locked_until).await? else {
+            return Ok(false);
+        };
+
+        let result = timeout(self.send_timeout, self.mail.send(&item))
+            .await
+            .unwrap_or(Err(MailError::Timeout));
+        if let Err(e) = &result {
+            error!("Failed to send {} email {}: {}", item.template, item.id, e);
+        }
+        MailOutboxManager::record_result(&self.db, &item, result, self.max_retries).await?;
+        Ok(true)
+    }
+
+    async fn run_pass(&self) -> Result<(), DbErr> {
+        loop {
+            let due = MailOutboxManager::list_due(&self.db, BATCH_SIZE).await?;
+            let batch_was_full = due.len() as u64 == BATCH_SIZE;
+
+            let claimed: Vec<bool> = stream::iter(due)
+                .map(|id| self.run_one(id))
+                .buffer_unordered(self.max_concurrency)
+                .try_collect()
+                .await?;
+            let claimed_any = claimed.contains(&true);
+
+            // If nothing in a full batch could be claimed, another worker has it covered
+            if !batch_was_full || !claimed_any {
+                break;
+            }
+        }
+
+        MailOutboxManager::delete_sent_before(&self.db, (Utc::now() - self.retention).naive_utc())
+            .await?;
+        Ok(())
+    }
+}
+
+pub async fn job_run_mail(
+    db: &DatabaseConnection,
+    config: &Config,
+    daemon: bool,
+) -> Result<(), DbErr> {
+    let worker = MailWorker::new(db, config).await;
+
+    if !daemon {
+        return worker.run_pass().await;
+    }
+
+    let poll_interval = Duration::from_secs(config.mail_poll_interval_secs);
+    info!("Running mail worker every {:?}", poll_interval);
+    loop {
+        if let Err(e) = worker.run_pass().await {
+            error!("Mail worker pass failed: {}", e);
+        }
+        sleep(poll_interval).await;
+    }
+}
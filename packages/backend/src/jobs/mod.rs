@@ -1,5 +1,10 @@
+pub mod backoff;
 pub mod delete_abandoned_emails;
 pub mod delete_old_audit_logs;
 pub mod delete_old_auth_tokens;
+pub mod delete_old_rate_limit_buckets;
 pub mod delete_old_submissions;
+pub mod delete_orphaned_submission_files;
+pub mod mail;
+pub mod warn_expiring_keys;
 pub mod webhooks;
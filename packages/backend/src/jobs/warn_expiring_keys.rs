@@ -0,0 +1,45 @@
+use chrono::{Duration, Utc};
+use sea_orm::{DatabaseConnection, DbErr, TransactionTrait};
+
+use crate::{
+    config::Config,
+    entity_managers::{keys::UserKeyManager, mail_outbox::MailOutboxManager},
+    mail::templates::KeyExpiryMail,
+};
+
+pub async fn job_warn_expiring_keys(db: &DatabaseConnection, config: &Config) -> Result<(), DbErr> {
+    let warn_before = (Utc::now() + Duration::days(config.key_expiry_warning_days)).naive_utc();
+    let expiring_keys = UserKeyManager::list_expiring_keys(db, warn_before).await?;
+
+    for (key, user) in expiring_keys {
+        let txn = db.begin().await?;
+
+        // Users who have already rotated to a newer key don't need a warning
+        let has_newer_key = UserKeyManager::has_key_valid_at(
+            &txn,
+            key.user_id,
+            key.organisation_id,
+            key.expires_at,
+        )
+        .await?;
+        if let (Some(user), false) = (user, has_newer_key) {
+            MailOutboxManager::enqueue(
+                &txn,
+                user.email,
+                &KeyExpiryMail {
+                    org_id: key.organisation_id.to_string(),
+                    key_fingerprint: key.cert_fingerprint.clone(),
+                    expires_at: key.expires_at.format("%Y-%m-%d %H:%M UTC").to_string(),
+                },
+                None,
+                Some(key.organisation_id),
+            )
+            .await?;
+        }
+
+        UserKeyManager::set_expiry_warning_sent(&txn, key.id).await?;
+        txn.commit().await?;
+    }
+
+    Ok(())
+}
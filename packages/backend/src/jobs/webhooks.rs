@@ -1,17 +1,198 @@
-use crate::entity_managers::webhook_jobs::WebhookJobsManager;
-use sea_orm::{AccessMode, DatabaseConnection, DbErr, IsolationLevel, TransactionTrait};
-
-pub async fn job_run_webhooks(db: &DatabaseConnection) -> Result<(), DbErr> {
-    let txn = db
-        .begin_with_config(
-            Some(IsolationLevel::Serializable),
-            Some(AccessMode::ReadWrite),
-        )
-        .await?;
-
-    let mut wjm = WebhookJobsManager::new(&txn);
-    wjm.run_all_jobs().await?;
-
-    txn.commit().await?;
-    Ok(())
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    sync::Arc,
+    time::Duration,
+};
+
+use crate::{config::Config, entity_managers::webhook_jobs::WebhookJobsManager};
+use chrono::Utc;
+use log::{error, info};
+use palform_tsid::{resources::IDWebhookJob, tsid::PalformDatabaseID};
+use rocket::tokio::{
+    sync::Semaphore,
+    task::{JoinError, JoinSet},
+    time::sleep,
+};
+use sea_orm::{DatabaseConnection, DbErr, TransactionTrait};
+
+const BATCH_SIZE: u64 = 200;
+/// Stop fetching more jobs once this many are waiting for an endpoint to free up
+const MAX_QUEUED_JOBS: usize = 1000;
+
+/// Delivers pending webhook jobs. Each job is claimed with a short lease before it's delivered, so
+/// several workers (or an overlapping cron run) can safely share the queue without holding a
+/// transaction open during the request, and one slow endpoint doesn't hold up the others.
+struct WebhookWorker {
+    db: DatabaseConnection,
+    http_client: reqwest::Client,
+    max_retries: i32,
+    lease: chrono::Duration,
+    global_limit: Arc<Semaphore>,
+    per_endpoint_limit: usize,
+    endpoint_limits: HashMap<String, Arc<Semaphore>>,
+}
+
+impl WebhookWorker {
+    fn new(db: &DatabaseConnection, config: &Config) -> Result<Self, DbErr> {
+        let http_client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.webhook_request_timeout_secs))
+            .build()
+            .map_err(|e| DbErr::Custom(format!("build HTTP client: {}", e)))?;
+
+        Ok(Self {
+            db: db.clone(),
+            http_client,
+            max_retries: config.webhook_max_retries,
+            // Long enough for the request to time out and the outcome to be recorded
+            lease: chrono::Duration::seconds(config.webhook_request_timeout_secs as i64 + 60),
+            global_limit: Arc::new(Semaphore::new(config.webhook_max_concurrency)),
+            per_endpoint_limit: config.webhook_max_concurrency_per_endpoint,
+            endpoint_limits: HashMap::new(),
+        })
+    }
+
+    fn endpoint_limit(&mut self, endpoint: String) -> Arc<Semaphore> {
+        self.endpoint_limits
+            .entry(endpoint)
+            .or_insert_with(|| Arc::new(Semaphore::new(self.per_endpoint_limit)))
+            .clone()
+    }
+
+    /// Claims and delivers the job, unless it's no longer due or another worker has claimed it.
+    async fn run_one(
+        db: DatabaseConnection,
+        http_client: reqwest::Client,
+        job_id: PalformDatabaseID<IDWebhookJob>,
+        max_retries: i32,
+        lease: chrono::Duration,
+    ) -> Result<(), DbErr> {
+        let mut wjm = WebhookJobsManager::new(&db);
+
+        let locked_until = (Utc::now() + lease).naive_utc();
+        let Some(job) = wjm.claim(job_id, locked_until).await? else {
+            return Ok(());
+        };
+
+        wjm.process_job(&job, max_retries, &http_client).await
+    }
+
+    /// Starts as many of the queued jobs as there are permits for, leaving the rest queued in
+    /// order. Both permits are taken before the job is spawned, so a job waiting on a busy
+    /// endpoint doesn't hold up a global slot.
+    fn start_queued(
+        &mut self,
+        queue: &mut VecDeque<(PalformDatabaseID<IDWebhookJob>, String)>,
+        tasks: &mut JoinSet<Result<(), DbErr>>,
+    ) {
+        let mut waiting = VecDeque::new();
+        while let Some((job_id, endpoint)) = queue.pop_front() {
+            let Ok(global_permit) = self.global_limit.clone().try_acquire_owned() else {
+                queue.push_front((job_id, endpoint));
+                break;
+            };
+            let Ok(endpoint_permit) = self.endpoint_limit(endpoint.clone()).try_acquire_owned()
+            else {
+                waiting.push_back((job_id, endpoint));
+                continue;
+            };
+
+            let db = self.db.clone();
+            let http_client = self.http_client.clone();
+            let max_retries = self.max_retries;
+            let lease = self.lease;
+            tasks.spawn(async move {
+                let _permits = (global_permit, endpoint_permit);
+                Self::run_one(db, http_client, job_id, max_retries, lease).await
+            });
+        }
+
+        waiting.append(queue);
+        *queue = waiting;
+    }
+
+    /// Keeps the global limit saturated until there's nothing left to run. Whenever the queued jobs
+    /// can't use up every global permit (e.g. they're all for a busy endpoint), more due jobs are
+    /// fetched after them.
+    async fn run_due(&mut self, tasks: &mut JoinSet<Result<(), DbErr>>) -> Result<(), DbErr> {
+        let mut queue = VecDeque::new();
+        let mut more_due = true;
+
+        loop {
+            self.start_queued(&mut queue, tasks);
+
+            if more_due
+                && self.global_limit.available_permits() > 0
+                && queue.len() < MAX_QUEUED_JOBS
+            {
+                // Running jobs hold a lease so they aren't listed again, and if one is listed just
+                // before it's claimed, the second claim fails and it still only runs once
+                let queued: HashSet<_> = queue.iter().map(|(job_id, _)| *job_id).collect();
+                let limit = queue.len() as u64 + BATCH_SIZE;
+                let due_jobs = WebhookJobsManager::new(&self.db).list_due(limit).await?;
+                more_due = due_jobs.len() as u64 == limit;
+                queue.extend(
+                    due_jobs
+                        .into_iter()
+                        .filter(|(job_id, _)| !queued.contains(job_id)),
+                );
+                self.start_queued(&mut queue, tasks);
+            }
+
+            let Some(result) = tasks.join_next().await else {
+                // If nothing is running, whatever is left in the queue can never be started
+                if queue.is_empty() && more_due {
+                    continue;
+                }
+                return Ok(());
+            };
+            Self::log_result(result);
+        }
+    }
+
+    fn log_result(result: Result<Result<(), DbErr>, JoinError>) {
+        match result {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => error!("Webhook job failed to run: {}", e),
+            Err(e) => error!("Webhook job task panicked: {}", e),
+        }
+    }
+
+    /// Run everything that's currently due.
+    async fn run_pass(&mut self) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+        WebhookJobsManager::new(&txn)
+            .queue_fill_token_expiries()
+            .await?;
+        txn.commit().await?;
+
+        let mut tasks = JoinSet::new();
+        let result = self.run_due(&mut tasks).await;
+
+        // Let jobs that have already been claimed finish rather than aborting them mid-request
+        while let Some(task_result) = tasks.join_next().await {
+            Self::log_result(task_result);
+        }
+        result
+    }
+}
+
+pub async fn job_run_webhooks(
+    db: &DatabaseConnection,
+    config: &Config,
+    daemon: bool,
+) -> Result<(), DbErr> {
+    let mut worker = WebhookWorker::new(db, config)?;
+
+    if !daemon {
+        return worker.run_pass().await;
+    }
+
+    let poll_interval = Duration::from_secs(config.webhook_poll_interval_secs);
+    info!("Running webhook worker every {:?}", poll_interval);
+    loop {
+        if let Err(e) = worker.run_pass().await {
+            error!("Webhook worker pass failed: {}", e);
+        }
+        sleep(poll_interval).await;
+    }
 }
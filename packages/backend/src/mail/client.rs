@@ -1,13 +1,40 @@
 use lettre::{
-    message::MessageBuilder, transport::smtp::authentication::Credentials, AsyncSmtpTransport,
-    AsyncTransport, Message, Tokio1Executor,
+    message::{header::ContentType, Mailbox, MessageBuilder, MultiPart},
+    transport::smtp::authentication::Credentials,
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
 };
+use palform_entities::mail_outbox;
+use thiserror::Error;
 
-use crate::config::Config;
+use crate::config::{Config, MailTemplateMode};
+
+use super::{
+    headers::{MailgunHeader, MailgunTemplateNameHeader, MailgunVariableListHeader},
+    templates::MailTemplates,
+};
+
+#[derive(Debug, Error)]
+pub enum MailError {
+    #[error("Parse recipient address: {0}")]
+    Address(#[from] lettre::address::AddressError),
+    #[error("Render template: {0}")]
+    Render(#[from] minijinja::Error),
+    #[error("Serialise Mailgun variables: {0}")]
+    Variables(#[from] serde_json::Error),
+    #[error("Build message: {0}")]
+    Message(#[from] lettre::error::Error),
+    #[error("Send message: {0}")]
+    Send(#[from] lettre::transport::smtp::Error),
+    #[error("Timed out sending message")]
+    Timeout,
+}
 
 pub struct PalformMailClient {
     smtp: AsyncSmtpTransport<Tokio1Executor>,
     from_address: String,
+    template_mode: MailTemplateMode,
+    templates: MailTemplates,
+    default_locale: String,
 }
 
 impl PalformMailClient {
@@ -35,10 +62,13 @@ impl PalformMailClient {
         Self {
             smtp: transport,
             from_address: config.smtp_from_address,
+            template_mode: config.mail_template_mode,
+            templates: MailTemplates::new(config.frontend_url.as_str()),
+            default_locale: config.mail_locale,
         }
     }
 
-    pub fn get_email_builder(&self) -> MessageBuilder {
+    fn get_email_builder(&self) -> MessageBuilder {
         Message::builder().from(
             format!("Palform <{}>", self.from_address)
                 .parse()
@@ -46,7 +76,44 @@ impl PalformMailClient {
         )
     }
 
-    pub async fn send_email(&self, email: Message) -> Result<(), lettre::transport::smtp::Error> {
-        self.smtp.send(email).await.map(|_| ())
+    /// Sends an email from the outbox (see
+    /// [`crate::entity_managers::mail_outbox::MailOutboxManager`]). If the recipient's locale
+    /// isn't known, the configured default is used.
+    pub async fn send(&self, item: &mail_outbox::Model) -> Result<(), MailError> {
+        let locale = item.locale.as_deref().unwrap_or(&self.default_locale);
+        let to: Mailbox = item.recipient.parse()?;
+        let builder = self.get_email_builder().to(to);
+
+        let message = if self.template_mode == MailTemplateMode::Mailgun
+            && MailTemplates::has_mailgun_template(&item.template)
+        {
+            builder
+                .subject(MailTemplates::subject(
+                    &item.template,
+                    &item.variables,
+                    locale,
+                ))
+                .header(MailgunHeader::<MailgunTemplateNameHeader>::new(
+                    item.template.clone(),
+                ))
+                .header(MailgunHeader::<MailgunVariableListHeader>::new(
+                    serde_json::to_string(&item.variables)?,
+                ))
+                .header(ContentType::TEXT_HTML)
+                .body(Vec::new())?
+        } else {
+            let rendered = self
+                .templates
+                .render(&item.template, &item.variables, locale)?;
+            builder
+                .subject(rendered.subject)
+                .multipart(MultiPart::alternative_plain_html(
+                    rendered.text,
+                    rendered.html,
+                ))?
+        };
+
+        self.smtp.send(message).await?;
+        Ok(())
     }
 }
@@ -1,3 +1,4 @@
 pub mod client;
 pub mod headers;
 pub mod events;
+pub mod templates;
@@ -0,0 +1,205 @@
+use minijinja::{context, value::Kwargs, Environment, Error, ErrorKind, State, Value};
+use serde::Serialize;
+
+/// An email with a bundled HTML and plain text template (in `templates/mail`). The serialised
+/// fields are available as template variables, and are also sent as the Mailgun variables in
+/// Mailgun mode. The subject comes from the `mail_{NAME}_subject` locale string, where the
+/// variables can be used as `%{name}` placeholders.
+pub trait MailTemplate: Serialize {
+    /// The name of the bundled template files, and of the template in Mailgun
+    const NAME: &'static str;
+}
+
+/// Templates that have been set up in Mailgun. Anything else is always rendered from the bundled
+/// template, even in Mailgun mode.
+const MAILGUN_TEMPLATES: [&str; 3] = [
+    VerifyEmailMail::NAME,
+    PasswordResetMail::NAME,
+    FormResponseMail::NAME,
+];
+
+#[derive(Serialize)]
+pub struct VerifyEmailMail {
+    pub verification_id: String,
+}
+impl MailTemplate for VerifyEmailMail {
+    const NAME: &'static str = "verify_email";
+}
+
+#[derive(Serialize)]
+pub struct PasswordResetMail {
+    pub verification_id: String,
+}
+impl MailTemplate for PasswordResetMail {
+    const NAME: &'static str = "password_reset";
+}
+
+#[derive(Serialize)]
+pub struct FormResponseMail {
+    pub form_editor_name: String,
+    pub org_id: String,
+    pub form_id: String,
+}
+impl MailTemplate for FormResponseMail {
+    const NAME: &'static str = "form_response";
+}
+
+#[derive(Serialize)]
+pub struct KeyExpiryMail {
+    pub org_id: String,
+    pub key_fingerprint: String,
+    pub expires_at: String,
+}
+impl MailTemplate for KeyExpiryMail {
+    const NAME: &'static str = "key_expiry";
+}
+
+/// Sent to Palform staff when something needs their attention
+#[derive(Serialize)]
+pub struct EventNotificationMail {
+    pub headline: String,
+    pub resource_id: String,
+}
+impl MailTemplate for EventNotificationMail {
+    const NAME: &'static str = "event_notification";
+}
+
+pub struct RenderedMail {
+    pub subject: String,
+    pub text: String,
+    pub html: String,
+}
+
+fn fill_placeholders(mut text: String, variables: &serde_json::Value) -> String {
+    if let Some(variables) = variables.as_object() {
+        for (name, value) in variables {
+            if let Some(value) = value.as_str() {
+                text = text.replace(&format!("%{{{}}}", name), value);
+            }
+        }
+    }
+    text
+}
+
+/// Looks up a string from `locales/` in the language the email is being rendered in. Keyword
+/// arguments fill in `%{name}` placeholders.
+fn translate(state: &State, key: &str, kwargs: Kwargs) -> Result<String, Error> {
+    let locale = state
+        .lookup("locale")
+        .and_then(|l| l.as_str().map(str::to_string))
+        .ok_or_else(|| Error::new(ErrorKind::UndefinedError, "locale is not set"))?;
+
+    let mut text = rust_i18n::t!(key, locale = locale).to_string();
+    for arg in kwargs.args() {
+        let value: String = kwargs.get(arg)?;
+        text = text.replace(&format!("%{{{}}}", arg), &value);
+    }
+    kwargs.assert_all_used()?;
+    Ok(text)
+}
+
+pub struct MailTemplates {
+    env: Environment<'static>,
+    frontend_url: String,
+}
+
+impl MailTemplates {
+    pub fn new(frontend_url: &str) -> Self {
+        let mut env = Environment::new();
+        env.set_trim_blocks(true);
+        env.add_function("t", translate);
+
+        for (name, source) in [
+            ("base.html", include_str!("../../templates/mail/base.html")),
+            ("base.txt", include_str!("../../templates/mail/base.txt")),
+            (
+                "verify_email.html",
+                include_str!("../../templates/mail/verify_email.html"),
+            ),
+            (
+                "verify_email.txt",
+                include_str!("../../templates/mail/verify_email.txt"),
+            ),
+            (
+                "password_reset.html",
+                include_str!("../../templates/mail/password_reset.html"),
+            ),
+            (
+                "password_reset.txt",
+                include_str!("../../templates/mail/password_reset.txt"),
+            ),
+            (
+                "form_response.html",
+                include_str!("../../templates/mail/form_response.html"),
+            ),
+            (
+                "form_response.txt",
+                include_str!("../../templates/mail/form_response.txt"),
+            ),
+            (
+                "key_expiry.html",
+                include_str!("../../templates/mail/key_expiry.html"),
+            ),
+            (
+                "key_expiry.txt",
+                include_str!("../../templates/mail/key_expiry.txt"),
+            ),
+            (
+                "event_notification.html",
+                include_str!("../../templates/mail/event_notification.html"),
+            ),
+            (
+                "event_notification.txt",
+                include_str!("../../templates/mail/event_notification.txt"),
+            ),
+        ] {
+            env.add_template(name, source)
+                .expect("Parse bundled mail template");
+        }
+
+        Self {
+            env,
+            frontend_url: frontend_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    pub fn has_mailgun_template(name: &str) -> bool {
+        MAILGUN_TEMPLATES.contains(&name)
+    }
+
+    pub fn subject(name: &str, variables: &serde_json::Value, locale: &str) -> String {
+        let key = format!("mail_{}_subject", name);
+        let subject = rust_i18n::t!(&key, locale = locale);
+        fill_placeholders(subject.to_string(), variables)
+    }
+
+    /// Renders the template called `name` with the variables from a serialised [`MailTemplate`]
+    pub fn render(
+        &self,
+        name: &str,
+        variables: &serde_json::Value,
+        locale: &str,
+    ) -> Result<RenderedMail, Error> {
+        let subject = Self::subject(name, variables, locale);
+        let ctx = context! {
+            locale => locale,
+            subject => subject,
+            frontend_url => self.frontend_url,
+            ..Value::from_serialize(variables)
+        };
+
+        let text = self
+            .env
+            .get_template(&format!("{}.txt", name))?
+            .render(&ctx)?;
+        let html = self
+            .env
+            .get_template(&format!("{}.html", name))?
+            .render(&ctx)?;
+        Ok(RenderedMail {
+            subject,
+            text,
+            html,
+        })
+    }
+}
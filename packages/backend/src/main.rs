@@ -1,16 +1,25 @@
-use clap::Command;
+use auth::api_keys::APIKeyScope;
+use clap::{Arg, ArgAction, Command};
+use commands::api_keys::{command_create_api_key, command_list_api_keys, command_revoke_api_key};
+use captcha::verifier::PalformCaptcha;
 use config::Config;
+use crypto::signing::ServerSigningKey;
 use database::init_db;
 use jobs::{
     delete_abandoned_emails::job_delete_abandoned_emails,
     delete_old_audit_logs::job_delete_old_audit_logs,
     delete_old_auth_tokens::job_delete_old_auth_tokens,
-    delete_old_submissions::job_delete_old_submissions, webhooks::job_run_webhooks,
+    delete_old_rate_limit_buckets::job_delete_old_rate_limit_buckets,
+    delete_old_submissions::job_delete_old_submissions,
+    delete_orphaned_submission_files::job_delete_orphaned_submission_files,
+    mail::job_run_mail,
+    warn_expiring_keys::job_warn_expiring_keys,
+    webhooks::job_run_webhooks,
 };
-use mail::client::PalformMailClient;
-use palform_s3::{
-    buckets::{S3BucketSubmissionAssets, S3BucketTeamAssets},
-    client::PalformS3Client,
+use rate_limit::RateLimiter;
+use storage::{
+    buckets::{SubmissionAssetsBucket, TeamAssetsBucket},
+    client::PalformStorage,
 };
 use rocket::{catchers, get, http::Method, routes};
 use rocket_cors::{AllowedOrigins, CorsOptions};
@@ -24,20 +33,22 @@ use rocket_okapi::{
     },
     settings::{OpenApiSettings, UrlObject},
 };
-use rocket_util::catchers::default_catcher;
+use rocket_util::catchers::{default_catcher, too_many_requests_catcher};
 
 mod api;
 mod api_entities;
 mod audit;
 mod auth;
 mod captcha;
+mod commands;
 mod config;
 mod crypto;
 mod database;
 mod entity_managers;
 mod jobs;
 mod mail;
-mod palform_s3;
+mod rate_limit;
+mod storage;
 mod rocket_util;
 
 #[cfg(feature = "saas")]
@@ -69,9 +80,61 @@ async fn main() -> Result<(), rocket::Error> {
                         .about("Delete abandoned/expired email verification requests"),
                     Command::new("delete-old-audit-logs").about("Delete expired audit log entries"),
                     Command::new("delete-old-auth-tokens").about("Delete expired auth tokens"),
+                    Command::new("delete-old-rate-limit-buckets")
+                        .about("Delete idle rate limit buckets stored in the database"),
                     Command::new("delete-old-submissions")
                         .about("Delete submissions in form with auto-delete enabled"),
-                    Command::new("webhooks").about("Run pending webhook jobs"),
+                    Command::new("delete-orphaned-submission-files")
+                        .about("Delete uploaded files that don't belong to any submission"),
+                    Command::new("warn-expiring-keys")
+                        .about("Email users whose encryption keys expire soon"),
+                    Command::new("webhooks")
+                        .about("Run pending webhook jobs")
+                        .arg(
+                            Arg::new("daemon")
+                                .long("daemon")
+                                .action(ArgAction::SetTrue)
+                                .help("Keep running and deliver new jobs as they become due"),
+                        ),
+                    Command::new("mail")
+                        .about("Send queued emails")
+                        .arg(
+                            Arg::new("daemon")
+                                .long("daemon")
+                                .action(ArgAction::SetTrue)
+                                .help("Keep running and send new emails as they are queued"),
+                        ),
+                ]),
+        )
+        .subcommand(
+            Command::new("api-keys")
+                .about("Manage service account API keys")
+                .subcommand_required(true)
+                .subcommands(vec![
+                    Command::new("create")
+                        .about("Create an API key and print it")
+                        .arg(Arg::new("user-id").long("user-id").required(true))
+                        .arg(Arg::new("name").long("name").required(true))
+                        .arg(
+                            Arg::new("scopes")
+                                .long("scopes")
+                                .required(true)
+                                .help(format!(
+                                    "Comma-separated list of scopes out of: {}",
+                                    APIKeyScope::all_names().join(", ")
+                                )),
+                        )
+                        .arg(
+                            Arg::new("expires-in-days")
+                                .long("expires-in-days")
+                                .default_value("90"),
+                        ),
+                    Command::new("list")
+                        .about("List a service account's API keys")
+                        .arg(Arg::new("user-id").long("user-id").required(true)),
+                    Command::new("revoke")
+                        .about("Revoke an API key")
+                        .arg(Arg::new("key-id").long("key-id").required(true)),
                 ]),
         )
         .get_matches();
@@ -84,8 +147,27 @@ async fn main() -> Result<(), rocket::Error> {
             Some(("delete-abandoned-emails", _)) => job_delete_abandoned_emails(&db).await,
             Some(("delete-old-audit-logs", _)) => job_delete_old_audit_logs(&db).await,
             Some(("delete-old-auth-tokens", _)) => job_delete_old_auth_tokens(&db).await,
+            Some(("delete-old-rate-limit-buckets", _)) => {
+                job_delete_old_rate_limit_buckets(&db).await
+            }
             Some(("delete-old-submissions", _)) => job_delete_old_submissions(&db).await,
-            Some(("webhooks", _)) => job_run_webhooks(&db).await,
+            Some(("delete-orphaned-submission-files", _)) => {
+                job_delete_orphaned_submission_files(&db, &config).await
+            }
+            Some(("warn-expiring-keys", _)) => job_warn_expiring_keys(&db, &config).await,
+            Some(("webhooks", webhook_matches)) => {
+                job_run_webhooks(&db, &config, webhook_matches.get_flag("daemon")).await
+            }
+            Some(("mail", mail_matches)) => {
+                job_run_mail(&db, &config, mail_matches.get_flag("daemon")).await
+            }
+            _ => unreachable!("Subcommands are required"),
+        }
+        .unwrap(),
+        Some(("api-keys", sub_matches)) => match sub_matches.subcommand() {
+            Some(("create", create_matches)) => command_create_api_key(&db, create_matches).await,
+            Some(("list", list_matches)) => command_list_api_keys(&db, list_matches).await,
+            Some(("revoke", revoke_matches)) => command_revoke_api_key(&db, revoke_matches).await,
             _ => unreachable!("Subcommands are required"),
         }
         .unwrap(),
@@ -111,24 +193,31 @@ async fn main() -> Result<(), rocket::Error> {
             .to_cors()
             .expect("Configure CORS");
 
-            let mail_client = PalformMailClient::new(config.clone()).await;
-            let s3_brand_assets =
-                PalformS3Client::<S3BucketTeamAssets>::init(&config).expect("Init S3 brand assets");
-            let s3_submission_assets = PalformS3Client::<S3BucketSubmissionAssets>::init(&config)
-                .expect("Init S3 submission assets");
+            let team_assets_storage =
+                PalformStorage::<TeamAssetsBucket>::init(&config).expect("Init team assets storage");
+            let submission_assets_storage = PalformStorage::<SubmissionAssetsBucket>::init(&config)
+                .expect("Init submission assets storage");
+
+            let captcha = PalformCaptcha::init(&config);
+            let rate_limiter = RateLimiter::init(&config, &db);
+            let server_signing_key =
+                ServerSigningKey::init(&config).expect("Init server signing key");
 
             let mut r = rocket::build()
                 .manage(config.clone())
                 .manage(db)
-                .manage(mail_client)
-                .manage(s3_brand_assets)
-                .manage(s3_submission_assets)
+                .manage(team_assets_storage)
+                .manage(submission_assets_storage)
+                .manage(server_signing_key)
+                .manage(captcha)
+                .manage(rate_limiter)
                 // Some routes are not yet supported by okapi (e.g. due to multipart files)
                 .mount(
                     "/",
                     routes![
                         api::team_assets::upload::handler,
                         api::submissions::assets::upload::handler,
+                        api::storage::get::handler,
                     ],
                 );
 
@@ -160,6 +249,7 @@ async fn main() -> Result<(), rocket::Error> {
                 api::keys::register::handler,
                 api::keys::register_backup::handler,
                 api::keys::delete::handler,
+                api::keys::revoke::handler,
                 api::keys::get::handler,
                 api::keys::get_backup::handler,
                 api::keys::team_fingerprints::handler,
@@ -215,6 +305,8 @@ async fn main() -> Result<(), rocket::Error> {
                 api::forms::relocate::handler,
                 api::forms::exchange_short_link::handler,
                 api::forms::set_auto_delete::handler,
+                api::forms::publish::handler,
+                api::forms::set_schedule::handler,
                 api::form_templates::list_categories::handler,
                 api::form_templates::get_category::handler,
                 api::form_templates::list::handler,
@@ -224,13 +316,20 @@ async fn main() -> Result<(), rocket::Error> {
                 api::form_templates::clone::handler,
                 api::submissions::crypto::handler,
                 api::submissions::list::handler,
+                api::submissions::bundle::handler,
+                api::submissions::chain::handler,
                 api::submissions::delete::handler,
                 api::submissions::num_since::handler,
+                api::submissions::recipients::handler,
+                api::submissions::rewrap::handler,
                 api::submissions::assets::download::handler,
                 api::questions::get::handler,
                 api::questions::list::handler,
                 api::questions::save::handler,
                 api::question_groups::list::handler,
+                api::form_revisions::list::handler,
+                api::form_revisions::get::handler,
+                api::form_revisions::diff::handler,
                 api::fill_tokens::list::handler,
                 api::fill_tokens::create::handler,
                 api::fill_tokens::delete::handler,
@@ -241,7 +340,18 @@ async fn main() -> Result<(), rocket::Error> {
                 api::webhooks::create::handler,
                 api::webhooks::delete::handler,
                 api::webhooks::list_jobs::handler,
+                api::webhooks::requeue_job::handler,
+                api::mail::list_failed::handler,
+                api::mail::requeue::handler,
+                api::webhooks::rotate_secret::handler,
+                api::webhooks::update_events::handler,
+                api::webhooks::disable_legacy_signature::handler,
+                api::service_account_api_keys::list::handler,
+                api::service_account_api_keys::create::handler,
+                api::service_account_api_keys::delete::handler,
                 api::feedback::create::handler,
+                api::server_signing_key::get::handler,
+                api::captcha::challenge::handler,
             ];
             route_lists.push(main_routes);
 
@@ -321,7 +431,7 @@ async fn main() -> Result<(), rocket::Error> {
                 }),
             )
             .attach(cors)
-            .register("/", catchers![default_catcher])
+            .register("/", catchers![default_catcher, too_many_requests_catcher])
             .launch()
             .await?;
         }
@@ -1,2 +0,0 @@
-pub mod client;
-pub mod buckets;
@@ -0,0 +1,68 @@
+use palform_client_common::errors::error::APIError;
+use rocket::{
+    outcome::try_outcome,
+    request::{self, FromRequest},
+    serde::json::Json,
+};
+use rocket_okapi::request::OpenApiFromRequest;
+
+use crate::{auth::fill_access::APIFillAccessToken, i18n::request::I18NManager, into_outcome, pt};
+
+use super::{FillRateLimitScope, RateLimiter};
+
+/// Limits requests to the public `/fill/orgs/<org_id>/forms/<form_id>/...` endpoints per client
+/// (IP address and fill token), per form and per organisation. Requests are only counted once
+/// their [`APIFillAccessToken`] has been checked, so this fails with the token's error if it's
+/// invalid.
+pub struct FillRateLimit;
+
+#[rocket::async_trait]
+impl<'a> FromRequest<'a> for FillRateLimit {
+    type Error = Json<APIError>;
+    async fn from_request(
+        request: &'a request::Request<'_>,
+    ) -> request::Outcome<Self, Self::Error> {
+        let i18n_manager = try_outcome!(I18NManager::from_request(request).await);
+        let limiter = into_outcome!(
+            request.rocket().state::<RateLimiter>().ok_or_else(|| {
+                APIError::report_internal_error_without_error("Rate limiter not found in state")
+            }),
+            request
+        );
+
+        // Only requests with a valid fill token take from the buckets, so made-up tokens can't
+        // get a fresh client bucket each time or use up the form's limit
+        let token = try_outcome!(request.guard::<APIFillAccessToken>().await);
+        let client_ip = limiter
+            .client_ip(request)
+            .map(|ip| ip.to_string())
+            .unwrap_or_default();
+
+        let checks = [
+            (
+                FillRateLimitScope::Client,
+                format!("{}:{}", client_ip, token.token_id),
+            ),
+            (FillRateLimitScope::Form, token.form_id.to_string()),
+            (FillRateLimitScope::Organisation, token.org_id.to_string()),
+        ];
+        if !limiter.check(&checks).await {
+            let err = APIError::RateLimited(pt!(i18n_manager, "fill_rate_limited",));
+            request.local_cache(|| err.clone());
+            return request::Outcome::Error(err.into());
+        }
+
+        request::Outcome::Success(FillRateLimit)
+    }
+}
+
+#[rocket::async_trait]
+impl<'a> OpenApiFromRequest<'a> for FillRateLimit {
+    fn from_request_input(
+        _gen: &mut rocket_okapi::gen::OpenApiGenerator,
+        _name: String,
+        _required: bool,
+    ) -> rocket_okapi::Result<rocket_okapi::request::RequestHeaderInput> {
+        Ok(rocket_okapi::request::RequestHeaderInput::None)
+    }
+}
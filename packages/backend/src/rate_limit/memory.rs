@@ -0,0 +1,57 @@
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use sea_orm::DbErr;
+
+use super::store::{RateLimit, RateLimitStore};
+
+/// Buckets that haven't been touched for this long are full again, so they can be dropped
+const IDLE_BUCKET_EXPIRY: Duration = Duration::from_secs(60 * 10);
+const PRUNE_THRESHOLD: usize = 10_000;
+
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+/// Keeps buckets in this process, so each server instance has its own limits
+#[derive(Default)]
+pub struct MemoryRateLimitStore {
+    buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+#[rocket::async_trait]
+impl RateLimitStore for MemoryRateLimitStore {
+    async fn take_all(&self, keys: &[(String, RateLimit)]) -> Result<Option<usize>, DbErr> {
+        let mut buckets = self.buckets.lock().expect("Lock rate limit buckets");
+        let now = Instant::now();
+
+        if buckets.len() > PRUNE_THRESHOLD {
+            buckets.retain(|_, b| now.duration_since(b.updated_at) < IDLE_BUCKET_EXPIRY);
+        }
+
+        for (key, limit) in keys {
+            let bucket = buckets.entry(key.clone()).or_insert(Bucket {
+                tokens: limit.capacity,
+                updated_at: now,
+            });
+
+            let elapsed = now.duration_since(bucket.updated_at).as_secs_f64();
+            bucket.tokens = (bucket.tokens + elapsed * limit.refill_per_sec).min(limit.capacity);
+            bucket.updated_at = now;
+        }
+
+        if let Some(empty) = keys.iter().position(|(key, _)| buckets[key].tokens < 1.0) {
+            return Ok(Some(empty));
+        }
+        for (key, _) in keys {
+            if let Some(bucket) = buckets.get_mut(key) {
+                bucket.tokens -= 1.0;
+            }
+        }
+        Ok(None)
+    }
+}
@@ -0,0 +1,129 @@
+use std::{
+    collections::HashMap,
+    net::IpAddr,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use log::{error, warn};
+use rocket::Request;
+use sea_orm::DatabaseConnection;
+
+use crate::config::{Config, RateLimitStoreKind};
+
+use self::{
+    memory::MemoryRateLimitStore,
+    postgres::PostgresRateLimitStore,
+    store::{RateLimit, RateLimitStore},
+};
+
+pub mod guard;
+pub mod postgres;
+pub mod store;
+
+mod memory;
+
+/// Rate limit events are logged under this target, so they can be filtered and alerted on
+const LOG_TARGET: &str = "palform::rate_limit";
+/// Each bucket is only logged once in this period while it's being limited
+const REPORT_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Clone, Copy, Debug)]
+pub enum FillRateLimitScope {
+    /// One IP address using one fill token
+    Client,
+    Form,
+    Organisation,
+}
+
+impl FillRateLimitScope {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Client => "fill_client",
+            Self::Form => "fill_form",
+            Self::Organisation => "fill_org",
+        }
+    }
+}
+
+/// Token bucket rate limits for the public endpoints
+pub struct RateLimiter {
+    store: Box<dyn RateLimitStore>,
+    fill_client: RateLimit,
+    fill_form: RateLimit,
+    fill_organisation: RateLimit,
+    trusted_proxy_header: Option<String>,
+    last_reported: Mutex<HashMap<String, Instant>>,
+}
+
+impl RateLimiter {
+    pub fn init(config: &Config, db: &DatabaseConnection) -> Self {
+        let store: Box<dyn RateLimitStore> = match config.rate_limit_store {
+            RateLimitStoreKind::Memory => Box::new(MemoryRateLimitStore::default()),
+            RateLimitStoreKind::Postgres => Box::new(PostgresRateLimitStore::new(db.clone())),
+        };
+
+        Self {
+            store,
+            fill_client: RateLimit::per_minute(config.rate_limit_fill_client_per_minute),
+            fill_form: RateLimit::per_minute(config.rate_limit_fill_form_per_minute),
+            fill_organisation: RateLimit::per_minute(config.rate_limit_fill_org_per_minute),
+            trusted_proxy_header: config.rate_limit_trusted_proxy_header.clone(),
+            last_reported: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn limit(&self, scope: FillRateLimitScope) -> &RateLimit {
+        match scope {
+            FillRateLimitScope::Client => &self.fill_client,
+            FillRateLimitScope::Form => &self.fill_form,
+            FillRateLimitScope::Organisation => &self.fill_organisation,
+        }
+    }
+
+    /// Takes a token from each scope's bucket, or none of them if any is empty. Returns `false` if
+    /// the request should be rejected. If the store can't be reached, requests are let through
+    /// rather than taking the endpoint down with it.
+    pub async fn check(&self, checks: &[(FillRateLimitScope, String)]) -> bool {
+        let buckets: Vec<(String, RateLimit)> = checks
+            .iter()
+            .map(|(scope, key)| (format!("{}:{}", scope.name(), key), *self.limit(*scope)))
+            .collect();
+        match self.store.take_all(&buckets).await {
+            Ok(None) => true,
+            Ok(Some(empty)) => {
+                self.report(&buckets[empty].0);
+                false
+            }
+            Err(e) => {
+                error!(target: LOG_TARGET, "Checking rate limits: {}", e);
+                true
+            }
+        }
+    }
+
+    /// The address to limit a request by. Only reads the configured proxy header, as anyone can
+    /// set it when the server isn't behind that proxy. If the header lists several addresses, the
+    /// last one is used, as that's the one the proxy added.
+    pub fn client_ip(&self, request: &Request<'_>) -> Option<IpAddr> {
+        match &self.trusted_proxy_header {
+            Some(header) => request
+                .headers()
+                .get_one(header)
+                .and_then(|value| value.rsplit(',').next())
+                .and_then(|ip| ip.trim().parse().ok()),
+            None => request.remote().map(|remote| remote.ip()),
+        }
+    }
+
+    fn report(&self, key: &str) {
+        let mut last_reported = self.last_reported.lock().expect("Lock rate limit reports");
+        let now = Instant::now();
+        last_reported.retain(|_, at| now.duration_since(*at) < REPORT_INTERVAL);
+
+        if !last_reported.contains_key(key) {
+            warn!(target: LOG_TARGET, "Rate limit exceeded: {}", key);
+            last_reported.insert(key.to_string(), now);
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+
+use chrono::{NaiveDateTime, Utc};
+use palform_entities::{prelude::*, rate_limit_bucket};
+use sea_orm::{
+    sea_query::{Expr, OnConflict},
+    ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set, TransactionTrait,
+};
+
+use super::store::{RateLimit, RateLimitStore};
+
+/// Keeps buckets in the database, so limits are shared by every server instance. This costs a
+/// short transaction with a few queries on each request.
+pub struct PostgresRateLimitStore {
+    db: DatabaseConnection,
+}
+
+impl PostgresRateLimitStore {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Buckets that haven't been used since `before` are full, so they can be deleted
+    pub async fn delete_idle_buckets<T: ConnectionTrait>(
+        conn: &T,
+        before: NaiveDateTime,
+    ) -> Result<u64, DbErr> {
+        let result = RateLimitBucket::delete_many()
+            .filter(rate_limit_bucket::Column::UpdatedAt.lt(before))
+            .exec(conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
+}
+
+#[rocket::async_trait]
+impl RateLimitStore for PostgresRateLimitStore {
+    async fn take_all(&self, keys: &[(String, RateLimit)]) -> Result<Option<usize>, DbErr> {
+        let now = Utc::now().naive_utc();
+        let txn = self.db.begin().await?;
+
+        RateLimitBucket::insert_many(keys.iter().map(|(key, limit)| {
+            rate_limit_bucket::ActiveModel {
+                key: Set(key.clone()),
+                tokens: Set(limit.capacity),
+                updated_at: Set(now),
+            }
+        }))
+        .on_conflict(
+            OnConflict::column(rate_limit_bucket::Column::Key)
+                .do_nothing()
+                .to_owned(),
+        )
+        .exec_without_returning(&txn)
+        .await?;
+
+        // Rows are locked in key order so that concurrent requests can't deadlock, and so that
+        // they can't both take the last token
+        let buckets: HashMap<String, rate_limit_bucket::Model> = RateLimitBucket::find()
+            .filter(rate_limit_bucket::Column::Key.is_in(keys.iter().map(|(key, _)| key.clone())))
+            .order_by_asc(rate_limit_bucket::Column::Key)
+            .lock_exclusive()
+            .all(&txn)
+            .await?
+            .into_iter()
+            .map(|b| (b.key.clone(), b))
+            .collect();
+
+        let mut refilled = Vec::with_capacity(keys.len());
+        for (key, limit) in keys {
+            let bucket = buckets
+                .get(key)
+                .ok_or_else(|| DbErr::RecordNotFound(format!("Rate limit bucket {}", key)))?;
+            let elapsed = (now - bucket.updated_at).num_milliseconds().max(0) as f64 / 1000.0;
+            refilled.push((bucket.tokens + elapsed * limit.refill_per_sec).min(limit.capacity));
+        }
+
+        // Nothing is written if a bucket is empty, as the stored tokens are still right for the
+        // stored time
+        if let Some(empty) = refilled.iter().position(|tokens| *tokens < 1.0) {
+            txn.rollback().await?;
+            return Ok(Some(empty));
+        }
+
+        for ((key, _), tokens) in keys.iter().zip(refilled) {
+            RateLimitBucket::update_many()
+                .col_expr(rate_limit_bucket::Column::Tokens, Expr::value(tokens - 1.0))
+                .col_expr(rate_limit_bucket::Column::UpdatedAt, Expr::value(now))
+                .filter(rate_limit_bucket::Column::Key.eq(key.as_str()))
+                .exec(&txn)
+                .await?;
+        }
+
+        txn.commit().await?;
+        Ok(None)
+    }
+}
@@ -0,0 +1,26 @@
+use sea_orm::DbErr;
+
+/// The size and refill rate of a token bucket
+#[derive(Clone, Copy, Debug)]
+pub struct RateLimit {
+    pub capacity: f64,
+    pub refill_per_sec: f64,
+}
+
+impl RateLimit {
+    /// Allows `n` requests per minute on average, and bursts of up to `n` requests at once
+    pub fn per_minute(n: u32) -> Self {
+        Self {
+            capacity: n as f64,
+            refill_per_sec: n as f64 / 60.0,
+        }
+    }
+}
+
+/// Somewhere to keep token buckets
+#[rocket::async_trait]
+pub trait RateLimitStore: Send + Sync {
+    /// Take a token from each of the named buckets, creating any that don't exist yet. If any of
+    /// them is empty, no tokens are taken and the index of the first empty bucket is returned.
+    async fn take_all(&self, buckets: &[(String, RateLimit)]) -> Result<Option<usize>, DbErr>;
+}
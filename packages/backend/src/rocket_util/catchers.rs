@@ -1,8 +1,24 @@
 use palform_client_common::errors::error::APIError;
-use rocket::{catch, serde::json::Json, Request};
+use rocket::{catch, http::Header, serde::json::Json, Request, Responder};
 
 #[catch(default)]
 pub fn default_catcher(req: &Request) -> Json<APIError> {
     let err = req.local_cache(|| APIError::BadRequest("unknown".to_string()));
     Json(err.to_owned())
 }
+
+#[derive(Responder)]
+pub struct RateLimitedResponse {
+    inner: Json<APIError>,
+    retry_after: Header<'static>,
+}
+
+/// Buckets refill over a minute at most, so clients are told to wait that long
+#[catch(429)]
+pub fn too_many_requests_catcher(req: &Request) -> RateLimitedResponse {
+    let err = req.local_cache(|| APIError::RateLimited("Too many requests".to_string()));
+    RateLimitedResponse {
+        inner: Json(err.to_owned()),
+        retry_after: Header::new("Retry-After", "60"),
+    }
+}
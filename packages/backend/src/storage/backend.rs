@@ -0,0 +1,44 @@
+use rocket::tokio::io::AsyncRead;
+
+use super::client::StorageError;
+
+/// The contents of a stored file, read as it's sent rather than all at once
+pub type ObjectReader = Box<dyn AsyncRead + Unpin + Send>;
+
+pub struct StoredObject {
+    pub data: ObjectReader,
+    pub content_type: Option<String>,
+}
+
+/// Somewhere to keep the files in a single bucket. Paths are `/`-separated and never start with
+/// `/`.
+#[rocket::async_trait]
+pub trait StorageBackend: Send + Sync {
+    async fn put(&self, path: &str, data: &[u8], content_type: &str) -> Result<(), StorageError>;
+
+    /// Stores everything `reader` produces without holding it all in memory. Returns the number
+    /// of bytes stored, or [`StorageError::TooLarge`] without storing anything if there are more
+    /// than `max_size`.
+    async fn put_stream(
+        &self,
+        path: &str,
+        reader: &mut (dyn AsyncRead + Unpin + Send),
+        max_size: u64,
+    ) -> Result<u64, StorageError>;
+
+    async fn get(&self, path: &str) -> Result<Option<StoredObject>, StorageError>;
+
+    /// Deleting a file that doesn't exist is not an error
+    async fn delete(&self, path: &str) -> Result<(), StorageError>;
+
+    /// The paths of all files starting with `prefix`
+    async fn list(&self, prefix: &str) -> Result<Vec<String>, StorageError>;
+
+    /// A temporary URL that browsers can download the file from directly. Backends that can't do
+    /// this return `None`, and the file is served through the API instead.
+    async fn presign_get(
+        &self,
+        path: &str,
+        expires_in_secs: u32,
+    ) -> Result<Option<String>, StorageError>;
+}
@@ -0,0 +1,29 @@
+use crate::config::Config;
+
+pub trait StorageBucket {
+    /// Used as the directory name for local storage, and in links to stored files
+    fn name() -> &'static str;
+    fn s3_name(config: &Config) -> Option<String>;
+}
+
+pub struct TeamAssetsBucket;
+impl StorageBucket for TeamAssetsBucket {
+    fn name() -> &'static str {
+        "team-assets"
+    }
+
+    fn s3_name(config: &Config) -> Option<String> {
+        config.s3_team_assets_bucket.clone()
+    }
+}
+
+pub struct SubmissionAssetsBucket;
+impl StorageBucket for SubmissionAssetsBucket {
+    fn name() -> &'static str {
+        "submission-assets"
+    }
+
+    fn s3_name(config: &Config) -> Option<String> {
+        config.s3_submission_assets_bucket.clone()
+    }
+}
@@ -0,0 +1,151 @@
+use std::{marker::PhantomData, ops::Deref};
+
+use base64::prelude::*;
+use chrono::Utc;
+use ring::{hmac, rand::SystemRandom};
+use s3::{creds::error::CredentialsError, error::S3Error};
+use sea_orm::DbErr;
+use thiserror::Error;
+use url::Url;
+
+use crate::config::{Config, StorageBackendKind};
+
+use super::{
+    backend::{StorageBackend, StoredObject},
+    buckets::StorageBucket,
+    local::LocalBackend,
+    memory::MemoryBackend,
+    s3::S3Backend,
+};
+
+#[derive(Debug, Error)]
+pub enum StorageError {
+    #[error("Initialising credentials: {0}")]
+    Credentials(#[from] CredentialsError),
+    #[error("S3: {0}")]
+    S3(#[from] S3Error),
+    #[error("S3 responded with status {0}")]
+    S3Status(u16),
+    #[error("Filesystem: {0}")]
+    IO(#[from] std::io::Error),
+    #[error("Missing config option: {0}")]
+    MissingConfig(&'static str),
+    #[error("Invalid storage path: {0}")]
+    InvalidPath(String),
+    #[error("storage_public_url can't be used as a base URL")]
+    InvalidPublicUrl,
+    #[error("Generating link signing key")]
+    KeyGeneration,
+    #[error("db: {0}")]
+    DBError(#[from] DbErr),
+    #[error("Asset not found")]
+    AssetNotFound,
+    #[error("File is too large")]
+    TooLarge,
+}
+
+/// The files in one bucket, stored with whichever backend is configured
+pub struct PalformStorage<T: StorageBucket> {
+    bucket_type: PhantomData<T>,
+    backend: Box<dyn StorageBackend>,
+    public_url: Option<Url>,
+    /// Signs links to files served by the API. It's regenerated on every start, which is fine
+    /// since links only last a short while anyway.
+    link_key: hmac::Key,
+}
+
+impl<T: StorageBucket> PalformStorage<T> {
+    pub fn init(config: &Config) -> Result<Self, StorageError> {
+        let backend: Box<dyn StorageBackend> = match config.storage_backend {
+            StorageBackendKind::S3 => Box::new(S3Backend::init::<T>(config)?),
+            StorageBackendKind::Local => {
+                let directory = config
+                    .storage_directory
+                    .clone()
+                    .ok_or(StorageError::MissingConfig("storage_directory"))?;
+                Box::new(LocalBackend::init(directory.join(T::name()))?)
+            }
+            StorageBackendKind::Memory => Box::new(MemoryBackend::default()),
+        };
+
+        let link_key = hmac::Key::generate(hmac::HMAC_SHA256, &SystemRandom::new())
+            .map_err(|_| StorageError::KeyGeneration)?;
+
+        Ok(Self {
+            bucket_type: PhantomData,
+            backend,
+            public_url: config.storage_public_url.clone(),
+            link_key,
+        })
+    }
+
+    fn link_message(path: &str, expires: i64) -> String {
+        format!("{}\n{}\n{}", T::name(), path, expires)
+    }
+
+    /// A URL that browsers can download the file from for the next `expires_in_secs` seconds
+    pub async fn download_url(
+        &self,
+        path: &str,
+        expires_in_secs: u32,
+    ) -> Result<String, StorageError> {
+        if let Some(url) = self.backend.presign_get(path, expires_in_secs).await? {
+            return Ok(url);
+        }
+
+        let mut url = self
+            .public_url
+            .clone()
+            .ok_or(StorageError::MissingConfig("storage_public_url"))?;
+        url.path_segments_mut()
+            .map_err(|_| StorageError::InvalidPublicUrl)?
+            .pop_if_empty()
+            .push("storage")
+            .push(T::name())
+            .extend(path.split('/'));
+
+        let expires = Utc::now().timestamp() + i64::from(expires_in_secs);
+        let signature = hmac::sign(&self.link_key, Self::link_message(path, expires).as_bytes());
+        url.query_pairs_mut()
+            .append_pair("expires", &expires.to_string())
+            .append_pair(
+                "signature",
+                &BASE64_URL_SAFE_NO_PAD.encode(signature.as_ref()),
+            );
+        Ok(url.to_string())
+    }
+
+    /// Gets a file using a link from `download_url`. Returns `None` if the link is invalid or has
+    /// expired.
+    pub async fn get_from_link(
+        &self,
+        path: &str,
+        expires: i64,
+        signature: &str,
+    ) -> Result<Option<StoredObject>, StorageError> {
+        if expires < Utc::now().timestamp() {
+            return Ok(None);
+        }
+        let Ok(signature) = BASE64_URL_SAFE_NO_PAD.decode(signature) else {
+            return Ok(None);
+        };
+        if hmac::verify(
+            &self.link_key,
+            Self::link_message(path, expires).as_bytes(),
+            &signature,
+        )
+        .is_err()
+        {
+            return Ok(None);
+        }
+
+        self.backend.get(path).await
+    }
+}
+
+impl<T: StorageBucket> Deref for PalformStorage<T> {
+    type Target = dyn StorageBackend;
+    fn deref(&self) -> &Self::Target {
+        self.backend.as_ref()
+    }
+}
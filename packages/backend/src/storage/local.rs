@@ -0,0 +1,163 @@
+use std::path::{Component, Path, PathBuf};
+
+use rocket::tokio::{
+    fs,
+    io::{self, AsyncRead, AsyncReadExt},
+};
+
+use super::{
+    backend::{StorageBackend, StoredObject},
+    client::StorageError,
+};
+
+/// Content types are kept next to each file, in a file with this suffix
+const CONTENT_TYPE_SUFFIX: &str = ".content-type";
+/// Streamed uploads are written to a file with this suffix and only renamed to their real path
+/// once they're complete
+const PARTIAL_SUFFIX: &str = ".partial";
+
+/// Keeps files in a directory on the server. Only suitable for installs running a single API
+/// server.
+pub struct LocalBackend {
+    root: PathBuf,
+}
+
+impl LocalBackend {
+    pub fn init(root: PathBuf) -> Result<Self, StorageError> {
+        std::fs::create_dir_all(&root)?;
+        Ok(Self { root })
+    }
+
+    fn file_path(&self, path: &str) -> Result<PathBuf, StorageError> {
+        let relative = Path::new(path);
+        if !relative
+            .components()
+            .all(|c| matches!(c, Component::Normal(_)))
+        {
+            return Err(StorageError::InvalidPath(path.to_string()));
+        }
+        Ok(self.root.join(relative))
+    }
+
+    fn with_suffix(file_path: &Path, suffix: &str) -> PathBuf {
+        let mut p = file_path.as_os_str().to_owned();
+        p.push(suffix);
+        PathBuf::from(p)
+    }
+
+    fn content_type_path(file_path: &Path) -> PathBuf {
+        Self::with_suffix(file_path, CONTENT_TYPE_SUFFIX)
+    }
+
+    async fn create_parent(file_path: &Path) -> Result<(), StorageError> {
+        if let Some(parent) = file_path.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        Ok(())
+    }
+}
+
+async fn remove_if_exists(path: &Path) -> Result<(), StorageError> {
+    match fs::remove_file(path).await {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e.into()),
+        _ => Ok(()),
+    }
+}
+
+#[rocket::async_trait]
+impl StorageBackend for LocalBackend {
+    async fn put(&self, path: &str, data: &[u8], content_type: &str) -> Result<(), StorageError> {
+        let file_path = self.file_path(path)?;
+        Self::create_parent(&file_path).await?;
+        fs::write(&file_path, data).await?;
+        fs::write(Self::content_type_path(&file_path), content_type).await?;
+        Ok(())
+    }
+
+    async fn put_stream(
+        &self,
+        path: &str,
+        reader: &mut (dyn AsyncRead + Unpin + Send),
+        max_size: u64,
+    ) -> Result<u64, StorageError> {
+        let file_path = self.file_path(path)?;
+        Self::create_parent(&file_path).await?;
+
+        let partial_path = Self::with_suffix(&file_path, PARTIAL_SUFFIX);
+        let mut file = fs::File::create(&partial_path).await?;
+        let copied = io::copy(&mut reader.take(max_size + 1), &mut file).await;
+        drop(file);
+
+        let size = match copied {
+            Ok(size) if size <= max_size => size,
+            Ok(_) => {
+                remove_if_exists(&partial_path).await?;
+                return Err(StorageError::TooLarge);
+            }
+            Err(e) => {
+                remove_if_exists(&partial_path).await?;
+                return Err(e.into());
+            }
+        };
+        fs::rename(&partial_path, &file_path).await?;
+        Ok(size)
+    }
+
+    async fn get(&self, path: &str) -> Result<Option<StoredObject>, StorageError> {
+        let file_path = self.file_path(path)?;
+        let data = match fs::File::open(&file_path).await {
+            Ok(file) => Box::new(file),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let content_type = fs::read_to_string(Self::content_type_path(&file_path))
+            .await
+            .ok();
+        Ok(Some(StoredObject { data, content_type }))
+    }
+
+    async fn delete(&self, path: &str) -> Result<(), StorageError> {
+        let file_path = self.file_path(path)?;
+        remove_if_exists(&file_path).await?;
+        remove_if_exists(&Self::content_type_path(&file_path)).await
+    }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<String>, StorageError> {
+        let mut paths = Vec::new();
+        let mut pending = vec![self.root.clone()];
+        while let Some(dir) = pending.pop() {
+            let mut entries = fs::read_dir(&dir).await?;
+            while let Some(entry) = entries.next_entry().await? {
+                if entry.file_type().await?.is_dir() {
+                    pending.push(entry.path());
+                    continue;
+                }
+
+                let Ok(relative) = entry.path().strip_prefix(&self.root).map(|p| p.to_owned())
+                else {
+                    continue;
+                };
+                let relative = relative
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/");
+                if relative.starts_with(prefix)
+                    && !relative.ends_with(CONTENT_TYPE_SUFFIX)
+                    && !relative.ends_with(PARTIAL_SUFFIX)
+                {
+                    paths.push(relative);
+                }
+            }
+        }
+        Ok(paths)
+    }
+
+    async fn presign_get(
+        &self,
+        _path: &str,
+        _expires_in_secs: u32,
+    ) -> Result<Option<String>, StorageError> {
+        Ok(None)
+    }
+}
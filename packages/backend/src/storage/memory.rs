@@ -0,0 +1,98 @@
+use std::{collections::HashMap, io::Cursor, sync::RwLock};
+
+use rocket::tokio::io::{AsyncRead, AsyncReadExt};
+
+use super::{
+    backend::{StorageBackend, StoredObject},
+    client::StorageError,
+};
+
+struct MemoryObject {
+    data: Vec<u8>,
+    content_type: Option<String>,
+}
+
+/// Keeps files in memory, so they're lost when the server stops. Meant for tests and trying
+/// Palform out without setting up S3.
+#[derive(Default)]
+pub struct MemoryBackend {
+    objects: RwLock<HashMap<String, MemoryObject>>,
+}
+
+impl MemoryBackend {
+    fn insert(&self, path: &str, object: MemoryObject) {
+        self.objects
+            .write()
+            .expect("storage lock poisoned")
+            .insert(path.to_string(), object);
+    }
+}
+
+#[rocket::async_trait]
+impl StorageBackend for MemoryBackend {
+    async fn put(&self, path: &str, data: &[u8], content_type: &str) -> Result<(), StorageError> {
+        self.insert(
+            path,
+            MemoryObject {
+                data: data.to_vec(),
+                content_type: Some(content_type.to_string()),
+            },
+        );
+        Ok(())
+    }
+
+    async fn put_stream(
+        &self,
+        path: &str,
+        reader: &mut (dyn AsyncRead + Unpin + Send),
+        max_size: u64,
+    ) -> Result<u64, StorageError> {
+        let mut data = Vec::new();
+        reader.take(max_size + 1).read_to_end(&mut data).await?;
+        let size = data.len() as u64;
+        if size > max_size {
+            return Err(StorageError::TooLarge);
+        }
+        self.insert(
+            path,
+            MemoryObject {
+                data,
+                content_type: None,
+            },
+        );
+        Ok(size)
+    }
+
+    async fn get(&self, path: &str) -> Result<Option<StoredObject>, StorageError> {
+        let objects = self.objects.read().expect("storage lock poisoned");
+        Ok(objects.get(path).map(|o| StoredObject {
+            data: Box::new(Cursor::new(o.data.clone())),
+            content_type: o.content_type.clone(),
+        }))
+    }
+
+    async fn delete(&self, path: &str) -> Result<(), StorageError> {
+        self.objects
+            .write()
+            .expect("storage lock poisoned")
+            .remove(path);
+        Ok(())
+    }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<String>, StorageError> {
+        let objects = self.objects.read().expect("storage lock poisoned");
+        Ok(objects
+            .keys()
+            .filter(|k| k.starts_with(prefix))
+            .cloned()
+            .collect())
+    }
+
+    async fn presign_get(
+        &self,
+        _path: &str,
+        _expires_in_secs: u32,
+    ) -> Result<Option<String>, StorageError> {
+        Ok(None)
+    }
+}
@@ -0,0 +1,6 @@
+pub mod backend;
+pub mod buckets;
+pub mod client;
+mod local;
+mod memory;
+mod s3;
@@ -0,0 +1,120 @@
+use std::io::Cursor;
+
+use rocket::tokio::io::{AsyncRead, AsyncReadExt};
+use s3::{creds::Credentials, Bucket, Region};
+
+use crate::config::Config;
+
+use super::{
+    backend::{StorageBackend, StoredObject},
+    buckets::StorageBucket,
+    client::StorageError,
+};
+
+pub struct S3Backend {
+    bucket: Box<Bucket>,
+}
+
+fn required<T: Clone>(value: &Option<T>, name: &'static str) -> Result<T, StorageError> {
+    value.clone().ok_or(StorageError::MissingConfig(name))
+}
+
+/// We don't enable rust-s3's `fail-on-err`, so unsuccessful responses have to be checked for
+fn check_status(status_code: u16) -> Result<(), StorageError> {
+    if (200..300).contains(&status_code) {
+        Ok(())
+    } else {
+        Err(StorageError::S3Status(status_code))
+    }
+}
+
+impl S3Backend {
+    pub fn init<T: StorageBucket>(config: &Config) -> Result<Self, StorageError> {
+        let access_key = required(&config.s3_access_key, "s3_access_key")?;
+        let secret_key = required(&config.s3_secret_key, "s3_secret_key")?;
+        let creds = Credentials::new(Some(&access_key), Some(&secret_key), None, None, None)?;
+
+        let region = Region::Custom {
+            region: required(&config.s3_region, "s3_region")?,
+            endpoint: required(&config.s3_endpoint_url, "s3_endpoint_url")?,
+        };
+
+        let bucket_name =
+            T::s3_name(config).ok_or(StorageError::MissingConfig("s3 bucket name"))?;
+        let bucket = Bucket::new(&bucket_name, region, creds)?;
+        Ok(Self { bucket })
+    }
+}
+
+#[rocket::async_trait]
+impl StorageBackend for S3Backend {
+    async fn put(&self, path: &str, data: &[u8], content_type: &str) -> Result<(), StorageError> {
+        let resp = self
+            .bucket
+            .put_object_with_content_type(path, data, content_type)
+            .await?;
+        check_status(resp.status_code())
+    }
+
+    async fn put_stream(
+        &self,
+        path: &str,
+        reader: &mut (dyn AsyncRead + Unpin + Send),
+        max_size: u64,
+    ) -> Result<u64, StorageError> {
+        // Large files are sent as a multipart upload. S3 can't tell how big the file will be up
+        // front, so one that turns out too large is deleted again afterwards.
+        let mut reader = reader.take(max_size + 1);
+        let resp = self.bucket.put_object_stream(&mut reader, path).await?;
+        check_status(resp.status_code())?;
+
+        let size = resp.uploaded_bytes() as u64;
+        if size > max_size {
+            self.delete(path).await?;
+            return Err(StorageError::TooLarge);
+        }
+        Ok(size)
+    }
+
+    async fn get(&self, path: &str) -> Result<Option<StoredObject>, StorageError> {
+        let resp = self.bucket.get_object(path).await?;
+        if resp.status_code() == 404 {
+            return Ok(None);
+        }
+        check_status(resp.status_code())?;
+
+        // Files are normally downloaded straight from S3 with a presigned link, so this reads the
+        // whole object rather than using rust-s3's stream (which doesn't give us the headers)
+        let content_type = resp.headers().get("content-type").cloned();
+        Ok(Some(StoredObject {
+            data: Box::new(Cursor::new(resp.to_vec())),
+            content_type,
+        }))
+    }
+
+    async fn delete(&self, path: &str) -> Result<(), StorageError> {
+        let resp = self.bucket.delete_object(path).await?;
+        if resp.status_code() == 404 {
+            return Ok(());
+        }
+        check_status(resp.status_code())
+    }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<String>, StorageError> {
+        let pages = self.bucket.list(prefix.to_string(), None).await?;
+        Ok(pages
+            .into_iter()
+            .flat_map(|p| p.contents)
+            .map(|o| o.key)
+            .collect())
+    }
+
+    async fn presign_get(
+        &self,
+        path: &str,
+        expires_in_secs: u32,
+    ) -> Result<Option<String>, StorageError> {
+        let url = self.bucket.presign_get(path, expires_in_secs, None).await?;
+        Ok(Some(url))
+    }
+}
@@ -102,7 +102,12 @@ async fn main() {
             encrypted_data: Set(encrypted_submission_data),
             form_id: Set(config.form_id),
             for_token: Set(None),
+            form_revision_id: Set(None),
             created_at: Set(current_time.naive_utc()),
+            signing_key: Set(None),
+            chain_index: Set(None),
+            data_hash: Set(None),
+            chain_hash: Set(None),
         };
         new_submission
             .insert(&txn)
@@ -0,0 +1,101 @@
+use std::{collections::HashMap, io::Read};
+
+use anyhow::{anyhow, bail};
+use base64::prelude::*;
+use palform_client_common::form_management::bundle::{
+    SubmissionBundleFile, SubmissionBundleManifest, SubmissionBundleSchema,
+    SUBMISSION_BUNDLE_MANIFEST_PATH, SUBMISSION_BUNDLE_MANIFEST_SIGNATURE_PATH,
+    SUBMISSION_BUNDLE_VERSION,
+};
+use ring::{
+    digest::{digest, SHA256},
+    signature::{UnparsedPublicKey, ED25519},
+};
+
+/// A submission bundle whose manifest signature and file hashes have all been checked.
+pub struct Bundle {
+    pub manifest: SubmissionBundleManifest,
+    pub schema: SubmissionBundleSchema,
+    files: HashMap<String, Vec<u8>>,
+}
+
+impl Bundle {
+    /// Reads and verifies a bundle. If `trusted_key` is None, the manifest is checked against the
+    /// key it names itself, which only proves the bundle wasn't corrupted.
+    pub fn open<R: Read>(reader: R, trusted_key: Option<&str>) -> Result<Self, anyhow::Error> {
+        let mut files = HashMap::new();
+        let mut archive = tar::Archive::new(reader);
+        for entry in archive.entries()? {
+            let mut entry = entry?;
+            let path = entry.path()?.to_string_lossy().to_string();
+            let mut data = Vec::new();
+            entry.read_to_end(&mut data)?;
+            files.insert(path, data);
+        }
+
+        let manifest_bytes = files.get(SUBMISSION_BUNDLE_MANIFEST_PATH).ok_or(anyhow!(
+            "Bundle has no manifest, the download may have been cut off"
+        ))?;
+        let signature = files
+            .get(SUBMISSION_BUNDLE_MANIFEST_SIGNATURE_PATH)
+            .ok_or(anyhow!("Bundle has no manifest signature"))?;
+
+        let manifest: SubmissionBundleManifest =
+            serde_json::from_slice(manifest_bytes).map_err(|e| anyhow!("parse manifest: {}", e))?;
+        if manifest.version != SUBMISSION_BUNDLE_VERSION {
+            bail!(
+                "Bundle version {} is not supported by this version of palform-cli",
+                manifest.version
+            );
+        }
+
+        if let Some(trusted_key) = trusted_key {
+            if trusted_key != manifest.signing_key {
+                bail!("Bundle was signed by a different key to the one provided");
+            }
+        }
+
+        let public_key = BASE64_STANDARD
+            .decode(&manifest.signing_key)
+            .map_err(|e| anyhow!("decode signing key: {}", e))?;
+        let signature = BASE64_STANDARD
+            .decode(signature.trim_ascii())
+            .map_err(|e| anyhow!("decode manifest signature: {}", e))?;
+        UnparsedPublicKey::new(&ED25519, public_key)
+            .verify(manifest_bytes, &signature)
+            .map_err(|_| anyhow!("Manifest signature is not valid"))?;
+
+        let schema = serde_json::from_slice(check_file(&files, &manifest.schema)?)
+            .map_err(|e| anyhow!("parse schema: {}", e))?;
+        for entry in &manifest.submissions {
+            check_file(&files, &entry.file)?;
+        }
+
+        Ok(Self {
+            manifest,
+            schema,
+            files,
+        })
+    }
+
+    /// Returns the contents of a file listed in the manifest, after checking its hash.
+    pub fn file(&self, file: &SubmissionBundleFile) -> Result<&[u8], anyhow::Error> {
+        check_file(&self.files, file)
+    }
+}
+
+fn check_file<'a>(
+    files: &'a HashMap<String, Vec<u8>>,
+    file: &SubmissionBundleFile,
+) -> Result<&'a [u8], anyhow::Error> {
+    let data = files.get(&file.path).ok_or(anyhow!(
+        "{} is listed in the manifest but missing",
+        file.path
+    ))?;
+
+    if faster_hex::hex_string(digest(&SHA256, data).as_ref()) != file.sha256 {
+        bail!("{} does not match the hash in the manifest", file.path);
+    }
+
+    Ok(data)
+}
@@ -0,0 +1,42 @@
+use std::{fs, path::PathBuf};
+
+use anyhow::anyhow;
+use palform_crypto::{
+    decrypt::key_resolver::KeyResolver,
+    keys::backup::{decrypt_backup::decrypt_backed_up_key, words::WordList},
+};
+
+/// Builds a key resolver from armored secret keys and encrypted key backups. The passphrase for
+/// each backup is read from the terminal so it never ends up in shell history.
+pub fn load_keys(
+    key_paths: Vec<PathBuf>,
+    backup_paths: Vec<PathBuf>,
+) -> Result<KeyResolver, anyhow::Error> {
+    let mut key_pems = Vec::new();
+    for path in key_paths {
+        key_pems.push(
+            fs::read_to_string(&path).map_err(|e| anyhow!("read key {}: {}", path.display(), e))?,
+        );
+    }
+
+    if !backup_paths.is_empty() {
+        let word_list = WordList::load()?;
+        for path in backup_paths {
+            let backup = fs::read_to_string(&path)
+                .map_err(|e| anyhow!("read backup {}: {}", path.display(), e))?;
+            let passphrase =
+                rpassword::prompt_password(format!("Passphrase for {}: ", path.display()))?;
+            let passphrase = word_list.normalise_passphrase(&passphrase)?;
+
+            let restored = decrypt_backed_up_key(backup, passphrase)
+                .map_err(|e| anyhow!("decrypt backup {}: {}", path.display(), e))?;
+            key_pems.push(restored.decrypted_private_pem);
+        }
+    }
+
+    if key_pems.is_empty() {
+        return Err(anyhow!("Provide at least one --key or --backup"));
+    }
+
+    KeyResolver::from_pems(key_pems)
+}
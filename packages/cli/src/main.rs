@@ -0,0 +1,215 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::PathBuf,
+};
+
+use anyhow::anyhow;
+use bundle::Bundle;
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
+use keys::load_keys;
+use palform_client_common::form_management::{
+    export::{
+        export_submissions, ExportSubmission, ExportSubmissionsConfig, ExportSubmissionsFormat,
+    },
+    revision::merge_form_revisions,
+};
+use palform_crypto::{
+    decrypt::decrypt_message::decrypt_verify_submission, signature::SignatureStatus,
+};
+
+mod bundle;
+mod keys;
+
+fn bundle_args() -> Vec<Arg> {
+    vec![
+        Arg::new("bundle")
+            .required(true)
+            .value_parser(value_parser!(PathBuf))
+            .help("Submission bundle downloaded from Palform"),
+        Arg::new("signing-key").long("signing-key").help(
+            "Base64 public key the bundle must be signed with, from the server's /server_signing_key endpoint",
+        ),
+    ]
+}
+
+fn open_bundle(matches: &ArgMatches) -> Result<Bundle, anyhow::Error> {
+    let path = matches
+        .get_one::<PathBuf>("bundle")
+        .expect("Bundle is required");
+    let file = fs::File::open(path).map_err(|e| anyhow!("open {}: {}", path.display(), e))?;
+
+    let signing_key = matches.get_one::<String>("signing-key");
+    if signing_key.is_none() {
+        eprintln!(
+            "warning: no --signing-key given, so the bundle is only checked for corruption and not for who made it"
+        );
+    }
+
+    Bundle::open(file, signing_key.map(|e| e.as_str()))
+}
+
+fn verify(matches: &ArgMatches) -> Result<(), anyhow::Error> {
+    let bundle = open_bundle(matches)?;
+    println!(
+        "Bundle for form {} is valid: {} submissions, exported at {}",
+        bundle.manifest.form_id,
+        bundle.manifest.submissions.len(),
+        bundle.manifest.exported_at
+    );
+    Ok(())
+}
+
+fn export(matches: &ArgMatches) -> Result<(), anyhow::Error> {
+    let bundle = open_bundle(matches)?;
+    let key_resolver = load_keys(
+        matches
+            .get_many::<PathBuf>("key")
+            .unwrap_or_default()
+            .cloned()
+            .collect(),
+        matches
+            .get_many::<PathBuf>("backup")
+            .unwrap_or_default()
+            .cloned()
+            .collect(),
+    )?;
+
+    let mut submissions = Vec::new();
+    let mut failed = 0;
+    for entry in &bundle.manifest.submissions {
+        let result = decrypt_verify_submission(
+            bundle.file(&entry.file)?,
+            &key_resolver,
+            entry.signing_key.clone(),
+        );
+        if let Ok(verified) = &result {
+            if let SignatureStatus::Invalid { error } = &verified.signature {
+                eprintln!(
+                    "warning: submission {} has an invalid signature: {}",
+                    entry.id, error
+                );
+            }
+        }
+
+        match result {
+            Ok(verified) => submissions.push(ExportSubmission {
+                id: entry.id,
+                created_at: entry.created_at,
+                fill_token_nickname: entry.for_token.and_then(|token_id| {
+                    bundle
+                        .schema
+                        .fill_tokens
+                        .iter()
+                        .find(|t| t.id == token_id)
+                        .map(|t| t.nickname.clone())
+                }),
+                form_revision_id: entry.form_revision_id,
+                submission: verified.submission,
+            }),
+            Err(e) => {
+                eprintln!("warning: could not decrypt submission {}: {}", entry.id, e);
+                failed += 1;
+            }
+        }
+    }
+
+    let format = match matches
+        .get_one::<String>("format")
+        .expect("Format has a default")
+        .as_str()
+    {
+        "csv" => ExportSubmissionsFormat::CSV,
+        "json" => ExportSubmissionsFormat::JSON,
+        "xlsx" => ExportSubmissionsFormat::XLSX,
+        "ods" => ExportSubmissionsFormat::ODS,
+        "spss" => ExportSubmissionsFormat::SPSS,
+        _ => unreachable!("Format is restricted by value_parser"),
+    };
+
+    let schema = merge_form_revisions(
+        bundle.schema.groups,
+        bundle.schema.questions,
+        &bundle.schema.revisions,
+    );
+    let output = export_submissions(
+        schema.groups,
+        submissions,
+        schema.questions,
+        ExportSubmissionsConfig {
+            use_question_ids: matches.get_flag("use-question-ids"),
+            use_group_ids: matches.get_flag("use-group-ids"),
+            format,
+        },
+    )?;
+
+    match matches.get_one::<PathBuf>("output") {
+        Some(path) => {
+            fs::write(path, output).map_err(|e| anyhow!("write {}: {}", path.display(), e))?
+        }
+        None => io::stdout().write_all(&output)?,
+    }
+
+    if failed > 0 {
+        eprintln!(
+            "warning: {} of {} submissions could not be decrypted with the keys provided",
+            failed,
+            bundle.manifest.submissions.len()
+        );
+    }
+
+    Ok(())
+}
+
+fn main() -> Result<(), anyhow::Error> {
+    let matches = Command::new("palform-cli")
+        .about("Verify and decrypt Palform submission bundles offline")
+        .subcommand_required(true)
+        .subcommand(
+            Command::new("verify")
+                .about("Check a bundle's signature and file hashes")
+                .args(bundle_args()),
+        )
+        .subcommand(
+            Command::new("export")
+                .about("Decrypt a bundle and export its submissions")
+                .args(bundle_args())
+                .args([
+                    Arg::new("key")
+                        .long("key")
+                        .action(ArgAction::Append)
+                        .value_parser(value_parser!(PathBuf))
+                        .help("Armored OpenPGP secret key"),
+                    Arg::new("backup")
+                        .long("backup")
+                        .action(ArgAction::Append)
+                        .value_parser(value_parser!(PathBuf))
+                        .help("Encrypted key backup; you'll be asked for its passphrase"),
+                    Arg::new("format")
+                        .long("format")
+                        .value_parser(["csv", "json", "xlsx", "ods", "spss"])
+                        .default_value("csv")
+                        .help("spss writes a zip with coded data, a codebook, and SPSS and R import scripts"),
+                    Arg::new("use-question-ids")
+                        .long("use-question-ids")
+                        .action(ArgAction::SetTrue)
+                        .help("Label answers with question IDs instead of titles"),
+                    Arg::new("use-group-ids")
+                        .long("use-group-ids")
+                        .action(ArgAction::SetTrue)
+                        .help("Label sections with their IDs instead of their position"),
+                    Arg::new("output")
+                        .long("output")
+                        .short('o')
+                        .value_parser(value_parser!(PathBuf))
+                        .help("File to write to instead of stdout"),
+                ]),
+        )
+        .get_matches();
+
+    match matches.subcommand() {
+        Some(("verify", sub_matches)) => verify(sub_matches),
+        Some(("export", sub_matches)) => export(sub_matches),
+        _ => unreachable!("Subcommands are required"),
+    }
+}
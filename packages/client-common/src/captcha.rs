@@ -0,0 +1,131 @@
+//! The self-hosted proof-of-work captcha. The server hands out a signed challenge, and the client
+//! has to find a nonce where `SHA-256("{challenge}:{nonce}")` starts with the number of zero bits
+//! given in the challenge. Checking a solution only takes one hash, but finding one takes about
+//! `2^difficulty` of them.
+
+use std::fmt::Display;
+
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ProofOfWorkError {
+    #[error("Malformed challenge")]
+    MalformedChallenge,
+    #[error("Malformed solution")]
+    MalformedSolution,
+}
+
+pub struct ProofOfWorkChallenge {
+    pub salt: String,
+    /// Unix timestamp in seconds
+    pub expires_at: i64,
+    pub difficulty: u8,
+    /// Only the server can check this, so the client just passes it back
+    pub signature: String,
+}
+
+impl ProofOfWorkChallenge {
+    /// The part of the challenge covered by the signature
+    pub fn signed_payload(salt: &str, expires_at: i64, difficulty: u8) -> String {
+        format!("{}.{}.{}", salt, expires_at, difficulty)
+    }
+
+    pub fn parse(challenge: &str) -> Result<Self, ProofOfWorkError> {
+        let mut parts = challenge.split('.');
+        let mut next = || parts.next().ok_or(ProofOfWorkError::MalformedChallenge);
+        let salt = next()?.to_string();
+        let expires_at = next()?
+            .parse()
+            .map_err(|_| ProofOfWorkError::MalformedChallenge)?;
+        let difficulty = next()?
+            .parse()
+            .map_err(|_| ProofOfWorkError::MalformedChallenge)?;
+        let signature = next()?.to_string();
+
+        if parts.next().is_some() || difficulty > 64 {
+            return Err(ProofOfWorkError::MalformedChallenge);
+        }
+
+        Ok(Self {
+            salt,
+            expires_at,
+            difficulty,
+            signature,
+        })
+    }
+
+    fn leading_zero_bits(hash: &[u8]) -> u32 {
+        let mut bits = 0;
+        for byte in hash {
+            bits += byte.leading_zeros();
+            if *byte != 0 {
+                break;
+            }
+        }
+        bits
+    }
+
+    /// Whether `nonce` solves this challenge
+    pub fn is_solution(&self, nonce: u64) -> bool {
+        let hash = Sha256::new()
+            .chain_update(self.to_string())
+            .chain_update(":")
+            .chain_update(nonce.to_string())
+            .finalize();
+        Self::leading_zero_bits(&hash) >= self.difficulty as u32
+    }
+
+    pub fn solve(&self) -> u64 {
+        (0..)
+            .find(|nonce| self.is_solution(*nonce))
+            .expect("Every nonce was tried")
+    }
+}
+
+impl Display for ProofOfWorkChallenge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}.{}",
+            Self::signed_payload(&self.salt, self.expires_at, self.difficulty),
+            self.signature
+        )
+    }
+}
+
+/// What the client sends as its captcha response, written as `{challenge}:{nonce}`
+pub struct ProofOfWorkSolution {
+    pub challenge: ProofOfWorkChallenge,
+    pub nonce: u64,
+}
+
+impl ProofOfWorkSolution {
+    pub fn parse(response: &str) -> Result<Self, ProofOfWorkError> {
+        let (challenge, nonce) = response
+            .rsplit_once(':')
+            .ok_or(ProofOfWorkError::MalformedSolution)?;
+        Ok(Self {
+            challenge: ProofOfWorkChallenge::parse(challenge)?,
+            nonce: nonce
+                .parse()
+                .map_err(|_| ProofOfWorkError::MalformedSolution)?,
+        })
+    }
+}
+
+impl Display for ProofOfWorkSolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.challenge, self.nonce)
+    }
+}
+
+/// Solves a challenge from the server, returning the response to send in the captcha header
+#[cfg(feature = "frontend-js")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn solve_proof_of_work_js(challenge: String) -> Result<String, wasm_bindgen::JsValue> {
+    let challenge = ProofOfWorkChallenge::parse(&challenge)
+        .map_err(|e| wasm_bindgen::JsValue::from_str(&e.to_string()))?;
+    let nonce = challenge.solve();
+    Ok(ProofOfWorkSolution { challenge, nonce }.to_string())
+}
@@ -13,6 +13,10 @@ pub enum APIError {
     NotAllowed,
     #[error("Subscription limit exceeded: {0}")]
     SubscriptionLimit(String),
+    #[error("Too large: {0}")]
+    PayloadTooLarge(String),
+    #[error("Rate limited: {0}")]
+    RateLimited(String),
     #[error("Internal")]
     Internal,
     #[error("Not found")]
@@ -31,6 +35,8 @@ impl APIError {
             Self::CaptchaError(_) => rocket::http::Status::BadRequest,
             Self::NotAllowed => rocket::http::Status::Forbidden,
             Self::SubscriptionLimit(_) => rocket::http::Status::PaymentRequired,
+            Self::PayloadTooLarge(_) => rocket::http::Status::PayloadTooLarge,
+            Self::RateLimited(_) => rocket::http::Status::TooManyRequests,
             Self::Internal => rocket::http::Status::InternalServerError,
             Self::NotFound => rocket::http::Status::NotFound,
         }
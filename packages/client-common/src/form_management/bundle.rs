@@ -0,0 +1,78 @@
+use chrono::{DateTime, Utc};
+use palform_tsid::{
+    resources::{IDFillAccessToken, IDForm, IDFormRevision, IDSubmission},
+    tsid::PalformDatabaseID,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    question_group::APIQuestionGroup, question_types::APIQuestion, revision::APIFormRevision,
+};
+
+/// Bumped whenever the layout of a bundle changes in a way older readers can't handle.
+pub const SUBMISSION_BUNDLE_VERSION: u32 = 1;
+
+pub const SUBMISSION_BUNDLE_MANIFEST_PATH: &str = "manifest.json";
+pub const SUBMISSION_BUNDLE_MANIFEST_SIGNATURE_PATH: &str = "manifest.json.sig";
+pub const SUBMISSION_BUNDLE_SCHEMA_PATH: &str = "schema.json";
+
+pub fn submission_bundle_path(submission_id: PalformDatabaseID<IDSubmission>) -> String {
+    format!("submissions/{}.asc", submission_id)
+}
+
+/// The form as it was at the time of export, so the bundle can be turned into CSV/JSON without
+/// talking to the server again.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SubmissionBundleSchema {
+    pub form_id: PalformDatabaseID<IDForm>,
+    pub title: Option<String>,
+    pub editor_name: String,
+    pub groups: Vec<APIQuestionGroup>,
+    pub questions: Vec<APIQuestion>,
+    #[serde(default)]
+    pub fill_tokens: Vec<SubmissionBundleFillToken>,
+    /// Every saved revision of the form, so submissions can be shown against the questions they
+    /// were filled in with
+    #[serde(default)]
+    pub revisions: Vec<APIFormRevision>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SubmissionBundleFillToken {
+    pub id: PalformDatabaseID<IDFillAccessToken>,
+    pub nickname: String,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SubmissionBundleFile {
+    pub path: String,
+    /// Hex-encoded SHA256 of the file contents
+    pub sha256: String,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SubmissionBundleEntry {
+    pub id: PalformDatabaseID<IDSubmission>,
+    pub created_at: DateTime<Utc>,
+    pub for_token: Option<PalformDatabaseID<IDFillAccessToken>>,
+    #[serde(default)]
+    pub form_revision_id: Option<PalformDatabaseID<IDFormRevision>>,
+    /// The public key the respondent signed the submission with, if any
+    #[serde(default)]
+    pub signing_key: Option<String>,
+    #[serde(flatten)]
+    pub file: SubmissionBundleFile,
+}
+
+/// Written to `manifest.json` at the end of the bundle. `manifest.json.sig` holds the base64
+/// Ed25519 signature of the exact manifest bytes, made with the server's signing key.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SubmissionBundleManifest {
+    pub version: u32,
+    pub form_id: PalformDatabaseID<IDForm>,
+    pub exported_at: DateTime<Utc>,
+    pub schema: SubmissionBundleFile,
+    pub submissions: Vec<SubmissionBundleEntry>,
+    /// Base64-encoded Ed25519 public key that signed the manifest
+    pub signing_key: String,
+}
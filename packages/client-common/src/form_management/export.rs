@@ -1,17 +1,30 @@
 use std::collections::HashMap;
 
 use anyhow::anyhow;
-use palform_tsid::{resources::IDQuestion, tsid::PalformDatabaseID};
+use chrono::{DateTime, Utc};
+use palform_tsid::{
+    resources::{IDFormRevision, IDQuestion, IDSubmission},
+    tsid::PalformDatabaseID,
+};
 use serde::Deserialize;
 
 use super::{
-    question_group::APIQuestionGroup, question_types::APIQuestion, submission::InProgressSubmission,
+    json_export::JsonExport,
+    question_group::APIQuestionGroup,
+    question_types::APIQuestion,
+    spreadsheet::{ods::write_ods, xlsx::write_xlsx, SpreadsheetWorkbook},
+    statistical_export::StatisticalExport,
+    submission::InProgressSubmission,
 };
 
 #[derive(PartialEq, Clone, Deserialize)]
 pub enum ExportSubmissionsFormat {
     CSV,
     JSON,
+    XLSX,
+    ODS,
+    /// A zip of a coded CSV, a codebook, and SPSS and R import scripts
+    SPSS,
 }
 
 #[derive(Clone, Deserialize)]
@@ -21,54 +34,27 @@ pub struct ExportSubmissionsConfig {
     pub format: ExportSubmissionsFormat,
 }
 
-type ExportIntermediate = HashMap<String, HashMap<String, String>>;
+/// A decrypted submission along with the metadata the server holds about it
+#[derive(Clone, Deserialize)]
+pub struct ExportSubmission {
+    pub id: PalformDatabaseID<IDSubmission>,
+    pub created_at: DateTime<Utc>,
+    pub fill_token_nickname: Option<String>,
+    #[serde(default)]
+    pub form_revision_id: Option<PalformDatabaseID<IDFormRevision>>,
+    pub submission: InProgressSubmission,
+}
 
 pub fn export_submissions(
     groups: Vec<APIQuestionGroup>,
-    submissions: Vec<InProgressSubmission>,
+    submissions: Vec<ExportSubmission>,
     questions: Vec<APIQuestion>,
     config: ExportSubmissionsConfig,
-) -> Result<String, anyhow::Error> {
+) -> Result<Vec<u8>, anyhow::Error> {
     match config.format {
         ExportSubmissionsFormat::JSON => {
-            let mut intermediates = Vec::<ExportIntermediate>::new();
-            for submission in submissions {
-                let mut intermediate = ExportIntermediate::new();
-
-                for (group_index, group) in groups.iter().enumerate() {
-                    let group_questions: Vec<APIQuestion> = questions
-                        .iter()
-                        .filter(|e| e.group_id == group.id)
-                        .cloned()
-                        .collect();
-
-                    let mut group_map = HashMap::<String, String>::new();
-                    for question in group_questions {
-                        let matching_question_submission = submission
-                            .questions
-                            .iter()
-                            .find(|e| e.question_id == question.id);
-
-                        if let Some(matching_question_submission) = matching_question_submission {
-                            let key = question
-                                .to_export_key(&groups, config.use_question_ids, true, false)
-                                .ok_or(anyhow!("Group ID of question failed to resolve"))?;
-                            group_map.insert(key, matching_question_submission.data.to_string());
-                        }
-                    }
-
-                    intermediate.insert(
-                        if config.use_group_ids {
-                            group.id.to_string()
-                        } else {
-                            format!("Section {}", group_index + 1)
-                        },
-                        group_map,
-                    );
-                }
-                intermediates.push(intermediate);
-            }
-            serde_json::to_string(&intermediates).map_err(|e| anyhow!("json serialize: {}", e))
+            let export = JsonExport::from_submissions(&groups, &submissions, &questions, &config)?;
+            serde_json::to_vec(&export).map_err(|e| anyhow!("json serialize: {}", e))
         }
         ExportSubmissionsFormat::CSV => {
             let mut w = csv::Writer::from_writer(Vec::new());
@@ -104,6 +90,7 @@ pub fn export_submissions(
                     let matching_question_id = question_key_map.get(&question_key);
                     if let Some(matching_question_id) = matching_question_id {
                         let matching_question_submission = submission
+                            .submission
                             .questions
                             .iter()
                             .find(|e| e.question_id == *matching_question_id);
@@ -122,11 +109,21 @@ pub fn export_submissions(
                     .map_err(|e| anyhow!("write submission row: {}", e))?;
             }
 
-            String::from_utf8(
-                w.into_inner()
-                    .map_err(|e| anyhow!("csv serialize: {}", e))?,
-            )
-            .map_err(|e| anyhow!("parse serialized csv: {}", e))
+            w.into_inner().map_err(|e| anyhow!("csv serialize: {}", e))
+        }
+        ExportSubmissionsFormat::XLSX => {
+            let workbook =
+                SpreadsheetWorkbook::from_submissions(&groups, &submissions, &questions, &config)?;
+            write_xlsx(&workbook).map_err(|e| anyhow!("xlsx serialize: {}", e))
+        }
+        ExportSubmissionsFormat::ODS => {
+            let workbook =
+                SpreadsheetWorkbook::from_submissions(&groups, &submissions, &questions, &config)?;
+            write_ods(&workbook).map_err(|e| anyhow!("ods serialize: {}", e))
+        }
+        ExportSubmissionsFormat::SPSS => {
+            StatisticalExport::from_submissions(&groups, &submissions, &questions, &config)?
+                .write_zip()
         }
     }
 }
@@ -138,7 +135,7 @@ pub fn export_submissions_js(
     submissions: wasm_bindgen::JsValue,
     questions: wasm_bindgen::JsValue,
     config: wasm_bindgen::JsValue,
-) -> Result<String, wasm_bindgen::JsValue> {
+) -> Result<Vec<u8>, wasm_bindgen::JsValue> {
     let groups = serde_wasm_bindgen::from_value(groups)?;
     let submissions = serde_wasm_bindgen::from_value(submissions)?;
     let questions = serde_wasm_bindgen::from_value(questions)?;
@@ -0,0 +1,268 @@
+use anyhow::anyhow;
+use chrono::{DateTime, Local, Utc};
+use palform_tsid::{
+    resources::{IDFormRevision, IDQuestion, IDQuestionGroup, IDSubmission},
+    tsid::PalformDatabaseID,
+};
+use serde::Serialize;
+
+use crate::address::{APIGenericAddress, APIGenericLocation};
+
+use super::{
+    export::{ExportSubmission, ExportSubmissionsConfig},
+    question_group::APIQuestionGroup,
+    question_types::{APIQuestion, APIQuestionConfiguration},
+    submission::QuestionSubmissionData,
+};
+
+/// Bumped whenever the JSON export changes in a way that would break existing consumers.
+pub const JSON_EXPORT_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+pub struct JsonExport {
+    pub version: u32,
+    pub exported_at: DateTime<Utc>,
+    pub questions: Vec<JsonExportQuestion>,
+    pub submissions: Vec<JsonExportSubmission>,
+}
+
+#[derive(Serialize)]
+pub struct JsonExportQuestion {
+    pub id: PalformDatabaseID<IDQuestion>,
+    pub group_id: PalformDatabaseID<IDQuestionGroup>,
+    /// The label used for this question's answers, following the export's ID settings
+    pub key: String,
+    pub title: String,
+    #[serde(rename = "type")]
+    pub question_type: &'static str,
+    pub required: bool,
+}
+
+#[derive(Serialize)]
+pub struct JsonExportSubmission {
+    pub id: PalformDatabaseID<IDSubmission>,
+    pub created_at: DateTime<Utc>,
+    pub fill_token_nickname: Option<String>,
+    /// The revision of the form this submission was filled against, if known
+    pub form_revision_id: Option<PalformDatabaseID<IDFormRevision>>,
+    /// Answers in the order the questions appear in the form. Unanswered questions are left out.
+    pub answers: Vec<JsonExportAnswer>,
+}
+
+#[derive(Serialize)]
+pub struct JsonExportAnswer {
+    pub question_id: PalformDatabaseID<IDQuestion>,
+    pub key: String,
+    #[serde(flatten)]
+    pub value: JsonExportValue,
+}
+
+#[derive(Serialize)]
+pub struct JsonExportMatrixRow {
+    pub row: String,
+    pub selected: Vec<String>,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum JsonExportValue {
+    Text {
+        value: String,
+    },
+    Choice {
+        selected: Vec<String>,
+    },
+    Scale {
+        value: Option<i32>,
+    },
+    Address {
+        address: APIGenericAddress,
+        location: Option<APIGenericLocation>,
+    },
+    PhoneNumber {
+        calling_code: String,
+        number: String,
+    },
+    FileUpload {
+        file_id: String,
+        content_type: String,
+    },
+    Signature {
+        full_name: Option<String>,
+        initial: Option<String>,
+        /// Strokes of `[x, y]` points, as drawn by the respondent
+        freeform: Vec<Vec<Vec<f32>>>,
+    },
+    ChoiceMatrix {
+        rows: Vec<JsonExportMatrixRow>,
+    },
+    DateTime {
+        value: Option<DateTime<Local>>,
+    },
+    Hidden {
+        value: String,
+    },
+}
+
+/// Sorts `selected` into the order of `options`, so the output doesn't depend on the order the
+/// respondent clicked things in. Values that are no longer in `options` go at the end, sorted.
+fn in_option_order(options: &[String], selected: &[String]) -> Vec<String> {
+    let mut ordered: Vec<String> = options
+        .iter()
+        .filter(|o| selected.contains(o))
+        .cloned()
+        .collect();
+
+    let mut removed: Vec<String> = selected
+        .iter()
+        .filter(|s| !options.contains(s))
+        .cloned()
+        .collect();
+    removed.sort();
+    ordered.extend(removed);
+    ordered
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    if value.is_empty() {
+        None
+    } else {
+        Some(value.to_string())
+    }
+}
+
+impl JsonExportValue {
+    fn new(configuration: &APIQuestionConfiguration, data: &QuestionSubmissionData) -> Self {
+        match data {
+            QuestionSubmissionData::Text { value } => Self::Text {
+                value: value.clone(),
+            },
+            QuestionSubmissionData::Choice { option } => Self::Choice {
+                selected: match configuration {
+                    APIQuestionConfiguration::Choice { options, .. } => {
+                        in_option_order(options, option)
+                    }
+                    _ => in_option_order(&[], option),
+                },
+            },
+            QuestionSubmissionData::Scale { value } => Self::Scale { value: *value },
+            QuestionSubmissionData::Address { address, point } => Self::Address {
+                address: address.clone(),
+                location: if point.is_empty() {
+                    None
+                } else {
+                    Some(point.clone())
+                },
+            },
+            QuestionSubmissionData::PhoneNumber {
+                calling_code,
+                number,
+            } => Self::PhoneNumber {
+                calling_code: calling_code.clone(),
+                number: number.clone(),
+            },
+            QuestionSubmissionData::FileUpload {
+                file_id,
+                content_type,
+            } => Self::FileUpload {
+                file_id: file_id.clone(),
+                content_type: content_type.clone(),
+            },
+            QuestionSubmissionData::Signature {
+                freeform,
+                initial,
+                full_name,
+            } => Self::Signature {
+                full_name: non_empty(full_name),
+                initial: non_empty(initial),
+                freeform: freeform.clone(),
+            },
+            QuestionSubmissionData::ChoiceMatrix { options } => {
+                let (rows, columns) = match configuration {
+                    APIQuestionConfiguration::ChoiceMatrix { rows, columns, .. } => {
+                        (rows.as_slice(), columns.as_slice())
+                    }
+                    _ => (&[] as &[String], &[] as &[String]),
+                };
+
+                let answered_rows: Vec<String> = options.keys().cloned().collect();
+                Self::ChoiceMatrix {
+                    rows: in_option_order(rows, &answered_rows)
+                        .into_iter()
+                        .map(|row| JsonExportMatrixRow {
+                            selected: in_option_order(
+                                columns,
+                                options.get(&row).map(Vec::as_slice).unwrap_or_default(),
+                            ),
+                            row,
+                        })
+                        .collect(),
+                }
+            }
+            QuestionSubmissionData::DateTime { value } => Self::DateTime { value: *value },
+            QuestionSubmissionData::Hidden { value } => Self::Hidden {
+                value: value.clone(),
+            },
+        }
+    }
+}
+
+impl JsonExport {
+    pub fn from_submissions(
+        groups: &[APIQuestionGroup],
+        submissions: &[ExportSubmission],
+        questions: &[APIQuestion],
+        config: &ExportSubmissionsConfig,
+    ) -> Result<Self, anyhow::Error> {
+        let mut export_questions = Vec::new();
+        let mut ordered_questions = Vec::new();
+        for group in groups {
+            for question in questions.iter().filter(|q| q.group_id == group.id) {
+                let key = question
+                    .to_export_key(groups, config.use_question_ids, false, config.use_group_ids)
+                    .ok_or(anyhow!("Group ID of question failed to resolve"))?;
+                export_questions.push(JsonExportQuestion {
+                    id: question.id,
+                    group_id: question.group_id,
+                    key: key.clone(),
+                    title: question.title.clone(),
+                    question_type: question.configuration.type_name(),
+                    required: question.required,
+                });
+                ordered_questions.push((question, key));
+            }
+        }
+
+        let export_submissions = submissions
+            .iter()
+            .map(|submission| JsonExportSubmission {
+                id: submission.id,
+                created_at: submission.created_at,
+                fill_token_nickname: submission.fill_token_nickname.clone(),
+                form_revision_id: submission.form_revision_id,
+                answers: ordered_questions
+                    .iter()
+                    .filter_map(|(question, key)| {
+                        submission
+                            .submission
+                            .questions
+                            .iter()
+                            .find(|q| q.question_id == question.id)
+                            .map(|q| JsonExportAnswer {
+                                question_id: question.id,
+                                key: key.clone(),
+                                value: JsonExportValue::new(&question.configuration, &q.data),
+                            })
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        Ok(Self {
+            version: JSON_EXPORT_VERSION,
+            exported_at: Utc::now(),
+            questions: export_questions,
+            submissions: export_submissions,
+        })
+    }
+}
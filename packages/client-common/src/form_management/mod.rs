@@ -4,3 +4,10 @@ pub mod submission;
 pub mod validation;
 pub mod export;
 pub mod form_end;
+pub mod bundle;
+pub mod spreadsheet;
+pub mod json_export;
+pub mod statistical_export;
+pub mod server_validation;
+pub mod revision;
+pub mod submission_chain;
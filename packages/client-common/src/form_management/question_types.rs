@@ -20,6 +20,10 @@ pub struct APIQuestion {
     pub required: bool,
     pub configuration: APIQuestionConfiguration,
     pub group_id: PalformDatabaseID<IDQuestionGroup>,
+    /// Sends a copy of this question's answer to the server alongside the encrypted submission,
+    /// so it can be validated there as well as in the browser.
+    #[serde(default)]
+    pub server_validation: Option<APIQuestionServerValidation>,
 }
 
 impl APIQuestion {
@@ -73,9 +77,28 @@ impl PartialEq for APIQuestion {
             && self.description == other.description
             && self.required == other.required
             && self.configuration == other.configuration
+            && self.server_validation == other.server_validation
     }
 }
 
+#[cfg_attr(feature = "backend", derive(schemars::JsonSchema))]
+#[derive(Clone, Deserialize, Serialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub enum APIQuestionServerValidation {
+    /// The answer is sent as-is, so every rule can be checked
+    #[serde(rename = "plaintext")]
+    Plaintext,
+    /// Only a SHA256 hash of the question ID and the answer is sent. This is enough to enforce
+    /// required questions, but the question ID is public, so short answers can still be guessed
+    /// from their hash.
+    #[serde(rename = "hashed")]
+    Hashed,
+    /// Number, scale and date answers are rounded down to a multiple of `bucket_size` (in
+    /// seconds for dates), which is enough to enforce their range without sending the exact value
+    #[serde(rename = "bucketed")]
+    Bucketed { bucket_size: f64 },
+}
+
 #[cfg_attr(feature = "backend", derive(schemars::JsonSchema))]
 #[derive(Clone, Deserialize, Serialize, PartialEq)]
 #[serde(deny_unknown_fields)]
@@ -115,6 +138,9 @@ pub enum APIQuestionConfiguration {
     #[serde(rename = "file_upload")]
     FileUpload {
         allowed_types: Vec<APIQuestionFileUploadType>,
+        /// Larger uploads are rejected. The server's own limit applies either way.
+        #[serde(default)]
+        max_size_mb: Option<u32>,
     },
     #[serde(rename = "signature")]
     Signature {
@@ -148,6 +174,23 @@ impl APIQuestionConfiguration {
             }
         )
     }
+
+    /// The name this question type is serialised with, e.g. `choice_matrix`
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::Info { .. } => "info",
+            Self::Text { .. } => "text",
+            Self::Choice { .. } => "choice",
+            Self::Scale { .. } => "scale",
+            Self::Address { .. } => "address",
+            Self::PhoneNumber { .. } => "phone_number",
+            Self::FileUpload { .. } => "file_upload",
+            Self::Signature { .. } => "signature",
+            Self::ChoiceMatrix { .. } => "choice_matrix",
+            Self::DateTime { .. } => "date_time",
+            Self::Hidden { .. } => "hidden",
+        }
+    }
 }
 
 #[cfg_attr(feature = "backend", derive(schemars::JsonSchema))]
@@ -233,6 +276,7 @@ impl APIQuestionConfiguration {
             "phone_number" | "PhoneNumber" => Ok(APIQuestionConfiguration::PhoneNumber {}),
             "file_upload" | "FileUpload" => Ok(APIQuestionConfiguration::FileUpload {
                 allowed_types: vec![APIQuestionFileUploadType::Any],
+                max_size_mb: None,
             }),
             "signature" | "Signature" => Ok(APIQuestionConfiguration::Signature {
                 allow_freeform: true,
@@ -0,0 +1,268 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+use palform_tsid::{
+    resources::{IDFormRevision, IDQuestion, IDQuestionGroup},
+    tsid::PalformDatabaseID,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{question_group::APIQuestionGroup, question_types::APIQuestion};
+
+/// An immutable snapshot of a form's questions and groups, taken every time they're saved.
+/// Submissions record the revision they were filled against.
+#[cfg_attr(feature = "backend", derive(schemars::JsonSchema))]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct APIFormRevision {
+    pub id: PalformDatabaseID<IDFormRevision>,
+    /// Counts up from 1 for each form
+    pub number: i32,
+    pub created_at: DateTime<Utc>,
+    pub groups: Vec<APIQuestionGroup>,
+    pub questions: Vec<APIQuestion>,
+}
+
+#[cfg_attr(feature = "backend", derive(schemars::JsonSchema))]
+#[derive(Clone, Serialize, Deserialize, PartialEq)]
+pub enum APIFormRevisionChangeKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+#[cfg_attr(feature = "backend", derive(schemars::JsonSchema))]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct APIFormRevisionQuestionChange {
+    pub question_id: PalformDatabaseID<IDQuestion>,
+    pub kind: APIFormRevisionChangeKind,
+    /// The fields that differ, for modified questions
+    pub fields: Vec<String>,
+    pub before: Option<APIQuestion>,
+    pub after: Option<APIQuestion>,
+}
+
+#[cfg_attr(feature = "backend", derive(schemars::JsonSchema))]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct APIFormRevisionGroupChange {
+    pub group_id: PalformDatabaseID<IDQuestionGroup>,
+    pub kind: APIFormRevisionChangeKind,
+    /// The fields that differ, for modified groups
+    pub fields: Vec<String>,
+    pub before: Option<APIQuestionGroup>,
+    pub after: Option<APIQuestionGroup>,
+}
+
+/// What changed between two revisions of a form. Unchanged questions and groups are left out.
+#[cfg_attr(feature = "backend", derive(schemars::JsonSchema))]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct APIFormRevisionDiff {
+    pub from: PalformDatabaseID<IDFormRevision>,
+    pub to: PalformDatabaseID<IDFormRevision>,
+    pub groups: Vec<APIFormRevisionGroupChange>,
+    pub questions: Vec<APIFormRevisionQuestionChange>,
+}
+
+fn changed_question_fields(
+    before: &APIQuestion,
+    before_position: usize,
+    after: &APIQuestion,
+    after_position: usize,
+) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut check = |name: &str, changed: bool| {
+        if changed {
+            fields.push(name.to_string());
+        }
+    };
+
+    check("title", before.title != after.title);
+    check("internal_name", before.internal_name != after.internal_name);
+    check("description", before.description != after.description);
+    check("required", before.required != after.required);
+    check(
+        "type",
+        before.configuration.type_name() != after.configuration.type_name(),
+    );
+    check("configuration", before.configuration != after.configuration);
+    check("group_id", before.group_id != after.group_id);
+    check("position", before_position != after_position);
+    check(
+        "server_validation",
+        before.server_validation != after.server_validation,
+    );
+    fields
+}
+
+fn changed_group_fields(
+    before: &APIQuestionGroup,
+    before_position: usize,
+    after: &APIQuestionGroup,
+    after_position: usize,
+) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut check = |name: &str, changed: bool| {
+        if changed {
+            fields.push(name.to_string());
+        }
+    };
+
+    check("title", before.title != after.title);
+    check("description", before.description != after.description);
+    // The step strategy doesn't implement PartialEq, but its JSON form is stable
+    check(
+        "step_strategy",
+        serde_json::to_value(&before.step_strategy).ok()
+            != serde_json::to_value(&after.step_strategy).ok(),
+    );
+    check("position", before_position != after_position);
+    fields
+}
+
+impl APIFormRevisionDiff {
+    pub fn between(from: &APIFormRevision, to: &APIFormRevision) -> Self {
+        let mut groups = Vec::new();
+        for (before_position, before) in from.groups.iter().enumerate() {
+            match to
+                .groups
+                .iter()
+                .enumerate()
+                .find(|(_, g)| g.id == before.id)
+            {
+                None => groups.push(APIFormRevisionGroupChange {
+                    group_id: before.id,
+                    kind: APIFormRevisionChangeKind::Removed,
+                    fields: Vec::new(),
+                    before: Some(before.clone()),
+                    after: None,
+                }),
+                Some((after_position, after)) => {
+                    let fields =
+                        changed_group_fields(before, before_position, after, after_position);
+                    if !fields.is_empty() {
+                        groups.push(APIFormRevisionGroupChange {
+                            group_id: before.id,
+                            kind: APIFormRevisionChangeKind::Modified,
+                            fields,
+                            before: Some(before.clone()),
+                            after: Some(after.clone()),
+                        });
+                    }
+                }
+            }
+        }
+        for after in &to.groups {
+            if !from.groups.iter().any(|g| g.id == after.id) {
+                groups.push(APIFormRevisionGroupChange {
+                    group_id: after.id,
+                    kind: APIFormRevisionChangeKind::Added,
+                    fields: Vec::new(),
+                    before: None,
+                    after: Some(after.clone()),
+                });
+            }
+        }
+
+        let mut questions = Vec::new();
+        for (before_position, before) in from.questions.iter().enumerate() {
+            match to
+                .questions
+                .iter()
+                .enumerate()
+                .find(|(_, q)| q.id == before.id)
+            {
+                None => questions.push(APIFormRevisionQuestionChange {
+                    question_id: before.id,
+                    kind: APIFormRevisionChangeKind::Removed,
+                    fields: Vec::new(),
+                    before: Some(before.clone()),
+                    after: None,
+                }),
+                Some((after_position, after)) => {
+                    let fields =
+                        changed_question_fields(before, before_position, after, after_position);
+                    if !fields.is_empty() {
+                        questions.push(APIFormRevisionQuestionChange {
+                            question_id: before.id,
+                            kind: APIFormRevisionChangeKind::Modified,
+                            fields,
+                            before: Some(before.clone()),
+                            after: Some(after.clone()),
+                        });
+                    }
+                }
+            }
+        }
+        for after in &to.questions {
+            if !from.questions.iter().any(|q| q.id == after.id) {
+                questions.push(APIFormRevisionQuestionChange {
+                    question_id: after.id,
+                    kind: APIFormRevisionChangeKind::Added,
+                    fields: Vec::new(),
+                    before: None,
+                    after: Some(after.clone()),
+                });
+            }
+        }
+
+        Self {
+            from: from.id,
+            to: to.id,
+            groups,
+            questions,
+        }
+    }
+}
+
+/// The questions and groups needed to show submissions made against any of the given revisions
+#[derive(Clone, Serialize, Deserialize)]
+pub struct MergedFormSchema {
+    pub groups: Vec<APIQuestionGroup>,
+    pub questions: Vec<APIQuestion>,
+}
+
+/// Starts with the form's current questions and groups, and adds any that have since been removed
+/// from the latest revision they appeared in. This way answers to removed questions still show up
+/// in tables and exports, under the question as it was when last answered.
+pub fn merge_form_revisions(
+    groups: Vec<APIQuestionGroup>,
+    questions: Vec<APIQuestion>,
+    revisions: &[APIFormRevision],
+) -> MergedFormSchema {
+    let mut revisions: Vec<&APIFormRevision> = revisions.iter().collect();
+    revisions.sort_by_key(|r| std::cmp::Reverse(r.number));
+
+    let mut seen_groups: HashSet<_> = groups.iter().map(|g| g.id).collect();
+    let mut seen_questions: HashSet<_> = questions.iter().map(|q| q.id).collect();
+    let mut merged = MergedFormSchema { groups, questions };
+
+    for revision in revisions {
+        for group in &revision.groups {
+            if seen_groups.insert(group.id) {
+                merged.groups.push(group.clone());
+            }
+        }
+        for question in &revision.questions {
+            if seen_questions.insert(question.id) {
+                merged.questions.push(question.clone());
+            }
+        }
+    }
+
+    merged
+}
+
+#[cfg(feature = "frontend-js")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn merge_form_revisions_js(
+    groups: wasm_bindgen::JsValue,
+    questions: wasm_bindgen::JsValue,
+    revisions: wasm_bindgen::JsValue,
+) -> Result<wasm_bindgen::JsValue, wasm_bindgen::JsValue> {
+    use crate::wasm_serializer::get_wasm_serializer;
+
+    let groups = serde_wasm_bindgen::from_value(groups)?;
+    let questions = serde_wasm_bindgen::from_value(questions)?;
+    let revisions = serde_wasm_bindgen::from_value::<Vec<APIFormRevision>>(revisions)?;
+    let merged = merge_form_revisions(groups, questions, &revisions);
+    Ok(merged.serialize(&get_wasm_serializer())?)
+}
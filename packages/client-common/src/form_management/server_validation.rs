@@ -0,0 +1,421 @@
+use anyhow::anyhow;
+use chrono::{DateTime, Local, NaiveTime};
+use palform_tsid::{
+    resources::{IDFormRevision, IDQuestion, IDQuestionGroup, IDSubmissionFile},
+    tsid::PalformDatabaseID,
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::{
+    question_types::{
+        APIQuestion, APIQuestionConfiguration, APIQuestionServerValidation,
+        APIQuestionTextValidator,
+    },
+    submission::{QuestionSubmission, QuestionSubmissionData},
+    validation::{validate_answer, ValidationError},
+};
+
+/// The body of a form fill request. Older clients send the armored submission on its own, which
+/// is treated the same as an empty `server_validation` list and no `revision_id`, `file_ids`,
+/// `visited_group_ids` or `signing_key`.
+#[derive(Serialize, Deserialize)]
+pub struct FormFillRequest {
+    pub encrypted_submission: String,
+    #[serde(default)]
+    pub server_validation: Vec<ServerValidationAnswer>,
+    /// The revision of the form that was shown to the respondent
+    #[serde(default)]
+    pub revision_id: Option<PalformDatabaseID<IDFormRevision>>,
+    /// Files uploaded for this submission, so they aren't cleaned up as abandoned uploads. Left
+    /// out by clients from before this was added, whose uploads can't be told apart from abandoned
+    /// ones.
+    #[serde(default)]
+    pub file_ids: Option<Vec<PalformDatabaseID<IDSubmissionFile>>>,
+    /// The groups the respondent reached, as opposed to those skipped by branching. Required
+    /// questions are only enforced in these groups, and not at all if this is left out.
+    #[serde(default)]
+    pub visited_group_ids: Option<Vec<PalformDatabaseID<IDQuestionGroup>>>,
+    /// The public key the submission was signed with, if the form asks for signed submissions
+    #[serde(default)]
+    pub signing_key: Option<String>,
+}
+
+impl FormFillRequest {
+    pub fn parse(body: String) -> Result<Self, serde_json::Error> {
+        if body.trim_start().starts_with('{') {
+            serde_json::from_str(&body)
+        } else {
+            Ok(Self {
+                encrypted_submission: body,
+                server_validation: Vec::new(),
+                revision_id: None,
+                file_ids: None,
+                visited_group_ids: None,
+                signing_key: None,
+            })
+        }
+    }
+}
+
+/// A copy of one answer, in the form chosen by the question's `server_validation` setting. Empty
+/// answers are never sent.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ServerValidationAnswer {
+    pub question_id: PalformDatabaseID<IDQuestion>,
+    pub value: ServerValidationValue,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub enum ServerValidationValue {
+    Plaintext {
+        data: QuestionSubmissionData,
+    },
+    Hashed {
+        /// Hex-encoded SHA256 of the question ID and the answer
+        sha256: String,
+    },
+    Bucketed {
+        /// The start of the bucket the answer falls in, or `None` if it isn't a number
+        start: Option<f64>,
+    },
+}
+
+impl APIQuestionServerValidation {
+    /// Whether this kind of server validation makes sense for a question
+    pub fn supports(&self, configuration: &APIQuestionConfiguration) -> bool {
+        if !configuration.requires_submission() {
+            return false;
+        }
+
+        match self {
+            APIQuestionServerValidation::Plaintext | APIQuestionServerValidation::Hashed => true,
+            APIQuestionServerValidation::Bucketed { bucket_size } => {
+                bucket_size.is_finite()
+                    && *bucket_size > 0.0
+                    && matches!(
+                        configuration,
+                        APIQuestionConfiguration::Scale { .. }
+                            | APIQuestionConfiguration::DateTime { .. }
+                            | APIQuestionConfiguration::Text {
+                                validator: Some(
+                                    APIQuestionTextValidator::Integer
+                                        | APIQuestionTextValidator::Float
+                                ),
+                                ..
+                            }
+                    )
+            }
+        }
+    }
+}
+
+fn hash_answer(
+    question_id: PalformDatabaseID<IDQuestion>,
+    data: &QuestionSubmissionData,
+) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(question_id.to_string());
+    hasher.update(":");
+    hasher.update(data.to_string());
+    faster_hex::hex_string(&hasher.finalize())
+}
+
+/// Date answers are compared by day and time answers by time of day, matching how they're
+/// validated in the browser. Everything is converted to UTC first, as `Local` is the browser's
+/// time zone when bucketing an answer but the server's when checking it against the bounds.
+fn date_time_seconds(value: &DateTime<Local>, collect_date: bool, collect_time: bool) -> f64 {
+    let value = value.naive_utc();
+    match (collect_date, collect_time) {
+        (true, false) => value.date().and_time(NaiveTime::MIN).and_utc().timestamp() as f64,
+        (false, true) => (value.time() - NaiveTime::MIN).num_seconds() as f64,
+        _ => value.and_utc().timestamp() as f64,
+    }
+}
+
+fn numeric_answer(
+    configuration: &APIQuestionConfiguration,
+    data: &QuestionSubmissionData,
+) -> Option<f64> {
+    match (configuration, data) {
+        (_, QuestionSubmissionData::Scale { value }) => value.map(f64::from),
+        (_, QuestionSubmissionData::Text { value }) => value.trim().parse::<f64>().ok(),
+        (
+            APIQuestionConfiguration::DateTime {
+                collect_date,
+                collect_time,
+                ..
+            },
+            QuestionSubmissionData::DateTime { value },
+        ) => value
+            .as_ref()
+            .map(|v| date_time_seconds(v, *collect_date, *collect_time)),
+        _ => None,
+    }
+}
+
+fn numeric_bounds(configuration: &APIQuestionConfiguration) -> (Option<f64>, Option<f64>) {
+    match configuration {
+        APIQuestionConfiguration::Scale { min, max, .. } => (Some(*min as f64), Some(*max as f64)),
+        APIQuestionConfiguration::DateTime {
+            collect_date,
+            collect_time,
+            min,
+            max,
+        } => (
+            min.as_ref()
+                .map(|m| date_time_seconds(m, *collect_date, *collect_time)),
+            max.as_ref()
+                .map(|m| date_time_seconds(m, *collect_date, *collect_time)),
+        ),
+        _ => (None, None),
+    }
+}
+
+/// Builds the copies of answers to send to the server, for questions that have opted in
+pub fn server_validation_answers(
+    questions: &[APIQuestion],
+    submissions: &[QuestionSubmission],
+) -> Vec<ServerValidationAnswer> {
+    questions
+        .iter()
+        .filter_map(|question| {
+            let mode = question.server_validation.as_ref()?;
+            let data = &submissions
+                .iter()
+                .find(|s| s.question_id == question.id)?
+                .data;
+            if data.is_empty() {
+                return None;
+            }
+
+            let value = match mode {
+                APIQuestionServerValidation::Plaintext => {
+                    ServerValidationValue::Plaintext { data: data.clone() }
+                }
+                APIQuestionServerValidation::Hashed => ServerValidationValue::Hashed {
+                    sha256: hash_answer(question.id, data),
+                },
+                APIQuestionServerValidation::Bucketed { bucket_size } => {
+                    ServerValidationValue::Bucketed {
+                        start: numeric_answer(&question.configuration, data)
+                            .map(|v| (v / bucket_size).floor() * bucket_size),
+                    }
+                }
+            };
+
+            Some(ServerValidationAnswer {
+                question_id: question.id,
+                value,
+            })
+        })
+        .collect()
+}
+
+/// Checks the answer copies sent with a submission. Returns an error if the copies don't match
+/// the form's settings at all, and a list of validation errors if any of the answers are invalid.
+///
+/// Required questions are only enforced in `visited_group_ids`, as the rest were skipped by
+/// branching. If the client didn't say which groups were visited, missing answers are allowed.
+pub fn validate_server_answers(
+    questions: &[APIQuestion],
+    answers: &[ServerValidationAnswer],
+    visited_group_ids: Option<&[PalformDatabaseID<IDQuestionGroup>]>,
+) -> Result<Vec<ValidationError>, anyhow::Error> {
+    for (index, answer) in answers.iter().enumerate() {
+        if answers[..index]
+            .iter()
+            .any(|a| a.question_id == answer.question_id)
+        {
+            return Err(anyhow!(
+                "Question {} was answered twice",
+                answer.question_id
+            ));
+        }
+
+        let mode = questions
+            .iter()
+            .find(|q| q.id == answer.question_id)
+            .and_then(|q| q.server_validation.as_ref())
+            .ok_or(anyhow!(
+                "Question {} doesn't use server validation",
+                answer.question_id
+            ))?;
+
+        let matches_mode = matches!(
+            (mode, &answer.value),
+            (
+                APIQuestionServerValidation::Plaintext,
+                ServerValidationValue::Plaintext { .. }
+            ) | (
+                APIQuestionServerValidation::Hashed,
+                ServerValidationValue::Hashed { .. }
+            ) | (
+                APIQuestionServerValidation::Bucketed { .. },
+                ServerValidationValue::Bucketed { .. }
+            )
+        );
+        if !matches_mode {
+            return Err(anyhow!(
+                "Question {} was sent in the wrong form",
+                answer.question_id
+            ));
+        }
+    }
+
+    let mut errors = Vec::new();
+    for question in questions {
+        let Some(mode) = &question.server_validation else {
+            continue;
+        };
+        if !question.configuration.requires_submission() {
+            continue;
+        }
+        let required = question.required
+            && visited_group_ids.is_some_and(|visited| visited.contains(&question.group_id));
+
+        let mut error = |error: &str| {
+            errors.push(ValidationError {
+                question_id: question.id.to_string(),
+                error: error.to_string(),
+            })
+        };
+
+        let Some(answer) = answers.iter().find(|a| a.question_id == question.id) else {
+            if required {
+                error("This question is required");
+            }
+            continue;
+        };
+
+        match &answer.value {
+            ServerValidationValue::Plaintext { data } => {
+                if data.is_empty() {
+                    if required {
+                        error("This question is required");
+                    }
+                } else if let Some(e) = validate_answer(question, data) {
+                    error(&e);
+                }
+            }
+            ServerValidationValue::Hashed { sha256 } => {
+                if sha256.len() != 64 || !sha256.chars().all(|c| c.is_ascii_hexdigit()) {
+                    return Err(anyhow!("Question {} has an invalid hash", question.id));
+                }
+            }
+            ServerValidationValue::Bucketed { start: None } => error("Value must be a number"),
+            ServerValidationValue::Bucketed { start: Some(start) } => {
+                let APIQuestionServerValidation::Bucketed { bucket_size } = mode else {
+                    unreachable!("Checked above");
+                };
+
+                // The bucket only has to overlap the allowed range, since the exact answer
+                // could be anywhere inside it
+                let (min, max) = numeric_bounds(&question.configuration);
+                if !start.is_finite()
+                    || min.is_some_and(|min| start + bucket_size <= min)
+                    || max.is_some_and(|max| *start > max)
+                {
+                    error("Value is outside the allowed range");
+                }
+            }
+        }
+    }
+
+    Ok(errors)
+}
+
+/// The IDs of all files referenced by file upload answers. Anything that doesn't look like a file
+/// ID is skipped, as the server would reject the request otherwise.
+pub fn uploaded_file_ids(
+    submissions: &[QuestionSubmission],
+) -> Vec<PalformDatabaseID<IDSubmissionFile>> {
+    submissions
+        .iter()
+        .filter_map(|s| match &s.data {
+            QuestionSubmissionData::FileUpload { file_id, .. } => {
+                PalformDatabaseID::from_str(file_id).ok()
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(feature = "frontend-js")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn form_fill_request_js(
+    encrypted_submission: String,
+    questions: wasm_bindgen::JsValue,
+    submissions: wasm_bindgen::JsValue,
+    groups_completed: wasm_bindgen::JsValue,
+    revision_id: wasm_bindgen::JsValue,
+    signing_key: Option<String>,
+) -> Result<String, wasm_bindgen::JsValue> {
+    let questions = serde_wasm_bindgen::from_value::<Vec<APIQuestion>>(questions)?;
+    let submissions = serde_wasm_bindgen::from_value::<Vec<QuestionSubmission>>(submissions)?;
+    let groups_completed = serde_wasm_bindgen::from_value(groups_completed)?;
+    let revision_id = serde_wasm_bindgen::from_value(revision_id)?;
+
+    serde_json::to_string(&FormFillRequest {
+        encrypted_submission,
+        server_validation: server_validation_answers(&questions, &submissions),
+        revision_id,
+        file_ids: Some(uploaded_file_ids(&submissions)),
+        visited_group_ids: Some(groups_completed),
+        signing_key,
+    })
+    .map_err(|e| wasm_bindgen::JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn required_text_question(group_id: PalformDatabaseID<IDQuestionGroup>) -> APIQuestion {
+        APIQuestion {
+            id: PalformDatabaseID::random(),
+            title: "Question".to_string(),
+            internal_name: None,
+            description: None,
+            required: true,
+            configuration: APIQuestionConfiguration::Text {
+                is_long: false,
+                validator: None,
+            },
+            group_id,
+            server_validation: Some(APIQuestionServerValidation::Plaintext),
+        }
+    }
+
+    #[test]
+    fn required_questions_in_skipped_groups_can_be_missing() {
+        let first_group = PalformDatabaseID::random();
+        let skipped_group = PalformDatabaseID::random();
+        let answered = required_text_question(first_group);
+        let skipped = required_text_question(skipped_group);
+        let questions = vec![answered.clone(), skipped.clone()];
+        let answers = vec![ServerValidationAnswer {
+            question_id: answered.id,
+            value: ServerValidationValue::Plaintext {
+                data: QuestionSubmissionData::Text {
+                    value: "Answer".to_string(),
+                },
+            },
+        }];
+
+        // The respondent branched past the second group
+        let errors = validate_server_answers(&questions, &answers, Some(&[first_group])).unwrap();
+        assert!(errors.is_empty());
+
+        // ...but if they reached it, its required question must be answered
+        let errors =
+            validate_server_answers(&questions, &answers, Some(&[first_group, skipped_group]))
+                .unwrap();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].question_id, skipped.id.to_string());
+
+        // Clients that don't say which groups they reached aren't held to required questions
+        let errors = validate_server_answers(&questions, &[], None).unwrap();
+        assert!(errors.is_empty());
+    }
+}
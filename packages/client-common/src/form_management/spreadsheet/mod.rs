@@ -0,0 +1,254 @@
+use anyhow::anyhow;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Utc};
+
+use super::{
+    export::{ExportSubmission, ExportSubmissionsConfig},
+    question_group::APIQuestionGroup,
+    question_types::{APIQuestion, APIQuestionConfiguration, APIQuestionTextValidator},
+    submission::QuestionSubmissionData,
+};
+
+pub mod ods;
+pub mod xlsx;
+
+pub enum SpreadsheetCell {
+    Empty,
+    /// Bold text, used for column headers
+    Heading(String),
+    Text(String),
+    Number(f64),
+    Bool(bool),
+    Date(NaiveDate),
+    Time(NaiveTime),
+    DateTime(NaiveDateTime),
+}
+
+pub struct SpreadsheetSheet {
+    pub name: String,
+    pub rows: Vec<Vec<SpreadsheetCell>>,
+}
+
+/// Format-independent representation of an export, written out by the `xlsx` and `ods` modules.
+pub struct SpreadsheetWorkbook {
+    pub sheets: Vec<SpreadsheetSheet>,
+}
+
+enum ColumnPart {
+    Value,
+    ChoiceOption(String),
+    MatrixRow(String),
+    Latitude,
+    Longitude,
+}
+
+struct SpreadsheetColumn<'a> {
+    header: String,
+    question: &'a APIQuestion,
+    part: ColumnPart,
+}
+
+fn columns_for_question(question: &APIQuestion, key: String) -> Vec<SpreadsheetColumn<'_>> {
+    let column = |header: String, part: ColumnPart| SpreadsheetColumn {
+        header,
+        question,
+        part,
+    };
+
+    match &question.configuration {
+        APIQuestionConfiguration::Info { .. } => Vec::new(),
+        APIQuestionConfiguration::Choice {
+            options,
+            multi: true,
+        } => options
+            .iter()
+            .map(|o| {
+                column(
+                    format!("{} [{}]", key, o),
+                    ColumnPart::ChoiceOption(o.clone()),
+                )
+            })
+            .collect(),
+        APIQuestionConfiguration::ChoiceMatrix { rows, .. } => rows
+            .iter()
+            .map(|r| column(format!("{} [{}]", key, r), ColumnPart::MatrixRow(r.clone())))
+            .collect(),
+        APIQuestionConfiguration::Address { .. } => vec![
+            column(format!("{} (latitude)", key), ColumnPart::Latitude),
+            column(format!("{} (longitude)", key), ColumnPart::Longitude),
+            column(key, ColumnPart::Value),
+        ],
+        _ => vec![column(key, ColumnPart::Value)],
+    }
+}
+
+fn text_or_empty(value: String) -> SpreadsheetCell {
+    if value.is_empty() {
+        SpreadsheetCell::Empty
+    } else {
+        SpreadsheetCell::Text(value)
+    }
+}
+
+fn cell_for_column(column: &SpreadsheetColumn, data: &QuestionSubmissionData) -> SpreadsheetCell {
+    match (&column.question.configuration, data, &column.part) {
+        (
+            APIQuestionConfiguration::Text {
+                validator: Some(APIQuestionTextValidator::Integer | APIQuestionTextValidator::Float),
+                ..
+            },
+            QuestionSubmissionData::Text { value },
+            _,
+        ) => match value.trim().parse::<f64>() {
+            Ok(number) => SpreadsheetCell::Number(number),
+            Err(_) => text_or_empty(value.clone()),
+        },
+        (_, QuestionSubmissionData::Choice { option }, ColumnPart::ChoiceOption(o)) => {
+            SpreadsheetCell::Bool(option.contains(o))
+        }
+        (
+            APIQuestionConfiguration::ChoiceMatrix { columns, .. },
+            QuestionSubmissionData::ChoiceMatrix { options },
+            ColumnPart::MatrixRow(row),
+        ) => {
+            let selected = options.get(row).cloned().unwrap_or_default();
+            // Follow the question's column order rather than the order they were clicked in
+            let selected: Vec<String> = columns
+                .iter()
+                .filter(|c| selected.contains(c))
+                .cloned()
+                .collect();
+            text_or_empty(selected.join(", "))
+        }
+        (_, QuestionSubmissionData::Scale { value }, _) => value
+            .map(|v| SpreadsheetCell::Number(v.into()))
+            .unwrap_or(SpreadsheetCell::Empty),
+        (_, QuestionSubmissionData::Address { point, .. }, ColumnPart::Latitude) => {
+            if point.is_empty() {
+                SpreadsheetCell::Empty
+            } else {
+                SpreadsheetCell::Number(point.get_lat())
+            }
+        }
+        (_, QuestionSubmissionData::Address { point, .. }, ColumnPart::Longitude) => {
+            if point.is_empty() {
+                SpreadsheetCell::Empty
+            } else {
+                SpreadsheetCell::Number(point.get_lng())
+            }
+        }
+        (_, QuestionSubmissionData::Address { address, .. }, _) => {
+            let lines: Vec<String> = address.to_string().lines().map(String::from).collect();
+            text_or_empty(lines.join(", "))
+        }
+        (
+            APIQuestionConfiguration::DateTime {
+                collect_date,
+                collect_time,
+                ..
+            },
+            QuestionSubmissionData::DateTime { value: Some(value) },
+            _,
+        ) => {
+            let value = value.naive_local();
+            match (collect_date, collect_time) {
+                (true, false) => SpreadsheetCell::Date(value.date()),
+                (false, true) => SpreadsheetCell::Time(value.time()),
+                _ => SpreadsheetCell::DateTime(value),
+            }
+        }
+        (_, data, _) => text_or_empty(data.to_string()),
+    }
+}
+
+impl SpreadsheetWorkbook {
+    pub fn from_submissions(
+        groups: &[APIQuestionGroup],
+        submissions: &[ExportSubmission],
+        questions: &[APIQuestion],
+        config: &ExportSubmissionsConfig,
+    ) -> Result<Self, anyhow::Error> {
+        let mut columns = Vec::new();
+        for group in groups {
+            for question in questions.iter().filter(|q| q.group_id == group.id) {
+                let key = question
+                    .to_export_key(groups, config.use_question_ids, false, config.use_group_ids)
+                    .ok_or(anyhow!("Group ID of question failed to resolve"))?;
+                columns.extend(columns_for_question(question, key));
+            }
+        }
+
+        let mut responses = vec![columns
+            .iter()
+            .map(|c| SpreadsheetCell::Heading(c.header.clone()))
+            .collect()];
+        for submission in submissions {
+            responses.push(
+                columns
+                    .iter()
+                    .map(|column| {
+                        submission
+                            .submission
+                            .questions
+                            .iter()
+                            .find(|q| q.question_id == column.question.id)
+                            .map(|q| cell_for_column(column, &q.data))
+                            .unwrap_or(SpreadsheetCell::Empty)
+                    })
+                    .collect(),
+            );
+        }
+
+        let mut form = vec![
+            vec![
+                SpreadsheetCell::Heading("Exported at".to_string()),
+                SpreadsheetCell::DateTime(Utc::now().naive_utc()),
+            ],
+            vec![
+                SpreadsheetCell::Heading("Responses".to_string()),
+                SpreadsheetCell::Number(submissions.len() as f64),
+            ],
+            Vec::new(),
+            [
+                "Section ID",
+                "Section",
+                "Question ID",
+                "Question",
+                "Type",
+                "Required",
+            ]
+            .into_iter()
+            .map(|h| SpreadsheetCell::Heading(h.to_string()))
+            .collect(),
+        ];
+        for (group_index, group) in groups.iter().enumerate() {
+            for question in questions.iter().filter(|q| q.group_id == group.id) {
+                form.push(vec![
+                    SpreadsheetCell::Text(group.id.to_string()),
+                    SpreadsheetCell::Text(
+                        group
+                            .title
+                            .clone()
+                            .unwrap_or(format!("Section {}", group_index + 1)),
+                    ),
+                    SpreadsheetCell::Text(question.id.to_string()),
+                    SpreadsheetCell::Text(question.title.clone()),
+                    SpreadsheetCell::Text(question.configuration.type_name().to_string()),
+                    SpreadsheetCell::Bool(question.required),
+                ]);
+            }
+        }
+
+        Ok(Self {
+            sheets: vec![
+                SpreadsheetSheet {
+                    name: "Responses".to_string(),
+                    rows: responses,
+                },
+                SpreadsheetSheet {
+                    name: "Form".to_string(),
+                    rows: form,
+                },
+            ],
+        })
+    }
+}
@@ -0,0 +1,130 @@
+use std::io::{Cursor, Write};
+
+use zip::{result::ZipError, write::SimpleFileOptions, CompressionMethod, ZipWriter};
+
+use super::{SpreadsheetCell, SpreadsheetWorkbook};
+
+const MIME_TYPE: &str = "application/vnd.oasis.opendocument.spreadsheet";
+
+const MANIFEST: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<manifest:manifest xmlns:manifest="urn:oasis:names:tc:opendocument:xmlns:manifest:1.0" manifest:version="1.3">
+<manifest:file-entry manifest:full-path="/" manifest:version="1.3" manifest:media-type="application/vnd.oasis.opendocument.spreadsheet"/>
+<manifest:file-entry manifest:full-path="content.xml" manifest:media-type="text/xml"/>
+</manifest:manifest>"#;
+
+const CONTENT_HEADER: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<office:document-content xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0" xmlns:style="urn:oasis:names:tc:opendocument:xmlns:style:1.0" xmlns:text="urn:oasis:names:tc:opendocument:xmlns:text:1.0" xmlns:table="urn:oasis:names:tc:opendocument:xmlns:table:1.0" xmlns:number="urn:oasis:names:tc:opendocument:xmlns:datastyle:1.0" xmlns:fo="urn:oasis:names:tc:opendocument:xmlns:xsl-fo-compatible:1.0" office:version="1.3">
+<office:automatic-styles>
+<number:date-style style:name="N1"><number:year number:style="long"/><number:text>-</number:text><number:month number:style="long"/><number:text>-</number:text><number:day number:style="long"/></number:date-style>
+<number:time-style style:name="N2"><number:hours number:style="long"/><number:text>:</number:text><number:minutes number:style="long"/><number:text>:</number:text><number:seconds number:style="long"/></number:time-style>
+<number:date-style style:name="N3"><number:year number:style="long"/><number:text>-</number:text><number:month number:style="long"/><number:text>-</number:text><number:day number:style="long"/><number:text> </number:text><number:hours number:style="long"/><number:text>:</number:text><number:minutes number:style="long"/><number:text>:</number:text><number:seconds number:style="long"/></number:date-style>
+<style:style style:name="heading" style:family="table-cell"><style:text-properties fo:font-weight="bold"/></style:style>
+<style:style style:name="date" style:family="table-cell" style:data-style-name="N1"/>
+<style:style style:name="time" style:family="table-cell" style:data-style-name="N2"/>
+<style:style style:name="date_time" style:family="table-cell" style:data-style-name="N3"/>
+</office:automatic-styles>
+<office:body>
+<office:spreadsheet>
+"#;
+
+const CONTENT_FOOTER: &str = "</office:spreadsheet>\n</office:body>\n</office:document-content>";
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn paragraphs(value: &str) -> String {
+    value
+        .lines()
+        .map(|line| format!("<text:p>{}</text:p>", escape_xml(line)))
+        .collect()
+}
+
+fn write_cell(content: &mut String, cell: &SpreadsheetCell) {
+    let cell = match cell {
+        SpreadsheetCell::Empty => "<table:table-cell/>".to_string(),
+        SpreadsheetCell::Heading(value) => format!(
+            r#"<table:table-cell table:style-name="heading" office:value-type="string">{}</table:table-cell>"#,
+            paragraphs(value)
+        ),
+        SpreadsheetCell::Text(value) => format!(
+            r#"<table:table-cell office:value-type="string">{}</table:table-cell>"#,
+            paragraphs(value)
+        ),
+        SpreadsheetCell::Number(value) => format!(
+            r#"<table:table-cell office:value-type="float" office:value="{0}"><text:p>{0}</text:p></table:table-cell>"#,
+            value
+        ),
+        SpreadsheetCell::Bool(value) => format!(
+            r#"<table:table-cell office:value-type="boolean" office:boolean-value="{}"><text:p>{}</text:p></table:table-cell>"#,
+            value,
+            if *value { "TRUE" } else { "FALSE" }
+        ),
+        SpreadsheetCell::Date(value) => format!(
+            r#"<table:table-cell table:style-name="date" office:value-type="date" office:date-value="{0}"><text:p>{0}</text:p></table:table-cell>"#,
+            value.format("%Y-%m-%d")
+        ),
+        SpreadsheetCell::Time(value) => format!(
+            r#"<table:table-cell table:style-name="time" office:value-type="time" office:time-value="{}"><text:p>{}</text:p></table:table-cell>"#,
+            value.format("PT%HH%MM%SS"),
+            value.format("%H:%M:%S")
+        ),
+        SpreadsheetCell::DateTime(value) => format!(
+            r#"<table:table-cell table:style-name="date_time" office:value-type="date" office:date-value="{}"><text:p>{}</text:p></table:table-cell>"#,
+            value.format("%Y-%m-%dT%H:%M:%S"),
+            value.format("%Y-%m-%d %H:%M:%S")
+        ),
+    };
+    content.push_str(&cell);
+}
+
+/// Writes an OpenDocument spreadsheet. Only `content.xml` and the manifest are included, which
+/// is all that's needed for LibreOffice, Excel and Google Sheets to open it.
+pub fn write_ods(spreadsheet: &SpreadsheetWorkbook) -> Result<Vec<u8>, ZipError> {
+    let mut content = CONTENT_HEADER.to_string();
+    for sheet in &spreadsheet.sheets {
+        let column_count = sheet.rows.iter().map(|r| r.len()).max().unwrap_or(0).max(1);
+        content.push_str(&format!(
+            r#"<table:table table:name="{}"><table:table-column table:number-columns-repeated="{}"/>"#,
+            escape_xml(&sheet.name),
+            column_count
+        ));
+
+        for row in &sheet.rows {
+            content.push_str("<table:table-row>");
+            if row.is_empty() {
+                write_cell(&mut content, &SpreadsheetCell::Empty);
+            }
+            for cell in row {
+                write_cell(&mut content, cell);
+            }
+            content.push_str("</table:table-row>\n");
+        }
+
+        content.push_str("</table:table>\n");
+    }
+    content.push_str(CONTENT_FOOTER);
+
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+
+    // The mimetype must be the first file and can't be compressed, so it can be read at a fixed
+    // offset to identify the file
+    zip.start_file(
+        "mimetype",
+        SimpleFileOptions::default().compression_method(CompressionMethod::Stored),
+    )?;
+    zip.write_all(MIME_TYPE.as_bytes())?;
+
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    zip.start_file("META-INF/manifest.xml", options)?;
+    zip.write_all(MANIFEST.as_bytes())?;
+    zip.start_file("content.xml", options)?;
+    zip.write_all(content.as_bytes())?;
+
+    Ok(zip.finish()?.into_inner())
+}
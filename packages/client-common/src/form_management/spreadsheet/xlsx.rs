@@ -0,0 +1,50 @@
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+
+use super::{SpreadsheetCell, SpreadsheetWorkbook};
+
+pub fn write_xlsx(spreadsheet: &SpreadsheetWorkbook) -> Result<Vec<u8>, XlsxError> {
+    let heading = Format::new().set_bold();
+    let date = Format::new().set_num_format("yyyy-mm-dd");
+    let time = Format::new().set_num_format("hh:mm:ss");
+    let date_time = Format::new().set_num_format("yyyy-mm-dd hh:mm:ss");
+
+    let mut workbook = Workbook::new();
+    for sheet in &spreadsheet.sheets {
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_name(&sheet.name)?;
+
+        for (row_index, row) in sheet.rows.iter().enumerate() {
+            let row_index = row_index as u32;
+            for (col_index, cell) in row.iter().enumerate() {
+                let col_index = col_index as u16;
+                match cell {
+                    SpreadsheetCell::Empty => continue,
+                    SpreadsheetCell::Heading(value) => {
+                        worksheet.write_string_with_format(row_index, col_index, value, &heading)?
+                    }
+                    SpreadsheetCell::Text(value) => {
+                        worksheet.write_string(row_index, col_index, value)?
+                    }
+                    SpreadsheetCell::Number(value) => {
+                        worksheet.write_number(row_index, col_index, *value)?
+                    }
+                    SpreadsheetCell::Bool(value) => {
+                        worksheet.write_boolean(row_index, col_index, *value)?
+                    }
+                    SpreadsheetCell::Date(value) => {
+                        worksheet.write_datetime_with_format(row_index, col_index, value, &date)?
+                    }
+                    SpreadsheetCell::Time(value) => {
+                        worksheet.write_datetime_with_format(row_index, col_index, value, &time)?
+                    }
+                    SpreadsheetCell::DateTime(value) => worksheet
+                        .write_datetime_with_format(row_index, col_index, value, &date_time)?,
+                };
+            }
+        }
+
+        worksheet.autofit();
+    }
+
+    workbook.save_to_buffer()
+}
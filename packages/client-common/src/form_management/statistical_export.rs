@@ -0,0 +1,825 @@
+use std::io::{Cursor, Write};
+
+use anyhow::anyhow;
+use zip::{write::SimpleFileOptions, CompressionMethod, ZipWriter};
+
+use super::{
+    export::{ExportSubmission, ExportSubmissionsConfig},
+    question_group::APIQuestionGroup,
+    question_types::{APIQuestion, APIQuestionConfiguration, APIQuestionTextValidator},
+    submission::QuestionSubmissionData,
+};
+
+/// Used when a respondent didn't answer the question
+pub const MISSING_NOT_ANSWERED: i64 = -99;
+/// Used when an answer can't be coded, e.g. the option it refers to has since been removed from
+/// the form, or a number question was answered with something that isn't a number
+pub const MISSING_INVALID: i64 = -98;
+
+const MISSING_LABELS: [(i64, &str); 2] = [
+    (MISSING_NOT_ANSWERED, "Not answered"),
+    (MISSING_INVALID, "Answer could not be coded"),
+];
+
+const DATA_PATH: &str = "data.csv";
+const CODEBOOK_PATH: &str = "codebook.csv";
+const SPSS_SYNTAX_PATH: &str = "import.sps";
+const R_SCRIPT_PATH: &str = "import.R";
+
+#[derive(Clone, Copy)]
+enum VariableType {
+    Numeric { width: u8, decimals: u8 },
+    String,
+    Date,
+    Time,
+    DateTime,
+}
+
+enum Measure {
+    Nominal,
+    Ordinal,
+    Scale,
+}
+
+impl Measure {
+    fn name(&self) -> &'static str {
+        match self {
+            Measure::Nominal => "nominal",
+            Measure::Ordinal => "ordinal",
+            Measure::Scale => "scale",
+        }
+    }
+}
+
+enum VariablePart {
+    SubmissionId,
+    CreatedAt,
+    FillToken,
+    Value,
+    ChoiceCode,
+    ChoiceOption(String),
+    MatrixRowCode(String),
+    MatrixCell(String, String),
+    Latitude,
+    Longitude,
+}
+
+struct Variable<'a> {
+    name: String,
+    label: String,
+    question: Option<&'a APIQuestion>,
+    part: VariablePart,
+    variable_type: VariableType,
+    measure: Measure,
+    value_labels: Vec<(i64, String)>,
+}
+
+impl Variable<'_> {
+    fn is_numeric(&self) -> bool {
+        matches!(self.variable_type, VariableType::Numeric { .. })
+    }
+
+    /// Numeric answers to questions use the missing-value codes. Metadata is never missing.
+    fn has_missing_codes(&self) -> bool {
+        self.question.is_some() && self.is_numeric()
+    }
+}
+
+struct MultipleResponseSet {
+    name: String,
+    label: String,
+    variables: Vec<String>,
+}
+
+const CODE: VariableType = VariableType::Numeric {
+    width: 8,
+    decimals: 0,
+};
+const DICHOTOMY_LABELS: [(i64, &str); 2] = [(0, "Not selected"), (1, "Selected")];
+
+fn coded(options: &[String]) -> Vec<(i64, String)> {
+    options
+        .iter()
+        .enumerate()
+        .map(|(i, o)| (i as i64 + 1, o.clone()))
+        .collect()
+}
+
+fn dichotomy_labels() -> Vec<(i64, String)> {
+    DICHOTOMY_LABELS
+        .iter()
+        .map(|(code, label)| (*code, label.to_string()))
+        .collect()
+}
+
+fn variables_for_question<'a>(
+    question: &'a APIQuestion,
+    name: String,
+    mr_sets: &mut Vec<MultipleResponseSet>,
+) -> Vec<Variable<'a>> {
+    let variable = |name: String,
+                    label: String,
+                    part: VariablePart,
+                    variable_type: VariableType,
+                    measure: Measure,
+                    value_labels: Vec<(i64, String)>| Variable {
+        name,
+        label,
+        question: Some(question),
+        part,
+        variable_type,
+        measure,
+        value_labels,
+    };
+    let title = question.title.clone();
+
+    match &question.configuration {
+        APIQuestionConfiguration::Info { .. } => Vec::new(),
+        APIQuestionConfiguration::Choice {
+            options,
+            multi: false,
+        } => vec![variable(
+            name,
+            title,
+            VariablePart::ChoiceCode,
+            CODE,
+            Measure::Nominal,
+            coded(options),
+        )],
+        APIQuestionConfiguration::Choice {
+            options,
+            multi: true,
+        } => {
+            let variables: Vec<Variable> = options
+                .iter()
+                .enumerate()
+                .map(|(i, o)| {
+                    variable(
+                        format!("{}_{}", name, i + 1),
+                        format!("{}: {}", title, o),
+                        VariablePart::ChoiceOption(o.clone()),
+                        CODE,
+                        Measure::Nominal,
+                        dichotomy_labels(),
+                    )
+                })
+                .collect();
+            mr_sets.push(MultipleResponseSet {
+                name: name.clone(),
+                label: title.clone(),
+                variables: variables.iter().map(|v| v.name.clone()).collect(),
+            });
+            variables
+        }
+        APIQuestionConfiguration::ChoiceMatrix {
+            columns,
+            rows,
+            multi_cols: false,
+        } => rows
+            .iter()
+            .enumerate()
+            .map(|(i, r)| {
+                variable(
+                    format!("{}_r{}", name, i + 1),
+                    format!("{}: {}", title, r),
+                    VariablePart::MatrixRowCode(r.clone()),
+                    CODE,
+                    Measure::Nominal,
+                    coded(columns),
+                )
+            })
+            .collect(),
+        APIQuestionConfiguration::ChoiceMatrix {
+            columns,
+            rows,
+            multi_cols: true,
+        } => {
+            let mut variables = Vec::new();
+            for (row_index, r) in rows.iter().enumerate() {
+                let row_name = format!("{}_r{}", name, row_index + 1);
+                let row_variables: Vec<Variable> = columns
+                    .iter()
+                    .enumerate()
+                    .map(|(col_index, c)| {
+                        variable(
+                            format!("{}_c{}", row_name, col_index + 1),
+                            format!("{}: {}: {}", title, r, c),
+                            VariablePart::MatrixCell(r.clone(), c.clone()),
+                            CODE,
+                            Measure::Nominal,
+                            dichotomy_labels(),
+                        )
+                    })
+                    .collect();
+                mr_sets.push(MultipleResponseSet {
+                    name: row_name,
+                    label: format!("{}: {}", title, r),
+                    variables: row_variables.iter().map(|v| v.name.clone()).collect(),
+                });
+                variables.extend(row_variables);
+            }
+            variables
+        }
+        APIQuestionConfiguration::Scale {
+            min,
+            min_label,
+            max,
+            max_label,
+            ..
+        } => {
+            let mut value_labels = Vec::new();
+            if let Some(min_label) = min_label {
+                value_labels.push((*min as i64, min_label.clone()));
+            }
+            if let Some(max_label) = max_label {
+                value_labels.push((*max as i64, max_label.clone()));
+            }
+            vec![variable(
+                name,
+                title,
+                VariablePart::Value,
+                CODE,
+                Measure::Ordinal,
+                value_labels,
+            )]
+        }
+        APIQuestionConfiguration::Text {
+            validator: Some(APIQuestionTextValidator::Integer),
+            ..
+        } => vec![variable(
+            name,
+            title,
+            VariablePart::Value,
+            VariableType::Numeric {
+                width: 12,
+                decimals: 0,
+            },
+            Measure::Scale,
+            Vec::new(),
+        )],
+        APIQuestionConfiguration::Text {
+            validator: Some(APIQuestionTextValidator::Float),
+            ..
+        } => vec![variable(
+            name,
+            title,
+            VariablePart::Value,
+            VariableType::Numeric {
+                width: 16,
+                decimals: 6,
+            },
+            Measure::Scale,
+            Vec::new(),
+        )],
+        APIQuestionConfiguration::DateTime {
+            collect_date,
+            collect_time,
+            ..
+        } => vec![variable(
+            name,
+            title,
+            VariablePart::Value,
+            match (collect_date, collect_time) {
+                (true, false) => VariableType::Date,
+                (false, true) => VariableType::Time,
+                _ => VariableType::DateTime,
+            },
+            Measure::Scale,
+            Vec::new(),
+        )],
+        APIQuestionConfiguration::Address { .. } => {
+            let coordinate = VariableType::Numeric {
+                width: 11,
+                decimals: 6,
+            };
+            vec![
+                variable(
+                    format!("{}_lat", name),
+                    format!("{} (latitude)", title),
+                    VariablePart::Latitude,
+                    coordinate,
+                    Measure::Scale,
+                    Vec::new(),
+                ),
+                variable(
+                    format!("{}_lng", name),
+                    format!("{} (longitude)", title),
+                    VariablePart::Longitude,
+                    coordinate,
+                    Measure::Scale,
+                    Vec::new(),
+                ),
+                variable(
+                    name,
+                    title,
+                    VariablePart::Value,
+                    VariableType::String,
+                    Measure::Nominal,
+                    Vec::new(),
+                ),
+            ]
+        }
+        _ => vec![variable(
+            name,
+            title,
+            VariablePart::Value,
+            VariableType::String,
+            Measure::Nominal,
+            Vec::new(),
+        )],
+    }
+}
+
+fn metadata_variable(name: &str, label: &str, part: VariablePart) -> Variable<'static> {
+    Variable {
+        name: name.to_string(),
+        label: label.to_string(),
+        question: None,
+        variable_type: match part {
+            VariablePart::CreatedAt => VariableType::DateTime,
+            _ => VariableType::String,
+        },
+        measure: match part {
+            VariablePart::CreatedAt => Measure::Scale,
+            _ => Measure::Nominal,
+        },
+        value_labels: Vec::new(),
+        part,
+    }
+}
+
+fn code_of(options: &[String], value: Option<&String>) -> String {
+    match value {
+        None => MISSING_NOT_ANSWERED.to_string(),
+        Some(value) => options
+            .iter()
+            .position(|o| o == value)
+            .map(|i| (i + 1).to_string())
+            .unwrap_or(MISSING_INVALID.to_string()),
+    }
+}
+
+fn cell_for_variable(variable: &Variable, submission: &ExportSubmission) -> String {
+    let Some(question) = variable.question else {
+        return match variable.part {
+            VariablePart::SubmissionId => submission.id.to_string(),
+            VariablePart::CreatedAt => submission
+                .created_at
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string(),
+            VariablePart::FillToken => submission.fill_token_nickname.clone().unwrap_or_default(),
+            _ => String::default(),
+        };
+    };
+
+    let data = submission
+        .submission
+        .questions
+        .iter()
+        .find(|q| q.question_id == question.id)
+        .map(|q| &q.data)
+        .filter(|d| !d.is_empty());
+    let Some(data) = data else {
+        return if variable.is_numeric() {
+            MISSING_NOT_ANSWERED.to_string()
+        } else {
+            String::default()
+        };
+    };
+
+    match (&question.configuration, data, &variable.part) {
+        (
+            APIQuestionConfiguration::Choice { options, .. },
+            QuestionSubmissionData::Choice { option },
+            VariablePart::ChoiceCode,
+        ) => code_of(options, option.first()),
+        (_, QuestionSubmissionData::Choice { option }, VariablePart::ChoiceOption(o)) => {
+            if option.contains(o) { "1" } else { "0" }.to_string()
+        }
+        (
+            APIQuestionConfiguration::ChoiceMatrix { columns, .. },
+            QuestionSubmissionData::ChoiceMatrix { options },
+            VariablePart::MatrixRowCode(row),
+        ) => code_of(columns, options.get(row).and_then(|s| s.first())),
+        (
+            _,
+            QuestionSubmissionData::ChoiceMatrix { options },
+            VariablePart::MatrixCell(row, column),
+        ) => match options.get(row) {
+            Some(selected) if selected.contains(column) => "1".to_string(),
+            Some(_) => "0".to_string(),
+            None => MISSING_NOT_ANSWERED.to_string(),
+        },
+        (_, QuestionSubmissionData::Scale { value }, _) => value
+            .map(|v| v.to_string())
+            .unwrap_or(MISSING_NOT_ANSWERED.to_string()),
+        (_, QuestionSubmissionData::Text { value }, _) if variable.is_numeric() => {
+            match value.trim().parse::<f64>() {
+                Ok(_) => value.trim().to_string(),
+                Err(_) => MISSING_INVALID.to_string(),
+            }
+        }
+        (_, QuestionSubmissionData::Address { point, .. }, VariablePart::Latitude) => {
+            if point.is_empty() {
+                MISSING_NOT_ANSWERED.to_string()
+            } else {
+                point.get_lat().to_string()
+            }
+        }
+        (_, QuestionSubmissionData::Address { point, .. }, VariablePart::Longitude) => {
+            if point.is_empty() {
+                MISSING_NOT_ANSWERED.to_string()
+            } else {
+                point.get_lng().to_string()
+            }
+        }
+        (_, QuestionSubmissionData::Address { address, .. }, _) => {
+            let lines: Vec<String> = address.to_string().lines().map(String::from).collect();
+            lines.join(", ")
+        }
+        (_, QuestionSubmissionData::DateTime { value: Some(value) }, _) => {
+            let value = value.naive_local();
+            match variable.variable_type {
+                VariableType::Date => value.format("%Y-%m-%d").to_string(),
+                VariableType::Time => value.format("%H:%M:%S").to_string(),
+                _ => value.format("%Y-%m-%d %H:%M:%S").to_string(),
+            }
+        }
+        // Anything we can't code (e.g. a question that changed type since it was answered)
+        _ if variable.is_numeric() => MISSING_INVALID.to_string(),
+        (_, data, _) => data.to_string(),
+    }
+}
+
+/// Quotes a string for SPSS syntax. Line breaks aren't allowed inside SPSS strings.
+fn spss_string(value: &str) -> String {
+    format!("'{}'", value.replace(['\r', '\n'], " ").replace('\'', "''"))
+}
+
+/// Quotes a string for an R script.
+fn r_string(value: &str) -> String {
+    format!(
+        "\"{}\"",
+        value
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace(['\r', '\n'], " ")
+    )
+}
+
+fn format_labels(labels: &[(i64, String)]) -> String {
+    labels
+        .iter()
+        .map(|(code, label)| format!("{}={}", code, label))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// A CSV data file with a numeric code for every choice and scale answer, along with a codebook
+/// and scripts to load it into SPSS and R with all the labels attached.
+pub struct StatisticalExport<'a> {
+    variables: Vec<Variable<'a>>,
+    mr_sets: Vec<MultipleResponseSet>,
+    rows: Vec<Vec<String>>,
+}
+
+impl<'a> StatisticalExport<'a> {
+    pub fn from_submissions(
+        groups: &[APIQuestionGroup],
+        submissions: &[ExportSubmission],
+        questions: &'a [APIQuestion],
+        config: &ExportSubmissionsConfig,
+    ) -> Result<Self, anyhow::Error> {
+        let mut variables = vec![
+            metadata_variable("id", "Submission ID", VariablePart::SubmissionId),
+            metadata_variable("created_at", "Submitted at (UTC)", VariablePart::CreatedAt),
+            metadata_variable("fill_token", "Share link", VariablePart::FillToken),
+        ];
+        let mut mr_sets = Vec::new();
+
+        let mut question_number = 0;
+        for group in groups {
+            for question in questions.iter().filter(|q| q.group_id == group.id) {
+                if !question.configuration.requires_submission() {
+                    continue;
+                }
+
+                question_number += 1;
+                // Question IDs are already valid SPSS and R variable names
+                let name = if config.use_question_ids {
+                    question.id.to_string()
+                } else {
+                    format!("q{}", question_number)
+                };
+                variables.extend(variables_for_question(question, name, &mut mr_sets));
+            }
+        }
+
+        let rows = submissions
+            .iter()
+            .map(|s| variables.iter().map(|v| cell_for_variable(v, s)).collect())
+            .collect();
+
+        Ok(Self {
+            variables,
+            mr_sets,
+            rows,
+        })
+    }
+
+    fn data_csv(&self) -> Result<Vec<u8>, anyhow::Error> {
+        let mut w = csv::Writer::from_writer(Vec::new());
+        w.write_record(self.variables.iter().map(|v| &v.name))
+            .map_err(|e| anyhow!("write header row: {}", e))?;
+        for row in &self.rows {
+            w.write_record(row)
+                .map_err(|e| anyhow!("write submission row: {}", e))?;
+        }
+        w.into_inner().map_err(|e| anyhow!("csv serialize: {}", e))
+    }
+
+    fn codebook_csv(&self) -> Result<Vec<u8>, anyhow::Error> {
+        let mut w = csv::Writer::from_writer(Vec::new());
+        w.write_record([
+            "variable",
+            "label",
+            "question_id",
+            "type",
+            "measure",
+            "value_labels",
+            "missing_values",
+            "multiple_response_set",
+        ])
+        .map_err(|e| anyhow!("write codebook header: {}", e))?;
+
+        let missing_labels: Vec<(i64, String)> = MISSING_LABELS
+            .iter()
+            .map(|(code, label)| (*code, label.to_string()))
+            .collect();
+        for variable in &self.variables {
+            let mr_set = self
+                .mr_sets
+                .iter()
+                .find(|s| s.variables.contains(&variable.name))
+                .map(|s| format!("${}", s.name))
+                .unwrap_or_default();
+
+            w.write_record([
+                variable.name.clone(),
+                variable.label.clone(),
+                variable
+                    .question
+                    .map(|q| q.id.to_string())
+                    .unwrap_or_default(),
+                match variable.variable_type {
+                    VariableType::Numeric { .. } => "numeric",
+                    VariableType::String => "string",
+                    VariableType::Date => "date",
+                    VariableType::Time => "time",
+                    VariableType::DateTime => "datetime",
+                }
+                .to_string(),
+                variable.measure.name().to_string(),
+                format_labels(&variable.value_labels),
+                if variable.has_missing_codes() {
+                    format_labels(&missing_labels)
+                } else {
+                    String::default()
+                },
+                mr_set,
+            ])
+            .map_err(|e| anyhow!("write codebook row: {}", e))?;
+        }
+
+        w.into_inner().map_err(|e| anyhow!("csv serialize: {}", e))
+    }
+
+    fn spss_syntax(&self) -> String {
+        let mut s = String::from(
+            "* Open this file in SPSS from the folder containing data.csv, then run it.\n",
+        );
+        s.push_str(&format!(
+            "GET DATA\n  /TYPE=TXT\n  /FILE={}\n  /ENCODING='UTF8'\n  /ARRANGEMENT=DELIMITED\n  /DELIMITERS=\",\"\n  /QUALIFIER='\"'\n  /FIRSTCASE=2\n  /VARIABLES=\n",
+            spss_string(DATA_PATH)
+        ));
+        for (index, variable) in self.variables.iter().enumerate() {
+            let format = match variable.variable_type {
+                VariableType::Numeric { width, decimals } => format!("F{}.{}", width, decimals),
+                VariableType::String => {
+                    let width = self
+                        .rows
+                        .iter()
+                        .map(|r| r[index].len())
+                        .max()
+                        .unwrap_or(0)
+                        .clamp(1, 32767);
+                    format!("A{}", width)
+                }
+                VariableType::Date => "SDATE10".to_string(),
+                VariableType::Time => "TIME8".to_string(),
+                VariableType::DateTime => "YMDHMS19".to_string(),
+            };
+            s.push_str(&format!("    {} {}\n", variable.name, format));
+        }
+        s.push_str(".\n\nVARIABLE LABELS\n");
+        let labels: Vec<String> = self
+            .variables
+            .iter()
+            .map(|v| format!("{} {}", v.name, spss_string(&v.label)))
+            .collect();
+        s.push_str(&format!("  {}", labels.join("\n  /")));
+        s.push_str(".\n");
+
+        let labelled: Vec<String> = self
+            .variables
+            .iter()
+            .filter(|v| !v.value_labels.is_empty())
+            .map(|v| {
+                let labels: Vec<String> = v
+                    .value_labels
+                    .iter()
+                    .map(|(code, label)| format!("{} {}", code, spss_string(label)))
+                    .collect();
+                format!("{} {}", v.name, labels.join(" "))
+            })
+            .collect();
+        if !labelled.is_empty() {
+            s.push_str("\nVALUE LABELS\n");
+            s.push_str(&format!("  {}", labelled.join("\n  /")));
+            s.push_str(".\n");
+        }
+
+        let missing: Vec<&str> = self
+            .variables
+            .iter()
+            .filter(|v| v.has_missing_codes())
+            .map(|v| v.name.as_str())
+            .collect();
+        if !missing.is_empty() {
+            let missing_values: Vec<String> = MISSING_LABELS
+                .iter()
+                .map(|(code, _)| code.to_string())
+                .collect();
+            let missing_labels: Vec<String> = MISSING_LABELS
+                .iter()
+                .map(|(code, label)| format!("{} {}", code, spss_string(label)))
+                .collect();
+            s.push_str(&format!(
+                "\nADD VALUE LABELS {} {}.\nMISSING VALUES {} ({}).\n",
+                missing.join(" "),
+                missing_labels.join(" "),
+                missing.join(" "),
+                missing_values.join(", ")
+            ));
+        }
+
+        let levels: Vec<String> = [Measure::Nominal, Measure::Ordinal, Measure::Scale]
+            .iter()
+            .filter_map(|measure| {
+                let names: Vec<&str> = self
+                    .variables
+                    .iter()
+                    .filter(|v| v.measure.name() == measure.name())
+                    .map(|v| v.name.as_str())
+                    .collect();
+                if names.is_empty() {
+                    None
+                } else {
+                    Some(format!(
+                        "{} ({})",
+                        names.join(" "),
+                        measure.name().to_uppercase()
+                    ))
+                }
+            })
+            .collect();
+        s.push_str("\nVARIABLE LEVEL\n");
+        s.push_str(&format!("  {}", levels.join("\n  /")));
+        s.push_str(".\n");
+
+        if !self.mr_sets.is_empty() {
+            s.push_str("\nMRSETS\n");
+            for set in &self.mr_sets {
+                s.push_str(&format!(
+                    "  /MDGROUP NAME=${} LABEL={} CATEGORYLABELS=VARLABELS VARIABLES={} VALUE=1\n",
+                    set.name,
+                    spss_string(&set.label),
+                    set.variables.join(" ")
+                ));
+            }
+            s.push_str(".\n");
+        }
+
+        s.push_str("\nEXECUTE.\n");
+        s
+    }
+
+    fn r_script(&self) -> String {
+        let mut s = String::from("# Run this from the folder containing data.csv, e.g. with source(\"import.R\", chdir = TRUE)\n");
+        s.push_str(&format!(
+            "data <- read.csv({}, na.strings = \"\", stringsAsFactors = FALSE, encoding = \"UTF-8\", colClasses = \"character\")\n\n",
+            r_string(DATA_PATH)
+        ));
+
+        let missing_values: Vec<String> = MISSING_LABELS
+            .iter()
+            .map(|(code, _)| code.to_string())
+            .collect();
+        for variable in &self.variables {
+            let column = format!("data[[{}]]", r_string(&variable.name));
+            match variable.variable_type {
+                VariableType::Numeric { .. } => {
+                    s.push_str(&format!("{0} <- as.numeric({0})\n", column));
+                    if variable.has_missing_codes() {
+                        s.push_str(&format!(
+                            "{0}[{0} %in% c({1})] <- NA\n",
+                            column,
+                            missing_values.join(", ")
+                        ));
+                    }
+                }
+                VariableType::String => {}
+                VariableType::Date => s.push_str(&format!("{0} <- as.Date({0})\n", column)),
+                VariableType::Time => {}
+                VariableType::DateTime => s.push_str(&format!(
+                    "{0} <- as.POSIXct({0}, format = \"%Y-%m-%d %H:%M:%S\", tz = {1})\n",
+                    column,
+                    r_string(if variable.question.is_some() {
+                        ""
+                    } else {
+                        "UTC"
+                    })
+                )),
+            }
+
+            // Single-choice codes become factors. Scale labels only cover the end points, so
+            // those stay numeric.
+            if matches!(
+                variable.part,
+                VariablePart::ChoiceCode | VariablePart::MatrixRowCode(_)
+            ) {
+                let codes: Vec<String> = variable
+                    .value_labels
+                    .iter()
+                    .map(|(code, _)| code.to_string())
+                    .collect();
+                let labels: Vec<String> = variable
+                    .value_labels
+                    .iter()
+                    .map(|(_, label)| r_string(label))
+                    .collect();
+                s.push_str(&format!(
+                    "{0} <- factor({0}, levels = c({1}), labels = c({2}))\n",
+                    column,
+                    codes.join(", "),
+                    labels.join(", ")
+                ));
+            }
+
+            s.push_str(&format!(
+                "attr({}, \"label\") <- {}\n",
+                column,
+                r_string(&variable.label)
+            ));
+        }
+
+        if !self.mr_sets.is_empty() {
+            s.push_str("\n# Multiple-response sets: each variable is 1 if the option was selected\nmr_sets <- list(\n");
+            let sets: Vec<String> = self
+                .mr_sets
+                .iter()
+                .map(|set| {
+                    let names: Vec<String> = set.variables.iter().map(|v| r_string(v)).collect();
+                    format!("  {} = c({})", set.name, names.join(", "))
+                })
+                .collect();
+            s.push_str(&sets.join(",\n"));
+            s.push_str("\n)\n");
+        }
+
+        s
+    }
+
+    /// Writes the data, codebook and import scripts into a single zip file
+    pub fn write_zip(&self) -> Result<Vec<u8>, anyhow::Error> {
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+        for (path, data) in [
+            (DATA_PATH, self.data_csv()?),
+            (CODEBOOK_PATH, self.codebook_csv()?),
+            (SPSS_SYNTAX_PATH, self.spss_syntax().into_bytes()),
+            (R_SCRIPT_PATH, self.r_script().into_bytes()),
+        ] {
+            zip.start_file(path, options)
+                .map_err(|e| anyhow!("start {}: {}", path, e))?;
+            zip.write_all(&data)
+                .map_err(|e| anyhow!("write {}: {}", path, e))?;
+        }
+
+        Ok(zip
+            .finish()
+            .map_err(|e| anyhow!("zip serialize: {}", e))?
+            .into_inner())
+    }
+}
@@ -120,6 +120,9 @@ impl Display for QuestionSubmissionData {
                 write!(f, "{}", option.join(","))
             }
             QuestionSubmissionData::ChoiceMatrix { options } => {
+                // Sort the rows so the output doesn't depend on HashMap iteration order
+                let mut options: Vec<_> = options.iter().collect();
+                options.sort_by_key(|(column, _)| *column);
                 for (index, (column, items)) in options.iter().enumerate() {
                     write!(f, "{}:{}", column, items.join(","))?;
                     if index != options.len() - 1 {
@@ -229,7 +232,7 @@ impl TryFrom<APIQuestion> for QuestionSubmission {
                 calling_code: String::default(),
                 number: String::default(),
             },
-            APIQuestionConfiguration::FileUpload { allowed_types: _ } => {
+            APIQuestionConfiguration::FileUpload { .. } => {
                 QuestionSubmissionData::FileUpload {
                     file_id: String::default(),
                     content_type: String::default(),
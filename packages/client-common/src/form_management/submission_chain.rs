@@ -0,0 +1,211 @@
+use chrono::{DateTime, Utc};
+use palform_tsid::{
+    resources::{IDForm, IDSubmission},
+    tsid::PalformDatabaseID,
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+#[cfg_attr(feature = "backend", derive(schemars::JsonSchema))]
+#[derive(Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum SubmissionChainEntryKind {
+    Created,
+    Deleted,
+}
+
+/// One link in a form's submission chain. Every submission that's created or deleted gets the
+/// next index, and a hash covering the entry and the hash of the entry before it.
+#[cfg_attr(feature = "backend", derive(schemars::JsonSchema))]
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct SubmissionChainEntry {
+    /// Counts up from 0 for each form
+    pub index: i64,
+    pub kind: SubmissionChainEntryKind,
+    pub submission_id: PalformDatabaseID<IDSubmission>,
+    /// Hex SHA256 of the submission's encrypted data, not counting its key packets. Only set for
+    /// `created` entries.
+    pub data_hash: Option<String>,
+    pub chain_hash: String,
+}
+
+/// The head of a form's submission chain at some point in time. The server signs the string from
+/// `signed_message` with its Ed25519 signing key.
+#[cfg_attr(feature = "backend", derive(schemars::JsonSchema))]
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct SubmissionChainCheckpoint {
+    pub form_id: PalformDatabaseID<IDForm>,
+    /// Number of entries in the chain
+    pub length: i64,
+    /// Hash of the last entry, or the genesis hash if the chain is empty
+    pub head: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl SubmissionChainCheckpoint {
+    pub fn signed_message(&self) -> String {
+        format!(
+            "palform-submission-chain-checkpoint:{}:{}:{}:{}",
+            self.form_id,
+            self.length,
+            self.head,
+            self.created_at.timestamp()
+        )
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum SubmissionChainError {
+    #[error("Entry {0} is missing or out of order")]
+    OutOfOrder(i64),
+    #[error("Entry {0} doesn't follow on from the entry before it")]
+    BrokenLink(i64),
+    #[error("Created entry {0} has no data hash")]
+    MissingDataHash(i64),
+    #[error("Chain has {0} entries but the checkpoint covers {1}")]
+    LengthMismatch(i64, i64),
+    #[error("Chain doesn't end at the checkpoint")]
+    HeadMismatch,
+    #[error("Chain has {0} entries but an earlier checkpoint had {1}")]
+    Rewound(i64, i64),
+    #[error("Entry {0} isn't the same as it was at an earlier checkpoint")]
+    Forked(i64),
+}
+
+fn sha256_hex(data: &[u8]) -> String {
+    faster_hex::hex_string(Sha256::digest(data).as_slice())
+}
+
+/// Hex SHA256 of the part of a submission's message the chain covers
+pub fn submission_chain_data_hash(data: &[u8]) -> String {
+    sha256_hex(data)
+}
+
+/// The hash the first entry in a form's chain follows on from
+pub fn submission_chain_genesis(form_id: PalformDatabaseID<IDForm>) -> String {
+    sha256_hex(format!("palform-submission-chain:{}", form_id).as_bytes())
+}
+
+pub fn next_submission_chain_hash(
+    previous_hash: &str,
+    index: i64,
+    kind: SubmissionChainEntryKind,
+    submission_id: PalformDatabaseID<IDSubmission>,
+    data_hash: Option<&str>,
+) -> String {
+    let kind = match kind {
+        SubmissionChainEntryKind::Created => "created",
+        SubmissionChainEntryKind::Deleted => "deleted",
+    };
+    sha256_hex(
+        format!(
+            "{}:{}:{}:{}:{}",
+            previous_hash,
+            index,
+            kind,
+            submission_id,
+            data_hash.unwrap_or_default()
+        )
+        .as_bytes(),
+    )
+}
+
+/// Checks that `entries` is the whole chain for the form, in order and unmodified, ending at the
+/// checkpoint. This doesn't check the checkpoint's signature.
+pub fn verify_submission_chain(
+    entries: &[SubmissionChainEntry],
+    checkpoint: &SubmissionChainCheckpoint,
+) -> Result<(), SubmissionChainError> {
+    let mut previous_hash = submission_chain_genesis(checkpoint.form_id);
+    for (expected_index, entry) in entries.iter().enumerate() {
+        if entry.index != expected_index as i64 {
+            return Err(SubmissionChainError::OutOfOrder(expected_index as i64));
+        }
+        if entry.kind == SubmissionChainEntryKind::Created && entry.data_hash.is_none() {
+            return Err(SubmissionChainError::MissingDataHash(entry.index));
+        }
+
+        let hash = next_submission_chain_hash(
+            &previous_hash,
+            entry.index,
+            entry.kind,
+            entry.submission_id,
+            entry.data_hash.as_deref(),
+        );
+        if hash != entry.chain_hash {
+            return Err(SubmissionChainError::BrokenLink(entry.index));
+        }
+        previous_hash = hash;
+    }
+
+    if entries.len() as i64 != checkpoint.length {
+        return Err(SubmissionChainError::LengthMismatch(
+            entries.len() as i64,
+            checkpoint.length,
+        ));
+    }
+    if previous_hash != checkpoint.head {
+        return Err(SubmissionChainError::HeadMismatch);
+    }
+
+    Ok(())
+}
+
+/// Checks that `entries` carries on from a checkpoint seen earlier, so the server hasn't removed or
+/// rewritten anything since. `entries` should already have been checked with
+/// `verify_submission_chain`, as each entry's hash covers every one before it.
+pub fn verify_submission_chain_extends(
+    entries: &[SubmissionChainEntry],
+    previous: &SubmissionChainCheckpoint,
+) -> Result<(), SubmissionChainError> {
+    if previous.length == 0 {
+        return Ok(());
+    }
+    if previous.length < 0 || (entries.len() as i64) < previous.length {
+        return Err(SubmissionChainError::Rewound(
+            entries.len() as i64,
+            previous.length,
+        ));
+    }
+
+    let last = &entries[previous.length as usize - 1];
+    if last.chain_hash != previous.head {
+        return Err(SubmissionChainError::Forked(last.index));
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "frontend-js")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn verify_submission_chain_js(
+    entries: wasm_bindgen::JsValue,
+    checkpoint: wasm_bindgen::JsValue,
+) -> Result<(), wasm_bindgen::JsValue> {
+    let entries = serde_wasm_bindgen::from_value::<Vec<SubmissionChainEntry>>(entries)?;
+    let checkpoint = serde_wasm_bindgen::from_value::<SubmissionChainCheckpoint>(checkpoint)?;
+    verify_submission_chain(&entries, &checkpoint)
+        .map_err(|e| wasm_bindgen::JsValue::from_str(&e.to_string()))
+}
+
+#[cfg(feature = "frontend-js")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn submission_chain_checkpoint_message_js(
+    checkpoint: wasm_bindgen::JsValue,
+) -> Result<String, wasm_bindgen::JsValue> {
+    let checkpoint = serde_wasm_bindgen::from_value::<SubmissionChainCheckpoint>(checkpoint)?;
+    Ok(checkpoint.signed_message())
+}
+
+#[cfg(feature = "frontend-js")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn verify_submission_chain_extends_js(
+    entries: wasm_bindgen::JsValue,
+    previous: wasm_bindgen::JsValue,
+) -> Result<(), wasm_bindgen::JsValue> {
+    let entries = serde_wasm_bindgen::from_value::<Vec<SubmissionChainEntry>>(entries)?;
+    let previous = serde_wasm_bindgen::from_value::<SubmissionChainCheckpoint>(previous)?;
+    verify_submission_chain_extends(&entries, &previous)
+        .map_err(|e| wasm_bindgen::JsValue::from_str(&e.to_string()))
+}
@@ -15,6 +15,68 @@ pub struct ValidationError {
     pub error: String,
 }
 
+fn out_of_range<T: PartialOrd>(value: T, min: Option<T>, max: Option<T>) -> Option<String> {
+    if min.is_some_and(|min| value < min) {
+        Some("Value is before the earliest allowed".to_string())
+    } else if max.is_some_and(|max| value > max) {
+        Some("Value is after the latest allowed".to_string())
+    } else {
+        None
+    }
+}
+
+/// Checks a non-empty answer against the rules in its question's configuration, returning a
+/// description of the first problem found.
+pub fn validate_answer(question: &APIQuestion, data: &QuestionSubmissionData) -> Option<String> {
+    match (&question.configuration, data) {
+        (
+            APIQuestionConfiguration::Text { validator, .. },
+            QuestionSubmissionData::Text { value },
+        ) => match validator {
+            Some(APIQuestionTextValidator::Email) if !value.validate_email() => {
+                Some("Value must be a valid email address".to_string())
+            }
+            Some(APIQuestionTextValidator::Integer) if value.parse::<i32>().is_err() => {
+                Some("Value must be a number".to_string())
+            }
+            Some(APIQuestionTextValidator::Float) if value.parse::<f64>().is_err() => {
+                Some("Value must be a decimal number".to_string())
+            }
+            _ => None,
+        },
+        (
+            APIQuestionConfiguration::Scale { min, max, .. },
+            QuestionSubmissionData::Scale { value: Some(value) },
+        ) => {
+            if value < min || value > max {
+                Some(format!("Value must be between {} and {}", min, max))
+            } else {
+                None
+            }
+        }
+        (
+            APIQuestionConfiguration::DateTime {
+                collect_date,
+                collect_time,
+                min,
+                max,
+            },
+            QuestionSubmissionData::DateTime { value: Some(value) },
+        ) => match (collect_date, collect_time) {
+            (true, false) => out_of_range(
+                value.date_naive(),
+                min.map(|m| m.date_naive()),
+                max.map(|m| m.date_naive()),
+            ),
+            (false, true) => {
+                out_of_range(value.time(), min.map(|m| m.time()), max.map(|m| m.time()))
+            }
+            _ => out_of_range(*value, *min, *max),
+        },
+        _ => None,
+    }
+}
+
 pub fn validate_questions(
     questions: Vec<APIQuestion>,
     submissions: Vec<QuestionSubmission>,
@@ -45,41 +107,11 @@ pub fn validate_questions(
             continue;
         }
 
-        if let APIQuestionConfiguration::Text {
-            is_long: _,
-            validator,
-        } = question.configuration
-        {
-            if let QuestionSubmissionData::Text { value } = submission.data.clone() {
-                if let Some(validator) = validator {
-                    match validator {
-                        APIQuestionTextValidator::Email => {
-                            if !value.validate_email() {
-                                errors.push(ValidationError {
-                                    question_id: question.id.to_string(),
-                                    error: "Value must be a valid email address".to_string(),
-                                })
-                            }
-                        }
-                        APIQuestionTextValidator::Integer => {
-                            if value.parse::<i32>().is_err() {
-                                errors.push(ValidationError {
-                                    question_id: question.id.to_string(),
-                                    error: "Value must be a number".to_string(),
-                                })
-                            }
-                        }
-                        APIQuestionTextValidator::Float => {
-                            if value.parse::<f64>().is_err() {
-                                errors.push(ValidationError {
-                                    question_id: question.id.to_string(),
-                                    error: "Value must be a decimal number".to_string(),
-                                })
-                            }
-                        }
-                    };
-                }
-            }
+        if let Some(error) = validate_answer(&question, &submission.data) {
+            errors.push(ValidationError {
+                question_id: question.id.to_string(),
+                error,
+            });
         }
     }
 
@@ -1,4 +1,5 @@
 pub mod address;
+pub mod captcha;
 pub mod errors;
 pub mod form_management;
 
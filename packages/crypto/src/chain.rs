@@ -0,0 +1,23 @@
+use palform_client_common::form_management::submission_chain::submission_chain_data_hash;
+use sequoia_openpgp::{packet::Packet, parse::Parse, serialize::SerializeInto, PacketPile};
+
+/// Hashes an encrypted submission (binary or armored) for the form's submission chain. Key
+/// packets are left out, so adding recipients to the submission later doesn't change its hash.
+pub fn submission_data_hash(message: &[u8]) -> Result<String, anyhow::Error> {
+    let pile = PacketPile::from_bytes(message)?;
+    let mut data = Vec::new();
+    for packet in pile.children() {
+        if matches!(packet, Packet::PKESK(_)) {
+            continue;
+        }
+        data.extend(packet.to_vec()?);
+    }
+    Ok(submission_chain_data_hash(&data))
+}
+
+#[cfg(feature = "frontend-js")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn submission_data_hash_js(message_pem: String) -> Result<String, wasm_bindgen::JsValue> {
+    submission_data_hash(message_pem.as_bytes())
+        .map_err(|e| wasm_bindgen::JsValue::from_str(&e.to_string()))
+}
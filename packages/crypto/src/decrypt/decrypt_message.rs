@@ -1,40 +1,91 @@
 use super::key_resolver::KeyResolver;
-use crate::policy::recipient_cert_policy;
+use crate::{policy::recipient_cert_policy, signature::SignatureStatus};
 use anyhow::anyhow;
 use palform_client_common::form_management::submission::InProgressSubmission;
 use sequoia_openpgp::parse::{stream::DecryptorBuilder, Parse};
+use serde::Serialize;
 use std::io::Read;
 
-fn decrypt_anything(data: &[u8], key_resolver: KeyResolver) -> Result<Vec<u8>, anyhow::Error> {
+pub fn decrypt_anything(data: &[u8], key_resolver: KeyResolver) -> Result<Vec<u8>, anyhow::Error> {
+    decrypt_verify_anything(data, key_resolver).map(|(content, _)| content)
+}
+
+/// Like [`decrypt_anything`], but also returns whether the message was signed by the resolver's
+/// signer
+pub fn decrypt_verify_anything(
+    data: &[u8],
+    key_resolver: KeyResolver,
+) -> Result<(Vec<u8>, SignatureStatus), anyhow::Error> {
     let p = recipient_cert_policy();
     let mut v = DecryptorBuilder::from_bytes(data)?.with_policy(&p, None, key_resolver)?;
 
     let mut content = Vec::new();
     v.read_to_end(&mut content)?;
 
-    Ok(content)
+    let signature = v.into_helper().signature.with_submission_hash(&content)?;
+    Ok((content, signature))
 }
 
-fn decode_submission_message(
+pub fn decode_submission_message(
     decrypted_message: Vec<u8>,
 ) -> Result<InProgressSubmission, anyhow::Error> {
     serde_json::from_slice::<InProgressSubmission>(&decrypted_message)
         .map_err(|e| anyhow!("parse submission: {}", e))
 }
 
+pub fn decrypt_decode_submission(
+    message_pem: &[u8],
+    key_resolver: &KeyResolver,
+) -> Result<InProgressSubmission, anyhow::Error> {
+    let decrypted_message = decrypt_anything(message_pem, key_resolver.to_owned())?;
+    decode_submission_message(decrypted_message)
+}
+
+#[derive(Serialize)]
+pub struct VerifiedSubmission {
+    pub submission: InProgressSubmission,
+    pub signature: SignatureStatus,
+}
+
+/// Decrypts a submission and checks its signature against `signing_key`, the public key it was
+/// sent with (if any)
+pub fn decrypt_verify_submission(
+    message_pem: &[u8],
+    key_resolver: &KeyResolver,
+    signing_key: Option<String>,
+) -> Result<VerifiedSubmission, anyhow::Error> {
+    let (decrypted_message, signature) =
+        decrypt_verify_anything(message_pem, key_resolver.with_signer(signing_key)?)?;
+    Ok(VerifiedSubmission {
+        submission: decode_submission_message(decrypted_message)?,
+        signature,
+    })
+}
+
 #[cfg(feature = "frontend-js")]
 #[wasm_bindgen::prelude::wasm_bindgen]
 pub fn decrypt_decode_submission_js(
     message_pem: String,
     frontend_key_store: &KeyResolver,
 ) -> Result<wasm_bindgen::JsValue, wasm_bindgen::JsValue> {
-    let decrypted_message = decrypt_anything(message_pem.as_bytes(), frontend_key_store.to_owned())
-        .map_err(|e| wasm_bindgen::JsValue::from_str(&e.to_string()))?;
-    let decoded_message = decode_submission_message(decrypted_message)
+    let decoded_message = decrypt_decode_submission(message_pem.as_bytes(), frontend_key_store)
         .map_err(|e| wasm_bindgen::JsValue::from_str(&e.to_string()))?;
     serde_wasm_bindgen::to_value(&decoded_message).map_err(|e| e.into())
 }
 
+#[cfg(feature = "frontend-js")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn decrypt_verify_submission_js(
+    message_pem: String,
+    frontend_key_store: &KeyResolver,
+    signing_key: Option<String>,
+) -> Result<wasm_bindgen::JsValue, wasm_bindgen::JsValue> {
+    let verified =
+        decrypt_verify_submission(message_pem.as_bytes(), frontend_key_store, signing_key)
+            .map_err(|e| wasm_bindgen::JsValue::from_str(&e.to_string()))?;
+    serde_wasm_bindgen::to_value(&verified).map_err(|e| e.into())
+}
+
 #[cfg(feature = "frontend-js")]
 #[wasm_bindgen::prelude::wasm_bindgen]
 pub fn decrypt_blob_js(
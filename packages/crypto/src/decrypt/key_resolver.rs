@@ -2,20 +2,28 @@ use anyhow::anyhow;
 use sequoia_openpgp::{
     crypto::{KeyPair, SessionKey},
     packet::{PKESK, SKESK},
-    parse::stream::{DecryptionHelper, VerificationHelper},
+    parse::stream::{DecryptionHelper, MessageStructure, VerificationHelper},
     types::SymmetricAlgorithm,
     Cert, Fingerprint, KeyHandle,
 };
 
 use crate::{
-    keys::{encryption_key::resolve_encryption_key, parse::secret_key_from_pem},
+    keys::{
+        encryption_key::resolve_encryption_key,
+        parse::{public_key_from_pem, secret_key_from_pem},
+    },
     policy::recipient_cert_policy,
+    signature::SignatureStatus,
 };
 
 #[derive(Clone)]
 #[cfg_attr(feature = "frontend-js", wasm_bindgen::prelude::wasm_bindgen)]
 pub struct KeyResolver {
     keys: Vec<(KeyPair, Fingerprint, Cert)>,
+    /// The key the message is expected to be signed with
+    signer: Option<Cert>,
+    /// Set once the whole message has been read
+    pub(crate) signature: SignatureStatus,
 }
 
 #[cfg(feature = "frontend-js")]
@@ -23,13 +31,29 @@ pub struct KeyResolver {
 impl KeyResolver {
     #[wasm_bindgen::prelude::wasm_bindgen(constructor)]
     pub fn new(key_pems: Vec<String>) -> Result<KeyResolver, wasm_bindgen::JsValue> {
-        let keys = parse_key_pems(key_pems)
-            .map_err(|e| wasm_bindgen::JsValue::from_str(&e.to_string()))?;
-        Ok(Self { keys })
+        Self::from_pems(key_pems).map_err(|e| wasm_bindgen::JsValue::from_str(&e.to_string()))
     }
 }
 
 impl KeyResolver {
+    pub fn from_pems(key_pems: Vec<String>) -> Result<Self, anyhow::Error> {
+        Ok(Self {
+            keys: parse_key_pems(key_pems)?,
+            signer: None,
+            signature: SignatureStatus::default(),
+        })
+    }
+
+    /// Returns a copy of the resolver that checks signatures against `signing_key`, the public
+    /// key a submission was sent with
+    pub fn with_signer(&self, signing_key: Option<String>) -> Result<Self, anyhow::Error> {
+        Ok(Self {
+            keys: self.keys.clone(),
+            signer: signing_key.map(public_key_from_pem).transpose()?,
+            signature: SignatureStatus::default(),
+        })
+    }
+
     fn find_key_for_handle(
         &self,
         key_handle: &KeyHandle,
@@ -41,6 +65,19 @@ impl KeyResolver {
         }
         None
     }
+
+    /// Recovers a message's session key from whichever of its PKESK packets one of our keys can
+    /// decrypt
+    pub(crate) fn decrypt_session_key(
+        &self,
+        pkesks: &[PKESK],
+        sym_algo: Option<SymmetricAlgorithm>,
+    ) -> Option<(Option<SymmetricAlgorithm>, SessionKey)> {
+        pkesks.iter().find_map(|pkesk| {
+            let (kp, _, _) = self.find_key_for_handle(&pkesk.recipient()?)?;
+            pkesk.decrypt(&mut kp.clone(), sym_algo)
+        })
+    }
 }
 
 impl VerificationHelper for KeyResolver {
@@ -48,13 +85,13 @@ impl VerificationHelper for KeyResolver {
         &mut self,
         _ids: &[sequoia_openpgp::KeyHandle],
     ) -> sequoia_openpgp::Result<Vec<Cert>> {
-        Ok(Vec::new())
+        Ok(self.signer.iter().cloned().collect())
     }
 
-    fn check(
-        &mut self,
-        _: sequoia_openpgp::parse::stream::MessageStructure,
-    ) -> sequoia_openpgp::Result<()> {
+    /// Never fails, so that submissions with a bad signature can still be read. The result is
+    /// kept in `signature` to be shown alongside the submission instead.
+    fn check(&mut self, structure: MessageStructure) -> sequoia_openpgp::Result<()> {
+        self.signature = SignatureStatus::from_message_structure(structure, self.signer.as_ref());
         Ok(())
     }
 }
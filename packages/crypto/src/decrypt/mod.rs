@@ -1,2 +1,3 @@
 pub mod key_resolver;
 pub mod decrypt_message;
+pub mod rewrap;
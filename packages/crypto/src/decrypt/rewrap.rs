@@ -0,0 +1,123 @@
+use anyhow::anyhow;
+use sequoia_openpgp::{
+    armor::{Kind, Writer},
+    packet::{
+        pkesk::{PKESK3, PKESK6},
+        Packet, PKESK, SEIP,
+    },
+    parse::{PacketParser, PacketParserResult, Parse},
+    serialize::Serialize,
+    KeyHandle,
+};
+
+use crate::{keys::parse::public_key_from_pem, policy::recipient_cert_policy};
+
+use super::key_resolver::KeyResolver;
+
+/// Encrypts the session key of `message` to any of `recipient_certs` that can't read it yet, so
+/// that keys added after the message was sent can decrypt it too. One of the keys in
+/// `key_resolver` must already be a recipient.
+///
+/// The message itself isn't changed. The new PKESK packets are returned as an armored file, which
+/// the server adds to the stored message. Returns `None` if every recipient can already read it.
+///
+/// With `replace_existing`, packets are made for recipients that already have one too, so that the
+/// server can replace any that an earlier rewrap added.
+pub fn rewrap_message(
+    message: &[u8],
+    key_resolver: &KeyResolver,
+    recipient_certs: Vec<String>,
+    replace_existing: bool,
+) -> Result<Option<String>, anyhow::Error> {
+    let mut pkesks = Vec::<PKESK>::new();
+    // Version 2 SEIP packets hold the symmetric algorithm, rather than the PKESK packets
+    let mut seip_v2_algo = None;
+    let mut encrypted = false;
+    let mut ppr = PacketParser::from_bytes(message)?;
+    while let PacketParserResult::Some(pp) = ppr {
+        match &pp.packet {
+            Packet::PKESK(pkesk) => pkesks.push(pkesk.clone()),
+            Packet::SEIP(seip) => {
+                if let SEIP::V2(seip) = seip {
+                    seip_v2_algo = Some(seip.symmetric_algo());
+                }
+                encrypted = true;
+                break;
+            }
+            _ => {}
+        }
+        ppr = pp.next()?.1;
+    }
+    if !encrypted {
+        return Err(anyhow!("Message is not encrypted"));
+    }
+
+    let (sym_algo, session_key) = key_resolver
+        .decrypt_session_key(&pkesks, seip_v2_algo)
+        .ok_or(anyhow!("None of your keys are able to decrypt this data"))?;
+
+    let existing_recipients: Vec<KeyHandle> = pkesks
+        .iter()
+        .filter_map(|pkesk| pkesk.recipient())
+        .collect();
+
+    let policy = recipient_cert_policy();
+    let mut new_pkesks = Vec::<Packet>::new();
+    for cert_pem in recipient_certs {
+        let cert = public_key_from_pem(cert_pem).map_err(|e| anyhow!("parse keys: {}", e))?;
+        let Ok(valid_cert) = cert.with_policy(&policy, None) else {
+            continue;
+        };
+
+        for key in valid_cert
+            .keys()
+            .supported()
+            .alive()
+            .revoked(false)
+            .for_storage_encryption()
+        {
+            let handle = KeyHandle::from(key.key().fingerprint());
+            if !replace_existing && existing_recipients.iter().any(|r| r.aliases(&handle)) {
+                continue;
+            }
+
+            let pkesk: PKESK = match seip_v2_algo {
+                Some(_) => PKESK6::for_recipient(&session_key, key.key())?.into(),
+                None => PKESK3::for_recipient(
+                    sym_algo.ok_or(anyhow!("Session key has no algorithm"))?,
+                    &session_key,
+                    key.key(),
+                )?
+                .into(),
+            };
+            new_pkesks.push(pkesk.into());
+        }
+    }
+
+    if new_pkesks.is_empty() {
+        return Ok(None);
+    }
+
+    let mut w = Writer::new(Vec::new(), Kind::File)?;
+    for packet in new_pkesks {
+        packet.serialize(&mut w)?;
+    }
+    Ok(Some(String::from_utf8_lossy(&w.finalize()?).to_string()))
+}
+
+#[cfg(feature = "frontend-js")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn rewrap_message_js(
+    message_pem: String,
+    frontend_key_store: &KeyResolver,
+    recipient_certs: Vec<String>,
+    replace_existing: bool,
+) -> Result<Option<String>, wasm_bindgen::JsValue> {
+    rewrap_message(
+        message_pem.as_bytes(),
+        frontend_key_store,
+        recipient_certs,
+        replace_existing,
+    )
+    .map_err(|e| wasm_bindgen::JsValue::from_str(&e.to_string()))
+}
@@ -4,14 +4,25 @@ use anyhow::anyhow;
 use palform_client_common::form_management::submission::InProgressSubmission;
 use sequoia_openpgp::{
     armor::{Kind, Reader, ReaderMode, Writer},
+    crypto::KeyPair,
     parse::Parse,
-    serialize::stream::{Encryptor, LiteralWriter, Message},
+    serialize::{
+        stream::{Encryptor, LiteralWriter, Message, Signer},
+        SerializeInto,
+    },
     Cert,
 };
 
-use crate::policy::recipient_cert_policy;
+use crate::{
+    policy::recipient_cert_policy,
+    signature::{generate_signing_key, submission_hash},
+};
 
-fn encrypt_anything(data: &[u8], recipient_certs: Vec<String>) -> Result<Vec<u8>, anyhow::Error> {
+fn encrypt_anything(
+    data: &[u8],
+    recipient_certs: Vec<String>,
+    signer: Option<KeyPair>,
+) -> Result<Vec<u8>, anyhow::Error> {
     let policy = recipient_cert_policy();
 
     let recipient_certs: Result<Vec<Cert>, anyhow::Error> = recipient_certs
@@ -50,7 +61,10 @@ fn encrypt_anything(data: &[u8], recipient_certs: Vec<String>) -> Result<Vec<u8>
     }
 
     let mut sink = Vec::new();
-    let message = Encryptor::for_recipients(Message::new(&mut sink), recipients).build()?;
+    let mut message = Encryptor::for_recipients(Message::new(&mut sink), recipients).build()?;
+    if let Some(signer) = signer {
+        message = Signer::new(message, signer)?.build()?;
+    }
     let mut w = LiteralWriter::new(message).build()?;
 
     w.write_all(data)?;
@@ -75,10 +89,52 @@ pub fn encrypt_submission(
     let submission =
         serde_json::to_vec(&submission).map_err(|e| anyhow!("serialize submission: {}", e))?;
 
-    let encrypted = encrypt_anything(&submission, recipient_certs)?;
+    let encrypted = encrypt_anything(&submission, recipient_certs, None)?;
     Ok(encrypted)
 }
 
+#[cfg_attr(
+    feature = "frontend-js",
+    wasm_bindgen::prelude::wasm_bindgen(getter_with_clone)
+)]
+pub struct SignedSubmission {
+    pub message: String,
+    /// The public half of the single-use key the submission was signed with, to be sent alongside
+    /// it
+    pub signing_key: String,
+    /// Shown to the respondent as their receipt, along with `submission_hash`
+    pub signer_fingerprint: String,
+    pub submission_hash: String,
+}
+
+/// Encrypts a submission signed with a new single-use key, so the respondent can later prove
+/// what they sent using the fingerprint and hash on their receipt
+pub fn encrypt_signed_submission(
+    submission: InProgressSubmission,
+    recipient_certs: Vec<String>,
+) -> Result<SignedSubmission, anyhow::Error> {
+    let submission =
+        serde_json::to_vec(&submission).map_err(|e| anyhow!("serialize submission: {}", e))?;
+
+    let signing_cert = generate_signing_key()?;
+    let signer = signing_cert
+        .primary_key()
+        .key()
+        .clone()
+        .parts_into_secret()?
+        .into_keypair()?;
+
+    let encrypted = encrypt_anything(&submission, recipient_certs, Some(signer))?;
+    // Only has the public key, as `Cert` leaves out secret keys unless serialised `as_tsk`
+    let signing_key = String::from_utf8(signing_cert.armored().to_vec()?)?;
+    Ok(SignedSubmission {
+        message: stringify_armor(&armor_message(&encrypted)?),
+        signing_key,
+        signer_fingerprint: signing_cert.fingerprint().to_hex(),
+        submission_hash: submission_hash(&submission)?,
+    })
+}
+
 #[cfg(feature = "frontend-js")]
 #[wasm_bindgen::prelude::wasm_bindgen]
 pub fn encrypt_submission_js(
@@ -101,9 +157,20 @@ pub fn encrypt_blob_js(
     blob: Vec<u8>,
     recipient_certs: Vec<String>,
 ) -> Result<Vec<u8>, wasm_bindgen::JsValue> {
-    let encrypted = encrypt_anything(&blob, recipient_certs)
+    let encrypted = encrypt_anything(&blob, recipient_certs, None)
         .map_err(|e| wasm_bindgen::JsValue::from_str(&e.to_string()))?;
     let armor =
         armor_message(&encrypted).map_err(|e| wasm_bindgen::JsValue::from_str(&e.to_string()))?;
     Ok(armor)
 }
+
+#[cfg(feature = "frontend-js")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn encrypt_signed_submission_js(
+    submission: wasm_bindgen::JsValue,
+    recipient_certs: Vec<String>,
+) -> Result<SignedSubmission, wasm_bindgen::JsValue> {
+    let submission: InProgressSubmission = serde_wasm_bindgen::from_value(submission)?;
+    encrypt_signed_submission(submission, recipient_certs)
+        .map_err(|e| wasm_bindgen::JsValue::from_str(&e.to_string()))
+}
@@ -3,14 +3,12 @@ use rand::{rngs, seq::SliceRandom, SeedableRng};
 
 const PASSPHRASE_WORD_COUNT: usize = 16;
 
-#[cfg(feature = "frontend-js")]
-struct WordList {
+pub struct WordList {
     list: Vec<String>,
 }
 
-#[cfg(feature = "frontend-js")]
 impl WordList {
-    fn load() -> Result<Self, anyhow::Error> {
+    pub fn load() -> Result<Self, anyhow::Error> {
         let word_list_raw = include_bytes!("./word_list.tsv") as &[u8];
         let mut r = csv::ReaderBuilder::new()
             .delimiter(b'\t')
@@ -44,6 +42,27 @@ impl WordList {
         }
         Ok(words)
     }
+
+    /// Turns a passphrase typed by hand into the exact string used to encrypt the backup, by
+    /// collapsing whitespace and checking each word is from the list.
+    pub fn normalise_passphrase(&self, passphrase: &str) -> Result<String, anyhow::Error> {
+        let words: Vec<&str> = passphrase.split_whitespace().collect();
+        if words.len() != PASSPHRASE_WORD_COUNT {
+            return Err(anyhow!(
+                "Expected {} words in passphrase but found {}",
+                PASSPHRASE_WORD_COUNT,
+                words.len()
+            ));
+        }
+
+        for word in &words {
+            if !self.list.iter().any(|e| e == word) {
+                return Err(anyhow!("{} is not a valid passphrase word", word));
+            }
+        }
+
+        Ok(words.join(" "))
+    }
 }
 
 #[cfg(feature = "frontend-js")]
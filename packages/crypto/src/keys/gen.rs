@@ -3,7 +3,7 @@ use core::time;
 use sequoia_openpgp::{cert::CertBuilder, serialize::SerializeInto};
 use serde::{Deserialize, Serialize};
 
-use crate::policy::NEW_KEY_SUITE;
+use crate::{keys::revoke::armor_signature, policy::NEW_KEY_SUITE};
 
 #[cfg_attr(
     feature = "frontend-js",
@@ -14,6 +14,9 @@ pub struct NewKeypair {
     pub public: String,
     pub private: String,
     pub key_id: String,
+    /// Revokes the key if uploaded. It should be kept somewhere safe in case the private key is
+    /// lost.
+    pub revocation: String,
 }
 
 pub fn generate_certificate(
@@ -21,7 +24,7 @@ pub fn generate_certificate(
     user_id: String,
     validity_period: time::Duration,
 ) -> Result<NewKeypair, anyhow::Error> {
-    let (cert, _) = CertBuilder::new()
+    let (cert, revocation) = CertBuilder::new()
         .set_cipher_suite(NEW_KEY_SUITE)
         .set_validity_period(if validity_period.is_zero() {
             time::Duration::from_secs(u64::from(u32::MAX))
@@ -42,6 +45,7 @@ pub fn generate_certificate(
         key_id: cert.fingerprint().to_hex(),
         public: public_pem,
         private: secret_pem,
+        revocation: armor_signature(revocation)?,
     })
 }
 
@@ -1,3 +1,10 @@
+use sequoia_openpgp::{
+    cert::CipherSuite,
+    crypto::mpi::PublicKey,
+    packet::{key::PublicParts, key::UnspecifiedRole, Key},
+    types::Curve,
+};
+
 use crate::{keys::parse::secret_or_public_key_from_pem, policy::recipient_cert_policy};
 
 use super::encryption_key::resolve_encryption_key;
@@ -10,9 +17,45 @@ use super::encryption_key::resolve_encryption_key;
 pub struct KeyMetadata {
     pub fingerprint: String,
     pub algo: String,
+    /// The cipher suite the encryption key belongs to (e.g. `Cv25519` or `RSA3k`), or its
+    /// algorithm if it doesn't match one
+    pub suite: String,
     pub has_secret: bool,
 }
 
+fn key_suite(key: &Key<PublicParts, UnspecifiedRole>) -> String {
+    let suite = match key.mpis() {
+        PublicKey::X25519 { .. }
+        | PublicKey::ECDH {
+            curve: Curve::Cv25519,
+            ..
+        } => Some(CipherSuite::Cv25519),
+        PublicKey::ECDH {
+            curve: Curve::NistP256,
+            ..
+        } => Some(CipherSuite::P256),
+        PublicKey::ECDH {
+            curve: Curve::NistP384,
+            ..
+        } => Some(CipherSuite::P384),
+        PublicKey::ECDH {
+            curve: Curve::NistP521,
+            ..
+        } => Some(CipherSuite::P521),
+        PublicKey::RSA { n, .. } => match n.bits() {
+            2048 => Some(CipherSuite::RSA2k),
+            3072 => Some(CipherSuite::RSA3k),
+            4096 => Some(CipherSuite::RSA4k),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    suite
+        .map(|s| format!("{:?}", s))
+        .unwrap_or_else(|| format!("{}", key.pk_algo()))
+}
+
 pub fn get_key_metadata(cert_pem: String) -> Result<KeyMetadata, anyhow::Error> {
     let cert = secret_or_public_key_from_pem(cert_pem)?;
     let policy = recipient_cert_policy();
@@ -20,6 +63,7 @@ pub fn get_key_metadata(cert_pem: String) -> Result<KeyMetadata, anyhow::Error>
     Ok(KeyMetadata {
         fingerprint: cert.fingerprint().to_hex(),
         algo: format!("{}", enc_key.key().pk_algo()),
+        suite: key_suite(enc_key.key()),
         has_secret: enc_key.key().has_unencrypted_secret(),
     })
 }
@@ -1,10 +1,10 @@
+pub mod backup;
 pub mod encryption_key;
 pub mod metadata;
 pub mod parse;
+pub mod revoke;
 pub mod strip;
 pub mod validate;
 
-#[cfg(feature = "frontend-js")]
-pub mod backup;
 #[cfg(feature = "frontend-js")]
 pub mod gen;
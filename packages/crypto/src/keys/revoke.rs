@@ -0,0 +1,52 @@
+use std::io::Write;
+
+use anyhow::anyhow;
+use sequoia_openpgp::{
+    armor::{Kind, Writer},
+    packet::{Packet, Signature},
+    serialize::Serialize,
+    types::ReasonForRevocation,
+};
+
+use super::parse::secret_key_from_pem;
+
+pub(crate) fn armor_signature(signature: Signature) -> Result<String, anyhow::Error> {
+    let mut w = Writer::new(Vec::new(), Kind::Signature)?;
+    Packet::from(signature).serialize(&mut w)?;
+    w.flush()?;
+    String::from_utf8(w.finalize()?).map_err(|e| anyhow!(e))
+}
+
+/// Makes a revocation certificate for a key, signed with its primary key. Uploading it marks the
+/// key as revoked, so new submissions are no longer encrypted to it. `superseded` should be set
+/// when the key is being replaced by a new one, rather than because it was lost or compromised.
+pub fn create_revocation_certificate(
+    private_key_pem: String,
+    superseded: bool,
+) -> Result<String, anyhow::Error> {
+    let cert = secret_key_from_pem(private_key_pem)?;
+    let mut signer = cert
+        .primary_key()
+        .key()
+        .clone()
+        .parts_into_secret()?
+        .into_keypair()?;
+
+    let (code, reason) = if superseded {
+        (ReasonForRevocation::KeySuperseded, "Replaced by a new key")
+    } else {
+        (ReasonForRevocation::Unspecified, "")
+    };
+    let signature = cert.revoke(&mut signer, code, reason.as_bytes())?;
+    armor_signature(signature)
+}
+
+#[cfg(feature = "frontend-js")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn create_revocation_certificate_js(
+    private_key_pem: String,
+    superseded: bool,
+) -> Result<String, wasm_bindgen::JsValue> {
+    create_revocation_certificate(private_key_pem, superseded)
+        .map_err(|e| wasm_bindgen::JsValue::from_str(&e.to_string()))
+}
@@ -1,7 +1,8 @@
+pub mod chain;
+pub mod decrypt;
 pub mod keys;
 pub mod policy;
+pub mod signature;
 
-#[cfg(feature = "frontend-js")]
-pub mod decrypt;
 #[cfg(feature = "frontend-js")]
 pub mod encrypt;
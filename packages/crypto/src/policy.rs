@@ -3,6 +3,8 @@ use sequoia_openpgp::{
     policy::StandardPolicy,
 };
 
+/// The suite new keys are generated with. sequoia-openpgp 2.0 doesn't implement the ML-KEM + X25519
+/// hybrid from the OpenPGP PQC draft yet, so there's no post-quantum suite to offer alongside it.
 pub const NEW_KEY_SUITE: CipherSuite = CipherSuite::Cv25519;
 
 pub fn recipient_cert_policy<'a>() -> StandardPolicy<'a> {
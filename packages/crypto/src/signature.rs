@@ -0,0 +1,94 @@
+use sequoia_openpgp::{
+    cert::CertBuilder,
+    fmt::hex,
+    parse::stream::{MessageLayer, MessageStructure},
+    types::{HashAlgorithm, KeyFlags},
+    Cert,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::policy::NEW_KEY_SUITE;
+
+/// Whether a decrypted submission was signed by the key it was sent with
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum SignatureStatus {
+    #[default]
+    Unsigned,
+    Valid {
+        signer_fingerprint: String,
+        /// Matches the hash on the respondent's receipt
+        submission_hash: String,
+    },
+    Invalid {
+        error: String,
+    },
+}
+
+impl SignatureStatus {
+    /// Reads the result of checking the signatures from a decrypted message. `expected_signer` is
+    /// the key the message was sent with, if any.
+    pub(crate) fn from_message_structure(
+        structure: MessageStructure,
+        expected_signer: Option<&Cert>,
+    ) -> Self {
+        let mut status = Self::Unsigned;
+        for layer in structure.into_iter() {
+            let MessageLayer::SignatureGroup { results } = layer else {
+                continue;
+            };
+            for result in results {
+                match result {
+                    Ok(checksum) => {
+                        return Self::Valid {
+                            signer_fingerprint: checksum.ka.cert().fingerprint().to_hex(),
+                            submission_hash: String::new(),
+                        }
+                    }
+                    Err(e) => {
+                        status = Self::Invalid {
+                            error: e.to_string(),
+                        }
+                    }
+                }
+            }
+        }
+
+        if status == Self::Unsigned && expected_signer.is_some() {
+            return Self::Invalid {
+                error: "Submission was sent with a signing key but isn't signed".to_string(),
+            };
+        }
+        status
+    }
+
+    pub(crate) fn with_submission_hash(self, plaintext: &[u8]) -> Result<Self, anyhow::Error> {
+        Ok(match self {
+            Self::Valid {
+                signer_fingerprint, ..
+            } => Self::Valid {
+                signer_fingerprint,
+                submission_hash: submission_hash(plaintext)?,
+            },
+            other => other,
+        })
+    }
+}
+
+/// The hex SHA-256 hash of the signed plaintext, which the respondent gets on their receipt
+pub fn submission_hash(plaintext: &[u8]) -> Result<String, anyhow::Error> {
+    let mut ctx = HashAlgorithm::SHA256.context()?.for_digest();
+    ctx.update(plaintext);
+    Ok(hex::encode(ctx.into_digest()?))
+}
+
+/// Makes a single-use key for signing one submission. It has no user ID and never expires, so
+/// its signature can be checked for as long as the submission is kept.
+pub fn generate_signing_key() -> Result<Cert, anyhow::Error> {
+    let (cert, _) = CertBuilder::new()
+        .set_cipher_suite(NEW_KEY_SUITE)
+        .set_primary_key_flags(KeyFlags::empty().set_certification().set_signing())
+        .set_validity_period(None)
+        .generate()?;
+    Ok(cert)
+}
@@ -17,6 +17,8 @@ pub struct Model {
     pub id: PalformDatabaseID<IDAdminPublicKey>,
     pub organisation_id: PalformDatabaseID<IDOrganisation>,
     pub user_id: PalformDatabaseID<IDAdminUser>,
+    pub revoked_at: Option<DateTime>,
+    pub expiry_warning_sent_at: Option<DateTime>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
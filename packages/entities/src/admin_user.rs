@@ -17,7 +17,6 @@ pub struct Model {
     pub manual_auth_password_hash: Option<String>,
     pub manual_auth_email_verified: Option<bool>,
     pub org_auth_sub: Option<String>,
-    pub service_account_auth_token: Option<String>,
     pub org_auth_organisation_id: Option<PalformDatabaseID<IDOrganisation>>,
     #[sea_orm(primary_key, auto_increment = false)]
     pub id: PalformDatabaseID<IDAdminUser>,
@@ -47,8 +46,12 @@ pub enum Relation {
     Organisation,
     #[sea_orm(has_many = "super::organisation_membership::Entity")]
     OrganisationMembership,
+    #[sea_orm(has_many = "super::service_account_api_key::Entity")]
+    ServiceAccountApiKey,
     #[sea_orm(has_many = "super::social_auth_connection::Entity")]
     SocialAuthConnection,
+    #[sea_orm(has_many = "super::submission_key_packet::Entity")]
+    SubmissionKeyPacket,
     #[sea_orm(has_many = "super::team_membership::Entity")]
     TeamMembership,
 }
@@ -95,12 +98,24 @@ impl Related<super::organisation_membership::Entity> for Entity {
     }
 }
 
+impl Related<super::service_account_api_key::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ServiceAccountApiKey.def()
+    }
+}
+
 impl Related<super::social_auth_connection::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::SocialAuthConnection.def()
     }
 }
 
+impl Related<super::submission_key_packet::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SubmissionKeyPacket.def()
+    }
+}
+
 impl Related<super::team_membership::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::TeamMembership.def()
@@ -14,6 +14,14 @@ pub struct Model {
     pub id: PalformDatabaseID<IDSubmission>,
     pub deleted_at: DateTime,
     pub form_id: PalformDatabaseID<IDForm>,
+    pub chain_index: Option<i64>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub chain_hash: Option<String>,
+    pub created_chain_index: Option<i64>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub created_data_hash: Option<String>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub created_chain_hash: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -17,6 +17,7 @@ pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
     pub id: PalformDatabaseID<IDFillAccessToken>,
     pub form_id: PalformDatabaseID<IDForm>,
+    pub expiry_event_queued: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -1,7 +1,7 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.0
 
 use palform_tsid::{
-    resources::{IDForm, IDFormBranding, IDTeam},
+    resources::{IDForm, IDFormBranding, IDFormRevision, IDTeam},
     tsid::PalformDatabaseID,
 };
 use sea_orm::entity::prelude::*;
@@ -23,6 +23,15 @@ pub struct Model {
     pub branding_id: Option<PalformDatabaseID<IDFormBranding>>,
     pub team_id: PalformDatabaseID<IDTeam>,
     pub one_question_per_page: bool,
+    pub published_revision_id: Option<PalformDatabaseID<IDFormRevision>>,
+    pub published_at: Option<DateTime>,
+    pub opens_at: Option<DateTime>,
+    pub closes_at: Option<DateTime>,
+    pub signed_submissions: bool,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub submission_chain_head: Option<String>,
+    pub submission_chain_length: i64,
+    pub submissions_without_file_ids: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -39,12 +48,24 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     FormBranding,
+    #[sea_orm(has_many = "super::form_revision::Entity")]
+    FormRevision,
+    #[sea_orm(
+        belongs_to = "super::form_revision::Entity",
+        from = "Column::PublishedRevisionId",
+        to = "super::form_revision::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    PublishedRevision,
     #[sea_orm(has_one = "super::form_template::Entity")]
     FormTemplate,
     #[sea_orm(has_many = "super::question_group::Entity")]
     QuestionGroup,
     #[sea_orm(has_many = "super::submission::Entity")]
     Submission,
+    #[sea_orm(has_many = "super::submission_file::Entity")]
+    SubmissionFile,
     #[sea_orm(
         belongs_to = "super::team::Entity",
         from = "Column::TeamId",
@@ -75,6 +96,12 @@ impl Related<super::form_branding::Entity> for Entity {
     }
 }
 
+impl Related<super::form_revision::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::FormRevision.def()
+    }
+}
+
 impl Related<super::form_template::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::FormTemplate.def()
@@ -93,6 +120,12 @@ impl Related<super::submission::Entity> for Entity {
     }
 }
 
+impl Related<super::submission_file::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SubmissionFile.def()
+    }
+}
+
 impl Related<super::team::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Team.def()
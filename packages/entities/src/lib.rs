@@ -15,9 +15,11 @@ pub mod fill_access_token;
 pub mod form;
 pub mod form_branding;
 pub mod form_branding_team_access;
+pub mod form_revision;
 pub mod form_template;
 pub mod form_template_category;
 pub mod form_template_category_assignment;
+pub mod mail_outbox;
 pub mod organisation;
 pub mod organisation_auth_config;
 pub mod organisation_auth_team_mapping;
@@ -26,11 +28,16 @@ pub mod organisation_invite;
 pub mod organisation_membership;
 pub mod question;
 pub mod question_group;
+pub mod rate_limit_bucket;
 pub mod sea_orm_active_enums;
+pub mod service_account_api_key;
 pub mod social_auth_connection;
 pub mod submission;
+pub mod submission_file;
+pub mod submission_key_packet;
 pub mod team;
 pub mod team_asset;
 pub mod team_membership;
 pub mod webhook;
+pub mod webhook_event_subscription;
 pub mod webhook_job;
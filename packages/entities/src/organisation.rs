@@ -30,6 +30,8 @@ pub enum Relation {
     AdminUser,
     #[sea_orm(has_many = "super::audit_log_entry::Entity")]
     AuditLogEntry,
+    #[sea_orm(has_many = "super::mail_outbox::Entity")]
+    MailOutbox,
     #[sea_orm(
         belongs_to = "super::organisation_auth_config::Entity",
         from = "Column::AuthConfig",
@@ -58,6 +60,12 @@ impl Related<super::audit_log_entry::Entity> for Entity {
     }
 }
 
+impl Related<super::mail_outbox::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::MailOutbox.def()
+    }
+}
+
 impl Related<super::organisation_auth_config::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::OrganisationAuthConfig.def()
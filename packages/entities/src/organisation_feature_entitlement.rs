@@ -22,6 +22,7 @@ pub struct Model {
     pub oidc: bool,
     pub submission_auto_delete: bool,
     pub form_captcha: bool,
+    pub submission_file_storage_mb: Option<i32>,
     #[sea_orm(primary_key, auto_increment = false)]
     pub organisation_id: PalformDatabaseID<IDOrganisation>,
 }
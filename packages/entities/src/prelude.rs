@@ -13,9 +13,11 @@ pub use super::fill_access_token::Entity as FillAccessToken;
 pub use super::form::Entity as Form;
 pub use super::form_branding::Entity as FormBranding;
 pub use super::form_branding_team_access::Entity as FormBrandingTeamAccess;
+pub use super::form_revision::Entity as FormRevision;
 pub use super::form_template::Entity as FormTemplate;
 pub use super::form_template_category::Entity as FormTemplateCategory;
 pub use super::form_template_category_assignment::Entity as FormTemplateCategoryAssignment;
+pub use super::mail_outbox::Entity as MailOutbox;
 pub use super::organisation::Entity as Organisation;
 pub use super::organisation_auth_config::Entity as OrganisationAuthConfig;
 pub use super::organisation_auth_team_mapping::Entity as OrganisationAuthTeamMapping;
@@ -24,10 +26,15 @@ pub use super::organisation_invite::Entity as OrganisationInvite;
 pub use super::organisation_membership::Entity as OrganisationMembership;
 pub use super::question::Entity as Question;
 pub use super::question_group::Entity as QuestionGroup;
+pub use super::rate_limit_bucket::Entity as RateLimitBucket;
+pub use super::service_account_api_key::Entity as ServiceAccountApiKey;
 pub use super::social_auth_connection::Entity as SocialAuthConnection;
 pub use super::submission::Entity as Submission;
+pub use super::submission_file::Entity as SubmissionFile;
+pub use super::submission_key_packet::Entity as SubmissionKeyPacket;
 pub use super::team::Entity as Team;
 pub use super::team_asset::Entity as TeamAsset;
 pub use super::team_membership::Entity as TeamMembership;
 pub use super::webhook::Entity as Webhook;
+pub use super::webhook_event_subscription::Entity as WebhookEventSubscription;
 pub use super::webhook_job::Entity as WebhookJob;
@@ -20,6 +20,8 @@ pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
     pub id: PalformDatabaseID<IDQuestion>,
     pub group_id: PalformDatabaseID<IDQuestionGroup>,
+    #[sea_orm(column_type = "JsonBinary", nullable)]
+    pub server_validation: Option<Json>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -90,6 +90,8 @@ pub enum AuditLogVerbEnum {
     Update,
     #[sea_orm(string_value = "delete")]
     Delete,
+    #[sea_orm(string_value = "publish")]
+    Publish,
 }
 #[derive(
     Debug,
@@ -219,3 +221,33 @@ pub enum OrganisationMemberRoleEnum {
     #[sea_orm(string_value = "admin")]
     Admin,
 }
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    Serialize,
+    Deserialize,
+    schemars :: JsonSchema,
+)]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "Enum",
+    enum_name = "webhook_event_enum"
+)]
+pub enum WebhookEventEnum {
+    #[sea_orm(string_value = "submission_created")]
+    SubmissionCreated,
+    #[sea_orm(string_value = "submission_deleted")]
+    SubmissionDeleted,
+    #[sea_orm(string_value = "form_updated")]
+    FormUpdated,
+    #[sea_orm(string_value = "fill_token_created")]
+    FillTokenCreated,
+    #[sea_orm(string_value = "fill_token_expired")]
+    FillTokenExpired,
+    #[sea_orm(string_value = "auto_delete_ran")]
+    AutoDeleteRan,
+}
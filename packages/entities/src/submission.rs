@@ -1,7 +1,7 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.0
 
 use palform_tsid::{
-    resources::{IDFillAccessToken, IDForm, IDSubmission},
+    resources::{IDFillAccessToken, IDForm, IDFormRevision, IDSubmission},
     tsid::PalformDatabaseID,
 };
 use sea_orm::entity::prelude::*;
@@ -17,6 +17,14 @@ pub struct Model {
     pub id: PalformDatabaseID<IDSubmission>,
     pub for_token: Option<PalformDatabaseID<IDFillAccessToken>>,
     pub form_id: PalformDatabaseID<IDForm>,
+    pub form_revision_id: Option<PalformDatabaseID<IDFormRevision>>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub signing_key: Option<String>,
+    pub chain_index: Option<i64>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub data_hash: Option<String>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub chain_hash: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -37,6 +45,18 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Form,
+    #[sea_orm(
+        belongs_to = "super::form_revision::Entity",
+        from = "Column::FormRevisionId",
+        to = "super::form_revision::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    FormRevision,
+    #[sea_orm(has_many = "super::submission_file::Entity")]
+    SubmissionFile,
+    #[sea_orm(has_many = "super::submission_key_packet::Entity")]
+    SubmissionKeyPacket,
     #[sea_orm(has_many = "super::webhook_job::Entity")]
     WebhookJob,
 }
@@ -53,6 +73,24 @@ impl Related<super::form::Entity> for Entity {
     }
 }
 
+impl Related<super::form_revision::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::FormRevision.def()
+    }
+}
+
+impl Related<super::submission_file::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SubmissionFile.def()
+    }
+}
+
+impl Related<super::submission_key_packet::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SubmissionKeyPacket.def()
+    }
+}
+
 impl Related<super::webhook_job::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::WebhookJob.def()
@@ -13,6 +13,9 @@ pub struct Model {
     pub endpoint: String,
     pub signing_secret: String,
     pub created_at: DateTime,
+    pub previous_signing_secret: Option<String>,
+    pub previous_signing_secret_expires_at: Option<DateTime>,
+    pub legacy_signature: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -25,6 +28,8 @@ pub enum Relation {
         on_delete = "NoAction"
     )]
     Form,
+    #[sea_orm(has_many = "super::webhook_event_subscription::Entity")]
+    WebhookEventSubscription,
     #[sea_orm(has_many = "super::webhook_job::Entity")]
     WebhookJob,
 }
@@ -35,6 +40,12 @@ impl Related<super::form::Entity> for Entity {
     }
 }
 
+impl Related<super::webhook_event_subscription::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::WebhookEventSubscription.def()
+    }
+}
+
 impl Related<super::webhook_job::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::WebhookJob.def()
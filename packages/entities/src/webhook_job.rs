@@ -1,5 +1,6 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.0
 
+use super::sea_orm_active_enums::WebhookEventEnum;
 use palform_tsid::{resources::{IDSubmission, IDWebhook, IDWebhookJob}, tsid::PalformDatabaseID};
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -10,11 +11,17 @@ pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
     pub id: PalformDatabaseID<IDWebhookJob>,
     pub webhook_id: PalformDatabaseID<IDWebhook>,
-    pub submission_id: PalformDatabaseID<IDSubmission>,
+    pub submission_id: Option<PalformDatabaseID<IDSubmission>>,
     pub created_at: DateTime,
     pub done_at: Option<DateTime>,
     pub error: Option<String>,
     pub retries: i32,
+    pub next_attempt_at: DateTime,
+    pub dead_at: Option<DateTime>,
+    pub locked_until: Option<DateTime>,
+    pub event: WebhookEventEnum,
+    #[sea_orm(column_type = "JsonBinary", nullable)]
+    pub payload: Option<Json>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -0,0 +1,29 @@
+import * as Comlink from "comlink";
+import { APIs } from "../common";
+import ProofOfWorkWorker from "./proofOfWorkWorker?worker";
+import type { SolveProofOfWorkFunction } from "./proofOfWorkWorker";
+
+export type CaptchaProvider =
+    | "turnstile"
+    | "hcaptcha"
+    | "recaptcha"
+    | "proof_of_work";
+
+export const captchaProvider: CaptchaProvider =
+    import.meta.env.VITE_CAPTCHA_PROVIDER ?? "turnstile";
+
+/**
+ * Gets a challenge from the backend and solves it in a worker, so the page stays responsive.
+ * Returns the value to send as the captcha response.
+ */
+export async function solveProofOfWorkCaptcha() {
+    const resp = await APIs.captcha.captchaChallenge();
+
+    const worker = new ProofOfWorkWorker();
+    try {
+        const solve = Comlink.wrap<SolveProofOfWorkFunction>(worker);
+        return await solve(resp.data.challenge);
+    } finally {
+        worker.terminate();
+    }
+}
@@ -0,0 +1,11 @@
+import * as Comlink from "comlink";
+
+async function solveProofOfWork(challenge: string) {
+    const { solve_proof_of_work_js } = await import(
+        "@paltiverse/palform-client-common"
+    );
+    return solve_proof_of_work_js(challenge);
+}
+
+Comlink.expose(solveProofOfWork);
+export type SolveProofOfWorkFunction = typeof solveProofOfWork;
@@ -6,12 +6,14 @@ import {
 	BillingEntitlementsApi,
 	BillingInvoicesApi,
 	BillingPlansApi,
+	CaptchaApi,
 	Class2FAMethodsApi,
 	Configuration,
 	CountryMetadataApi,
 	FeedbackApi,
 	FillAccessTokensApi,
 	FormBrandingsApi,
+	FormRevisionsApi,
 	FormTemplatesApi,
 	FormsApi,
 	InductionApi,
@@ -69,6 +71,7 @@ export const APIs = {
 	orgTeams: () => apiWithAuth(OrganisationTeamsApi, baseAPIConfig),
 	orgTeamMembers: () => apiWithAuth(OrganisationTeamMembersApi, baseAPIConfig),
 	formBrandings: () => apiWithAuth(FormBrandingsApi, baseAPIConfig),
+	formRevisions: () => apiWithAuth(FormRevisionsApi, baseAPIConfig),
 	teamAssets: () => apiWithAuth(TeamAssetsApi, baseAPIConfig),
 	forms: () => apiWithAuth(FormsApi, baseAPIConfig),
 	formTemplates: new FormTemplatesApi(new Configuration(baseAPIConfig)),
@@ -100,6 +103,7 @@ export const APIs = {
 	countries: new CountryMetadataApi(new Configuration(baseAPIConfig)),
 	webhooks: () => apiWithAuth(WebhooksApi, baseAPIConfig),
 	feedback: new FeedbackApi(new Configuration(baseAPIConfig)),
+	captcha: new CaptchaApi(new Configuration(baseAPIConfig)),
 };
 
 // biome-ignore lint/suspicious/noExplicitAny: catch has an any error type
@@ -129,6 +133,12 @@ export function humaniseAPIErrorInternal(d: APIError, resourceName = "That") {
 	if ("CaptchaError" in d) {
 		return `Captcha: ${d.CaptchaError}`;
 	}
+	if ("PayloadTooLarge" in d) {
+		return d.PayloadTooLarge;
+	}
+	if ("RateLimited" in d) {
+		return d.RateLimited;
+	}
 
 	return t("error_upgrade") + ": " + d.SubscriptionLimit;
 }
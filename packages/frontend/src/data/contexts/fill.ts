@@ -17,6 +17,7 @@ import type {
     APIQuestionGroup,
 } from "@paltiverse/palform-typescript-openapi";
 import { qIsHidden, qIsInfo } from "./formEditor";
+import type { SubmissionReceipt } from "../crypto/submissions";
 
 const formFillSaveMutex = new Mutex();
 export interface FormFillContext {
@@ -68,6 +69,7 @@ export interface FillSendState {
     loading: boolean;
     error: string | undefined;
     done: boolean;
+    receipt?: SubmissionReceipt;
 }
 export const fillSendStore = writable<FillSendState | undefined>(undefined);
 
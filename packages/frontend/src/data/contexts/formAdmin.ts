@@ -1,5 +1,6 @@
 import type {
     APIFillToken,
+    APIFormRevision,
     APIQuestion,
     APIQuestionGroup,
     APIQuestionGroupStepStrategy,
@@ -10,6 +11,7 @@ import {
     type DecryptedSubmission,
     type DecryptedSubmissionSuccess,
 } from "../crypto/results";
+import type { SubmissionChainStatus } from "../crypto/chain";
 import { derived, readable, type Writable } from "svelte/store";
 import { getContext, setContext } from "svelte";
 
@@ -19,6 +21,10 @@ export interface FormAdminContext {
     groups: APIQuestionGroup[];
     submissions: DecryptedSubmission[];
     tokens: APIFillToken[];
+    /** The revisions that submissions were made against */
+    revisions: APIFormRevision[];
+    /** Whether the synced submissions match the form's submission chain */
+    chainStatus?: SubmissionChainStatus;
 }
 
 export function setFormAdminContext(ctx: Writable<FormAdminContext>) {
@@ -29,11 +35,19 @@ export function setFormAdminContext(ctx: Writable<FormAdminContext>) {
 export function getFormAdminContext() {
     return getContext<Writable<FormAdminContext>>("responseCtx");
 }
+function revisionsNewestFirst(ctx: FormAdminContext) {
+    return [...(ctx.revisions ?? [])].sort((a, b) => b.number - a.number);
+}
 export function ctxGetGroup(groupId: string) {
     return derived(
         [readable(groupId), getFormAdminContext()],
         ([groupId, ctx]) => {
-            return ctx.groups.find((e) => e.id === groupId);
+            return (
+                ctx.groups.find((e) => e.id === groupId) ??
+                revisionsNewestFirst(ctx)
+                    .flatMap((r) => r.groups)
+                    .find((e) => e.id === groupId)
+            );
         }
     );
 }
@@ -42,7 +56,12 @@ export function ctxGetQuestion(questionId: string) {
     return derived(
         [readable(questionId), getFormAdminContext()],
         ([_, $ctx]) => {
-            return $ctx.questions.find((q) => q.id === questionId);
+            return (
+                $ctx.questions.find((q) => q.id === questionId) ??
+                revisionsNewestFirst($ctx)
+                    .flatMap((r) => r.questions)
+                    .find((q) => q.id === questionId)
+            );
         }
     );
 }
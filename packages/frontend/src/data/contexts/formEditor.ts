@@ -121,6 +121,7 @@ export function insertQuestion(
 		required: false,
 		configuration: default_question_for_type_js(questionType),
 		group_id: groupId,
+		server_validation: null,
 	};
 	ctx.update((ctx) => {
 		if (!(groupId in ctx.questions)) {
@@ -0,0 +1,155 @@
+import type { APISubmission } from "@paltiverse/palform-typescript-openapi";
+import {
+    submission_chain_checkpoint_message_js,
+    verify_submission_chain_extends_js,
+    verify_submission_chain_js,
+} from "@paltiverse/palform-client-common";
+import { submission_data_hash_js } from "@paltiverse/palform-crypto";
+import { APIs } from "../common";
+import { serverSigningKeyDb, submissionChainCheckpointDb } from "../pouch";
+
+export type SubmissionChainStatus =
+    | { ok: true; length: number }
+    | { ok: false; error: string };
+
+function decodeBase64(data: string) {
+    return Uint8Array.from(atob(data), (c) => c.charCodeAt(0));
+}
+
+// If set, this is the only key we'll accept checkpoints from. Otherwise we trust the first key we
+// see for each org.
+const configuredSigningKey = import.meta.env.VITE_SERVER_SIGNING_KEY as
+    | string
+    | undefined;
+
+async function checkSigningKeyPinned(orgId: string, signingKey: string) {
+    if (configuredSigningKey) {
+        return configuredSigningKey === signingKey;
+    }
+
+    try {
+        const pinned = await serverSigningKeyDb.get(orgId);
+        return pinned.signingKey === signingKey;
+    } catch (e) {
+        await serverSigningKeyDb.put({ _id: orgId, signingKey });
+        return true;
+    }
+}
+
+async function getPreviousCheckpoint(formId: string) {
+    try {
+        return await submissionChainCheckpointDb.get(formId);
+    } catch (e) {
+        return undefined;
+    }
+}
+
+async function verifyCheckpointSignature(
+    message: string,
+    signature: string,
+    signingKey: string
+) {
+    const key = await crypto.subtle.importKey(
+        "raw",
+        decodeBase64(signingKey),
+        { name: "Ed25519" },
+        false,
+        ["verify"]
+    );
+    return await crypto.subtle.verify(
+        { name: "Ed25519" },
+        key,
+        decodeBase64(signature),
+        new TextEncoder().encode(message)
+    );
+}
+
+/**
+ * Checks the submissions we've synced against the form's submission chain, to make sure the
+ * server hasn't left any out or changed them since they were sent. `newSubmissions` are the ones
+ * we've just downloaded, whose encrypted data can be checked too.
+ */
+export async function checkSubmissionChain(
+    orgId: string,
+    formId: string,
+    newSubmissions: APISubmission[],
+    submissionIds: string[],
+    deletedIds: string[]
+): Promise<SubmissionChainStatus> {
+    const resp = await APIs.submissions().then((a) =>
+        a.submissionsChain(orgId, formId)
+    );
+    const { entries, checkpoint, signature, signing_key } = resp.data;
+
+    const message = submission_chain_checkpoint_message_js(checkpoint);
+    if (!(await verifyCheckpointSignature(message, signature, signing_key))) {
+        return { ok: false, error: "The checkpoint signature is not valid" };
+    }
+
+    if (!(await checkSigningKeyPinned(orgId, signing_key))) {
+        return {
+            ok: false,
+            error: "The checkpoint was signed by a key we haven't seen before",
+        };
+    }
+
+    const previous = await getPreviousCheckpoint(formId);
+    try {
+        verify_submission_chain_js(entries, checkpoint);
+        if (previous) {
+            verify_submission_chain_extends_js(entries, {
+                form_id: formId,
+                length: previous.length,
+                head: previous.head,
+                created_at: checkpoint.created_at,
+            });
+        }
+    } catch (e) {
+        return { ok: false, error: String(e) };
+    }
+
+    const dataHashes = new Map<string, string>();
+    for (const entry of entries) {
+        if (entry.kind === "created" && entry.data_hash) {
+            dataHashes.set(entry.submission_id, entry.data_hash);
+        } else if (entry.kind === "deleted") {
+            dataHashes.delete(entry.submission_id);
+            if (!deletedIds.includes(entry.submission_id)) {
+                return {
+                    ok: false,
+                    error: `Response ${entry.submission_id} was deleted, but isn't listed as deleted`,
+                };
+            }
+        }
+    }
+
+    const haveIds = new Set(submissionIds);
+    const missing = [...dataHashes.keys()].filter((id) => !haveIds.has(id));
+    if (missing.length > 0) {
+        return {
+            ok: false,
+            error: `${missing.length} response(s) are in the chain but weren't downloaded`,
+        };
+    }
+
+    for (const submission of newSubmissions) {
+        const expected = dataHashes.get(submission.id);
+        if (expected === undefined) continue;
+        if (submission_data_hash_js(submission.data) !== expected) {
+            return {
+                ok: false,
+                error: `Response ${submission.id} doesn't match its hash in the chain`,
+            };
+        }
+    }
+
+    await submissionChainCheckpointDb.put({
+        _id: formId,
+        _rev: previous?._rev,
+        orgId,
+        length: checkpoint.length,
+        head: checkpoint.head,
+    });
+
+    return { ok: true, length: checkpoint.length };
+}
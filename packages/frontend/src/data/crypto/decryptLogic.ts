@@ -3,9 +3,10 @@ import type {
     DecryptedSubmission,
     DecryptedSubmissionBase,
     DecryptedSubmissionSuccess,
+    SubmissionSignatureStatus,
 } from "./results";
 import type {
-    decrypt_decode_submission_js,
+    decrypt_verify_submission_js,
     KeyResolver,
 } from "@paltiverse/palform-crypto";
 import type { InProgressSubmission } from "@paltiverse/palform-client-js-extra-types/InProgressSubmission";
@@ -17,7 +18,7 @@ export async function decryptAllSubmissionsInternal(
     allHook: () => void,
 
     wasm: {
-        decrypt_decode_submission_js: typeof decrypt_decode_submission_js;
+        decrypt_verify_submission_js: typeof decrypt_verify_submission_js;
         KeyResolver: typeof KeyResolver;
     }
 ) {
@@ -30,17 +31,23 @@ export async function decryptAllSubmissionsInternal(
             id: sub.id,
             createdAt: sub.created_at,
             forToken: sub.for_token ?? null,
+            formRevisionId: sub.form_revision_id ?? null,
         };
 
         try {
-            const result = wasm.decrypt_decode_submission_js(
+            const result = wasm.decrypt_verify_submission_js(
                 sub.data,
-                resolver
-            ) as InProgressSubmission;
+                resolver,
+                sub.signing_key ?? undefined
+            ) as {
+                submission: InProgressSubmission;
+                signature: SubmissionSignatureStatus;
+            };
             const submission: DecryptedSubmissionSuccess = {
                 ...base,
-                questions: result.questions,
-                groups: result.groups_completed,
+                questions: result.submission.questions,
+                groups: result.submission.groups_completed,
+                signature: result.signature,
             };
             d.push(submission);
             await successHook(submission);
@@ -9,7 +9,7 @@ async function decryptAllSubmissions(
     statusUpdate: () => void,
     cacheSubmission: (submission: DecryptedSubmissionSuccess) => Promise<void>
 ) {
-    const { decrypt_decode_submission_js, KeyResolver } = await import(
+    const { decrypt_verify_submission_js, KeyResolver } = await import(
         "@paltiverse/palform-crypto"
     );
 
@@ -18,7 +18,7 @@ async function decryptAllSubmissions(
         keyPEMs,
         cacheSubmission,
         statusUpdate,
-        { decrypt_decode_submission_js, KeyResolver }
+        { decrypt_verify_submission_js, KeyResolver }
     );
 }
 
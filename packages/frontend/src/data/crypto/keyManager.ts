@@ -6,6 +6,7 @@ import {
     type CryptoKeyRecord,
 } from "../pouch";
 import {
+    create_revocation_certificate_js,
     decrypt_backed_up_key_js,
     encrypt_key_for_backup_js,
     generate_certificate_js,
@@ -13,7 +14,9 @@ import {
     strip_secret_bits_js,
 } from "@paltiverse/palform-crypto";
 import downloadFile from "../util/downloadFile";
+import { parseServerTime } from "../util/time";
 import type { APIUserKey } from "@paltiverse/palform-typescript-openapi";
+import { rewrapSubmissionsForForm } from "./rewrap";
 
 export async function findKey(
     serverId: string
@@ -46,6 +49,7 @@ export async function registerKey(expiryDays: number, orgId: string) {
         serverId: serverIdResp.data,
         orgId,
         userId,
+        revocation: newKeypair.revocation,
     });
 
     return serverIdResp.data;
@@ -80,6 +84,69 @@ export function downloadPrivateKey(record: CryptoKeyRecord) {
     downloadFile(`${record.serverId}.asc`, record.privateKey);
 }
 
+export function downloadRevocation(record: CryptoKeyRecord) {
+    if (!record.revocation) return;
+    downloadFile(`${record.serverId}-revocation.asc`, record.revocation);
+}
+
+/**
+ * Revokes a key using the revocation certificate saved when it was generated, or a new one made
+ * with the private key. Pass `revocation` to upload a certificate from somewhere else (e.g. if
+ * the private key was lost).
+ */
+export async function revokeKey(
+    orgId: string,
+    serverId: string,
+    superseded: boolean,
+    revocation?: string
+) {
+    if (revocation === undefined) {
+        const keyRecord = await findKey(serverId);
+        if (!keyRecord)
+            throw new Error(`Key with server ID ${serverId} not found`);
+
+        revocation = superseded
+            ? create_revocation_certificate_js(keyRecord.privateKey, true)
+            : (keyRecord.revocation ??
+              create_revocation_certificate_js(keyRecord.privateKey, false));
+    }
+
+    await APIs.keys().then((a) =>
+        a.keysRevoke(orgId, serverId, {
+            revocation,
+        })
+    );
+}
+
+/**
+ * Replaces a key with a new one with the same validity period. Before the old key is revoked,
+ * every submission we can read is re-wrapped so that the rest of the team (including the new key)
+ * can still read it. Forms we can't edit are skipped. Returns the server ID of the new key.
+ */
+export async function rotateKey(orgId: string, oldKey: APIUserKey) {
+    const validity = parseServerTime(oldKey.expires_at).diff(
+        parseServerTime(oldKey.created_at),
+        ["years", "days"]
+    );
+    // Keys that never expire are stored with an expiry far in the future
+    const validityDays =
+        validity.years > 80 ? 0 : Math.round(validity.as("days"));
+    const newServerId = await registerKey(validityDays, orgId);
+    if (!newServerId) return;
+
+    const forms = await APIs.forms().then((a) => a.formsList(orgId));
+    for (const form of forms.data) {
+        try {
+            await rewrapSubmissionsForForm(orgId, form.id);
+        } catch (e) {
+            console.warn(`Failed to re-wrap submissions for ${form.id}`, e);
+        }
+    }
+
+    await revokeKey(orgId, oldKey.id, true);
+    return newServerId;
+}
+
 export async function deleteLocalKey(record: CryptoKeyRecord) {
     if (record._rev === undefined) return;
     await privateKeyDb.remove({
@@ -134,6 +201,9 @@ export async function restoreKeyFromBackup(
 export async function checkLocalKeyAvailability(keys: APIUserKey[]) {
     if (keys.length === 0) return;
 
+    keys = keys.filter((k) => !k.revoked_at);
+    if (keys.length === 0) return;
+
     for (const apiKey of keys) {
         const localKey = await findKey(apiKey.id);
         if (localKey) return;
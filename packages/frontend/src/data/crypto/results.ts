@@ -12,19 +12,28 @@ import { parseServerTime } from "../util/time";
 import type { FormAdminContext } from "../contexts/formAdmin";
 import {
     decrypt_blob_js,
-    decrypt_decode_submission_js,
+    decrypt_verify_submission_js,
     KeyResolver,
 } from "@paltiverse/palform-crypto";
 import { decryptAllSubmissionsInternal } from "./decryptLogic";
+import { checkSubmissionChain, type SubmissionChainStatus } from "./chain";
 
 export interface DecryptedSubmissionBase {
     id: string;
     createdAt: string;
     forToken: string | null;
+    /** Submissions cached before revisions were added won't have this */
+    formRevisionId?: string | null;
 }
+export type SubmissionSignatureStatus =
+    | { status: "unsigned" }
+    | { status: "valid"; signer_fingerprint: string; submission_hash: string }
+    | { status: "invalid"; error: string };
 export interface DecryptedSubmissionSuccess extends DecryptedSubmissionBase {
     questions: QuestionSubmission[];
     groups: string[];
+    /** Submissions cached before signatures were checked won't have this */
+    signature?: SubmissionSignatureStatus;
 }
 export interface DecryptedSubmissionError extends DecryptedSubmissionBase {
     error: string;
@@ -123,7 +132,7 @@ export async function downloadSubmissionsForForm(
             cacheSubmission,
             updateStatus,
             {
-                decrypt_decode_submission_js,
+                decrypt_verify_submission_js,
                 KeyResolver,
             }
         );
@@ -160,8 +169,16 @@ export async function downloadSubmissionsForForm(
         return aDate === bDate ? 0 : aDate < bDate ? -1 : 1;
     });
 
+    const chainStatus = await checkSubmissionChain(
+        orgId,
+        formId,
+        sStream.new,
+        flatResp.map((s) => s.id),
+        sStream.deleted
+    ).catch((e): SubmissionChainStatus => ({ ok: false, error: String(e) }));
+
     formAdminCtx.update((ctx) => {
-        return { ...ctx, submissions: flatResp };
+        return { ...ctx, submissions: flatResp, chainStatus };
     });
 }
 
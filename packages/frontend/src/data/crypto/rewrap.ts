@@ -0,0 +1,61 @@
+import type { APISubmissionRewrap } from "@paltiverse/palform-typescript-openapi";
+import { KeyResolver, rewrap_message_js } from "@paltiverse/palform-crypto";
+import { APIs } from "../common";
+import { getPrivateKeys } from "../contexts/keys";
+
+const REWRAP_BATCH_SIZE = 100;
+
+/**
+ * Encrypts the session key of each of the form's submissions to any team keys that can't read it
+ * yet, using the keys on this device. Submissions none of our keys can read are skipped. Returns
+ * the number of submissions that were updated.
+ *
+ * With `replaceExisting`, every team key gets a new packet, and the server replaces any that an
+ * earlier rewrap added. This fixes packets that were added but don't actually work.
+ */
+export async function rewrapSubmissionsForForm(
+    orgId: string,
+    formId: string,
+    replaceExisting = false
+) {
+    const [submissionsResp, recipientsResp] = await Promise.all([
+        APIs.submissions().then((a) => a.submissionsList(orgId, formId)),
+        APIs.submissions().then((a) =>
+            a.submissionsRecipients(orgId, formId)
+        ),
+    ]);
+
+    const resolver = new KeyResolver(await getPrivateKeys());
+    const rewraps: APISubmissionRewrap[] = [];
+    for (const submission of submissionsResp.data.new) {
+        try {
+            const keyPackets = rewrap_message_js(
+                submission.data,
+                resolver,
+                recipientsResp.data,
+                replaceExisting
+            );
+            if (keyPackets !== undefined) {
+                rewraps.push({
+                    submission_id: submission.id,
+                    key_packets: keyPackets,
+                });
+            }
+        } catch {
+            // None of our keys can read it, so nor can we share it
+            continue;
+        }
+    }
+
+    for (let i = 0; i < rewraps.length; i += REWRAP_BATCH_SIZE) {
+        await APIs.submissions().then((a) =>
+            a.submissionsRewrap(
+                orgId,
+                formId,
+                rewraps.slice(i, i + REWRAP_BATCH_SIZE)
+            )
+        );
+    }
+
+    return rewraps.length;
+}
@@ -1,6 +1,9 @@
 import type { InProgressSubmissionRecord } from "../pouch";
 import type { InProgressSubmission } from "@paltiverse/palform-client-js-extra-types/InProgressSubmission";
 import { APIs } from "../common";
+import type { APIQuestion } from "@paltiverse/palform-typescript-openapi";
+import { form_fill_request_js } from "@paltiverse/palform-client-common";
+import { encrypt_signed_submission_js } from "@paltiverse/palform-crypto";
 import { createMessage, type Key, readKey } from "openpgp";
 import {
     filterKeysByFingerprint,
@@ -71,15 +74,26 @@ export async function encryptSubmissionAsset(
     return new TextEncoder().encode(encryptedData.armor());
 }
 
+/** Lets the respondent prove what they sent, if the form asks for signed submissions */
+export interface SubmissionReceipt {
+    formId: string;
+    submittedAt: string;
+    signerFingerprint: string;
+    submissionHash: string;
+}
+
 export async function sendSubmission(
     submission: InProgressSubmissionRecord,
+    questions: APIQuestion[],
+    revisionId: string | null | undefined,
     orgId: string,
     formId: string,
     fillAccessToken: string,
     lastGroupId: string,
     requireFingerprints: boolean,
+    signed: boolean,
     captchaValue?: string
-) {
+): Promise<SubmissionReceipt | undefined> {
     const formKeys = await getFormKeys(
         orgId,
         formId,
@@ -93,18 +107,48 @@ export async function sendSubmission(
         groups_completed: [...submission.groups_completed, lastGroupId],
     };
 
-    const encodedSubmission = new TextEncoder().encode(
-        JSON.stringify(submissionToEncrypt)
-    );
-    const encryptedSubmission = await encryptAnything(
-        encodedSubmission,
-        formKeys
+    let armoredSubmission: string;
+    let signingKey: string | undefined;
+    let receipt: SubmissionReceipt | undefined;
+    if (signed) {
+        const signedSubmission = encrypt_signed_submission_js(
+            submissionToEncrypt,
+            formKeys.map((k) => k.armor())
+        );
+        armoredSubmission = signedSubmission.message;
+        signingKey = signedSubmission.signing_key;
+        receipt = {
+            formId,
+            submittedAt: new Date().toISOString(),
+            signerFingerprint: signedSubmission.signer_fingerprint,
+            submissionHash: signedSubmission.submission_hash,
+        };
+    } else {
+        const encodedSubmission = new TextEncoder().encode(
+            JSON.stringify(submissionToEncrypt)
+        );
+        const encryptedSubmission = await encryptAnything(
+            encodedSubmission,
+            formKeys
+        );
+        armoredSubmission = encryptedSubmission.armor();
+    }
+
+    // Questions can opt in to sending a copy of their answer for the server to validate
+    const body = form_fill_request_js(
+        armoredSubmission,
+        questions,
+        submissionToEncrypt.questions,
+        submissionToEncrypt.groups_completed,
+        revisionId ?? null,
+        signingKey
     );
 
     await APIs.fill(fillAccessToken).forms.formsFill(
         orgId,
         formId,
-        encryptedSubmission.armor(),
+        body,
         captchaValue
     );
+    return receipt;
 }
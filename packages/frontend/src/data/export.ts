@@ -1,5 +1,8 @@
-import { export_submissions_js } from "@paltiverse/palform-client-common";
-import type { ResponsesContext } from "./contexts/results";
+import {
+    export_submissions_js,
+    merge_form_revisions_js,
+} from "@paltiverse/palform-client-common";
+import type { FormAdminContext } from "./contexts/formAdmin";
 import {
     submissionIsSuccess,
     type DecryptedSubmissionSuccess,
@@ -9,7 +12,7 @@ import downloadFile from "./util/downloadFile";
 export interface ExportSubmissionsConfig {
     use_question_ids: boolean;
     use_group_ids: boolean;
-    format: "JSON" | "CSV";
+    format: "JSON" | "CSV" | "XLSX" | "ODS" | "SPSS";
 }
 
 export const exportFormats: {
@@ -24,10 +27,40 @@ export const exportFormats: {
         name: "JSON",
         value: "JSON",
     },
+    {
+        name: "Excel (XLSX)",
+        value: "XLSX",
+    },
+    {
+        name: "OpenDocument (ODS)",
+        value: "ODS",
+    },
+    {
+        name: "SPSS / R with codebook (ZIP)",
+        value: "SPSS",
+    },
 ];
 
+const exportFileTypes: Record<
+    ExportSubmissionsConfig["format"],
+    { extension: string; mimeType: string }
+> = {
+    CSV: { extension: "csv", mimeType: "text/csv" },
+    JSON: { extension: "json", mimeType: "application/json" },
+    XLSX: {
+        extension: "xlsx",
+        mimeType:
+            "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+    },
+    ODS: {
+        extension: "ods",
+        mimeType: "application/vnd.oasis.opendocument.spreadsheet",
+    },
+    SPSS: { extension: "zip", mimeType: "application/zip" },
+};
+
 export function exportFormSubmissions(
-    ctx: ResponsesContext,
+    ctx: FormAdminContext,
     config: ExportSubmissionsConfig,
 ) {
     const submissions = ctx.submissions
@@ -35,27 +68,32 @@ export function exportFormSubmissions(
         .map((_e) => {
             const e = _e as DecryptedSubmissionSuccess;
             return {
-                form_id: ctx.formId,
-                groups_completed: ctx.groups.map((e) => e.id),
-                questions: e.questions,
+                id: e.id,
+                created_at: e.createdAt,
+                fill_token_nickname:
+                    ctx.tokens.find((t) => t.id === e.forToken)?.nickname ??
+                    null,
+                submission: {
+                    form_id: ctx.formId,
+                    groups_completed: ctx.groups.map((e) => e.id),
+                    questions: e.questions,
+                },
             };
         });
 
-    const resp = export_submissions_js(
+    // Include questions that have been removed since some submissions were made
+    const schema = merge_form_revisions_js(
         ctx.groups,
-        submissions,
         ctx.questions,
+        ctx.revisions,
+    );
+    const resp = export_submissions_js(
+        schema.groups,
+        submissions,
+        schema.questions,
         config,
     );
 
-    let extension = "";
-    switch (config.format) {
-        case "CSV":
-            extension = "csv";
-            break;
-        case "JSON":
-            extension = "json";
-    }
-
-    downloadFile(`form_export.${extension}`, resp);
+    const { extension, mimeType } = exportFileTypes[config.format];
+    downloadFile(`form_export.${extension}`, resp, mimeType);
 }
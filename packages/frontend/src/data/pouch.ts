@@ -37,6 +37,8 @@ export interface CryptoKeyRecord {
     serverId: string;
     orgId: string;
     userId: string;
+    // Only set for keys generated on this device
+    revocation?: string;
 }
 export const privateKeyDb = new PouchDB<CryptoKeyRecord>("palform_private_key");
 privateKeyDb.createIndex({
@@ -78,5 +80,29 @@ export const orgVisitDb = new PouchDB<OrgVisitRecord>("palform_org_visit", {
     auto_compaction: true,
 });
 
+export interface ServerSigningKeyRecord {
+    _id: string;
+    _rev?: string;
+    signingKey: string;
+}
+// Keyed by org ID. The first signing key we see for an org is the only one we'll trust for it.
+export const serverSigningKeyDb = new PouchDB<ServerSigningKeyRecord>(
+    "palform_server_signing_key"
+);
+
+export interface SubmissionChainCheckpointRecord {
+    _id: string;
+    _rev?: string;
+    orgId: string;
+    length: number;
+    head: string;
+}
+// Keyed by form ID
+export const submissionChainCheckpointDb =
+    new PouchDB<SubmissionChainCheckpointRecord>(
+        "palform_submission_chain_checkpoint",
+        { auto_compaction: true }
+    );
+
 // literally just a really big number
 export const pouchInfiniteLimit = 1_000_000_000;
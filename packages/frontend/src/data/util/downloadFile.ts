@@ -1,9 +1,15 @@
-export default function downloadFile(fileName: string, data: string) {
+export default function downloadFile(
+    fileName: string,
+    data: string | Uint8Array,
+    mimeType = "text/plain;charset=utf-8",
+) {
+    const url = URL.createObjectURL(new Blob([data], { type: mimeType }));
     const link = document.createElement("a");
     link.download = fileName;
-    link.href = `data:text/plain;charset=utf-8,${encodeURIComponent(data)}`;
+    link.href = url;
     link.style.display = "none";
     document.body.appendChild(link);
     link.click();
     document.body.removeChild(link);
+    URL.revokeObjectURL(url);
 }
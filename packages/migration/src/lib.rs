@@ -56,6 +56,27 @@ mod m20241123_170713_webauthn;
 mod m20250308_153858_feedback;
 mod m20250928_151739_add_submission_audit;
 mod m20250928_171251_add_public_key_audit;
+mod m20261018_104512_webhook_job_backoff;
+mod m20261018_161207_webhook_secret_rotation;
+mod m20261018_193044_webhook_events;
+mod m20261019_093021_question_server_validation;
+mod m20261019_141207_form_revisions;
+mod m20261019_182245_form_publishing;
+mod m20261020_091533_submission_files;
+mod m20261020_143806_submission_file_quotas;
+mod m20261021_102318_service_account_api_keys;
+mod m20261021_154027_mail_outbox;
+mod m20261022_093412_rate_limit_buckets;
+mod m20261023_101544_admin_public_key_revocation;
+mod m20261024_112037_signed_submissions;
+mod m20261025_094118_submission_chain;
+mod m20261026_101933_form_revision_published;
+mod m20261026_143508_submissions_without_file_ids;
+mod m20261027_091245_webhook_job_lease;
+mod m20261028_104516_submission_key_packets;
+mod m20261029_094207_mail_outbox_lease;
+mod m20261029_131552_webhook_legacy_signature;
+mod m20261029_160318_submission_file_keep_deleted_forms;
 
 pub struct Migrator;
 
@@ -119,6 +140,27 @@ impl MigratorTrait for Migrator {
             Box::new(m20250308_153858_feedback::Migration),
             Box::new(m20250928_151739_add_submission_audit::Migration),
             Box::new(m20250928_171251_add_public_key_audit::Migration),
+            Box::new(m20261018_104512_webhook_job_backoff::Migration),
+            Box::new(m20261018_161207_webhook_secret_rotation::Migration),
+            Box::new(m20261018_193044_webhook_events::Migration),
+            Box::new(m20261019_093021_question_server_validation::Migration),
+            Box::new(m20261019_141207_form_revisions::Migration),
+            Box::new(m20261019_182245_form_publishing::Migration),
+            Box::new(m20261020_091533_submission_files::Migration),
+            Box::new(m20261020_143806_submission_file_quotas::Migration),
+            Box::new(m20261021_102318_service_account_api_keys::Migration),
+            Box::new(m20261021_154027_mail_outbox::Migration),
+            Box::new(m20261022_093412_rate_limit_buckets::Migration),
+            Box::new(m20261023_101544_admin_public_key_revocation::Migration),
+            Box::new(m20261024_112037_signed_submissions::Migration),
+            Box::new(m20261025_094118_submission_chain::Migration),
+            Box::new(m20261026_101933_form_revision_published::Migration),
+            Box::new(m20261026_143508_submissions_without_file_ids::Migration),
+            Box::new(m20261027_091245_webhook_job_lease::Migration),
+            Box::new(m20261028_104516_submission_key_packets::Migration),
+            Box::new(m20261029_094207_mail_outbox_lease::Migration),
+            Box::new(m20261029_131552_webhook_legacy_signature::Migration),
+            Box::new(m20261029_160318_submission_file_keep_deleted_forms::Migration),
         ]
     }
 }
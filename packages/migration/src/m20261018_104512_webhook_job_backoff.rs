@@ -0,0 +1,43 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(WebhookJob::Table)
+                    .add_column(
+                        ColumnDef::new(WebhookJob::NextAttemptAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .add_column(ColumnDef::new(WebhookJob::DeadAt).timestamp().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(WebhookJob::Table)
+                    .drop_column(WebhookJob::NextAttemptAt)
+                    .drop_column(WebhookJob::DeadAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum WebhookJob {
+    Table,
+    NextAttemptAt,
+    DeadAt,
+}
@@ -0,0 +1,46 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Webhook::Table)
+                    .add_column(
+                        ColumnDef::new(Webhook::PreviousSigningSecret)
+                            .string()
+                            .null(),
+                    )
+                    .add_column(
+                        ColumnDef::new(Webhook::PreviousSigningSecretExpiresAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Webhook::Table)
+                    .drop_column(Webhook::PreviousSigningSecret)
+                    .drop_column(Webhook::PreviousSigningSecretExpiresAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Webhook {
+    Table,
+    PreviousSigningSecret,
+    PreviousSigningSecretExpiresAt,
+}
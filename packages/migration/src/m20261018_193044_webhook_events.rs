@@ -0,0 +1,212 @@
+use sea_orm_migration::{
+    prelude::*,
+    sea_orm::{EnumIter, Iterable},
+    sea_query::extension::postgres::Type,
+};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(WebhookEventEnum)
+                    .values(WebhookEventVariants::iter())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(WebhookEventSubscription::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(WebhookEventSubscription::WebhookId)
+                            .big_unsigned()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_webhook_event_subscription_webhook")
+                            .from(
+                                WebhookEventSubscription::Table,
+                                WebhookEventSubscription::WebhookId,
+                            )
+                            .to(Webhook::Table, Webhook::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .col(
+                        ColumnDef::new(WebhookEventSubscription::Event)
+                            .enumeration(WebhookEventEnum, WebhookEventVariants::iter())
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(WebhookEventSubscription::WebhookId)
+                            .col(WebhookEventSubscription::Event),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Existing webhooks only ever received new submissions
+        manager
+            .exec_stmt(
+                Query::insert()
+                    .into_table(WebhookEventSubscription::Table)
+                    .columns([
+                        WebhookEventSubscription::WebhookId,
+                        WebhookEventSubscription::Event,
+                    ])
+                    .select_from(
+                        Query::select()
+                            .column(Webhook::Id)
+                            .expr(
+                                Expr::val(WebhookEventVariants::SubmissionCreated.to_string())
+                                    .as_enum(WebhookEventEnum),
+                            )
+                            .from(Webhook::Table)
+                            .to_owned(),
+                    )
+                    .map_err(|e| DbErr::Migration(e.to_string()))?
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(WebhookJob::Table)
+                    .modify_column(
+                        ColumnDef::new(WebhookJob::SubmissionId)
+                            .big_unsigned()
+                            .null(),
+                    )
+                    .add_column(
+                        ColumnDef::new(WebhookJob::Event)
+                            .enumeration(WebhookEventEnum, WebhookEventVariants::iter())
+                            .not_null()
+                            .default(WebhookEventVariants::SubmissionCreated.to_string()),
+                    )
+                    .add_column(ColumnDef::new(WebhookJob::Payload).json_binary().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(FillAccessToken::Table)
+                    .add_column(
+                        ColumnDef::new(FillAccessToken::ExpiryEventQueued)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Don't send a flood of expiry events for tokens that expired before this existed
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(FillAccessToken::Table)
+                    .value(FillAccessToken::ExpiryEventQueued, true)
+                    .and_where(Expr::col(FillAccessToken::ExpiresAt).lt(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(FillAccessToken::Table)
+                    .drop_column(FillAccessToken::ExpiryEventQueued)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .exec_stmt(
+                Query::delete()
+                    .from_table(WebhookJob::Table)
+                    .and_where(Expr::col(WebhookJob::SubmissionId).is_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(WebhookJob::Table)
+                    .modify_column(
+                        ColumnDef::new(WebhookJob::SubmissionId)
+                            .big_unsigned()
+                            .not_null(),
+                    )
+                    .drop_column(WebhookJob::Event)
+                    .drop_column(WebhookJob::Payload)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(WebhookEventSubscription::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_type(Type::drop().name(WebhookEventEnum).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Webhook {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum WebhookEventSubscription {
+    Table,
+    WebhookId,
+    Event,
+}
+
+#[derive(DeriveIden)]
+enum WebhookJob {
+    Table,
+    SubmissionId,
+    Event,
+    Payload,
+}
+
+#[derive(DeriveIden)]
+enum FillAccessToken {
+    Table,
+    ExpiresAt,
+    ExpiryEventQueued,
+}
+
+#[derive(DeriveIden)]
+struct WebhookEventEnum;
+#[derive(DeriveIden, EnumIter)]
+enum WebhookEventVariants {
+    SubmissionCreated,
+    SubmissionDeleted,
+    FormUpdated,
+    FillTokenCreated,
+    FillTokenExpired,
+    AutoDeleteRan,
+}
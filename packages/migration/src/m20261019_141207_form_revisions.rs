@@ -0,0 +1,126 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(FormRevision::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(FormRevision::Id)
+                            .big_unsigned()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(FormRevision::FormId)
+                            .big_unsigned()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(FormRevision::Number).integer().not_null())
+                    .col(
+                        ColumnDef::new(FormRevision::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(FormRevision::Groups)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(FormRevision::Questions)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_form_revision_form")
+                            .from(FormRevision::Table, FormRevision::FormId)
+                            .to(Form::Table, Form::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_form_revision_form_number")
+                    .table(FormRevision::Table)
+                    .col(FormRevision::FormId)
+                    .col(FormRevision::Number)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Submission::Table)
+                    .add_column(
+                        ColumnDef::new(Submission::FormRevisionId)
+                            .big_unsigned()
+                            .null(),
+                    )
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_submission_form_revision")
+                            .from_tbl(Submission::Table)
+                            .from_col(Submission::FormRevisionId)
+                            .to_tbl(FormRevision::Table)
+                            .to_col(FormRevision::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Submission::Table)
+                    .drop_foreign_key(Alias::new("fk_submission_form_revision"))
+                    .drop_column(Submission::FormRevisionId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(FormRevision::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum FormRevision {
+    Table,
+    Id,
+    FormId,
+    Number,
+    CreatedAt,
+    Groups,
+    Questions,
+}
+
+#[derive(DeriveIden)]
+enum Form {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Submission {
+    Table,
+    FormRevisionId,
+}
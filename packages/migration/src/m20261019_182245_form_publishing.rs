@@ -0,0 +1,116 @@
+use sea_orm_migration::prelude::{extension::postgres::Type, *};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Form::Table)
+                    .add_column(
+                        ColumnDef::new(Form::PublishedRevisionId)
+                            .big_unsigned()
+                            .null(),
+                    )
+                    .add_column(ColumnDef::new(Form::PublishedAt).timestamp().null())
+                    .add_column(ColumnDef::new(Form::OpensAt).timestamp().null())
+                    .add_column(ColumnDef::new(Form::ClosesAt).timestamp().null())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_form_published_revision")
+                            .from_tbl(Form::Table)
+                            .from_col(Form::PublishedRevisionId)
+                            .to_tbl(FormRevision::Table)
+                            .to_col(FormRevision::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Existing forms are already live, so treat them as published at their latest revision.
+        // Forms without any revisions keep serving their saved questions until they're next
+        // edited.
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(Form::Table)
+                    .value(Form::PublishedAt, Expr::col(Form::CreatedAt))
+                    .value(
+                        Form::PublishedRevisionId,
+                        SimpleExpr::SubQuery(
+                            None,
+                            Box::new(
+                                Query::select()
+                                    .column(FormRevision::Id)
+                                    .from(FormRevision::Table)
+                                    .and_where(
+                                        Expr::col((FormRevision::Table, FormRevision::FormId))
+                                            .equals((Form::Table, Form::Id)),
+                                    )
+                                    .order_by(FormRevision::Number, Order::Desc)
+                                    .limit(1)
+                                    .to_owned()
+                                    .into_sub_query_statement(),
+                            ),
+                        ),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_type(
+                Type::alter()
+                    .name(AuditLogVerbEnum)
+                    .add_value(AuditLogVerbVariants::Publish),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        println!("Warning: not dropping enum values for AuditLogVerbEnum. See here if you really need to https://stackoverflow.com/a/47305844.");
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Form::Table)
+                    .drop_foreign_key(Alias::new("fk_form_published_revision"))
+                    .drop_column(Form::PublishedRevisionId)
+                    .drop_column(Form::PublishedAt)
+                    .drop_column(Form::OpensAt)
+                    .drop_column(Form::ClosesAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Form {
+    Table,
+    Id,
+    CreatedAt,
+    PublishedRevisionId,
+    PublishedAt,
+    OpensAt,
+    ClosesAt,
+}
+
+#[derive(DeriveIden)]
+enum FormRevision {
+    Table,
+    Id,
+    FormId,
+    Number,
+}
+
+#[derive(DeriveIden)]
+struct AuditLogVerbEnum;
+#[derive(DeriveIden)]
+enum AuditLogVerbVariants {
+    Publish,
+}
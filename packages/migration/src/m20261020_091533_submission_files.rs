@@ -0,0 +1,94 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SubmissionFile::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SubmissionFile::Id)
+                            .big_unsigned()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SubmissionFile::FormId)
+                            .big_unsigned()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SubmissionFile::SubmissionId)
+                            .big_unsigned()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SubmissionFile::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_submission_file_form")
+                            .from(SubmissionFile::Table, SubmissionFile::FormId)
+                            .to(Form::Table, Form::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    // Files are left behind when their submission is deleted, so the cleanup job
+                    // can remove them from S3 too
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_submission_file_submission")
+                            .from(SubmissionFile::Table, SubmissionFile::SubmissionId)
+                            .to(Submission::Table, Submission::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_submission_file_unclaimed")
+                    .table(SubmissionFile::Table)
+                    .col(SubmissionFile::SubmissionId)
+                    .col(SubmissionFile::CreatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SubmissionFile::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SubmissionFile {
+    Table,
+    Id,
+    FormId,
+    SubmissionId,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Form {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Submission {
+    Table,
+    Id,
+}
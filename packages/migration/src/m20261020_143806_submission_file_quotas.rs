@@ -0,0 +1,70 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SubmissionFile::Table)
+                    .add_column(
+                        ColumnDef::new(SubmissionFile::Size)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Existing organisations aren't limited until their plan is next synced, so nobody loses
+        // access to uploads they've already got
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(OrganisationFeatureEntitlement::Table)
+                    .add_column(
+                        ColumnDef::new(OrganisationFeatureEntitlement::SubmissionFileStorageMb)
+                            .integer()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SubmissionFile::Table)
+                    .drop_column(SubmissionFile::Size)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(OrganisationFeatureEntitlement::Table)
+                    .drop_column(OrganisationFeatureEntitlement::SubmissionFileStorageMb)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SubmissionFile {
+    Table,
+    Size,
+}
+
+#[derive(DeriveIden)]
+enum OrganisationFeatureEntitlement {
+    Table,
+    SubmissionFileStorageMb,
+}
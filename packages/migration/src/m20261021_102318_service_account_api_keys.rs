@@ -0,0 +1,167 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// Existing tokens become keys with every scope and no expiry, so current integrations keep
+/// working
+const LEGACY_TOKEN_SCOPES: &str = r#"["org:write","members:write","teams:write","keys:write","forms:write","submissions:write","webhooks:write"]"#;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ServiceAccountApiKey::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ServiceAccountApiKey::Id)
+                            .big_unsigned()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ServiceAccountApiKey::UserId)
+                            .big_unsigned()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ServiceAccountApiKey::Name)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ServiceAccountApiKey::Prefix)
+                            .string()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ServiceAccountApiKey::Hash)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ServiceAccountApiKey::Scopes)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ServiceAccountApiKey::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    // Keys without an expiry last until they're revoked
+                    .col(
+                        ColumnDef::new(ServiceAccountApiKey::ExpiresAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(ServiceAccountApiKey::LastUsedAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_service_account_api_key_user")
+                            .from(ServiceAccountApiKey::Table, ServiceAccountApiKey::UserId)
+                            .to(AdminUser::Table, AdminUser::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // The user ID is reused as the key ID, as each user had at most one token. The lookup
+        // prefix is the first 12 characters of the key, which works for old tokens too. Prefixes
+        // have to be unique though, so old tokens that start the same way get a prefix from their
+        // hash instead.
+        manager
+            .exec_stmt(
+                Query::insert()
+                    .into_table(ServiceAccountApiKey::Table)
+                    .columns([
+                        ServiceAccountApiKey::Id,
+                        ServiceAccountApiKey::UserId,
+                        ServiceAccountApiKey::Name,
+                        ServiceAccountApiKey::Prefix,
+                        ServiceAccountApiKey::Hash,
+                        ServiceAccountApiKey::Scopes,
+                    ])
+                    .select_from(
+                        Query::select()
+                            .column(AdminUser::Id)
+                            .column(AdminUser::Id)
+                            .expr(Expr::val("Service account token"))
+                            .expr(Expr::cust(
+                                "CASE WHEN count(*) OVER (PARTITION BY left(service_account_auth_token, 12)) > 1 \
+                                THEN 'sha256:' || left(encode(sha256(convert_to(service_account_auth_token, 'UTF8')), 'hex'), 16) \
+                                ELSE left(service_account_auth_token, 12) END",
+                            ))
+                            .expr(Expr::cust(
+                                "encode(sha256(convert_to(service_account_auth_token, 'UTF8')), 'hex')",
+                            ))
+                            .expr(Expr::val(LEGACY_TOKEN_SCOPES).cast_as(Alias::new("jsonb")))
+                            .from(AdminUser::Table)
+                            .and_where(Expr::col(AdminUser::ServiceAccountAuthToken).is_not_null())
+                            .to_owned(),
+                    )
+                    .map_err(|e| DbErr::Migration(e.to_string()))?
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AdminUser::Table)
+                    .drop_column(AdminUser::ServiceAccountAuthToken)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Keys are only stored hashed, so they can't be turned back into tokens
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AdminUser::Table)
+                    .add_column(
+                        ColumnDef::new(AdminUser::ServiceAccountAuthToken)
+                            .string()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(ServiceAccountApiKey::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum ServiceAccountApiKey {
+    Table,
+    Id,
+    UserId,
+    Name,
+    Prefix,
+    Hash,
+    Scopes,
+    CreatedAt,
+    ExpiresAt,
+    LastUsedAt,
+}
+
+#[derive(DeriveIden)]
+enum AdminUser {
+    Table,
+    Id,
+    ServiceAccountAuthToken,
+}
@@ -0,0 +1,108 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(MailOutbox::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(MailOutbox::Id)
+                            .big_unsigned()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(MailOutbox::OrganisationId)
+                            .big_unsigned()
+                            .null(),
+                    )
+                    .col(ColumnDef::new(MailOutbox::Recipient).string().not_null())
+                    .col(ColumnDef::new(MailOutbox::Template).string().not_null())
+                    .col(
+                        ColumnDef::new(MailOutbox::Variables)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(MailOutbox::Locale).string().null())
+                    .col(
+                        ColumnDef::new(MailOutbox::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(MailOutbox::SentAt).timestamp().null())
+                    .col(ColumnDef::new(MailOutbox::Error).string().null())
+                    .col(
+                        ColumnDef::new(MailOutbox::Retries)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(MailOutbox::NextAttemptAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(MailOutbox::DeadAt).timestamp().null())
+                    // Lets org admins see failed notification emails. Emails that aren't about an
+                    // organisation (e.g. email verification) don't have one.
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_mail_outbox_organisation")
+                            .from(MailOutbox::Table, MailOutbox::OrganisationId)
+                            .to(Organisation::Table, Organisation::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_mail_outbox_due")
+                    .table(MailOutbox::Table)
+                    .col(MailOutbox::SentAt)
+                    .col(MailOutbox::DeadAt)
+                    .col(MailOutbox::NextAttemptAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(MailOutbox::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum MailOutbox {
+    Table,
+    Id,
+    OrganisationId,
+    Recipient,
+    Template,
+    Variables,
+    Locale,
+    CreatedAt,
+    SentAt,
+    Error,
+    Retries,
+    NextAttemptAt,
+    DeadAt,
+}
+
+#[derive(DeriveIden)]
+enum Organisation {
+    Table,
+    Id,
+}
@@ -0,0 +1,130 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // The latest hash in the form's submission chain, and how many entries it has. Updated
+        // with the form row locked, so entries are added one at a time.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Form::Table)
+                    .add_column(ColumnDef::new(Form::SubmissionChainHead).text().null())
+                    .add_column(
+                        ColumnDef::new(Form::SubmissionChainLength)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Submissions made before the chain existed are left out of it
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Submission::Table)
+                    .add_column(ColumnDef::new(Submission::ChainIndex).big_integer().null())
+                    .add_column(ColumnDef::new(Submission::DataHash).text().null())
+                    .add_column(ColumnDef::new(Submission::ChainHash).text().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // Deleted submissions keep the details of the entry that created them, as well as getting
+        // an entry of their own
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(DeletedSubmission::Table)
+                    .add_column(
+                        ColumnDef::new(DeletedSubmission::ChainIndex)
+                            .big_integer()
+                            .null(),
+                    )
+                    .add_column(ColumnDef::new(DeletedSubmission::ChainHash).text().null())
+                    .add_column(
+                        ColumnDef::new(DeletedSubmission::CreatedChainIndex)
+                            .big_integer()
+                            .null(),
+                    )
+                    .add_column(
+                        ColumnDef::new(DeletedSubmission::CreatedDataHash)
+                            .text()
+                            .null(),
+                    )
+                    .add_column(
+                        ColumnDef::new(DeletedSubmission::CreatedChainHash)
+                            .text()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(DeletedSubmission::Table)
+                    .drop_column(DeletedSubmission::ChainIndex)
+                    .drop_column(DeletedSubmission::ChainHash)
+                    .drop_column(DeletedSubmission::CreatedChainIndex)
+                    .drop_column(DeletedSubmission::CreatedDataHash)
+                    .drop_column(DeletedSubmission::CreatedChainHash)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Submission::Table)
+                    .drop_column(Submission::ChainIndex)
+                    .drop_column(Submission::DataHash)
+                    .drop_column(Submission::ChainHash)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Form::Table)
+                    .drop_column(Form::SubmissionChainHead)
+                    .drop_column(Form::SubmissionChainLength)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Form {
+    Table,
+    SubmissionChainHead,
+    SubmissionChainLength,
+}
+
+#[derive(DeriveIden)]
+enum Submission {
+    Table,
+    ChainIndex,
+    DataHash,
+    ChainHash,
+}
+
+#[derive(DeriveIden)]
+enum DeletedSubmission {
+    Table,
+    ChainIndex,
+    ChainHash,
+    CreatedChainIndex,
+    CreatedDataHash,
+    CreatedChainHash,
+}
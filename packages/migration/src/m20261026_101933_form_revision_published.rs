@@ -0,0 +1,81 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // When the revision was first published, so submissions can only be made against
+        // revisions respondents could actually have been shown
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(FormRevision::Table)
+                    .add_column(ColumnDef::new(FormRevision::PublishedAt).timestamp().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // Earlier published revisions weren't recorded, so only the current one can be marked
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(FormRevision::Table)
+                    .value(
+                        FormRevision::PublishedAt,
+                        SimpleExpr::SubQuery(
+                            None,
+                            Box::new(
+                                Query::select()
+                                    .column(Form::PublishedAt)
+                                    .from(Form::Table)
+                                    .and_where(
+                                        Expr::col((Form::Table, Form::PublishedRevisionId))
+                                            .equals((FormRevision::Table, FormRevision::Id)),
+                                    )
+                                    .limit(1)
+                                    .to_owned()
+                                    .into_sub_query_statement(),
+                            ),
+                        ),
+                    )
+                    .and_where(
+                        Expr::col((FormRevision::Table, FormRevision::Id)).in_subquery(
+                            Query::select()
+                                .column(Form::PublishedRevisionId)
+                                .from(Form::Table)
+                                .and_where(Expr::col(Form::PublishedRevisionId).is_not_null())
+                                .to_owned(),
+                        ),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(FormRevision::Table)
+                    .drop_column(FormRevision::PublishedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Form {
+    Table,
+    PublishedRevisionId,
+    PublishedAt,
+}
+
+#[derive(DeriveIden)]
+enum FormRevision {
+    Table,
+    Id,
+    PublishedAt,
+}
@@ -0,0 +1,42 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Set once a client submits to the form without saying which files it uploaded. Its
+        // unclaimed files are then kept, as some of them might belong to that submission.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Form::Table)
+                    .add_column(
+                        ColumnDef::new(Form::SubmissionsWithoutFileIds)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Form::Table)
+                    .drop_column(Form::SubmissionsWithoutFileIds)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Form {
+    Table,
+    SubmissionsWithoutFileIds,
+}
@@ -0,0 +1,37 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Set while a worker is delivering the job. If the worker dies part-way through, the job
+        // becomes due again once this passes.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(WebhookJob::Table)
+                    .add_column(ColumnDef::new(WebhookJob::LockedUntil).timestamp().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(WebhookJob::Table)
+                    .drop_column(WebhookJob::LockedUntil)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum WebhookJob {
+    Table,
+    LockedUntil,
+}
@@ -0,0 +1,89 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Key packets added to a submission's message by rewraps, rather than by the respondent
+        manager
+            .create_table(
+                Table::create()
+                    .table(SubmissionKeyPacket::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SubmissionKeyPacket::SubmissionId)
+                            .big_unsigned()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SubmissionKeyPacket::Recipient)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SubmissionKeyPacket::AddedById)
+                            .big_unsigned()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SubmissionKeyPacket::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(SubmissionKeyPacket::SubmissionId)
+                            .col(SubmissionKeyPacket::Recipient),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_submission_key_packet_submission")
+                            .from(
+                                SubmissionKeyPacket::Table,
+                                SubmissionKeyPacket::SubmissionId,
+                            )
+                            .to(Submission::Table, Submission::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_submission_key_packet_added_by")
+                            .from(SubmissionKeyPacket::Table, SubmissionKeyPacket::AddedById)
+                            .to(AdminUser::Table, AdminUser::Id)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SubmissionKeyPacket::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SubmissionKeyPacket {
+    Table,
+    SubmissionId,
+    Recipient,
+    AddedById,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Submission {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum AdminUser {
+    Table,
+    Id,
+}
@@ -0,0 +1,36 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Set while a worker is sending the email, like webhook_job.locked_until
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(MailOutbox::Table)
+                    .add_column(ColumnDef::new(MailOutbox::LockedUntil).timestamp().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(MailOutbox::Table)
+                    .drop_column(MailOutbox::LockedUntil)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum MailOutbox {
+    Table,
+    LockedUntil,
+}
@@ -0,0 +1,61 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // File rows are kept when their form is deleted, so the cleanup job knows which storage
+        // prefixes still need removing
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name("fk_submission_file_form")
+                    .table(SubmissionFile::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .exec_stmt(
+                Query::delete()
+                    .from_table(SubmissionFile::Table)
+                    .and_where(
+                        Expr::col(SubmissionFile::FormId).not_in_subquery(
+                            Query::select()
+                                .column(Form::Id)
+                                .from(Form::Table)
+                                .to_owned(),
+                        ),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk_submission_file_form")
+                    .from(SubmissionFile::Table, SubmissionFile::FormId)
+                    .to(Form::Table, Form::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SubmissionFile {
+    Table,
+    FormId,
+}
+
+#[derive(DeriveIden)]
+enum Form {
+    Table,
+    Id,
+}
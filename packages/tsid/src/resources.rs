@@ -17,12 +17,15 @@ id_resource_type!(IDFillAccessToken, "fat");
 id_resource_type!(IDForm, "form");
 id_resource_type!(IDFormTemplateCategory, "tmplcat");
 id_resource_type!(IDFormBranding, "brand");
+id_resource_type!(IDFormRevision, "frev");
+id_resource_type!(IDMailOutboxItem, "mail");
 id_resource_type!(IDOrganisation, "org");
 id_resource_type!(IDOrganisationAuthConfig, "org_auth_conf");
 id_resource_type!(IDOrganisationAuthTeamMapping, "org_auth_team_map");
 id_resource_type!(IDOrganisationInvite, "org_invite");
 id_resource_type!(IDQuestion, "qu");
 id_resource_type!(IDQuestionGroup, "qg");
+id_resource_type!(IDServiceAccountAPIKey, "apikey");
 id_resource_type!(IDSubmission, "sub");
 id_resource_type!(IDSubmissionFile, "subf");
 id_resource_type!(IDTeam, "team");
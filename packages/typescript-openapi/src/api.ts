@@ -674,6 +674,19 @@ export interface APIBillingUpcomingInvoicePromotion {
      */
     'amount_off'?: number | null;
 }
+/**
+ * 
+ * @export
+ * @interface APICaptchaChallenge
+ */
+export interface APICaptchaChallenge {
+    /**
+     * Solve this with `solve_proof_of_work_js` and send the result in the `X-Captcha-Response` header
+     * @type {string}
+     * @memberof APICaptchaChallenge
+     */
+    'challenge': string;
+}
 /**
  * 
  * @export
@@ -814,12 +827,18 @@ export interface APIEntitlementInfo {
      * @memberof APIEntitlementInfo
      */
     'form_captcha': boolean;
+    /**
+     * Total size of files uploaded to the organisation\'s forms
+     * @type {number}
+     * @memberof APIEntitlementInfo
+     */
+    'submission_file_storage_mb'?: number | null;
 }
 /**
  * @type APIEntitlementRequest
  * @export
  */
-export type APIEntitlementRequest = APIEntitlementRequestOneOf | string;
+export type APIEntitlementRequest = APIEntitlementRequestOneOf | APIEntitlementRequestOneOf1 | string;
 
 /**
  * 
@@ -834,11 +853,24 @@ export interface APIEntitlementRequestOneOf {
      */
     'QuestionPerFormCount': string;
 }
+/**
+ * The size in bytes of a file about to be stored
+ * @export
+ * @interface APIEntitlementRequestOneOf1
+ */
+export interface APIEntitlementRequestOneOf1 {
+    /**
+     * 
+     * @type {number}
+     * @memberof APIEntitlementRequestOneOf1
+     */
+    'SubmissionFileStorage': number;
+}
 /**
  * @type APIError
  * @export
  */
-export type APIError = APIErrorOneOf | APIErrorOneOf1 | APIErrorOneOf2 | APIErrorOneOf3 | string;
+export type APIError = APIErrorOneOf | APIErrorOneOf1 | APIErrorOneOf2 | APIErrorOneOf3 | APIErrorOneOf4 | APIErrorOneOf5 | string;
 
 /**
  * 
@@ -892,6 +924,32 @@ export interface APIErrorOneOf3 {
      */
     'SubscriptionLimit': string;
 }
+/**
+ * 
+ * @export
+ * @interface APIErrorOneOf4
+ */
+export interface APIErrorOneOf4 {
+    /**
+     * 
+     * @type {string}
+     * @memberof APIErrorOneOf4
+     */
+    'PayloadTooLarge': string;
+}
+/**
+ * 
+ * @export
+ * @interface APIErrorOneOf5
+ */
+export interface APIErrorOneOf5 {
+    /**
+     * 
+     * @type {string}
+     * @memberof APIErrorOneOf5
+     */
+    'RateLimited': string;
+}
 /**
  * 
  * @export
@@ -923,6 +981,61 @@ export interface APIExchangedShortLink {
      */
     'expires_at'?: string | null;
 }
+/**
+ * An email about the organisation that hasn't been delivered yet, either because it's waiting to be retried or because it ran out of retries
+ * @export
+ * @interface APIFailedMail
+ */
+export interface APIFailedMail {
+    /**
+     * 
+     * @type {string}
+     * @memberof APIFailedMail
+     */
+    'id': string;
+    /**
+     * 
+     * @type {string}
+     * @memberof APIFailedMail
+     */
+    'recipient': string;
+    /**
+     * 
+     * @type {string}
+     * @memberof APIFailedMail
+     */
+    'template': string;
+    /**
+     * 
+     * @type {string}
+     * @memberof APIFailedMail
+     */
+    'created_at': string;
+    /**
+     * 
+     * @type {number}
+     * @memberof APIFailedMail
+     */
+    'retries': number;
+    /**
+     * 
+     * @type {string}
+     * @memberof APIFailedMail
+     */
+    'error'?: string | null;
+    /**
+     * 
+     * @type {string}
+     * @memberof APIFailedMail
+     */
+    'next_attempt_at': string;
+    /**
+     * 
+     * @type {string}
+     * @memberof APIFailedMail
+     */
+    'dead_at'?: string | null;
+}
 /**
  * 
  * @export
@@ -1062,7 +1175,36 @@ export interface APIForm {
      * @type {boolean}
      * @memberof APIForm
      */
-    'one_question_per_page': boolean;
+    'one_question_per_page': boolean;    /**
+     * The revision respondents see. Saving questions doesn\'t change this until the form is published again.
+     * @type {string}
+     * @memberof APIForm
+     */
+    'published_revision_id'?: string | null;
+    /**
+     * `None` if the form is still a draft
+     * @type {string}
+     * @memberof APIForm
+     */
+    'published_at'?: string | null;
+    /**
+     * 
+     * @type {string}
+     * @memberof APIForm
+     */
+    'opens_at'?: string | null;
+    /**
+     * 
+     * @type {string}
+     * @memberof APIForm
+     */
+    'closes_at'?: string | null;
+    /**
+     * Respondents sign their submission with a single-use key, and get a receipt they can use to prove what they sent
+     * @type {boolean}
+     * @memberof APIForm
+     */
+    'signed_submissions': boolean;
 }
 /**
  * 
@@ -1348,6 +1490,188 @@ export interface APIFormEndConfiguration {
      */
     'show_restart': boolean;
 }
+/**
+ * An immutable snapshot of a form\'s questions and groups, taken every time they\'re saved. Submissions record the revision they were filled against.
+ * @export
+ * @interface APIFormRevision
+ */
+export interface APIFormRevision {
+    /**
+     * 
+     * @type {string}
+     * @memberof APIFormRevision
+     */
+    'id': string;
+    /**
+     * Counts up from 1 for each form
+     * @type {number}
+     * @memberof APIFormRevision
+     */
+    'number': number;
+    /**
+     * 
+     * @type {string}
+     * @memberof APIFormRevision
+     */
+    'created_at': string;
+    /**
+     * 
+     * @type {Array<APIQuestionGroup>}
+     * @memberof APIFormRevision
+     */
+    'groups': Array<APIQuestionGroup>;
+    /**
+     * 
+     * @type {Array<APIQuestion>}
+     * @memberof APIFormRevision
+     */
+    'questions': Array<APIQuestion>;
+}
+/**
+ * 
+ * @export
+ * @enum {string}
+ */
+
+export const APIFormRevisionChangeKind = {
+    Added: 'Added',
+    Removed: 'Removed',
+    Modified: 'Modified'
+} as const;
+
+export type APIFormRevisionChangeKind = typeof APIFormRevisionChangeKind[keyof typeof APIFormRevisionChangeKind];
+
+
+/**
+ * What changed between two revisions of a form. Unchanged questions and groups are left out.
+ * @export
+ * @interface APIFormRevisionDiff
+ */
+export interface APIFormRevisionDiff {
+    /**
+     * 
+     * @type {string}
+     * @memberof APIFormRevisionDiff
+     */
+    'from': string;
+    /**
+     * 
+     * @type {string}
+     * @memberof APIFormRevisionDiff
+     */
+    'to': string;
+    /**
+     * 
+     * @type {Array<APIFormRevisionGroupChange>}
+     * @memberof APIFormRevisionDiff
+     */
+    'groups': Array<APIFormRevisionGroupChange>;
+    /**
+     * 
+     * @type {Array<APIFormRevisionQuestionChange>}
+     * @memberof APIFormRevisionDiff
+     */
+    'questions': Array<APIFormRevisionQuestionChange>;
+}
+/**
+ * 
+ * @export
+ * @interface APIFormRevisionGroupChange
+ */
+export interface APIFormRevisionGroupChange {
+    /**
+     * 
+     * @type {string}
+     * @memberof APIFormRevisionGroupChange
+     */
+    'group_id': string;
+    /**
+     * 
+     * @type {APIFormRevisionChangeKind}
+     * @memberof APIFormRevisionGroupChange
+     */
+    'kind': APIFormRevisionChangeKind;
+    /**
+     * The fields that differ, for modified groups
+     * @type {Array<string>}
+     * @memberof APIFormRevisionGroupChange
+     */
+    'fields': Array<string>;
+    /**
+     * 
+     * @type {APIQuestionGroup}
+     * @memberof APIFormRevisionGroupChange
+     */
+    'before'?: APIQuestionGroup | null;
+    /**
+     * 
+     * @type {APIQuestionGroup}
+     * @memberof APIFormRevisionGroupChange
+     */
+    'after'?: APIQuestionGroup | null;
+}
+/**
+ * 
+ * @export
+ * @interface APIFormRevisionQuestionChange
+ */
+export interface APIFormRevisionQuestionChange {
+    /**
+     * 
+     * @type {string}
+     * @memberof APIFormRevisionQuestionChange
+     */
+    'question_id': string;
+    /**
+     * 
+     * @type {APIFormRevisionChangeKind}
+     * @memberof APIFormRevisionQuestionChange
+     */
+    'kind': APIFormRevisionChangeKind;
+    /**
+     * The fields that differ, for modified questions
+     * @type {Array<string>}
+     * @memberof APIFormRevisionQuestionChange
+     */
+    'fields': Array<string>;
+    /**
+     * 
+     * @type {APIQuestion}
+     * @memberof APIFormRevisionQuestionChange
+     */
+    'before'?: APIQuestion | null;
+    /**
+     * 
+     * @type {APIQuestion}
+     * @memberof APIFormRevisionQuestionChange
+     */
+    'after'?: APIQuestion | null;
+}
+/**
+ * 
+ * @export
+ * @interface APIFormRevisionSummary
+ */
+export interface APIFormRevisionSummary {
+    /**
+     * 
+     * @type {string}
+     * @memberof APIFormRevisionSummary
+     */
+    'id': string;
+    /**
+     * 
+     * @type {number}
+     * @memberof APIFormRevisionSummary
+     */
+    'number': number;
+    /**
+     * 
+     * @type {string}
+     * @memberof APIFormRevisionSummary
+     */
+    'created_at': string;
+}
 /**
  * 
  * @export
@@ -1463,7 +1787,12 @@ export interface APIFormWithQuestions {
      * @type {string}
      * @memberof APIFormWithQuestions
      */
-    'o': string;
+    'o': string;    /**
+     * The revision the questions and groups belong to, to be sent back with the submission
+     * @type {string}
+     * @memberof APIFormWithQuestions
+     */
+    'r'?: string | null;
 }
 /**
  * 
@@ -1507,6 +1836,12 @@ export interface APIFrontendForm {
      * @memberof APIFrontendForm
      */
     'one_question_per_page': boolean;
+    /**
+     * 
+     * @type {boolean}
+     * @memberof APIFrontendForm
+     */
+    'signed_submissions': boolean;
 }
 /**
  * 
@@ -1911,6 +2246,12 @@ export interface APIQuestion {
      * @memberof APIQuestion
      */
     'group_id': string;
+    /**
+     * Sends a copy of this question\'s answer to the server alongside the encrypted submission, so it can be validated there as well as in the browser.
+     * @type {APIQuestionServerValidation}
+     * @memberof APIQuestion
+     */
+    'server_validation'?: APIQuestionServerValidation | null;
 }
 /**
  * @type APIQuestionConfiguration
@@ -2139,6 +2480,12 @@ export interface APIQuestionConfigurationOneOf6FileUpload {
      * @memberof APIQuestionConfigurationOneOf6FileUpload
      */
     'allowed_types': Array<APIQuestionFileUploadType>;
+    /**
+     * Larger uploads are rejected. The server\'s own limit applies either way.
+     * @type {number}
+     * @memberof APIQuestionConfigurationOneOf6FileUpload
+     */
+    'max_size_mb'?: number | null;
 }
 /**
  * 
@@ -2705,6 +3052,38 @@ export const APIQuestionScaleIcon = {
 export type APIQuestionScaleIcon = typeof APIQuestionScaleIcon[keyof typeof APIQuestionScaleIcon];
 
 
+/**
+ * @type APIQuestionServerValidation
+ * @export
+ */
+export type APIQuestionServerValidation = APIQuestionServerValidationOneOf | string;
+
+/**
+ * Number, scale and date answers are rounded down to a multiple of `bucket_size` (in seconds for dates), which is enough to enforce their range without sending the exact value
+ * @export
+ * @interface APIQuestionServerValidationOneOf
+ */
+export interface APIQuestionServerValidationOneOf {
+    /**
+     * 
+     * @type {APIQuestionServerValidationOneOfBucketed}
+     * @memberof APIQuestionServerValidationOneOf
+     */
+    'bucketed': APIQuestionServerValidationOneOfBucketed;
+}
+/**
+ * 
+ * @export
+ * @interface APIQuestionServerValidationOneOfBucketed
+ */
+export interface APIQuestionServerValidationOneOfBucketed {
+    /**
+     * 
+     * @type {number}
+     * @memberof APIQuestionServerValidationOneOfBucketed
+     */
+    'bucket_size': number;
+}
 /**
  * 
  * @export
@@ -2740,15 +3119,70 @@ export interface APISaveQuestionsRequest {
     'groups': Array<APIQuestionGroup>;
 }
 /**
- * 
+ * An API key for a service account in the organisation. The key itself is only shown once, when it\'s created.
  * @export
- * @interface APISubmission
+ * @interface APIServiceAccountAPIKey
  */
-export interface APISubmission {
+export interface APIServiceAccountAPIKey {
     /**
      * 
      * @type {string}
-     * @memberof APISubmission
+     * @memberof APIServiceAccountAPIKey
+     */
+    'id': string;
+    /**
+     * 
+     * @type {string}
+     * @memberof APIServiceAccountAPIKey
+     */
+    'user_id': string;
+    /**
+     * 
+     * @type {string}
+     * @memberof APIServiceAccountAPIKey
+     */
+    'name': string;
+    /**
+     * The first few characters of the key, to help tell keys apart
+     * @type {string}
+     * @memberof APIServiceAccountAPIKey
+     */
+    'prefix': string;
+    /**
+     * e.g. `forms:write`
+     * @type {Array<string>}
+     * @memberof APIServiceAccountAPIKey
+     */
+    'scopes': Array<string>;
+    /**
+     * 
+     * @type {string}
+     * @memberof APIServiceAccountAPIKey
+     */
+    'created_at': string;
+    /**
+     * Keys without an expiry last until they\'re revoked
+     * @type {string}
+     * @memberof APIServiceAccountAPIKey
+     */
+    'expires_at'?: string | null;
+    /**
+     * 
+     * @type {string}
+     * @memberof APIServiceAccountAPIKey
+     */
+    'last_used_at'?: string | null;
+}
+/**
+ * 
+ * @export
+ * @interface APISubmission
+ */
+export interface APISubmission {
+    /**
+     * 
+     * @type {string}
+     * @memberof APISubmission
      */
     'id': string;
     /**
@@ -2763,12 +3197,55 @@ export interface APISubmission {
      * @memberof APISubmission
      */
     'for_token'?: string | null;
+    /**
+     * The revision of the form this submission was filled against. Submissions made before revisions were introduced don\'t have one, and use the form\'s current questions.
+     * @type {string}
+     * @memberof APISubmission
+     */
+    'form_revision_id'?: string | null;
     /**
      * 
      * @type {string}
      * @memberof APISubmission
      */
     'data': string;
+    /**
+     * The public key the respondent signed the submission with, if the form asked for signed submissions
+     * @type {string}
+     * @memberof APISubmission
+     */
+    'signing_key'?: string | null;
+}
+/**
+ * The whole of a form\'s submission chain, and a checkpoint of its head signed by the server
+ * @export
+ * @interface APISubmissionChain
+ */
+export interface APISubmissionChain {
+    /**
+     * 
+     * @type {Array<SubmissionChainEntry>}
+     * @memberof APISubmissionChain
+     */
+    'entries': Array<SubmissionChainEntry>;
+    /**
+     * 
+     * @type {SubmissionChainCheckpoint}
+     * @memberof APISubmissionChain
+     */
+    'checkpoint': SubmissionChainCheckpoint;
+    /**
+     * Base64-encoded Ed25519 signature of the checkpoint\'s signed message
+     * @type {string}
+     * @memberof APISubmissionChain
+     */
+    'signature': string;
+    /**
+     * Base64-encoded Ed25519 public key that made the signature. Clients should check this against a key they already trust, rather than trusting it as-is.
+     * @type {string}
+     * @memberof APISubmissionChain
+     */
+    'signing_key': string;
 }
 /**
  * 
@@ -2795,6 +3272,25 @@ export interface APISubmissionCountPerForm {
      */
     'new_submission_count': number;
 }
+/**
+ * New PKESK packets for a submission, made by re-wrapping its session key on the client
+ * @export
+ * @interface APISubmissionRewrap
+ */
+export interface APISubmissionRewrap {
+    /**
+     * 
+     * @type {string}
+     * @memberof APISubmissionRewrap
+     */
+    'submission_id': string;
+    /**
+     * Armored PKESK packets to add to the submission\'s message. A packet for a key that an earlier rewrap added a packet for replaces it.
+     * @type {string}
+     * @memberof APISubmissionRewrap
+     */
+    'key_packets': string;
+}
 /**
  * 
  * @export
@@ -2893,6 +3389,12 @@ export interface APIUserKey {
      * @memberof APIUserKey
      */
     'expires_at': string;
+    /**
+     * New submissions aren\'t encrypted to revoked keys
+     * @type {string}
+     * @memberof APIUserKey
+     */
+    'revoked_at'?: string | null;
 }
 /**
  * 
@@ -2942,6 +3444,12 @@ export interface APIUserKeyWithIdentity {
      * @memberof APIUserKeyWithIdentity
      */
     'expires_at': string;
+    /**
+     * 
+     * @type {string}
+     * @memberof APIUserKeyWithIdentity
+     */
+    'revoked_at'?: string | null;
 }
 /**
  * 
@@ -3136,6 +3644,7 @@ export type AuditLogTargetResourceEnum = typeof AuditLogTargetResourceEnum[keyof
 export const AuditLogVerbEnum = {
     Create: 'Create',
     Delete: 'Delete',
+    Publish: 'Publish',
     Read: 'Read',
     Update: 'Update'
 } as const;
@@ -3336,6 +3845,56 @@ export interface CreateUserRequest {
      */
     'password': string;
 }
+/**
+ * 
+ * @export
+ * @interface CreateServiceAccountAPIKeyRequest
+ */
+export interface CreateServiceAccountAPIKeyRequest {
+    /**
+     * Must be a member of this organisation and no other
+     * @type {string}
+     * @memberof CreateServiceAccountAPIKeyRequest
+     */
+    'user_id': string;
+    /**
+     * 
+     * @type {string}
+     * @memberof CreateServiceAccountAPIKeyRequest
+     */
+    'name': string;
+    /**
+     * e.g. `forms:write`
+     * @type {Array<string>}
+     * @memberof CreateServiceAccountAPIKeyRequest
+     */
+    'scopes': Array<string>;
+    /**
+     * The key never expires if not provided
+     * @type {number}
+     * @memberof CreateServiceAccountAPIKeyRequest
+     */
+    'expires_in_days'?: number | null;
+}
+/**
+ * 
+ * @export
+ * @interface CreateServiceAccountAPIKeyResponse
+ */
+export interface CreateServiceAccountAPIKeyResponse {
+    /**
+     * 
+     * @type {string}
+     * @memberof CreateServiceAccountAPIKeyResponse
+     */
+    'id': string;
+    /**
+     * This is the only time the key is shown
+     * @type {string}
+     * @memberof CreateServiceAccountAPIKeyResponse
+     */
+    'key': string;
+}
 /**
  * 
  * @export
@@ -3789,6 +4348,19 @@ export interface RegisterKeyRequest {
      */
     'key_data': string;
 }
+/**
+ * 
+ * @export
+ * @interface RevokeKeyRequest
+ */
+export interface RevokeKeyRequest {
+    /**
+     * Armored revocation certificate signed by the key
+     * @type {string}
+     * @memberof RevokeKeyRequest
+     */
+    'revocation': string;
+}
 /**
  * 
  * @export
@@ -3834,6 +4406,25 @@ export interface SendPasswordResetRequest {
      */
     'email': string;
 }
+/**
+ * Times are in UTC. Either can be left out to keep the form open in that direction.
+ * @export
+ * @interface SetFormScheduleRequest
+ */
+export interface SetFormScheduleRequest {
+    /**
+     * 
+     * @type {string}
+     * @memberof SetFormScheduleRequest
+     */
+    'opens_at'?: string | null;
+    /**
+     * 
+     * @type {string}
+     * @memberof SetFormScheduleRequest
+     */
+    'closes_at'?: string | null;
+}
 /**
  * 
  * @export
@@ -4109,6 +4700,88 @@ export interface StartWebauthnResponse {
      */
     'session': string;
 }
+/**
+ * The head of a form\'s submission chain at some point in time. The server signs the string from `signed_message` with its Ed25519 signing key.
+ * @export
+ * @interface SubmissionChainCheckpoint
+ */
+export interface SubmissionChainCheckpoint {
+    /**
+     * 
+     * @type {string}
+     * @memberof SubmissionChainCheckpoint
+     */
+    'form_id': string;
+    /**
+     * Number of entries in the chain
+     * @type {number}
+     * @memberof SubmissionChainCheckpoint
+     */
+    'length': number;
+    /**
+     * Hash of the last entry, or the genesis hash if the chain is empty
+     * @type {string}
+     * @memberof SubmissionChainCheckpoint
+     */
+    'head': string;
+    /**
+     * 
+     * @type {string}
+     * @memberof SubmissionChainCheckpoint
+     */
+    'created_at': string;
+}
+/**
+ * One link in a form\'s submission chain. Every submission that\'s created or deleted gets the next index, and a hash covering the entry and the hash of the entry before it.
+ * @export
+ * @interface SubmissionChainEntry
+ */
+export interface SubmissionChainEntry {
+    /**
+     * Counts up from 0 for each form
+     * @type {number}
+     * @memberof SubmissionChainEntry
+     */
+    'index': number;
+    /**
+     * 
+     * @type {SubmissionChainEntryKind}
+     * @memberof SubmissionChainEntry
+     */
+    'kind': SubmissionChainEntryKind;
+    /**
+     * 
+     * @type {string}
+     * @memberof SubmissionChainEntry
+     */
+    'submission_id': string;
+    /**
+     * Hex SHA256 of the submission\'s encrypted data, not counting its key packets. Only set for `created` entries.
+     * @type {string}
+     * @memberof SubmissionChainEntry
+     */
+    'data_hash'?: string | null;
+    /**
+     * 
+     * @type {string}
+     * @memberof SubmissionChainEntry
+     */
+    'chain_hash': string;
+}
+/**
+ * 
+ * @export
+ * @enum {string}
+ */
+
+export const SubmissionChainEntryKind = {
+    Created: 'created',
+    Deleted: 'deleted'
+} as const;
+
+export type SubmissionChainEntryKind = typeof SubmissionChainEntryKind[keyof typeof SubmissionChainEntryKind];
+
+
 /**
  * 
  * @export
@@ -4203,6 +4876,12 @@ export interface UpdateFormRequest {
      * @memberof UpdateFormRequest
      */
     'enable_captcha': boolean;
+    /**
+     * 
+     * @type {boolean}
+     * @memberof UpdateFormRequest
+     */
+    'signed_submissions': boolean;
 }
 /**
  * 
@@ -4611,7 +5290,7 @@ export const AuthenticationApiAxiosParamCreator = function (configuration?: Conf
         },
         /**
          * 
-         * @param {string} xCaptchaResponse Response from the Cloudflare Turnstile captcha
+         * @param {string} xCaptchaResponse Response from the captcha, or the solved proof-of-work challenge
          * @param {SignInRequest} signInRequest 
          * @param {*} [options] Override http request option.
          * @throws {RequiredError}
@@ -4653,7 +5332,7 @@ export const AuthenticationApiAxiosParamCreator = function (configuration?: Conf
         },
         /**
          * 
-         * @param {string} xCaptchaResponse Response from the Cloudflare Turnstile captcha
+         * @param {string} xCaptchaResponse Response from the captcha, or the solved proof-of-work challenge
          * @param {CreateUserRequest} createUserRequest 
          * @param {*} [options] Override http request option.
          * @throws {RequiredError}
@@ -4981,7 +5660,7 @@ export const AuthenticationApiFp = function(configuration?: Configuration) {
         },
         /**
          * 
-         * @param {string} xCaptchaResponse Response from the Cloudflare Turnstile captcha
+         * @param {string} xCaptchaResponse Response from the captcha, or the solved proof-of-work challenge
          * @param {SignInRequest} signInRequest 
          * @param {*} [options] Override http request option.
          * @throws {RequiredError}
@@ -4994,7 +5673,7 @@ export const AuthenticationApiFp = function(configuration?: Configuration) {
         },
         /**
          * 
-         * @param {string} xCaptchaResponse Response from the Cloudflare Turnstile captcha
+         * @param {string} xCaptchaResponse Response from the captcha, or the solved proof-of-work challenge
          * @param {CreateUserRequest} createUserRequest 
          * @param {*} [options] Override http request option.
          * @throws {RequiredError}
@@ -5127,7 +5806,7 @@ export const AuthenticationApiFactory = function (configuration?: Configuration,
         },
         /**
          * 
-         * @param {string} xCaptchaResponse Response from the Cloudflare Turnstile captcha
+         * @param {string} xCaptchaResponse Response from the captcha, or the solved proof-of-work challenge
          * @param {SignInRequest} signInRequest 
          * @param {*} [options] Override http request option.
          * @throws {RequiredError}
@@ -5137,7 +5816,7 @@ export const AuthenticationApiFactory = function (configuration?: Configuration,
         },
         /**
          * 
-         * @param {string} xCaptchaResponse Response from the Cloudflare Turnstile captcha
+         * @param {string} xCaptchaResponse Response from the captcha, or the solved proof-of-work challenge
          * @param {CreateUserRequest} createUserRequest 
          * @param {*} [options] Override http request option.
          * @throws {RequiredError}
@@ -5252,7 +5931,7 @@ export class AuthenticationApi extends BaseAPI {
 
     /**
      * 
-     * @param {string} xCaptchaResponse Response from the Cloudflare Turnstile captcha
+     * @param {string} xCaptchaResponse Response from the captcha, or the solved proof-of-work challenge
      * @param {SignInRequest} signInRequest 
      * @param {*} [options] Override http request option.
      * @throws {RequiredError}
@@ -5264,7 +5943,7 @@ export class AuthenticationApi extends BaseAPI {
 
     /**
      * 
-     * @param {string} xCaptchaResponse Response from the Cloudflare Turnstile captcha
+     * @param {string} xCaptchaResponse Response from the captcha, or the solved proof-of-work challenge
      * @param {CreateUserRequest} createUserRequest 
      * @param {*} [options] Override http request option.
      * @throws {RequiredError}
@@ -6338,22 +7017,18 @@ export class BillingPlansApi extends BaseAPI {
 
 
 /**
- * Class2FAMethodsApi - axios parameter creator
+ * CaptchaApi - axios parameter creator
  * @export
  */
-export const Class2FAMethodsApiAxiosParamCreator = function (configuration?: Configuration) {
+export const CaptchaApiAxiosParamCreator = function (configuration?: Configuration) {
     return {
         /**
-         * 
-         * @param {string} factorId 
+         * Get a new proof-of-work challenge, if the server is set up to use the built-in captcha
          * @param {*} [options] Override http request option.
          * @throws {RequiredError}
          */
-        userSecondFactorsDelete: async (factorId: string, options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
-            // verify required parameter 'factorId' is not null or undefined
-            assertParamExists('userSecondFactorsDelete', 'factorId', factorId)
-            const localVarPath = `/users/me/second_factors/{factor_id}`
-                .replace(`{${"factor_id"}}`, encodeURIComponent(String(factorId)));
+        captchaChallenge: async (options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
+            const localVarPath = `/captcha/challenge`;
             // use dummy base URL string because the URL constructor only accepts absolute URLs.
             const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
             let baseOptions;
@@ -6361,14 +7036,10 @@ export const Class2FAMethodsApiAxiosParamCreator = function (configuration?: Con
                 baseOptions = configuration.baseOptions;
             }
 
-            const localVarRequestOptions = { method: 'DELETE', ...baseOptions, ...options};
+            const localVarRequestOptions = { method: 'POST', ...baseOptions, ...options};
             const localVarHeaderParameter = {} as any;
             const localVarQueryParameter = {} as any;
 
-            // authentication APIAuthToken required
-            // http basic authentication required
-            setBasicAuthToObject(localVarRequestOptions, configuration)
-
 
     
             setSearchParams(localVarUrlObj, localVarQueryParameter);
@@ -6380,34 +7051,139 @@ export const Class2FAMethodsApiAxiosParamCreator = function (configuration?: Con
                 options: localVarRequestOptions,
             };
         },
+    }
+};
+
+/**
+ * CaptchaApi - functional programming interface
+ * @export
+ */
+export const CaptchaApiFp = function(configuration?: Configuration) {
+    const localVarAxiosParamCreator = CaptchaApiAxiosParamCreator(configuration)
+    return {
         /**
-         * 
-         * @param {EnrollTOTPRequest} enrollTOTPRequest 
+         * Get a new proof-of-work challenge, if the server is set up to use the built-in captcha
          * @param {*} [options] Override http request option.
          * @throws {RequiredError}
          */
-        userSecondFactorsEnroll: async (enrollTOTPRequest: EnrollTOTPRequest, options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
-            // verify required parameter 'enrollTOTPRequest' is not null or undefined
-            assertParamExists('userSecondFactorsEnroll', 'enrollTOTPRequest', enrollTOTPRequest)
-            const localVarPath = `/users/me/second_factors/totp`;
-            // use dummy base URL string because the URL constructor only accepts absolute URLs.
-            const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
-            let baseOptions;
-            if (configuration) {
-                baseOptions = configuration.baseOptions;
-            }
-
-            const localVarRequestOptions = { method: 'POST', ...baseOptions, ...options};
-            const localVarHeaderParameter = {} as any;
-            const localVarQueryParameter = {} as any;
-
-            // authentication APIAuthToken required
-            // http basic authentication required
-            setBasicAuthToObject(localVarRequestOptions, configuration)
-
+        async captchaChallenge(options?: RawAxiosRequestConfig): Promise<(axios?: AxiosInstance, basePath?: string) => AxiosPromise<APICaptchaChallenge>> {
+            const localVarAxiosArgs = await localVarAxiosParamCreator.captchaChallenge(options);
+            const localVarOperationServerIndex = configuration?.serverIndex ?? 0;
+            const localVarOperationServerBasePath = operationServerMap['CaptchaApi.captchaChallenge']?.[localVarOperationServerIndex]?.url;
+            return (axios, basePath) => createRequestFunction(localVarAxiosArgs, globalAxios, BASE_PATH, configuration)(axios, localVarOperationServerBasePath || basePath);
+        },
+    }
+};
 
-    
-            localVarHeaderParameter['Content-Type'] = 'application/json';
+/**
+ * CaptchaApi - factory interface
+ * @export
+ */
+export const CaptchaApiFactory = function (configuration?: Configuration, basePath?: string, axios?: AxiosInstance) {
+    const localVarFp = CaptchaApiFp(configuration)
+    return {
+        /**
+         * Get a new proof-of-work challenge, if the server is set up to use the built-in captcha
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        captchaChallenge(options?: any): AxiosPromise<APICaptchaChallenge> {
+            return localVarFp.captchaChallenge(options).then((request) => request(axios, basePath));
+        },
+    };
+};
+
+/**
+ * CaptchaApi - object-oriented interface
+ * @export
+ * @class CaptchaApi
+ * @extends {BaseAPI}
+ */
+export class CaptchaApi extends BaseAPI {
+    /**
+     * Get a new proof-of-work challenge, if the server is set up to use the built-in captcha
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     * @memberof CaptchaApi
+     */
+    public captchaChallenge(options?: RawAxiosRequestConfig) {
+        return CaptchaApiFp(this.configuration).captchaChallenge(options).then((request) => request(this.axios, this.basePath));
+    }
+}
+
+
+
+/**
+ * Class2FAMethodsApi - axios parameter creator
+ * @export
+ */
+export const Class2FAMethodsApiAxiosParamCreator = function (configuration?: Configuration) {
+    return {
+        /**
+         * 
+         * @param {string} factorId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        userSecondFactorsDelete: async (factorId: string, options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
+            // verify required parameter 'factorId' is not null or undefined
+            assertParamExists('userSecondFactorsDelete', 'factorId', factorId)
+            const localVarPath = `/users/me/second_factors/{factor_id}`
+                .replace(`{${"factor_id"}}`, encodeURIComponent(String(factorId)));
+            // use dummy base URL string because the URL constructor only accepts absolute URLs.
+            const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+            let baseOptions;
+            if (configuration) {
+                baseOptions = configuration.baseOptions;
+            }
+
+            const localVarRequestOptions = { method: 'DELETE', ...baseOptions, ...options};
+            const localVarHeaderParameter = {} as any;
+            const localVarQueryParameter = {} as any;
+
+            // authentication APIAuthToken required
+            // http basic authentication required
+            setBasicAuthToObject(localVarRequestOptions, configuration)
+
+
+    
+            setSearchParams(localVarUrlObj, localVarQueryParameter);
+            let headersFromBaseOptions = baseOptions && baseOptions.headers ? baseOptions.headers : {};
+            localVarRequestOptions.headers = {...localVarHeaderParameter, ...headersFromBaseOptions, ...options.headers};
+
+            return {
+                url: toPathString(localVarUrlObj),
+                options: localVarRequestOptions,
+            };
+        },
+        /**
+         * 
+         * @param {EnrollTOTPRequest} enrollTOTPRequest 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        userSecondFactorsEnroll: async (enrollTOTPRequest: EnrollTOTPRequest, options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
+            // verify required parameter 'enrollTOTPRequest' is not null or undefined
+            assertParamExists('userSecondFactorsEnroll', 'enrollTOTPRequest', enrollTOTPRequest)
+            const localVarPath = `/users/me/second_factors/totp`;
+            // use dummy base URL string because the URL constructor only accepts absolute URLs.
+            const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+            let baseOptions;
+            if (configuration) {
+                baseOptions = configuration.baseOptions;
+            }
+
+            const localVarRequestOptions = { method: 'POST', ...baseOptions, ...options};
+            const localVarHeaderParameter = {} as any;
+            const localVarQueryParameter = {} as any;
+
+            // authentication APIAuthToken required
+            // http basic authentication required
+            setBasicAuthToObject(localVarRequestOptions, configuration)
+
+
+    
+            localVarHeaderParameter['Content-Type'] = 'application/json';
 
             setSearchParams(localVarUrlObj, localVarQueryParameter);
             let headersFromBaseOptions = baseOptions && baseOptions.headers ? baseOptions.headers : {};
@@ -7964,22 +8740,34 @@ export class FormBrandingsApi extends BaseAPI {
 
 
 /**
- * FormTemplatesApi - axios parameter creator
+ * FormRevisionsApi - axios parameter creator
  * @export
  */
-export const FormTemplatesApiAxiosParamCreator = function (configuration?: Configuration) {
+export const FormRevisionsApiAxiosParamCreator = function (configuration?: Configuration) {
     return {
         /**
-         * 
-         * @param {string} categoryId 
+         * Compares two revisions of a form. Either revision can be the older one.
+         * @param {string} orgId 
+         * @param {string} formId 
+         * @param {string} fromId 
+         * @param {string} toId 
          * @param {*} [options] Override http request option.
          * @throws {RequiredError}
          */
-        formTemplatesCategoriesGet: async (categoryId: string, options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
-            // verify required parameter 'categoryId' is not null or undefined
-            assertParamExists('formTemplatesCategoriesGet', 'categoryId', categoryId)
-            const localVarPath = `/templates/categories/{category_id}`
-                .replace(`{${"category_id"}}`, encodeURIComponent(String(categoryId)));
+        formRevisionsDiff: async (orgId: string, formId: string, fromId: string, toId: string, options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
+            // verify required parameter 'orgId' is not null or undefined
+            assertParamExists('formRevisionsDiff', 'orgId', orgId)
+            // verify required parameter 'formId' is not null or undefined
+            assertParamExists('formRevisionsDiff', 'formId', formId)
+            // verify required parameter 'fromId' is not null or undefined
+            assertParamExists('formRevisionsDiff', 'fromId', fromId)
+            // verify required parameter 'toId' is not null or undefined
+            assertParamExists('formRevisionsDiff', 'toId', toId)
+            const localVarPath = `/users/me/orgs/{org_id}/forms/{form_id}/revisions/{from_id}/diff/{to_id}`
+                .replace(`{${"org_id"}}`, encodeURIComponent(String(orgId)))
+                .replace(`{${"form_id"}}`, encodeURIComponent(String(formId)))
+                .replace(`{${"from_id"}}`, encodeURIComponent(String(fromId)))
+                .replace(`{${"to_id"}}`, encodeURIComponent(String(toId)));
             // use dummy base URL string because the URL constructor only accepts absolute URLs.
             const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
             let baseOptions;
@@ -7991,6 +8779,10 @@ export const FormTemplatesApiAxiosParamCreator = function (configuration?: Confi
             const localVarHeaderParameter = {} as any;
             const localVarQueryParameter = {} as any;
 
+            // authentication APIAuthTokenWithRole_Viewer_OrgViewAndTeamRoleFromForm_Any required
+            // http basic authentication required
+            setBasicAuthToObject(localVarRequestOptions, configuration)
+
 
     
             setSearchParams(localVarUrlObj, localVarQueryParameter);
@@ -8004,11 +8796,23 @@ export const FormTemplatesApiAxiosParamCreator = function (configuration?: Confi
         },
         /**
          * 
+         * @param {string} orgId 
+         * @param {string} formId 
+         * @param {string} revisionId 
          * @param {*} [options] Override http request option.
          * @throws {RequiredError}
          */
-        formTemplatesCategoriesList: async (options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
-            const localVarPath = `/templates/categories`;
+        formRevisionsGet: async (orgId: string, formId: string, revisionId: string, options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
+            // verify required parameter 'orgId' is not null or undefined
+            assertParamExists('formRevisionsGet', 'orgId', orgId)
+            // verify required parameter 'formId' is not null or undefined
+            assertParamExists('formRevisionsGet', 'formId', formId)
+            // verify required parameter 'revisionId' is not null or undefined
+            assertParamExists('formRevisionsGet', 'revisionId', revisionId)
+            const localVarPath = `/users/me/orgs/{org_id}/forms/{form_id}/revisions/{revision_id}`
+                .replace(`{${"org_id"}}`, encodeURIComponent(String(orgId)))
+                .replace(`{${"form_id"}}`, encodeURIComponent(String(formId)))
+                .replace(`{${"revision_id"}}`, encodeURIComponent(String(revisionId)));
             // use dummy base URL string because the URL constructor only accepts absolute URLs.
             const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
             let baseOptions;
@@ -8020,6 +8824,10 @@ export const FormTemplatesApiAxiosParamCreator = function (configuration?: Confi
             const localVarHeaderParameter = {} as any;
             const localVarQueryParameter = {} as any;
 
+            // authentication APIAuthTokenWithRole_Viewer_OrgViewAndTeamRoleFromForm_Any required
+            // http basic authentication required
+            setBasicAuthToObject(localVarRequestOptions, configuration)
+
 
     
             setSearchParams(localVarUrlObj, localVarQueryParameter);
@@ -8032,23 +8840,20 @@ export const FormTemplatesApiAxiosParamCreator = function (configuration?: Confi
             };
         },
         /**
-         * 
+         * Lists every saved revision of the form\'s questions, newest first
          * @param {string} orgId 
-         * @param {string} templateId 
-         * @param {CloneFormTemplateRequest} cloneFormTemplateRequest 
+         * @param {string} formId 
          * @param {*} [options] Override http request option.
          * @throws {RequiredError}
          */
-        formTemplatesClone: async (orgId: string, templateId: string, cloneFormTemplateRequest: CloneFormTemplateRequest, options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
+        formRevisionsList: async (orgId: string, formId: string, options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
             // verify required parameter 'orgId' is not null or undefined
-            assertParamExists('formTemplatesClone', 'orgId', orgId)
-            // verify required parameter 'templateId' is not null or undefined
-            assertParamExists('formTemplatesClone', 'templateId', templateId)
-            // verify required parameter 'cloneFormTemplateRequest' is not null or undefined
-            assertParamExists('formTemplatesClone', 'cloneFormTemplateRequest', cloneFormTemplateRequest)
-            const localVarPath = `/users/me/orgs/{org_id}/templates/{template_id}/clone`
+            assertParamExists('formRevisionsList', 'orgId', orgId)
+            // verify required parameter 'formId' is not null or undefined
+            assertParamExists('formRevisionsList', 'formId', formId)
+            const localVarPath = `/users/me/orgs/{org_id}/forms/{form_id}/revisions`
                 .replace(`{${"org_id"}}`, encodeURIComponent(String(orgId)))
-                .replace(`{${"template_id"}}`, encodeURIComponent(String(templateId)));
+                .replace(`{${"form_id"}}`, encodeURIComponent(String(formId)));
             // use dummy base URL string because the URL constructor only accepts absolute URLs.
             const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
             let baseOptions;
@@ -8056,39 +8861,189 @@ export const FormTemplatesApiAxiosParamCreator = function (configuration?: Confi
                 baseOptions = configuration.baseOptions;
             }
 
-            const localVarRequestOptions = { method: 'POST', ...baseOptions, ...options};
+            const localVarRequestOptions = { method: 'GET', ...baseOptions, ...options};
             const localVarHeaderParameter = {} as any;
             const localVarQueryParameter = {} as any;
 
-            // authentication APIAuthTokenWithRole_None_OrgView_Any required
+            // authentication APIAuthTokenWithRole_Viewer_OrgViewAndTeamRoleFromForm_Any required
             // http basic authentication required
             setBasicAuthToObject(localVarRequestOptions, configuration)
 
 
     
-            localVarHeaderParameter['Content-Type'] = 'application/json';
-
             setSearchParams(localVarUrlObj, localVarQueryParameter);
             let headersFromBaseOptions = baseOptions && baseOptions.headers ? baseOptions.headers : {};
             localVarRequestOptions.headers = {...localVarHeaderParameter, ...headersFromBaseOptions, ...options.headers};
-            localVarRequestOptions.data = serializeDataIfNeeded(cloneFormTemplateRequest, localVarRequestOptions, configuration)
 
             return {
                 url: toPathString(localVarUrlObj),
                 options: localVarRequestOptions,
             };
         },
+    }
+};
+
+/**
+ * FormRevisionsApi - functional programming interface
+ * @export
+ */
+export const FormRevisionsApiFp = function(configuration?: Configuration) {
+    const localVarAxiosParamCreator = FormRevisionsApiAxiosParamCreator(configuration)
+    return {
+        /**
+         * Compares two revisions of a form. Either revision can be the older one.
+         * @param {string} orgId 
+         * @param {string} formId 
+         * @param {string} fromId 
+         * @param {string} toId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        async formRevisionsDiff(orgId: string, formId: string, fromId: string, toId: string, options?: RawAxiosRequestConfig): Promise<(axios?: AxiosInstance, basePath?: string) => AxiosPromise<APIFormRevisionDiff>> {
+            const localVarAxiosArgs = await localVarAxiosParamCreator.formRevisionsDiff(orgId, formId, fromId, toId, options);
+            const localVarOperationServerIndex = configuration?.serverIndex ?? 0;
+            const localVarOperationServerBasePath = operationServerMap['FormRevisionsApi.formRevisionsDiff']?.[localVarOperationServerIndex]?.url;
+            return (axios, basePath) => createRequestFunction(localVarAxiosArgs, globalAxios, BASE_PATH, configuration)(axios, localVarOperationServerBasePath || basePath);
+        },
         /**
          * 
-         * @param {string} templateId 
+         * @param {string} orgId 
+         * @param {string} formId 
+         * @param {string} revisionId 
          * @param {*} [options] Override http request option.
          * @throws {RequiredError}
          */
-        formTemplatesGet: async (templateId: string, options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
-            // verify required parameter 'templateId' is not null or undefined
-            assertParamExists('formTemplatesGet', 'templateId', templateId)
-            const localVarPath = `/templates/{template_id}`
-                .replace(`{${"template_id"}}`, encodeURIComponent(String(templateId)));
+        async formRevisionsGet(orgId: string, formId: string, revisionId: string, options?: RawAxiosRequestConfig): Promise<(axios?: AxiosInstance, basePath?: string) => AxiosPromise<APIFormRevision>> {
+            const localVarAxiosArgs = await localVarAxiosParamCreator.formRevisionsGet(orgId, formId, revisionId, options);
+            const localVarOperationServerIndex = configuration?.serverIndex ?? 0;
+            const localVarOperationServerBasePath = operationServerMap['FormRevisionsApi.formRevisionsGet']?.[localVarOperationServerIndex]?.url;
+            return (axios, basePath) => createRequestFunction(localVarAxiosArgs, globalAxios, BASE_PATH, configuration)(axios, localVarOperationServerBasePath || basePath);
+        },
+        /**
+         * Lists every saved revision of the form\'s questions, newest first
+         * @param {string} orgId 
+         * @param {string} formId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        async formRevisionsList(orgId: string, formId: string, options?: RawAxiosRequestConfig): Promise<(axios?: AxiosInstance, basePath?: string) => AxiosPromise<Array<APIFormRevisionSummary>>> {
+            const localVarAxiosArgs = await localVarAxiosParamCreator.formRevisionsList(orgId, formId, options);
+            const localVarOperationServerIndex = configuration?.serverIndex ?? 0;
+            const localVarOperationServerBasePath = operationServerMap['FormRevisionsApi.formRevisionsList']?.[localVarOperationServerIndex]?.url;
+            return (axios, basePath) => createRequestFunction(localVarAxiosArgs, globalAxios, BASE_PATH, configuration)(axios, localVarOperationServerBasePath || basePath);
+        },
+    }
+};
+
+/**
+ * FormRevisionsApi - factory interface
+ * @export
+ */
+export const FormRevisionsApiFactory = function (configuration?: Configuration, basePath?: string, axios?: AxiosInstance) {
+    const localVarFp = FormRevisionsApiFp(configuration)
+    return {
+        /**
+         * Compares two revisions of a form. Either revision can be the older one.
+         * @param {string} orgId 
+         * @param {string} formId 
+         * @param {string} fromId 
+         * @param {string} toId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        formRevisionsDiff(orgId: string, formId: string, fromId: string, toId: string, options?: any): AxiosPromise<APIFormRevisionDiff> {
+            return localVarFp.formRevisionsDiff(orgId, formId, fromId, toId, options).then((request) => request(axios, basePath));
+        },
+        /**
+         * 
+         * @param {string} orgId 
+         * @param {string} formId 
+         * @param {string} revisionId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        formRevisionsGet(orgId: string, formId: string, revisionId: string, options?: any): AxiosPromise<APIFormRevision> {
+            return localVarFp.formRevisionsGet(orgId, formId, revisionId, options).then((request) => request(axios, basePath));
+        },
+        /**
+         * Lists every saved revision of the form\'s questions, newest first
+         * @param {string} orgId 
+         * @param {string} formId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        formRevisionsList(orgId: string, formId: string, options?: any): AxiosPromise<Array<APIFormRevisionSummary>> {
+            return localVarFp.formRevisionsList(orgId, formId, options).then((request) => request(axios, basePath));
+        },
+    };
+};
+
+/**
+ * FormRevisionsApi - object-oriented interface
+ * @export
+ * @class FormRevisionsApi
+ * @extends {BaseAPI}
+ */
+export class FormRevisionsApi extends BaseAPI {
+    /**
+     * Compares two revisions of a form. Either revision can be the older one.
+     * @param {string} orgId 
+     * @param {string} formId 
+     * @param {string} fromId 
+     * @param {string} toId 
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     * @memberof FormRevisionsApi
+     */
+    public formRevisionsDiff(orgId: string, formId: string, fromId: string, toId: string, options?: RawAxiosRequestConfig) {
+        return FormRevisionsApiFp(this.configuration).formRevisionsDiff(orgId, formId, fromId, toId, options).then((request) => request(this.axios, this.basePath));
+    }
+
+    /**
+     * 
+     * @param {string} orgId 
+     * @param {string} formId 
+     * @param {string} revisionId 
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     * @memberof FormRevisionsApi
+     */
+    public formRevisionsGet(orgId: string, formId: string, revisionId: string, options?: RawAxiosRequestConfig) {
+        return FormRevisionsApiFp(this.configuration).formRevisionsGet(orgId, formId, revisionId, options).then((request) => request(this.axios, this.basePath));
+    }
+
+    /**
+     * Lists every saved revision of the form\'s questions, newest first
+     * @param {string} orgId 
+     * @param {string} formId 
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     * @memberof FormRevisionsApi
+     */
+    public formRevisionsList(orgId: string, formId: string, options?: RawAxiosRequestConfig) {
+        return FormRevisionsApiFp(this.configuration).formRevisionsList(orgId, formId, options).then((request) => request(this.axios, this.basePath));
+    }
+}
+
+
+
+/**
+ * FormTemplatesApi - axios parameter creator
+ * @export
+ */
+export const FormTemplatesApiAxiosParamCreator = function (configuration?: Configuration) {
+    return {
+        /**
+         * 
+         * @param {string} categoryId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        formTemplatesCategoriesGet: async (categoryId: string, options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
+            // verify required parameter 'categoryId' is not null or undefined
+            assertParamExists('formTemplatesCategoriesGet', 'categoryId', categoryId)
+            const localVarPath = `/templates/categories/{category_id}`
+                .replace(`{${"category_id"}}`, encodeURIComponent(String(categoryId)));
             // use dummy base URL string because the URL constructor only accepts absolute URLs.
             const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
             let baseOptions;
@@ -8113,11 +9068,120 @@ export const FormTemplatesApiAxiosParamCreator = function (configuration?: Confi
         },
         /**
          * 
-         * @param {string} categoryId 
          * @param {*} [options] Override http request option.
          * @throws {RequiredError}
          */
-        formTemplatesList: async (categoryId: string, options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
+        formTemplatesCategoriesList: async (options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
+            const localVarPath = `/templates/categories`;
+            // use dummy base URL string because the URL constructor only accepts absolute URLs.
+            const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+            let baseOptions;
+            if (configuration) {
+                baseOptions = configuration.baseOptions;
+            }
+
+            const localVarRequestOptions = { method: 'GET', ...baseOptions, ...options};
+            const localVarHeaderParameter = {} as any;
+            const localVarQueryParameter = {} as any;
+
+
+    
+            setSearchParams(localVarUrlObj, localVarQueryParameter);
+            let headersFromBaseOptions = baseOptions && baseOptions.headers ? baseOptions.headers : {};
+            localVarRequestOptions.headers = {...localVarHeaderParameter, ...headersFromBaseOptions, ...options.headers};
+
+            return {
+                url: toPathString(localVarUrlObj),
+                options: localVarRequestOptions,
+            };
+        },
+        /**
+         * 
+         * @param {string} orgId 
+         * @param {string} templateId 
+         * @param {CloneFormTemplateRequest} cloneFormTemplateRequest 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        formTemplatesClone: async (orgId: string, templateId: string, cloneFormTemplateRequest: CloneFormTemplateRequest, options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
+            // verify required parameter 'orgId' is not null or undefined
+            assertParamExists('formTemplatesClone', 'orgId', orgId)
+            // verify required parameter 'templateId' is not null or undefined
+            assertParamExists('formTemplatesClone', 'templateId', templateId)
+            // verify required parameter 'cloneFormTemplateRequest' is not null or undefined
+            assertParamExists('formTemplatesClone', 'cloneFormTemplateRequest', cloneFormTemplateRequest)
+            const localVarPath = `/users/me/orgs/{org_id}/templates/{template_id}/clone`
+                .replace(`{${"org_id"}}`, encodeURIComponent(String(orgId)))
+                .replace(`{${"template_id"}}`, encodeURIComponent(String(templateId)));
+            // use dummy base URL string because the URL constructor only accepts absolute URLs.
+            const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+            let baseOptions;
+            if (configuration) {
+                baseOptions = configuration.baseOptions;
+            }
+
+            const localVarRequestOptions = { method: 'POST', ...baseOptions, ...options};
+            const localVarHeaderParameter = {} as any;
+            const localVarQueryParameter = {} as any;
+
+            // authentication APIAuthTokenWithRole_None_OrgView_Any required
+            // http basic authentication required
+            setBasicAuthToObject(localVarRequestOptions, configuration)
+
+
+    
+            localVarHeaderParameter['Content-Type'] = 'application/json';
+
+            setSearchParams(localVarUrlObj, localVarQueryParameter);
+            let headersFromBaseOptions = baseOptions && baseOptions.headers ? baseOptions.headers : {};
+            localVarRequestOptions.headers = {...localVarHeaderParameter, ...headersFromBaseOptions, ...options.headers};
+            localVarRequestOptions.data = serializeDataIfNeeded(cloneFormTemplateRequest, localVarRequestOptions, configuration)
+
+            return {
+                url: toPathString(localVarUrlObj),
+                options: localVarRequestOptions,
+            };
+        },
+        /**
+         * 
+         * @param {string} templateId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        formTemplatesGet: async (templateId: string, options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
+            // verify required parameter 'templateId' is not null or undefined
+            assertParamExists('formTemplatesGet', 'templateId', templateId)
+            const localVarPath = `/templates/{template_id}`
+                .replace(`{${"template_id"}}`, encodeURIComponent(String(templateId)));
+            // use dummy base URL string because the URL constructor only accepts absolute URLs.
+            const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+            let baseOptions;
+            if (configuration) {
+                baseOptions = configuration.baseOptions;
+            }
+
+            const localVarRequestOptions = { method: 'GET', ...baseOptions, ...options};
+            const localVarHeaderParameter = {} as any;
+            const localVarQueryParameter = {} as any;
+
+
+    
+            setSearchParams(localVarUrlObj, localVarQueryParameter);
+            let headersFromBaseOptions = baseOptions && baseOptions.headers ? baseOptions.headers : {};
+            localVarRequestOptions.headers = {...localVarHeaderParameter, ...headersFromBaseOptions, ...options.headers};
+
+            return {
+                url: toPathString(localVarUrlObj),
+                options: localVarRequestOptions,
+            };
+        },
+        /**
+         * 
+         * @param {string} categoryId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        formTemplatesList: async (categoryId: string, options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
             // verify required parameter 'categoryId' is not null or undefined
             assertParamExists('formTemplatesList', 'categoryId', categoryId)
             const localVarPath = `/templates/categories/{category_id}/all`
@@ -8600,7 +9664,7 @@ export const FormsApiAxiosParamCreator = function (configuration?: Configuration
          * @param {string} orgId 
          * @param {string} formId 
          * @param {string} body 
-         * @param {string} [xCaptchaResponse] Response from the Cloudflare Turnstile captcha
+         * @param {string} [xCaptchaResponse] Response from the captcha, or the solved proof-of-work challenge
          * @param {string} [acceptLanguage] Defaults to &#x60;en&#x60;
          * @param {*} [options] Override http request option.
          * @throws {RequiredError}
@@ -8760,6 +9824,47 @@ export const FormsApiAxiosParamCreator = function (configuration?: Configuration
 
 
     
+            setSearchParams(localVarUrlObj, localVarQueryParameter);
+            let headersFromBaseOptions = baseOptions && baseOptions.headers ? baseOptions.headers : {};
+            localVarRequestOptions.headers = {...localVarHeaderParameter, ...headersFromBaseOptions, ...options.headers};
+
+            return {
+                url: toPathString(localVarUrlObj),
+                options: localVarRequestOptions,
+            };
+        },
+        /**
+         * Makes the form\'s latest saved questions live for respondents
+         * @param {string} orgId 
+         * @param {string} formId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        formsPublish: async (orgId: string, formId: string, options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
+            // verify required parameter 'orgId' is not null or undefined
+            assertParamExists('formsPublish', 'orgId', orgId)
+            // verify required parameter 'formId' is not null or undefined
+            assertParamExists('formsPublish', 'formId', formId)
+            const localVarPath = `/users/me/orgs/{org_id}/forms/{form_id}/publish`
+                .replace(`{${"org_id"}}`, encodeURIComponent(String(orgId)))
+                .replace(`{${"form_id"}}`, encodeURIComponent(String(formId)));
+            // use dummy base URL string because the URL constructor only accepts absolute URLs.
+            const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+            let baseOptions;
+            if (configuration) {
+                baseOptions = configuration.baseOptions;
+            }
+
+            const localVarRequestOptions = { method: 'POST', ...baseOptions, ...options};
+            const localVarHeaderParameter = {} as any;
+            const localVarQueryParameter = {} as any;
+
+            // authentication APIAuthTokenWithRole_Editor_OrgViewAndTeamRoleFromForm_Any required
+            // http basic authentication required
+            setBasicAuthToObject(localVarRequestOptions, configuration)
+
+
+    
             setSearchParams(localVarUrlObj, localVarQueryParameter);
             let headersFromBaseOptions = baseOptions && baseOptions.headers ? baseOptions.headers : {};
             localVarRequestOptions.headers = {...localVarHeaderParameter, ...headersFromBaseOptions, ...options.headers};
@@ -8863,6 +9968,53 @@ export const FormsApiAxiosParamCreator = function (configuration?: Configuration
                 options: localVarRequestOptions,
             };
         },
+        /**
+         * Sets when a published form starts and stops accepting responses
+         * @param {string} orgId 
+         * @param {string} formId 
+         * @param {SetFormScheduleRequest} setFormScheduleRequest 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        formsSetSchedule: async (orgId: string, formId: string, setFormScheduleRequest: SetFormScheduleRequest, options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
+            // verify required parameter 'orgId' is not null or undefined
+            assertParamExists('formsSetSchedule', 'orgId', orgId)
+            // verify required parameter 'formId' is not null or undefined
+            assertParamExists('formsSetSchedule', 'formId', formId)
+            // verify required parameter 'setFormScheduleRequest' is not null or undefined
+            assertParamExists('formsSetSchedule', 'setFormScheduleRequest', setFormScheduleRequest)
+            const localVarPath = `/users/me/orgs/{org_id}/forms/{form_id}/schedule`
+                .replace(`{${"org_id"}}`, encodeURIComponent(String(orgId)))
+                .replace(`{${"form_id"}}`, encodeURIComponent(String(formId)));
+            // use dummy base URL string because the URL constructor only accepts absolute URLs.
+            const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+            let baseOptions;
+            if (configuration) {
+                baseOptions = configuration.baseOptions;
+            }
+
+            const localVarRequestOptions = { method: 'PUT', ...baseOptions, ...options};
+            const localVarHeaderParameter = {} as any;
+            const localVarQueryParameter = {} as any;
+
+            // authentication APIAuthTokenWithRole_Editor_OrgViewAndTeamRoleFromForm_Any required
+            // http basic authentication required
+            setBasicAuthToObject(localVarRequestOptions, configuration)
+
+
+    
+            localVarHeaderParameter['Content-Type'] = 'application/json';
+
+            setSearchParams(localVarUrlObj, localVarQueryParameter);
+            let headersFromBaseOptions = baseOptions && baseOptions.headers ? baseOptions.headers : {};
+            localVarRequestOptions.headers = {...localVarHeaderParameter, ...headersFromBaseOptions, ...options.headers};
+            localVarRequestOptions.data = serializeDataIfNeeded(setFormScheduleRequest, localVarRequestOptions, configuration)
+
+            return {
+                url: toPathString(localVarUrlObj),
+                options: localVarRequestOptions,
+            };
+        },
         /**
          * 
          * @param {string} orgId 
@@ -9005,7 +10157,7 @@ export const FormsApiFp = function(configuration?: Configuration) {
          * @param {string} orgId 
          * @param {string} formId 
          * @param {string} body 
-         * @param {string} [xCaptchaResponse] Response from the Cloudflare Turnstile captcha
+         * @param {string} [xCaptchaResponse] Response from the captcha, or the solved proof-of-work challenge
          * @param {string} [acceptLanguage] Defaults to &#x60;en&#x60;
          * @param {*} [options] Override http request option.
          * @throws {RequiredError}
@@ -9054,6 +10206,19 @@ export const FormsApiFp = function(configuration?: Configuration) {
             const localVarOperationServerBasePath = operationServerMap['FormsApi.formsList']?.[localVarOperationServerIndex]?.url;
             return (axios, basePath) => createRequestFunction(localVarAxiosArgs, globalAxios, BASE_PATH, configuration)(axios, localVarOperationServerBasePath || basePath);
         },
+        /**
+         * Makes the form\'s latest saved questions live for respondents
+         * @param {string} orgId 
+         * @param {string} formId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        async formsPublish(orgId: string, formId: string, options?: RawAxiosRequestConfig): Promise<(axios?: AxiosInstance, basePath?: string) => AxiosPromise<APIForm>> {
+            const localVarAxiosArgs = await localVarAxiosParamCreator.formsPublish(orgId, formId, options);
+            const localVarOperationServerIndex = configuration?.serverIndex ?? 0;
+            const localVarOperationServerBasePath = operationServerMap['FormsApi.formsPublish']?.[localVarOperationServerIndex]?.url;
+            return (axios, basePath) => createRequestFunction(localVarAxiosArgs, globalAxios, BASE_PATH, configuration)(axios, localVarOperationServerBasePath || basePath);
+        },
         /**
          * 
          * @param {string} orgId 
@@ -9082,6 +10247,20 @@ export const FormsApiFp = function(configuration?: Configuration) {
             const localVarOperationServerBasePath = operationServerMap['FormsApi.formsSetAutoDelete']?.[localVarOperationServerIndex]?.url;
             return (axios, basePath) => createRequestFunction(localVarAxiosArgs, globalAxios, BASE_PATH, configuration)(axios, localVarOperationServerBasePath || basePath);
         },
+        /**
+         * Sets when a published form starts and stops accepting responses
+         * @param {string} orgId 
+         * @param {string} formId 
+         * @param {SetFormScheduleRequest} setFormScheduleRequest 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        async formsSetSchedule(orgId: string, formId: string, setFormScheduleRequest: SetFormScheduleRequest, options?: RawAxiosRequestConfig): Promise<(axios?: AxiosInstance, basePath?: string) => AxiosPromise<void>> {
+            const localVarAxiosArgs = await localVarAxiosParamCreator.formsSetSchedule(orgId, formId, setFormScheduleRequest, options);
+            const localVarOperationServerIndex = configuration?.serverIndex ?? 0;
+            const localVarOperationServerBasePath = operationServerMap['FormsApi.formsSetSchedule']?.[localVarOperationServerIndex]?.url;
+            return (axios, basePath) => createRequestFunction(localVarAxiosArgs, globalAxios, BASE_PATH, configuration)(axios, localVarOperationServerBasePath || basePath);
+        },
         /**
          * 
          * @param {string} orgId 
@@ -9155,7 +10334,7 @@ export const FormsApiFactory = function (configuration?: Configuration, basePath
          * @param {string} orgId 
          * @param {string} formId 
          * @param {string} body 
-         * @param {string} [xCaptchaResponse] Response from the Cloudflare Turnstile captcha
+         * @param {string} [xCaptchaResponse] Response from the captcha, or the solved proof-of-work challenge
          * @param {string} [acceptLanguage] Defaults to &#x60;en&#x60;
          * @param {*} [options] Override http request option.
          * @throws {RequiredError}
@@ -9192,6 +10371,16 @@ export const FormsApiFactory = function (configuration?: Configuration, basePath
         formsList(orgId: string, options?: any): AxiosPromise<Array<APIForm>> {
             return localVarFp.formsList(orgId, options).then((request) => request(axios, basePath));
         },
+        /**
+         * Makes the form\'s latest saved questions live for respondents
+         * @param {string} orgId 
+         * @param {string} formId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        formsPublish(orgId: string, formId: string, options?: any): AxiosPromise<APIForm> {
+            return localVarFp.formsPublish(orgId, formId, options).then((request) => request(axios, basePath));
+        },
         /**
          * 
          * @param {string} orgId 
@@ -9214,6 +10403,17 @@ export const FormsApiFactory = function (configuration?: Configuration, basePath
         formsSetAutoDelete(orgId: string, formId: string, setSubmissionAutoDeleteRequest: SetSubmissionAutoDeleteRequest, options?: any): AxiosPromise<void> {
             return localVarFp.formsSetAutoDelete(orgId, formId, setSubmissionAutoDeleteRequest, options).then((request) => request(axios, basePath));
         },
+        /**
+         * Sets when a published form starts and stops accepting responses
+         * @param {string} orgId 
+         * @param {string} formId 
+         * @param {SetFormScheduleRequest} setFormScheduleRequest 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        formsSetSchedule(orgId: string, formId: string, setFormScheduleRequest: SetFormScheduleRequest, options?: any): AxiosPromise<void> {
+            return localVarFp.formsSetSchedule(orgId, formId, setFormScheduleRequest, options).then((request) => request(axios, basePath));
+        },
         /**
          * 
          * @param {string} orgId 
@@ -9287,7 +10487,7 @@ export class FormsApi extends BaseAPI {
      * @param {string} orgId 
      * @param {string} formId 
      * @param {string} body 
-     * @param {string} [xCaptchaResponse] Response from the Cloudflare Turnstile captcha
+     * @param {string} [xCaptchaResponse] Response from the captcha, or the solved proof-of-work challenge
      * @param {string} [acceptLanguage] Defaults to &#x60;en&#x60;
      * @param {*} [options] Override http request option.
      * @throws {RequiredError}
@@ -9332,6 +10532,18 @@ export class FormsApi extends BaseAPI {
         return FormsApiFp(this.configuration).formsList(orgId, options).then((request) => request(this.axios, this.basePath));
     }
 
+    /**
+     * Makes the form\'s latest saved questions live for respondents
+     * @param {string} orgId 
+     * @param {string} formId 
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     * @memberof FormsApi
+     */
+    public formsPublish(orgId: string, formId: string, options?: RawAxiosRequestConfig) {
+        return FormsApiFp(this.configuration).formsPublish(orgId, formId, options).then((request) => request(this.axios, this.basePath));
+    }
+
     /**
      * 
      * @param {string} orgId 
@@ -9358,6 +10570,19 @@ export class FormsApi extends BaseAPI {
         return FormsApiFp(this.configuration).formsSetAutoDelete(orgId, formId, setSubmissionAutoDeleteRequest, options).then((request) => request(this.axios, this.basePath));
     }
 
+    /**
+     * Sets when a published form starts and stops accepting responses
+     * @param {string} orgId 
+     * @param {string} formId 
+     * @param {SetFormScheduleRequest} setFormScheduleRequest 
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     * @memberof FormsApi
+     */
+    public formsSetSchedule(orgId: string, formId: string, setFormScheduleRequest: SetFormScheduleRequest, options?: RawAxiosRequestConfig) {
+        return FormsApiFp(this.configuration).formsSetSchedule(orgId, formId, setFormScheduleRequest, options).then((request) => request(this.axios, this.basePath));
+    }
+
     /**
      * 
      * @param {string} orgId 
@@ -9564,18 +10789,22 @@ export class InductionApi extends BaseAPI {
 
 
 /**
- * MetaApi - axios parameter creator
+ * MailApi - axios parameter creator
  * @export
  */
-export const MetaApiAxiosParamCreator = function (configuration?: Configuration) {
+export const MailApiAxiosParamCreator = function (configuration?: Configuration) {
     return {
         /**
          * 
+         * @param {string} orgId 
          * @param {*} [options] Override http request option.
          * @throws {RequiredError}
          */
-        metaHealth: async (options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
-            const localVarPath = `/healthz`;
+        mailListFailed: async (orgId: string, options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
+            // verify required parameter 'orgId' is not null or undefined
+            assertParamExists('mailListFailed', 'orgId', orgId)
+            const localVarPath = `/users/me/orgs/{org_id}/mail/failed`
+                .replace(`{${"org_id"}}`, encodeURIComponent(String(orgId)));
             // use dummy base URL string because the URL constructor only accepts absolute URLs.
             const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
             let baseOptions;
@@ -9587,6 +10816,10 @@ export const MetaApiAxiosParamCreator = function (configuration?: Configuration)
             const localVarHeaderParameter = {} as any;
             const localVarQueryParameter = {} as any;
 
+            // authentication APIAuthTokenWithRole_None_OrgAdmin_Any required
+            // http basic authentication required
+            setBasicAuthToObject(localVarRequestOptions, configuration)
+
 
     
             setSearchParams(localVarUrlObj, localVarQueryParameter);
@@ -9598,16 +10831,192 @@ export const MetaApiAxiosParamCreator = function (configuration?: Configuration)
                 options: localVarRequestOptions,
             };
         },
-    }
-};
-
-/**
- * MetaApi - functional programming interface
- * @export
- */
-export const MetaApiFp = function(configuration?: Configuration) {
-    const localVarAxiosParamCreator = MetaApiAxiosParamCreator(configuration)
-    return {
+        /**
+         * 
+         * @param {string} orgId 
+         * @param {string} mailId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        mailRequeue: async (orgId: string, mailId: string, options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
+            // verify required parameter 'orgId' is not null or undefined
+            assertParamExists('mailRequeue', 'orgId', orgId)
+            // verify required parameter 'mailId' is not null or undefined
+            assertParamExists('mailRequeue', 'mailId', mailId)
+            const localVarPath = `/users/me/orgs/{org_id}/mail/{mail_id}/requeue`
+                .replace(`{${"org_id"}}`, encodeURIComponent(String(orgId)))
+                .replace(`{${"mail_id"}}`, encodeURIComponent(String(mailId)));
+            // use dummy base URL string because the URL constructor only accepts absolute URLs.
+            const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+            let baseOptions;
+            if (configuration) {
+                baseOptions = configuration.baseOptions;
+            }
+
+            const localVarRequestOptions = { method: 'POST', ...baseOptions, ...options};
+            const localVarHeaderParameter = {} as any;
+            const localVarQueryParameter = {} as any;
+
+            // authentication APIAuthTokenWithRole_None_OrgAdmin_Any required
+            // http basic authentication required
+            setBasicAuthToObject(localVarRequestOptions, configuration)
+
+
+    
+            setSearchParams(localVarUrlObj, localVarQueryParameter);
+            let headersFromBaseOptions = baseOptions && baseOptions.headers ? baseOptions.headers : {};
+            localVarRequestOptions.headers = {...localVarHeaderParameter, ...headersFromBaseOptions, ...options.headers};
+
+            return {
+                url: toPathString(localVarUrlObj),
+                options: localVarRequestOptions,
+            };
+        },
+    }
+};
+
+/**
+ * MailApi - functional programming interface
+ * @export
+ */
+export const MailApiFp = function(configuration?: Configuration) {
+    const localVarAxiosParamCreator = MailApiAxiosParamCreator(configuration)
+    return {
+        /**
+         * 
+         * @param {string} orgId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        async mailListFailed(orgId: string, options?: RawAxiosRequestConfig): Promise<(axios?: AxiosInstance, basePath?: string) => AxiosPromise<Array<APIFailedMail>>> {
+            const localVarAxiosArgs = await localVarAxiosParamCreator.mailListFailed(orgId, options);
+            const localVarOperationServerIndex = configuration?.serverIndex ?? 0;
+            const localVarOperationServerBasePath = operationServerMap['MailApi.mailListFailed']?.[localVarOperationServerIndex]?.url;
+            return (axios, basePath) => createRequestFunction(localVarAxiosArgs, globalAxios, BASE_PATH, configuration)(axios, localVarOperationServerBasePath || basePath);
+        },
+        /**
+         * 
+         * @param {string} orgId 
+         * @param {string} mailId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        async mailRequeue(orgId: string, mailId: string, options?: RawAxiosRequestConfig): Promise<(axios?: AxiosInstance, basePath?: string) => AxiosPromise<void>> {
+            const localVarAxiosArgs = await localVarAxiosParamCreator.mailRequeue(orgId, mailId, options);
+            const localVarOperationServerIndex = configuration?.serverIndex ?? 0;
+            const localVarOperationServerBasePath = operationServerMap['MailApi.mailRequeue']?.[localVarOperationServerIndex]?.url;
+            return (axios, basePath) => createRequestFunction(localVarAxiosArgs, globalAxios, BASE_PATH, configuration)(axios, localVarOperationServerBasePath || basePath);
+        },
+    }
+};
+
+/**
+ * MailApi - factory interface
+ * @export
+ */
+export const MailApiFactory = function (configuration?: Configuration, basePath?: string, axios?: AxiosInstance) {
+    const localVarFp = MailApiFp(configuration)
+    return {
+        /**
+         * 
+         * @param {string} orgId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        mailListFailed(orgId: string, options?: any): AxiosPromise<Array<APIFailedMail>> {
+            return localVarFp.mailListFailed(orgId, options).then((request) => request(axios, basePath));
+        },
+        /**
+         * 
+         * @param {string} orgId 
+         * @param {string} mailId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        mailRequeue(orgId: string, mailId: string, options?: any): AxiosPromise<void> {
+            return localVarFp.mailRequeue(orgId, mailId, options).then((request) => request(axios, basePath));
+        },
+    };
+};
+
+/**
+ * MailApi - object-oriented interface
+ * @export
+ * @class MailApi
+ * @extends {BaseAPI}
+ */
+export class MailApi extends BaseAPI {
+    /**
+     * 
+     * @param {string} orgId 
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     * @memberof MailApi
+     */
+    public mailListFailed(orgId: string, options?: RawAxiosRequestConfig) {
+        return MailApiFp(this.configuration).mailListFailed(orgId, options).then((request) => request(this.axios, this.basePath));
+    }
+
+    /**
+     * 
+     * @param {string} orgId 
+     * @param {string} mailId 
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     * @memberof MailApi
+     */
+    public mailRequeue(orgId: string, mailId: string, options?: RawAxiosRequestConfig) {
+        return MailApiFp(this.configuration).mailRequeue(orgId, mailId, options).then((request) => request(this.axios, this.basePath));
+    }
+}
+
+
+
+/**
+ * MetaApi - axios parameter creator
+ * @export
+ */
+export const MetaApiAxiosParamCreator = function (configuration?: Configuration) {
+    return {
+        /**
+         * 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        metaHealth: async (options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
+            const localVarPath = `/healthz`;
+            // use dummy base URL string because the URL constructor only accepts absolute URLs.
+            const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+            let baseOptions;
+            if (configuration) {
+                baseOptions = configuration.baseOptions;
+            }
+
+            const localVarRequestOptions = { method: 'GET', ...baseOptions, ...options};
+            const localVarHeaderParameter = {} as any;
+            const localVarQueryParameter = {} as any;
+
+
+    
+            setSearchParams(localVarUrlObj, localVarQueryParameter);
+            let headersFromBaseOptions = baseOptions && baseOptions.headers ? baseOptions.headers : {};
+            localVarRequestOptions.headers = {...localVarHeaderParameter, ...headersFromBaseOptions, ...options.headers};
+
+            return {
+                url: toPathString(localVarUrlObj),
+                options: localVarRequestOptions,
+            };
+        },
+    }
+};
+
+/**
+ * MetaApi - functional programming interface
+ * @export
+ */
+export const MetaApiFp = function(configuration?: Configuration) {
+    const localVarAxiosParamCreator = MetaApiAxiosParamCreator(configuration)
+    return {
         /**
          * 
          * @param {*} [options] Override http request option.
@@ -12957,30 +14366,25 @@ export class QuestionsApi extends BaseAPI {
 
 
 /**
- * SubmissionAssetApi - axios parameter creator
+ * ServiceAccountAPIKeysApi - axios parameter creator
  * @export
  */
-export const SubmissionAssetApiAxiosParamCreator = function (configuration?: Configuration) {
+export const ServiceAccountAPIKeysApiAxiosParamCreator = function (configuration?: Configuration) {
     return {
         /**
-         * 
+         * Create an API key for a service account in the organisation. If the request is itself made with an API key, the new key can only have scopes that key has.
          * @param {string} orgId 
-         * @param {string} formId 
-         * @param {string} fileId 
+         * @param {CreateServiceAccountAPIKeyRequest} createServiceAccountAPIKeyRequest 
          * @param {*} [options] Override http request option.
          * @throws {RequiredError}
          */
-        submissionAssetsGetLink: async (orgId: string, formId: string, fileId: string, options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
+        serviceAccountApiKeysCreate: async (orgId: string, createServiceAccountAPIKeyRequest: CreateServiceAccountAPIKeyRequest, options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
             // verify required parameter 'orgId' is not null or undefined
-            assertParamExists('submissionAssetsGetLink', 'orgId', orgId)
-            // verify required parameter 'formId' is not null or undefined
-            assertParamExists('submissionAssetsGetLink', 'formId', formId)
-            // verify required parameter 'fileId' is not null or undefined
-            assertParamExists('submissionAssetsGetLink', 'fileId', fileId)
-            const localVarPath = `/user/me/orgs/{_org_id}/forms/{form_id}/submission-assets/{file_id}`
-                .replace(`{${"_org_id"}}`, encodeURIComponent(String(orgId)))
-                .replace(`{${"form_id"}}`, encodeURIComponent(String(formId)))
-                .replace(`{${"file_id"}}`, encodeURIComponent(String(fileId)));
+            assertParamExists('serviceAccountApiKeysCreate', 'orgId', orgId)
+            // verify required parameter 'createServiceAccountAPIKeyRequest' is not null or undefined
+            assertParamExists('serviceAccountApiKeysCreate', 'createServiceAccountAPIKeyRequest', createServiceAccountAPIKeyRequest)
+            const localVarPath = `/users/me/orgs/{org_id}/api-keys`
+                .replace(`{${"org_id"}}`, encodeURIComponent(String(orgId)));
             // use dummy base URL string because the URL constructor only accepts absolute URLs.
             const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
             let baseOptions;
@@ -12988,61 +14392,328 @@ export const SubmissionAssetApiAxiosParamCreator = function (configuration?: Con
                 baseOptions = configuration.baseOptions;
             }
 
-            const localVarRequestOptions = { method: 'GET', ...baseOptions, ...options};
+            const localVarRequestOptions = { method: 'POST', ...baseOptions, ...options};
             const localVarHeaderParameter = {} as any;
             const localVarQueryParameter = {} as any;
 
-            // authentication APIAuthTokenWithRole_Viewer_OrgViewAndTeamRoleFromForm_Any required
+            // authentication APIAuthTokenWithRole_None_OrgAdmin_Any required
             // http basic authentication required
             setBasicAuthToObject(localVarRequestOptions, configuration)
 
 
     
+            localVarHeaderParameter['Content-Type'] = 'application/json';
+
             setSearchParams(localVarUrlObj, localVarQueryParameter);
             let headersFromBaseOptions = baseOptions && baseOptions.headers ? baseOptions.headers : {};
             localVarRequestOptions.headers = {...localVarHeaderParameter, ...headersFromBaseOptions, ...options.headers};
+            localVarRequestOptions.data = serializeDataIfNeeded(createServiceAccountAPIKeyRequest, localVarRequestOptions, configuration)
 
             return {
                 url: toPathString(localVarUrlObj),
                 options: localVarRequestOptions,
             };
         },
-    }
-};
+        /**
+         * Revoke an API key, which stops working straight away
+         * @param {string} orgId 
+         * @param {string} keyId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        serviceAccountApiKeysDelete: async (orgId: string, keyId: string, options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
+            // verify required parameter 'orgId' is not null or undefined
+            assertParamExists('serviceAccountApiKeysDelete', 'orgId', orgId)
+            // verify required parameter 'keyId' is not null or undefined
+            assertParamExists('serviceAccountApiKeysDelete', 'keyId', keyId)
+            const localVarPath = `/users/me/orgs/{org_id}/api-keys/{key_id}`
+                .replace(`{${"org_id"}}`, encodeURIComponent(String(orgId)))
+                .replace(`{${"key_id"}}`, encodeURIComponent(String(keyId)));
+            // use dummy base URL string because the URL constructor only accepts absolute URLs.
+            const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+            let baseOptions;
+            if (configuration) {
+                baseOptions = configuration.baseOptions;
+            }
 
-/**
- * SubmissionAssetApi - functional programming interface
- * @export
- */
-export const SubmissionAssetApiFp = function(configuration?: Configuration) {
-    const localVarAxiosParamCreator = SubmissionAssetApiAxiosParamCreator(configuration)
-    return {
+            const localVarRequestOptions = { method: 'DELETE', ...baseOptions, ...options};
+            const localVarHeaderParameter = {} as any;
+            const localVarQueryParameter = {} as any;
+
+            // authentication APIAuthTokenWithRole_None_OrgAdmin_Any required
+            // http basic authentication required
+            setBasicAuthToObject(localVarRequestOptions, configuration)
+
+
+    
+            setSearchParams(localVarUrlObj, localVarQueryParameter);
+            let headersFromBaseOptions = baseOptions && baseOptions.headers ? baseOptions.headers : {};
+            localVarRequestOptions.headers = {...localVarHeaderParameter, ...headersFromBaseOptions, ...options.headers};
+
+            return {
+                url: toPathString(localVarUrlObj),
+                options: localVarRequestOptions,
+            };
+        },
         /**
-         * 
+         * List the API keys of service accounts that are only in this organisation
          * @param {string} orgId 
-         * @param {string} formId 
-         * @param {string} fileId 
          * @param {*} [options] Override http request option.
          * @throws {RequiredError}
          */
-        async submissionAssetsGetLink(orgId: string, formId: string, fileId: string, options?: RawAxiosRequestConfig): Promise<(axios?: AxiosInstance, basePath?: string) => AxiosPromise<Array<number>>> {
-            const localVarAxiosArgs = await localVarAxiosParamCreator.submissionAssetsGetLink(orgId, formId, fileId, options);
-            const localVarOperationServerIndex = configuration?.serverIndex ?? 0;
-            const localVarOperationServerBasePath = operationServerMap['SubmissionAssetApi.submissionAssetsGetLink']?.[localVarOperationServerIndex]?.url;
-            return (axios, basePath) => createRequestFunction(localVarAxiosArgs, globalAxios, BASE_PATH, configuration)(axios, localVarOperationServerBasePath || basePath);
+        serviceAccountApiKeysList: async (orgId: string, options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
+            // verify required parameter 'orgId' is not null or undefined
+            assertParamExists('serviceAccountApiKeysList', 'orgId', orgId)
+            const localVarPath = `/users/me/orgs/{org_id}/api-keys`
+                .replace(`{${"org_id"}}`, encodeURIComponent(String(orgId)));
+            // use dummy base URL string because the URL constructor only accepts absolute URLs.
+            const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+            let baseOptions;
+            if (configuration) {
+                baseOptions = configuration.baseOptions;
+            }
+
+            const localVarRequestOptions = { method: 'GET', ...baseOptions, ...options};
+            const localVarHeaderParameter = {} as any;
+            const localVarQueryParameter = {} as any;
+
+            // authentication APIAuthTokenWithRole_None_OrgAdmin_Any required
+            // http basic authentication required
+            setBasicAuthToObject(localVarRequestOptions, configuration)
+
+
+    
+            setSearchParams(localVarUrlObj, localVarQueryParameter);
+            let headersFromBaseOptions = baseOptions && baseOptions.headers ? baseOptions.headers : {};
+            localVarRequestOptions.headers = {...localVarHeaderParameter, ...headersFromBaseOptions, ...options.headers};
+
+            return {
+                url: toPathString(localVarUrlObj),
+                options: localVarRequestOptions,
+            };
         },
     }
 };
 
 /**
- * SubmissionAssetApi - factory interface
+ * ServiceAccountAPIKeysApi - functional programming interface
  * @export
  */
-export const SubmissionAssetApiFactory = function (configuration?: Configuration, basePath?: string, axios?: AxiosInstance) {
-    const localVarFp = SubmissionAssetApiFp(configuration)
+export const ServiceAccountAPIKeysApiFp = function(configuration?: Configuration) {
+    const localVarAxiosParamCreator = ServiceAccountAPIKeysApiAxiosParamCreator(configuration)
     return {
         /**
-         * 
+         * Create an API key for a service account in the organisation. If the request is itself made with an API key, the new key can only have scopes that key has.
+         * @param {string} orgId 
+         * @param {CreateServiceAccountAPIKeyRequest} createServiceAccountAPIKeyRequest 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        async serviceAccountApiKeysCreate(orgId: string, createServiceAccountAPIKeyRequest: CreateServiceAccountAPIKeyRequest, options?: RawAxiosRequestConfig): Promise<(axios?: AxiosInstance, basePath?: string) => AxiosPromise<CreateServiceAccountAPIKeyResponse>> {
+            const localVarAxiosArgs = await localVarAxiosParamCreator.serviceAccountApiKeysCreate(orgId, createServiceAccountAPIKeyRequest, options);
+            const localVarOperationServerIndex = configuration?.serverIndex ?? 0;
+            const localVarOperationServerBasePath = operationServerMap['ServiceAccountAPIKeysApi.serviceAccountApiKeysCreate']?.[localVarOperationServerIndex]?.url;
+            return (axios, basePath) => createRequestFunction(localVarAxiosArgs, globalAxios, BASE_PATH, configuration)(axios, localVarOperationServerBasePath || basePath);
+        },
+        /**
+         * Revoke an API key, which stops working straight away
+         * @param {string} orgId 
+         * @param {string} keyId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        async serviceAccountApiKeysDelete(orgId: string, keyId: string, options?: RawAxiosRequestConfig): Promise<(axios?: AxiosInstance, basePath?: string) => AxiosPromise<void>> {
+            const localVarAxiosArgs = await localVarAxiosParamCreator.serviceAccountApiKeysDelete(orgId, keyId, options);
+            const localVarOperationServerIndex = configuration?.serverIndex ?? 0;
+            const localVarOperationServerBasePath = operationServerMap['ServiceAccountAPIKeysApi.serviceAccountApiKeysDelete']?.[localVarOperationServerIndex]?.url;
+            return (axios, basePath) => createRequestFunction(localVarAxiosArgs, globalAxios, BASE_PATH, configuration)(axios, localVarOperationServerBasePath || basePath);
+        },
+        /**
+         * List the API keys of service accounts that are only in this organisation
+         * @param {string} orgId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        async serviceAccountApiKeysList(orgId: string, options?: RawAxiosRequestConfig): Promise<(axios?: AxiosInstance, basePath?: string) => AxiosPromise<Array<APIServiceAccountAPIKey>>> {
+            const localVarAxiosArgs = await localVarAxiosParamCreator.serviceAccountApiKeysList(orgId, options);
+            const localVarOperationServerIndex = configuration?.serverIndex ?? 0;
+            const localVarOperationServerBasePath = operationServerMap['ServiceAccountAPIKeysApi.serviceAccountApiKeysList']?.[localVarOperationServerIndex]?.url;
+            return (axios, basePath) => createRequestFunction(localVarAxiosArgs, globalAxios, BASE_PATH, configuration)(axios, localVarOperationServerBasePath || basePath);
+        },
+    }
+};
+
+/**
+ * ServiceAccountAPIKeysApi - factory interface
+ * @export
+ */
+export const ServiceAccountAPIKeysApiFactory = function (configuration?: Configuration, basePath?: string, axios?: AxiosInstance) {
+    const localVarFp = ServiceAccountAPIKeysApiFp(configuration)
+    return {
+        /**
+         * Create an API key for a service account in the organisation. If the request is itself made with an API key, the new key can only have scopes that key has.
+         * @param {string} orgId 
+         * @param {CreateServiceAccountAPIKeyRequest} createServiceAccountAPIKeyRequest 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        serviceAccountApiKeysCreate(orgId: string, createServiceAccountAPIKeyRequest: CreateServiceAccountAPIKeyRequest, options?: any): AxiosPromise<CreateServiceAccountAPIKeyResponse> {
+            return localVarFp.serviceAccountApiKeysCreate(orgId, createServiceAccountAPIKeyRequest, options).then((request) => request(axios, basePath));
+        },
+        /**
+         * Revoke an API key, which stops working straight away
+         * @param {string} orgId 
+         * @param {string} keyId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        serviceAccountApiKeysDelete(orgId: string, keyId: string, options?: any): AxiosPromise<void> {
+            return localVarFp.serviceAccountApiKeysDelete(orgId, keyId, options).then((request) => request(axios, basePath));
+        },
+        /**
+         * List the API keys of service accounts that are only in this organisation
+         * @param {string} orgId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        serviceAccountApiKeysList(orgId: string, options?: any): AxiosPromise<Array<APIServiceAccountAPIKey>> {
+            return localVarFp.serviceAccountApiKeysList(orgId, options).then((request) => request(axios, basePath));
+        },
+    };
+};
+
+/**
+ * ServiceAccountAPIKeysApi - object-oriented interface
+ * @export
+ * @class ServiceAccountAPIKeysApi
+ * @extends {BaseAPI}
+ */
+export class ServiceAccountAPIKeysApi extends BaseAPI {
+    /**
+     * Create an API key for a service account in the organisation. If the request is itself made with an API key, the new key can only have scopes that key has.
+     * @param {string} orgId 
+     * @param {CreateServiceAccountAPIKeyRequest} createServiceAccountAPIKeyRequest 
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     * @memberof ServiceAccountAPIKeysApi
+     */
+    public serviceAccountApiKeysCreate(orgId: string, createServiceAccountAPIKeyRequest: CreateServiceAccountAPIKeyRequest, options?: RawAxiosRequestConfig) {
+        return ServiceAccountAPIKeysApiFp(this.configuration).serviceAccountApiKeysCreate(orgId, createServiceAccountAPIKeyRequest, options).then((request) => request(this.axios, this.basePath));
+    }
+
+    /**
+     * Revoke an API key, which stops working straight away
+     * @param {string} orgId 
+     * @param {string} keyId 
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     * @memberof ServiceAccountAPIKeysApi
+     */
+    public serviceAccountApiKeysDelete(orgId: string, keyId: string, options?: RawAxiosRequestConfig) {
+        return ServiceAccountAPIKeysApiFp(this.configuration).serviceAccountApiKeysDelete(orgId, keyId, options).then((request) => request(this.axios, this.basePath));
+    }
+
+    /**
+     * List the API keys of service accounts that are only in this organisation
+     * @param {string} orgId 
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     * @memberof ServiceAccountAPIKeysApi
+     */
+    public serviceAccountApiKeysList(orgId: string, options?: RawAxiosRequestConfig) {
+        return ServiceAccountAPIKeysApiFp(this.configuration).serviceAccountApiKeysList(orgId, options).then((request) => request(this.axios, this.basePath));
+    }
+}
+
+
+
+/**
+ * SubmissionAssetApi - axios parameter creator
+ * @export
+ */
+export const SubmissionAssetApiAxiosParamCreator = function (configuration?: Configuration) {
+    return {
+        /**
+         * 
+         * @param {string} orgId 
+         * @param {string} formId 
+         * @param {string} fileId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        submissionAssetsGetLink: async (orgId: string, formId: string, fileId: string, options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
+            // verify required parameter 'orgId' is not null or undefined
+            assertParamExists('submissionAssetsGetLink', 'orgId', orgId)
+            // verify required parameter 'formId' is not null or undefined
+            assertParamExists('submissionAssetsGetLink', 'formId', formId)
+            // verify required parameter 'fileId' is not null or undefined
+            assertParamExists('submissionAssetsGetLink', 'fileId', fileId)
+            const localVarPath = `/user/me/orgs/{_org_id}/forms/{form_id}/submission-assets/{file_id}`
+                .replace(`{${"_org_id"}}`, encodeURIComponent(String(orgId)))
+                .replace(`{${"form_id"}}`, encodeURIComponent(String(formId)))
+                .replace(`{${"file_id"}}`, encodeURIComponent(String(fileId)));
+            // use dummy base URL string because the URL constructor only accepts absolute URLs.
+            const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+            let baseOptions;
+            if (configuration) {
+                baseOptions = configuration.baseOptions;
+            }
+
+            const localVarRequestOptions = { method: 'GET', ...baseOptions, ...options};
+            const localVarHeaderParameter = {} as any;
+            const localVarQueryParameter = {} as any;
+
+            // authentication APIAuthTokenWithRole_Viewer_OrgViewAndTeamRoleFromForm_Any required
+            // http basic authentication required
+            setBasicAuthToObject(localVarRequestOptions, configuration)
+
+
+    
+            setSearchParams(localVarUrlObj, localVarQueryParameter);
+            let headersFromBaseOptions = baseOptions && baseOptions.headers ? baseOptions.headers : {};
+            localVarRequestOptions.headers = {...localVarHeaderParameter, ...headersFromBaseOptions, ...options.headers};
+
+            return {
+                url: toPathString(localVarUrlObj),
+                options: localVarRequestOptions,
+            };
+        },
+    }
+};
+
+/**
+ * SubmissionAssetApi - functional programming interface
+ * @export
+ */
+export const SubmissionAssetApiFp = function(configuration?: Configuration) {
+    const localVarAxiosParamCreator = SubmissionAssetApiAxiosParamCreator(configuration)
+    return {
+        /**
+         * 
+         * @param {string} orgId 
+         * @param {string} formId 
+         * @param {string} fileId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        async submissionAssetsGetLink(orgId: string, formId: string, fileId: string, options?: RawAxiosRequestConfig): Promise<(axios?: AxiosInstance, basePath?: string) => AxiosPromise<Array<number>>> {
+            const localVarAxiosArgs = await localVarAxiosParamCreator.submissionAssetsGetLink(orgId, formId, fileId, options);
+            const localVarOperationServerIndex = configuration?.serverIndex ?? 0;
+            const localVarOperationServerBasePath = operationServerMap['SubmissionAssetApi.submissionAssetsGetLink']?.[localVarOperationServerIndex]?.url;
+            return (axios, basePath) => createRequestFunction(localVarAxiosArgs, globalAxios, BASE_PATH, configuration)(axios, localVarOperationServerBasePath || basePath);
+        },
+    }
+};
+
+/**
+ * SubmissionAssetApi - factory interface
+ * @export
+ */
+export const SubmissionAssetApiFactory = function (configuration?: Configuration, basePath?: string, axios?: AxiosInstance) {
+    const localVarFp = SubmissionAssetApiFp(configuration)
+    return {
+        /**
+         * 
          * @param {string} orgId 
          * @param {string} formId 
          * @param {string} fileId 
@@ -13084,6 +14755,47 @@ export class SubmissionAssetApi extends BaseAPI {
  */
 export const SubmissionsApiAxiosParamCreator = function (configuration?: Configuration) {
     return {
+        /**
+         * Lists every entry in the form\'s submission chain, with a signed checkpoint of its head. Each submission and deletion is chained to the one before it, so clients can use this to check that the submissions they\'ve synced are complete and in order. Submissions made before the chain was added aren\'t in it.
+         * @param {string} orgId 
+         * @param {string} formId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        submissionsChain: async (orgId: string, formId: string, options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
+            // verify required parameter 'orgId' is not null or undefined
+            assertParamExists('submissionsChain', 'orgId', orgId)
+            // verify required parameter 'formId' is not null or undefined
+            assertParamExists('submissionsChain', 'formId', formId)
+            const localVarPath = `/users/me/orgs/{org_id}/forms/{form_id}/submissions/chain`
+                .replace(`{${"org_id"}}`, encodeURIComponent(String(orgId)))
+                .replace(`{${"form_id"}}`, encodeURIComponent(String(formId)));
+            // use dummy base URL string because the URL constructor only accepts absolute URLs.
+            const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+            let baseOptions;
+            if (configuration) {
+                baseOptions = configuration.baseOptions;
+            }
+
+            const localVarRequestOptions = { method: 'GET', ...baseOptions, ...options};
+            const localVarHeaderParameter = {} as any;
+            const localVarQueryParameter = {} as any;
+
+            // authentication APIAuthTokenWithRole_Viewer_OrgViewAndTeamRoleFromForm_Any required
+            // http basic authentication required
+            setBasicAuthToObject(localVarRequestOptions, configuration)
+
+
+    
+            setSearchParams(localVarUrlObj, localVarQueryParameter);
+            let headersFromBaseOptions = baseOptions && baseOptions.headers ? baseOptions.headers : {};
+            localVarRequestOptions.headers = {...localVarHeaderParameter, ...headersFromBaseOptions, ...options.headers};
+
+            return {
+                url: toPathString(localVarUrlObj),
+                options: localVarRequestOptions,
+            };
+        },
         /**
          * 
          * @param {string} orgId 
@@ -13258,6 +14970,94 @@ export const SubmissionsApiAxiosParamCreator = function (configuration?: Configu
             localVarRequestOptions.headers = {...localVarHeaderParameter, ...headersFromBaseOptions, ...options.headers};
             localVarRequestOptions.data = serializeDataIfNeeded(submissionCountSinceRequest, localVarRequestOptions, configuration)
 
+            return {
+                url: toPathString(localVarUrlObj),
+                options: localVarRequestOptions,
+            };
+        },
+        /**
+         * The public keys that new submissions to the form are encrypted to. Existing submissions can be re-wrapped for any of these that can\'t read them yet.
+         * @param {string} orgId 
+         * @param {string} formId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        submissionsRecipients: async (orgId: string, formId: string, options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
+            // verify required parameter 'orgId' is not null or undefined
+            assertParamExists('submissionsRecipients', 'orgId', orgId)
+            // verify required parameter 'formId' is not null or undefined
+            assertParamExists('submissionsRecipients', 'formId', formId)
+            const localVarPath = `/users/me/orgs/{org_id}/forms/{form_id}/submissions/recipients`
+                .replace(`{${"org_id"}}`, encodeURIComponent(String(orgId)))
+                .replace(`{${"form_id"}}`, encodeURIComponent(String(formId)));
+            // use dummy base URL string because the URL constructor only accepts absolute URLs.
+            const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+            let baseOptions;
+            if (configuration) {
+                baseOptions = configuration.baseOptions;
+            }
+
+            const localVarRequestOptions = { method: 'GET', ...baseOptions, ...options};
+            const localVarHeaderParameter = {} as any;
+            const localVarQueryParameter = {} as any;
+
+            // authentication APIAuthTokenWithRole_Viewer_OrgViewAndTeamRoleFromForm_Any required
+            // http basic authentication required
+            setBasicAuthToObject(localVarRequestOptions, configuration)
+
+
+    
+            setSearchParams(localVarUrlObj, localVarQueryParameter);
+            let headersFromBaseOptions = baseOptions && baseOptions.headers ? baseOptions.headers : {};
+            localVarRequestOptions.headers = {...localVarHeaderParameter, ...headersFromBaseOptions, ...options.headers};
+
+            return {
+                url: toPathString(localVarUrlObj),
+                options: localVarRequestOptions,
+            };
+        },
+        /**
+         * Adds key packets made on the client to existing submissions, so that keys added to the team after a submission was made can decrypt it. The server never sees the session keys, so it can only check that the packets are for keys in the form\'s team.
+         * @param {string} orgId 
+         * @param {string} formId 
+         * @param {Array<APISubmissionRewrap>} aPISubmissionRewrap 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        submissionsRewrap: async (orgId: string, formId: string, aPISubmissionRewrap: Array<APISubmissionRewrap>, options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
+            // verify required parameter 'orgId' is not null or undefined
+            assertParamExists('submissionsRewrap', 'orgId', orgId)
+            // verify required parameter 'formId' is not null or undefined
+            assertParamExists('submissionsRewrap', 'formId', formId)
+            // verify required parameter 'aPISubmissionRewrap' is not null or undefined
+            assertParamExists('submissionsRewrap', 'aPISubmissionRewrap', aPISubmissionRewrap)
+            const localVarPath = `/users/me/orgs/{org_id}/forms/{form_id}/submissions/rewrap`
+                .replace(`{${"org_id"}}`, encodeURIComponent(String(orgId)))
+                .replace(`{${"form_id"}}`, encodeURIComponent(String(formId)));
+            // use dummy base URL string because the URL constructor only accepts absolute URLs.
+            const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+            let baseOptions;
+            if (configuration) {
+                baseOptions = configuration.baseOptions;
+            }
+
+            const localVarRequestOptions = { method: 'POST', ...baseOptions, ...options};
+            const localVarHeaderParameter = {} as any;
+            const localVarQueryParameter = {} as any;
+
+            // authentication APIAuthTokenWithRole_Editor_OrgViewAndTeamRoleFromForm_Any required
+            // http basic authentication required
+            setBasicAuthToObject(localVarRequestOptions, configuration)
+
+
+    
+            localVarHeaderParameter['Content-Type'] = 'application/json';
+
+            setSearchParams(localVarUrlObj, localVarQueryParameter);
+            let headersFromBaseOptions = baseOptions && baseOptions.headers ? baseOptions.headers : {};
+            localVarRequestOptions.headers = {...localVarHeaderParameter, ...headersFromBaseOptions, ...options.headers};
+            localVarRequestOptions.data = serializeDataIfNeeded(aPISubmissionRewrap, localVarRequestOptions, configuration)
+
             return {
                 url: toPathString(localVarUrlObj),
                 options: localVarRequestOptions,
@@ -13273,6 +15073,19 @@ export const SubmissionsApiAxiosParamCreator = function (configuration?: Configu
 export const SubmissionsApiFp = function(configuration?: Configuration) {
     const localVarAxiosParamCreator = SubmissionsApiAxiosParamCreator(configuration)
     return {
+        /**
+         * Lists every entry in the form\'s submission chain, with a signed checkpoint of its head. Each submission and deletion is chained to the one before it, so clients can use this to check that the submissions they\'ve synced are complete and in order. Submissions made before the chain was added aren\'t in it.
+         * @param {string} orgId 
+         * @param {string} formId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        async submissionsChain(orgId: string, formId: string, options?: RawAxiosRequestConfig): Promise<(axios?: AxiosInstance, basePath?: string) => AxiosPromise<APISubmissionChain>> {
+            const localVarAxiosArgs = await localVarAxiosParamCreator.submissionsChain(orgId, formId, options);
+            const localVarOperationServerIndex = configuration?.serverIndex ?? 0;
+            const localVarOperationServerBasePath = operationServerMap['SubmissionsApi.submissionsChain']?.[localVarOperationServerIndex]?.url;
+            return (axios, basePath) => createRequestFunction(localVarAxiosArgs, globalAxios, BASE_PATH, configuration)(axios, localVarOperationServerBasePath || basePath);
+        },
         /**
          * 
          * @param {string} orgId 
@@ -13328,6 +15141,33 @@ export const SubmissionsApiFp = function(configuration?: Configuration) {
             const localVarOperationServerBasePath = operationServerMap['SubmissionsApi.submissionsNumSince']?.[localVarOperationServerIndex]?.url;
             return (axios, basePath) => createRequestFunction(localVarAxiosArgs, globalAxios, BASE_PATH, configuration)(axios, localVarOperationServerBasePath || basePath);
         },
+        /**
+         * The public keys that new submissions to the form are encrypted to. Existing submissions can be re-wrapped for any of these that can\'t read them yet.
+         * @param {string} orgId 
+         * @param {string} formId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        async submissionsRecipients(orgId: string, formId: string, options?: RawAxiosRequestConfig): Promise<(axios?: AxiosInstance, basePath?: string) => AxiosPromise<Array<string>>> {
+            const localVarAxiosArgs = await localVarAxiosParamCreator.submissionsRecipients(orgId, formId, options);
+            const localVarOperationServerIndex = configuration?.serverIndex ?? 0;
+            const localVarOperationServerBasePath = operationServerMap['SubmissionsApi.submissionsRecipients']?.[localVarOperationServerIndex]?.url;
+            return (axios, basePath) => createRequestFunction(localVarAxiosArgs, globalAxios, BASE_PATH, configuration)(axios, localVarOperationServerBasePath || basePath);
+        },
+        /**
+         * Adds key packets made on the client to existing submissions, so that keys added to the team after a submission was made can decrypt it. The server never sees the session keys, so it can only check that the packets are for keys in the form\'s team.
+         * @param {string} orgId 
+         * @param {string} formId 
+         * @param {Array<APISubmissionRewrap>} aPISubmissionRewrap 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        async submissionsRewrap(orgId: string, formId: string, aPISubmissionRewrap: Array<APISubmissionRewrap>, options?: RawAxiosRequestConfig): Promise<(axios?: AxiosInstance, basePath?: string) => AxiosPromise<void>> {
+            const localVarAxiosArgs = await localVarAxiosParamCreator.submissionsRewrap(orgId, formId, aPISubmissionRewrap, options);
+            const localVarOperationServerIndex = configuration?.serverIndex ?? 0;
+            const localVarOperationServerBasePath = operationServerMap['SubmissionsApi.submissionsRewrap']?.[localVarOperationServerIndex]?.url;
+            return (axios, basePath) => createRequestFunction(localVarAxiosArgs, globalAxios, BASE_PATH, configuration)(axios, localVarOperationServerBasePath || basePath);
+        },
     }
 };
 
@@ -13338,6 +15178,16 @@ export const SubmissionsApiFp = function(configuration?: Configuration) {
 export const SubmissionsApiFactory = function (configuration?: Configuration, basePath?: string, axios?: AxiosInstance) {
     const localVarFp = SubmissionsApiFp(configuration)
     return {
+        /**
+         * Lists every entry in the form\'s submission chain, with a signed checkpoint of its head. Each submission and deletion is chained to the one before it, so clients can use this to check that the submissions they\'ve synced are complete and in order. Submissions made before the chain was added aren\'t in it.
+         * @param {string} orgId 
+         * @param {string} formId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        submissionsChain(orgId: string, formId: string, options?: any): AxiosPromise<APISubmissionChain> {
+            return localVarFp.submissionsChain(orgId, formId, options).then((request) => request(axios, basePath));
+        },
         /**
          * 
          * @param {string} orgId 
@@ -13381,6 +15231,27 @@ export const SubmissionsApiFactory = function (configuration?: Configuration, ba
         submissionsNumSince(orgId: string, submissionCountSinceRequest: SubmissionCountSinceRequest, options?: any): AxiosPromise<Array<APISubmissionCountPerForm>> {
             return localVarFp.submissionsNumSince(orgId, submissionCountSinceRequest, options).then((request) => request(axios, basePath));
         },
+        /**
+         * The public keys that new submissions to the form are encrypted to. Existing submissions can be re-wrapped for any of these that can\'t read them yet.
+         * @param {string} orgId 
+         * @param {string} formId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        submissionsRecipients(orgId: string, formId: string, options?: any): AxiosPromise<Array<string>> {
+            return localVarFp.submissionsRecipients(orgId, formId, options).then((request) => request(axios, basePath));
+        },
+        /**
+         * Adds key packets made on the client to existing submissions, so that keys added to the team after a submission was made can decrypt it. The server never sees the session keys, so it can only check that the packets are for keys in the form\'s team.
+         * @param {string} orgId 
+         * @param {string} formId 
+         * @param {Array<APISubmissionRewrap>} aPISubmissionRewrap 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        submissionsRewrap(orgId: string, formId: string, aPISubmissionRewrap: Array<APISubmissionRewrap>, options?: any): AxiosPromise<void> {
+            return localVarFp.submissionsRewrap(orgId, formId, aPISubmissionRewrap, options).then((request) => request(axios, basePath));
+        },
     };
 };
 
@@ -13391,6 +15262,18 @@ export const SubmissionsApiFactory = function (configuration?: Configuration, ba
  * @extends {BaseAPI}
  */
 export class SubmissionsApi extends BaseAPI {
+    /**
+     * Lists every entry in the form\'s submission chain, with a signed checkpoint of its head. Each submission and deletion is chained to the one before it, so clients can use this to check that the submissions they\'ve synced are complete and in order. Submissions made before the chain was added aren\'t in it.
+     * @param {string} orgId 
+     * @param {string} formId 
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     * @memberof SubmissionsApi
+     */
+    public submissionsChain(orgId: string, formId: string, options?: RawAxiosRequestConfig) {
+        return SubmissionsApiFp(this.configuration).submissionsChain(orgId, formId, options).then((request) => request(this.axios, this.basePath));
+    }
+
     /**
      * 
      * @param {string} orgId 
@@ -13441,6 +15324,31 @@ export class SubmissionsApi extends BaseAPI {
     public submissionsNumSince(orgId: string, submissionCountSinceRequest: SubmissionCountSinceRequest, options?: RawAxiosRequestConfig) {
         return SubmissionsApiFp(this.configuration).submissionsNumSince(orgId, submissionCountSinceRequest, options).then((request) => request(this.axios, this.basePath));
     }
+
+    /**
+     * The public keys that new submissions to the form are encrypted to. Existing submissions can be re-wrapped for any of these that can\'t read them yet.
+     * @param {string} orgId 
+     * @param {string} formId 
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     * @memberof SubmissionsApi
+     */
+    public submissionsRecipients(orgId: string, formId: string, options?: RawAxiosRequestConfig) {
+        return SubmissionsApiFp(this.configuration).submissionsRecipients(orgId, formId, options).then((request) => request(this.axios, this.basePath));
+    }
+
+    /**
+     * Adds key packets made on the client to existing submissions, so that keys added to the team after a submission was made can decrypt it. The server never sees the session keys, so it can only check that the packets are for keys in the form\'s team.
+     * @param {string} orgId 
+     * @param {string} formId 
+     * @param {Array<APISubmissionRewrap>} aPISubmissionRewrap 
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     * @memberof SubmissionsApi
+     */
+    public submissionsRewrap(orgId: string, formId: string, aPISubmissionRewrap: Array<APISubmissionRewrap>, options?: RawAxiosRequestConfig) {
+        return SubmissionsApiFp(this.configuration).submissionsRewrap(orgId, formId, aPISubmissionRewrap, options).then((request) => request(this.axios, this.basePath));
+    }
 }
 
 
@@ -13980,6 +15888,57 @@ export const UserKeysApiAxiosParamCreator = function (configuration?: Configurat
             localVarRequestOptions.headers = {...localVarHeaderParameter, ...headersFromBaseOptions, ...options.headers};
             localVarRequestOptions.data = serializeDataIfNeeded(registerBackupKeyRequest, localVarRequestOptions, configuration)
 
+            return {
+                url: toPathString(localVarUrlObj),
+                options: localVarRequestOptions,
+            };
+        },
+        /**
+         * 
+         * @param {string} orgId 
+         * @param {string} keyId 
+         * @param {RevokeKeyRequest} revokeKeyRequest 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        keysRevoke: async (orgId: string, keyId: string, revokeKeyRequest: RevokeKeyRequest, options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
+            // verify required parameter 'orgId' is not null or undefined
+            assertParamExists('keysRevoke', 'orgId', orgId)
+            // verify required parameter 'keyId' is not null or undefined
+            assertParamExists('keysRevoke', 'keyId', keyId)
+            // verify required parameter 'revokeKeyRequest' is not null or undefined
+            assertParamExists('keysRevoke', 'revokeKeyRequest', revokeKeyRequest)
+            const localVarPath = `/users/me/orgs/{org_id}/keys/{key_id}/revoke`
+                .replace(`{${"org_id"}}`, encodeURIComponent(String(orgId)))
+                .replace(`{${"key_id"}}`, encodeURIComponent(String(keyId)));
+            // use dummy base URL string because the URL constructor only accepts absolute URLs.
+            const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+            let baseOptions;
+            if (configuration) {
+                baseOptions = configuration.baseOptions;
+            }
+
+            const localVarRequestOptions = { method: 'POST', ...baseOptions, ...options};
+            const localVarHeaderParameter = {} as any;
+            const localVarQueryParameter = {} as any;
+
+            // authentication APIAuthTokenWithRole_None_OrgView_Any required
+            // http basic authentication required
+            setBasicAuthToObject(localVarRequestOptions, configuration)
+
+            // authentication APIAuthTokenWithRole_None_OrgAdmin_Any required
+            // http basic authentication required
+            setBasicAuthToObject(localVarRequestOptions, configuration)
+
+
+    
+            localVarHeaderParameter['Content-Type'] = 'application/json';
+
+            setSearchParams(localVarUrlObj, localVarQueryParameter);
+            let headersFromBaseOptions = baseOptions && baseOptions.headers ? baseOptions.headers : {};
+            localVarRequestOptions.headers = {...localVarHeaderParameter, ...headersFromBaseOptions, ...options.headers};
+            localVarRequestOptions.data = serializeDataIfNeeded(revokeKeyRequest, localVarRequestOptions, configuration)
+
             return {
                 url: toPathString(localVarUrlObj),
                 options: localVarRequestOptions,
@@ -14073,6 +16032,20 @@ export const UserKeysApiFp = function(configuration?: Configuration) {
             const localVarOperationServerBasePath = operationServerMap['UserKeysApi.keysRegisterBackup']?.[localVarOperationServerIndex]?.url;
             return (axios, basePath) => createRequestFunction(localVarAxiosArgs, globalAxios, BASE_PATH, configuration)(axios, localVarOperationServerBasePath || basePath);
         },
+        /**
+         * 
+         * @param {string} orgId 
+         * @param {string} keyId 
+         * @param {RevokeKeyRequest} revokeKeyRequest 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        async keysRevoke(orgId: string, keyId: string, revokeKeyRequest: RevokeKeyRequest, options?: RawAxiosRequestConfig): Promise<(axios?: AxiosInstance, basePath?: string) => AxiosPromise<void>> {
+            const localVarAxiosArgs = await localVarAxiosParamCreator.keysRevoke(orgId, keyId, revokeKeyRequest, options);
+            const localVarOperationServerIndex = configuration?.serverIndex ?? 0;
+            const localVarOperationServerBasePath = operationServerMap['UserKeysApi.keysRevoke']?.[localVarOperationServerIndex]?.url;
+            return (axios, basePath) => createRequestFunction(localVarAxiosArgs, globalAxios, BASE_PATH, configuration)(axios, localVarOperationServerBasePath || basePath);
+        },
     }
 };
 
@@ -14143,6 +16116,17 @@ export const UserKeysApiFactory = function (configuration?: Configuration, baseP
         keysRegisterBackup(orgId: string, keyId: string, registerBackupKeyRequest: RegisterBackupKeyRequest, options?: any): AxiosPromise<void> {
             return localVarFp.keysRegisterBackup(orgId, keyId, registerBackupKeyRequest, options).then((request) => request(axios, basePath));
         },
+        /**
+         * 
+         * @param {string} orgId 
+         * @param {string} keyId 
+         * @param {RevokeKeyRequest} revokeKeyRequest 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        keysRevoke(orgId: string, keyId: string, revokeKeyRequest: RevokeKeyRequest, options?: any): AxiosPromise<void> {
+            return localVarFp.keysRevoke(orgId, keyId, revokeKeyRequest, options).then((request) => request(axios, basePath));
+        },
     };
 };
 
@@ -14224,6 +16208,19 @@ export class UserKeysApi extends BaseAPI {
     public keysRegisterBackup(orgId: string, keyId: string, registerBackupKeyRequest: RegisterBackupKeyRequest, options?: RawAxiosRequestConfig) {
         return UserKeysApiFp(this.configuration).keysRegisterBackup(orgId, keyId, registerBackupKeyRequest, options).then((request) => request(this.axios, this.basePath));
     }
+
+    /**
+     * 
+     * @param {string} orgId 
+     * @param {string} keyId 
+     * @param {RevokeKeyRequest} revokeKeyRequest 
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     * @memberof UserKeysApi
+     */
+    public keysRevoke(orgId: string, keyId: string, revokeKeyRequest: RevokeKeyRequest, options?: RawAxiosRequestConfig) {
+        return UserKeysApiFp(this.configuration).keysRevoke(orgId, keyId, revokeKeyRequest, options).then((request) => request(this.axios, this.basePath));
+    }
 }
 
 
@@ -14403,6 +16400,55 @@ export const WebhooksApiAxiosParamCreator = function (configuration?: Configurat
 
 
     
+            setSearchParams(localVarUrlObj, localVarQueryParameter);
+            let headersFromBaseOptions = baseOptions && baseOptions.headers ? baseOptions.headers : {};
+            localVarRequestOptions.headers = {...localVarHeaderParameter, ...headersFromBaseOptions, ...options.headers};
+
+            return {
+                url: toPathString(localVarUrlObj),
+                options: localVarRequestOptions,
+            };
+        },
+        /**
+         * 
+         * @param {string} orgId 
+         * @param {string} formId 
+         * @param {string} webhookId 
+         * @param {string} jobId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        webhooksRequeueJob: async (orgId: string, formId: string, webhookId: string, jobId: string, options: RawAxiosRequestConfig = {}): Promise<RequestArgs> => {
+            // verify required parameter 'orgId' is not null or undefined
+            assertParamExists('webhooksRequeueJob', 'orgId', orgId)
+            // verify required parameter 'formId' is not null or undefined
+            assertParamExists('webhooksRequeueJob', 'formId', formId)
+            // verify required parameter 'webhookId' is not null or undefined
+            assertParamExists('webhooksRequeueJob', 'webhookId', webhookId)
+            // verify required parameter 'jobId' is not null or undefined
+            assertParamExists('webhooksRequeueJob', 'jobId', jobId)
+            const localVarPath = `/users/me/orgs/{_org_id}/forms/{form_id}/webhooks/{webhook_id}/jobs/{job_id}/requeue`
+                .replace(`{${"_org_id"}}`, encodeURIComponent(String(orgId)))
+                .replace(`{${"form_id"}}`, encodeURIComponent(String(formId)))
+                .replace(`{${"webhook_id"}}`, encodeURIComponent(String(webhookId)))
+                .replace(`{${"job_id"}}`, encodeURIComponent(String(jobId)));
+            // use dummy base URL string because the URL constructor only accepts absolute URLs.
+            const localVarUrlObj = new URL(localVarPath, DUMMY_BASE_URL);
+            let baseOptions;
+            if (configuration) {
+                baseOptions = configuration.baseOptions;
+            }
+
+            const localVarRequestOptions = { method: 'POST', ...baseOptions, ...options};
+            const localVarHeaderParameter = {} as any;
+            const localVarQueryParameter = {} as any;
+
+            // authentication APIAuthTokenWithRole_Editor_OrgViewAndTeamRoleFromForm_Any required
+            // http basic authentication required
+            setBasicAuthToObject(localVarRequestOptions, configuration)
+
+
+    
             setSearchParams(localVarUrlObj, localVarQueryParameter);
             let headersFromBaseOptions = baseOptions && baseOptions.headers ? baseOptions.headers : {};
             localVarRequestOptions.headers = {...localVarHeaderParameter, ...headersFromBaseOptions, ...options.headers};
@@ -14477,6 +16523,21 @@ export const WebhooksApiFp = function(configuration?: Configuration) {
             const localVarOperationServerBasePath = operationServerMap['WebhooksApi.webhooksListJobs']?.[localVarOperationServerIndex]?.url;
             return (axios, basePath) => createRequestFunction(localVarAxiosArgs, globalAxios, BASE_PATH, configuration)(axios, localVarOperationServerBasePath || basePath);
         },
+        /**
+         * 
+         * @param {string} orgId 
+         * @param {string} formId 
+         * @param {string} webhookId 
+         * @param {string} jobId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        async webhooksRequeueJob(orgId: string, formId: string, webhookId: string, jobId: string, options?: RawAxiosRequestConfig): Promise<(axios?: AxiosInstance, basePath?: string) => AxiosPromise<void>> {
+            const localVarAxiosArgs = await localVarAxiosParamCreator.webhooksRequeueJob(orgId, formId, webhookId, jobId, options);
+            const localVarOperationServerIndex = configuration?.serverIndex ?? 0;
+            const localVarOperationServerBasePath = operationServerMap['WebhooksApi.webhooksRequeueJob']?.[localVarOperationServerIndex]?.url;
+            return (axios, basePath) => createRequestFunction(localVarAxiosArgs, globalAxios, BASE_PATH, configuration)(axios, localVarOperationServerBasePath || basePath);
+        },
     }
 };
 
@@ -14530,6 +16591,18 @@ export const WebhooksApiFactory = function (configuration?: Configuration, baseP
         webhooksListJobs(orgId: string, formId: string, webhookId: string, options?: any): AxiosPromise<Array<APIWebhookJob>> {
             return localVarFp.webhooksListJobs(orgId, formId, webhookId, options).then((request) => request(axios, basePath));
         },
+        /**
+         * 
+         * @param {string} orgId 
+         * @param {string} formId 
+         * @param {string} webhookId 
+         * @param {string} jobId 
+         * @param {*} [options] Override http request option.
+         * @throws {RequiredError}
+         */
+        webhooksRequeueJob(orgId: string, formId: string, webhookId: string, jobId: string, options?: any): AxiosPromise<void> {
+            return localVarFp.webhooksRequeueJob(orgId, formId, webhookId, jobId, options).then((request) => request(axios, basePath));
+        },
     };
 };
 
@@ -14590,6 +16663,20 @@ export class WebhooksApi extends BaseAPI {
     public webhooksListJobs(orgId: string, formId: string, webhookId: string, options?: RawAxiosRequestConfig) {
         return WebhooksApiFp(this.configuration).webhooksListJobs(orgId, formId, webhookId, options).then((request) => request(this.axios, this.basePath));
     }
+
+    /**
+     * 
+     * @param {string} orgId 
+     * @param {string} formId 
+     * @param {string} webhookId 
+     * @param {string} jobId 
+     * @param {*} [options] Override http request option.
+     * @throws {RequiredError}
+     * @memberof WebhooksApi
+     */
+    public webhooksRequeueJob(orgId: string, formId: string, webhookId: string, jobId: string, options?: RawAxiosRequestConfig) {
+        return WebhooksApiFp(this.configuration).webhooksRequeueJob(orgId, formId, webhookId, jobId, options).then((request) => request(this.axios, this.basePath));
+    }
 }
 
 
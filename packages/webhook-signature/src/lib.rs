@@ -0,0 +1,211 @@
+//! Signing and verification of Palform webhook deliveries.
+//!
+//! Every delivery carries three headers:
+//!
+//! - [`DELIVERY_ID_HEADER`]: a unique ID for the delivery. It stays the same across retries of
+//!   the same event, so receivers can use it to drop duplicates and replays.
+//! - [`TIMESTAMP_HEADER`]: the UNIX time (in seconds) at which the delivery was signed.
+//! - [`SIGNATURE_HEADER`]: a list of signatures in the form `v1=<hex>,v1=<hex>`. There is more
+//!   than one `v1` signature while a signing secret is being rotated, one for each secret that's
+//!   still valid. A receiver only needs one of them to match.
+//!
+//! A `v1` signature is the hex-encoded HMAC-SHA256 of `v1:<timestamp>:<delivery id>:<body>`.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use ring::hmac;
+use thiserror::Error;
+
+pub const SIGNATURE_HEADER: &str = "x-palform-signature-v1";
+pub const TIMESTAMP_HEADER: &str = "x-palform-timestamp";
+pub const DELIVERY_ID_HEADER: &str = "x-palform-delivery-id";
+
+/// How far a delivery's timestamp may be from the receiver's clock before [`verify`] rejects it.
+pub const DEFAULT_TOLERANCE: Duration = Duration::from_secs(5 * 60);
+
+const VERSION: &str = "v1";
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum VerifyError {
+    #[error("Timestamp header is not a valid integer")]
+    MalformedTimestamp,
+    #[error("Signature header did not contain any {VERSION} signatures")]
+    NoSignatures,
+    #[error("Timestamp is outside the allowed tolerance")]
+    TimestampOutOfRange,
+    #[error("No signature matched the payload")]
+    Mismatch,
+}
+
+fn signed_content(timestamp: i64, delivery_id: &str, body: &[u8]) -> Vec<u8> {
+    let mut content = format!("{}:{}:{}:", VERSION, timestamp, delivery_id).into_bytes();
+    content.extend_from_slice(body);
+    content
+}
+
+/// Compute a single hex-encoded `v1` signature.
+pub fn sign(secret: &str, timestamp: i64, delivery_id: &str, body: &[u8]) -> String {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+    let tag = hmac::sign(&key, &signed_content(timestamp, delivery_id, body));
+    faster_hex::hex_string(tag.as_ref())
+}
+
+/// Build the value of [`SIGNATURE_HEADER`], with one signature per secret.
+pub fn signature_header(
+    secrets: &[&str],
+    timestamp: i64,
+    delivery_id: &str,
+    body: &[u8],
+) -> String {
+    secrets
+        .iter()
+        .map(|secret| format!("{}={}", VERSION, sign(secret, timestamp, delivery_id, body)))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Check a delivery against your signing secret, using the raw values of the three headers and
+/// the unparsed request body.
+///
+/// This rejects deliveries whose timestamp is more than `tolerance` away from the current time.
+/// To fully protect against replays, also remember the delivery IDs you've accepted within that
+/// window and reject any repeats.
+pub fn verify(
+    secret: &str,
+    signature_header: &str,
+    timestamp_header: &str,
+    delivery_id: &str,
+    body: &[u8],
+    tolerance: Duration,
+) -> Result<(), VerifyError> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default();
+    verify_at(
+        secret,
+        signature_header,
+        timestamp_header,
+        delivery_id,
+        body,
+        tolerance,
+        now,
+    )
+}
+
+/// Same as [`verify`], but with an explicit current UNIX time.
+pub fn verify_at(
+    secret: &str,
+    signature_header: &str,
+    timestamp_header: &str,
+    delivery_id: &str,
+    body: &[u8],
+    tolerance: Duration,
+    now: i64,
+) -> Result<(), VerifyError> {
+    let timestamp: i64 = timestamp_header
+        .trim()
+        .parse()
+        .map_err(|_| VerifyError::MalformedTimestamp)?;
+
+    if now.abs_diff(timestamp) > tolerance.as_secs() {
+        return Err(VerifyError::TimestampOutOfRange);
+    }
+
+    let signatures: Vec<Vec<u8>> = signature_header
+        .split(',')
+        .filter_map(|part| part.trim().split_once('='))
+        .filter(|(version, _)| *version == VERSION)
+        .filter_map(|(_, sig)| {
+            let mut decoded = vec![0u8; sig.len() / 2];
+            faster_hex::hex_decode(sig.as_bytes(), &mut decoded)
+                .ok()
+                .map(|_| decoded)
+        })
+        .collect();
+
+    if signatures.is_empty() {
+        return Err(VerifyError::NoSignatures);
+    }
+
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+    let content = signed_content(timestamp, delivery_id, body);
+    if signatures
+        .iter()
+        .any(|sig| hmac::verify(&key, &content, sig).is_ok())
+    {
+        Ok(())
+    } else {
+        Err(VerifyError::Mismatch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "whsec_test";
+    const DELIVERY_ID: &str = "delivery-1";
+    const BODY: &[u8] = br#"{"event":"submission_created"}"#;
+    const TIMESTAMP: i64 = 1_700_000_000;
+
+    fn verify_now(secret: &str, header: &str, body: &[u8]) -> Result<(), VerifyError> {
+        verify_at(
+            secret,
+            header,
+            &TIMESTAMP.to_string(),
+            DELIVERY_ID,
+            body,
+            DEFAULT_TOLERANCE,
+            TIMESTAMP,
+        )
+    }
+
+    #[test]
+    fn round_trip() {
+        let header = signature_header(&[SECRET], TIMESTAMP, DELIVERY_ID, BODY);
+        assert_eq!(verify_now(SECRET, &header, BODY), Ok(()));
+    }
+
+    #[test]
+    fn tampered_body() {
+        let header = signature_header(&[SECRET], TIMESTAMP, DELIVERY_ID, BODY);
+        assert_eq!(
+            verify_now(SECRET, &header, br#"{"event":"submission_deleted"}"#),
+            Err(VerifyError::Mismatch)
+        );
+    }
+
+    #[test]
+    fn expired_timestamp() {
+        let header = signature_header(&[SECRET], TIMESTAMP, DELIVERY_ID, BODY);
+        let late = TIMESTAMP + DEFAULT_TOLERANCE.as_secs() as i64 + 1;
+        assert_eq!(
+            verify_at(
+                SECRET,
+                &header,
+                &TIMESTAMP.to_string(),
+                DELIVERY_ID,
+                BODY,
+                DEFAULT_TOLERANCE,
+                late,
+            ),
+            Err(VerifyError::TimestampOutOfRange)
+        );
+    }
+
+    #[test]
+    fn rotated_secret() {
+        let new_secret = "whsec_rotated";
+        let header = signature_header(&[SECRET, new_secret], TIMESTAMP, DELIVERY_ID, BODY);
+        assert_eq!(verify_now(SECRET, &header, BODY), Ok(()));
+        assert_eq!(verify_now(new_secret, &header, BODY), Ok(()));
+
+        // Once the old secret has been dropped, it no longer verifies
+        let header = signature_header(&[new_secret], TIMESTAMP, DELIVERY_ID, BODY);
+        assert_eq!(
+            verify_now(SECRET, &header, BODY),
+            Err(VerifyError::Mismatch)
+        );
+    }
+}